@@ -13,7 +13,7 @@
 // limitations under the License.
 // You may obtain a copy of the License at the root of this project source code
 
-use crate::custom_commands::VerifyProofSigSubCommand;
+use crate::custom_commands::{EthyProofsSubCommand, VerifyProofSigSubCommand};
 use sc_cli::{Error, Result};
 
 #[allow(missing_docs)]
@@ -31,9 +31,10 @@ pub struct RunCmd {
 	#[clap(long, default_value = "2048")]
 	pub fee_history_limit: u64,
 
-	/// Ethereum JSON-RPC client endpoint
+	/// Ethereum JSON-RPC client endpoint(s)
+	/// multiple comma separated endpoints are cross-checked by the bridge
 	#[clap(
-		parse(try_from_str = parse_uri),
+		parse(try_from_str = parse_uris),
 		long = "eth-http",
 	)]
 	pub eth_http: Option<String>,
@@ -44,6 +45,23 @@ pub struct RunCmd {
 		long = "xrp-http",
 	)]
 	pub xrp_http: Option<String>,
+
+	/// Secondary EVM chain JSON-RPC client endpoint(s)
+	/// multiple comma separated endpoints are cross-checked by the bridge
+	#[clap(
+		parse(try_from_str = parse_uris),
+		long = "secondary-evm-http",
+	)]
+	pub secondary_evm_http: Option<String>,
+
+	/// Number of finalized blocks to keep Ethy event proofs for
+	/// older proofs are pruned, all proofs are kept if unset
+	#[clap(long)]
+	pub ethy_proof_retention: Option<u32>,
+
+	/// Seconds after which an Ethy event without consensus is reported as stalled
+	#[clap(long, default_value = "600")]
+	pub ethy_stall_threshold: u64,
 }
 
 /// Parse HTTP `uri`
@@ -53,6 +71,14 @@ fn parse_uri(uri: &str) -> Result<String> {
 	Ok(uri.into())
 }
 
+/// Parse comma separated HTTP `uris`
+fn parse_uris(uris: &str) -> Result<String> {
+	for uri in uris.split(',') {
+		let _ = parse_uri(uri.trim())?;
+	}
+	Ok(uris.into())
+}
+
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
 	#[clap(subcommand)]
@@ -107,4 +133,8 @@ pub enum Subcommand {
 	/// verify proof signatures
 	#[clap(subcommand)]
 	VerifyProofSig(VerifyProofSigSubCommand),
+
+	/// Export or import Ethy event proofs
+	#[clap(subcommand)]
+	EthyProofs(EthyProofsSubCommand),
 }
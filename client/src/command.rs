@@ -16,6 +16,7 @@
 use crate::{
 	benchmarking, chain_spec,
 	cli::{Cli, Subcommand},
+	custom_commands::EthyProofsSubCommand,
 	service,
 };
 use sc_cli::{ChainSpec, RuntimeVersion, SubstrateCli};
@@ -220,6 +221,22 @@ pub fn run() -> sc_cli::Result<()> {
 			runner.sync_run(|config| cmd.run::<Block>(&config))
 		},
 		Some(Subcommand::VerifyProofSig(cmd)) => cmd.run(&cli),
+		Some(Subcommand::EthyProofs(EthyProofsSubCommand::Export(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, .. } =
+					service::new_partial(&config, &cli)?;
+				Ok((cmd.run::<Block, _>(client), task_manager))
+			})
+		},
+		Some(Subcommand::EthyProofs(EthyProofsSubCommand::Import(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, .. } =
+					service::new_partial(&config, &cli)?;
+				Ok((cmd.run(client), task_manager))
+			})
+		},
 		None => {
 			let runner = cli.create_runner(&cli.run.base)?;
 			runner.run_node_until_exit(|config| async move {
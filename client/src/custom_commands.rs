@@ -13,12 +13,28 @@
 // limitations under the License.
 // You may obtain a copy of the License at the root of this project source code
 
+use std::{
+	fs::File,
+	io::{BufRead, BufReader, BufWriter, Write},
+	path::PathBuf,
+	sync::Arc,
+};
+
 use clap::Parser;
-use ethy_gadget::data_to_digest;
+use codec::Decode;
+use ethy_gadget::{
+	archive::{requested_proofs, StoredProof},
+	data_to_digest,
+};
 use hex::ToHex;
 use libsecp256k1::{Message, PublicKey, Signature};
-use sc_cli::{Error, SubstrateCli};
-use seed_primitives::ethy::EthyChainId;
+use sc_cli::{CliConfiguration, DatabaseParams, Error, SharedParams, SubstrateCli};
+use sc_client_api::AuxStore;
+use seed_primitives::ethy::{EthyChainId, EventBatchInclusion, EventProofId, VersionedEventProof};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum VerifyProofSigSubCommand {
@@ -70,3 +86,238 @@ impl XrplVerifyCommand {
 		Ok(())
 	}
 }
+
+/// Export or import the Ethy event proofs stored by the node
+#[derive(Debug, clap::Subcommand)]
+pub enum EthyProofsSubCommand {
+	/// Export event proofs as JSON lines
+	Export(ExportProofsCmd),
+	/// Import event proofs from JSON lines, the node must not be running
+	Import(ImportProofsCmd),
+}
+
+/// The kind of an exported proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProofKind {
+	/// A `VersionedEventProof` signed by the validators
+	Event,
+	/// The `EventBatchInclusion` of an event in a signed batch
+	BatchInclusion,
+}
+
+/// An exported proof, one JSON object per line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofRecord {
+	pub kind: ProofKind,
+	pub chain_id: u8,
+	pub event_id: EventProofId,
+	/// The SCALE encoded proof
+	pub data: Bytes,
+}
+
+impl ProofRecord {
+	fn new(proof: StoredProof, data: Vec<u8>) -> Self {
+		let (kind, chain_id, event_id) = match proof {
+			StoredProof::Event(chain_id, event_id) => (ProofKind::Event, chain_id, event_id),
+			StoredProof::BatchInclusion(chain_id, event_id) =>
+				(ProofKind::BatchInclusion, chain_id, event_id),
+		};
+		Self { kind, chain_id: chain_id.into(), event_id, data: data.into() }
+	}
+
+	/// Decode and check the record, returning where it is stored
+	fn stored_proof(&self) -> Result<StoredProof, String> {
+		let chain_id = EthyChainId::decode(&mut [self.chain_id].as_slice())
+			.map_err(|_| format!("unknown chain Id: {}", self.chain_id))?;
+		match self.kind {
+			ProofKind::Event => {
+				let proof = VersionedEventProof::decode(&mut self.data.as_ref())
+					.map_err(|_| "invalid event proof".to_string())?;
+				let event_id = match proof {
+					VersionedEventProof::V1(proof) => proof.event_id,
+					VersionedEventProof::V2(proof) => proof.event_id,
+				};
+				if event_id != self.event_id {
+					return Err(format!("event proof is for event Id: {}", event_id))
+				}
+				Ok(StoredProof::Event(chain_id, self.event_id))
+			},
+			ProofKind::BatchInclusion => {
+				EventBatchInclusion::decode(&mut self.data.as_ref())
+					.map_err(|_| "invalid batch inclusion proof".to_string())?;
+				Ok(StoredProof::BatchInclusion(chain_id, self.event_id))
+			},
+		}
+	}
+}
+
+/// Parse an Ethy chain name
+fn parse_chain_id(chain: &str) -> Result<EthyChainId, Error> {
+	match chain {
+		"ethereum" => Ok(EthyChainId::Ethereum),
+		"xrpl" => Ok(EthyChainId::Xrpl),
+		"secondary-evm" => Ok(EthyChainId::SecondaryEvm),
+		_ => Err(Error::Input("Expected one of: ethereum, xrpl, secondary-evm".into())),
+	}
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct ExportProofsCmd {
+	/// Export the proofs requested from this block number
+	#[clap(long)]
+	pub from_block: Option<u32>,
+	/// Export the proofs requested up to this block number (inclusive)
+	#[clap(long)]
+	pub to_block: Option<u32>,
+	/// Export the proofs from this event Id
+	#[clap(long)]
+	pub from_id: Option<EventProofId>,
+	/// Export the proofs up to this event Id (inclusive)
+	#[clap(long)]
+	pub to_id: Option<EventProofId>,
+	/// Only export proofs for this chain (ethereum, xrpl, secondary-evm)
+	#[clap(long, parse(try_from_str = parse_chain_id))]
+	pub ethy_chain: Option<EthyChainId>,
+	/// Output file, defaults to stdout
+	#[clap(long)]
+	pub output: Option<PathBuf>,
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportProofsCmd {
+	pub async fn run<B, C>(&self, client: Arc<C>) -> Result<(), Error>
+	where
+		B: BlockT,
+		C: AuxStore + HeaderBackend<B>,
+	{
+		let chain_ids = match self.ethy_chain {
+			Some(chain_id) => vec![chain_id],
+			None => vec![EthyChainId::Ethereum, EthyChainId::Xrpl, EthyChainId::SecondaryEvm],
+		};
+		let proofs: Vec<StoredProof> =
+			match (self.from_block, self.to_block, self.from_id, self.to_id) {
+				(Some(from), Some(to), None, None) => {
+					let mut proofs = vec![];
+					for number in from..=to {
+						let header = client
+							.header(BlockId::Number(number.into()))?
+							.ok_or_else(|| Error::Input(format!("Unknown block #{}", number)))?;
+						proofs.extend(requested_proofs::<B>(&header).into_iter().filter(
+							|proof| match proof {
+								StoredProof::Event(chain_id, _) |
+								StoredProof::BatchInclusion(chain_id, _) =>
+									chain_ids.contains(chain_id),
+							},
+						));
+					}
+					proofs
+				},
+				(None, None, Some(from), Some(to)) => chain_ids
+					.iter()
+					.flat_map(|chain_id| {
+						(from..=to).flat_map(move |event_id| {
+							[
+								StoredProof::Event(*chain_id, event_id),
+								StoredProof::BatchInclusion(*chain_id, event_id),
+							]
+						})
+					})
+					.collect(),
+				_ =>
+					return Err(Error::Input(
+						"Expected a block range (--from-block, --to-block) or an event Id range (--from-id, --to-id)"
+							.into(),
+					)),
+			};
+
+		let mut output: Box<dyn Write> = match &self.output {
+			Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+			None => Box::new(std::io::stdout()),
+		};
+		let mut exported = 0_usize;
+		for proof in proofs {
+			let Some(data) = client.get_aux(&proof.aux_key())? else { continue };
+			serde_json::to_writer(&mut output, &ProofRecord::new(proof, data))
+				.map_err(|err| Error::Input(err.to_string()))?;
+			writeln!(output)?;
+			exported += 1;
+		}
+		output.flush()?;
+		log::info!("💎 exported {} Ethy proofs", exported);
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportProofsCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct ImportProofsCmd {
+	/// Input file of JSON lines as written by `ethy-proofs export`, defaults to stdin
+	#[clap(long)]
+	pub input: Option<PathBuf>,
+	/// Replace proofs already stored by the node
+	#[clap(long)]
+	pub overwrite: bool,
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ImportProofsCmd {
+	pub async fn run<C: AuxStore>(&self, client: Arc<C>) -> Result<(), Error> {
+		let input: Box<dyn BufRead> = match &self.input {
+			Some(path) => Box::new(BufReader::new(File::open(path)?)),
+			None => Box::new(BufReader::new(std::io::stdin())),
+		};
+		let (mut imported, mut skipped) = (0_usize, 0_usize);
+		for (index, line) in input.lines().enumerate() {
+			let line = line?;
+			if line.trim().is_empty() {
+				continue
+			}
+			let invalid =
+				|err: String| Error::Input(format!("Invalid proof on line {}: {}", index + 1, err));
+			let record: ProofRecord =
+				serde_json::from_str(&line).map_err(|err| invalid(err.to_string()))?;
+			let key = record.stored_proof().map_err(invalid)?.aux_key();
+			if !self.overwrite && client.get_aux(&key)?.is_some() {
+				skipped += 1;
+				continue
+			}
+			client.insert_aux(&[(key.as_slice(), record.data.as_ref())], &[])?;
+			imported += 1;
+		}
+		log::info!("💎 imported {} Ethy proofs, skipped {} existing proofs", imported, skipped);
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ImportProofsCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -53,7 +53,7 @@ use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheLimit, FilterPool};
 use ethy_gadget::notification::EthyEventProofStream;
 use ethy_gadget_rpc::{EthyApiServer, EthyRpcHandler};
 use seed_primitives::{ethy::EthyApi, opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
-use seed_runtime::Runtime;
+use seed_runtime::{Runtime, RuntimeCall};
 
 /// Extra RPC deps for Ethy
 pub struct EthyDeps {
@@ -173,9 +173,11 @@ where
 	C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
 	C::Api: pallet_dex_rpc::DexRuntimeApi<Block, Runtime>,
+	C::Api: pallet_fee_proxy_rpc::FeeProxyRuntimeApi<Block, RuntimeCall>,
 	C::Api: pallet_nft_rpc::NftRuntimeApi<Block, AccountId, Runtime>,
 	C::Api: pallet_sft_rpc::SftRuntimeApi<Block, Runtime>,
 	C::Api: pallet_assets_ext_rpc::AssetsExtRuntimeApi<Block, AccountId>,
+	C::Api: pallet_xrpl_bridge_rpc::XrplBridgeRuntimeApi<Block>,
 	P: TransactionPool<Block = Block> + 'static,
 	SC: SelectChain<Block> + 'static,
 {
@@ -185,9 +187,11 @@ where
 	};
 	use pallet_assets_ext_rpc::{AssetsExt, AssetsExtApiServer};
 	use pallet_dex_rpc::{Dex, DexApiServer};
+	use pallet_fee_proxy_rpc::{FeeProxy, FeeProxyApiServer};
 	use pallet_nft_rpc::{Nft, NftApiServer};
 	use pallet_sft_rpc::{Sft, SftApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use pallet_xrpl_bridge_rpc::{XrplBridge, XrplBridgeApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_finality_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -258,9 +262,11 @@ where
 
 	// The Root Network RPCs
 	io.merge(Dex::new(client.clone()).into_rpc())?;
+	io.merge(FeeProxy::new(client.clone()).into_rpc())?;
 	io.merge(Nft::new(client.clone()).into_rpc())?;
 	io.merge(Sft::new(client.clone()).into_rpc())?;
 	io.merge(AssetsExt::new(client.clone()).into_rpc())?;
+	io.merge(XrplBridge::new(client.clone()).into_rpc())?;
 
 	// Ethereum compatible RPCs
 	io.merge(
@@ -39,7 +39,11 @@ use std::{
 	time::Duration,
 };
 
-use seed_primitives::{ethy::ETH_HTTP_URI, opaque::Block, XRP_HTTP_URI};
+use seed_primitives::{
+	ethy::{ETH_HTTP_URI, SECONDARY_EVM_HTTP_URI},
+	opaque::Block,
+	XRP_HTTP_URI,
+};
 use seed_runtime::{self, RuntimeApi};
 
 use crate::cli::Cli;
@@ -268,6 +272,14 @@ pub fn new_full(mut config: Configuration, cli: &Cli) -> Result<TaskManager, Ser
 		);
 	}
 
+	if let Some(ref secondary_evm_http_uri) = cli.run.secondary_evm_http {
+		backend.offchain_storage().unwrap().set(
+			sp_core::offchain::STORAGE_PREFIX,
+			&SECONDARY_EVM_HTTP_URI,
+			secondary_evm_http_uri.as_bytes(),
+		);
+	}
+
 	if let Some(ref xrp_http_uri) = cli.run.xrp_http {
 		backend.offchain_storage().unwrap().set(
 			sp_core::offchain::STORAGE_PREFIX,
@@ -505,6 +517,8 @@ pub fn new_full(mut config: Configuration, cli: &Cli) -> Result<TaskManager, Ser
 		event_proof_sender,
 		prometheus_registry: prometheus_registry.clone(),
 		protocol_name: ethy_protocol_name,
+		proof_retention: cli.run.ethy_proof_retention,
+		event_stall_threshold: Duration::from_secs(cli.run.ethy_stall_threshold),
 		_phantom: std::marker::PhantomData,
 	};
 
@@ -227,6 +227,9 @@ export const DEX_PRECOMPILE_ADDRESS = "0x000000000000000000000000000000000000DDD
 // Precompile address for marketplace precompile
 export const MARKETPLACE_PRECOMPILE_ADDRESS = "0x00000000000000000000000000000000000006CD";
 
+// Precompile address for fee preference precompile is 1212
+export const FEE_PREFERENCE_PRECOMPILE_ADDRESS = "0x00000000000000000000000000000000000004bc";
+
 // Futurepass delegate reserve amount
 export const FP_DELEGATE_RESERVE = 126 * 1; // ProxyDepositFactor * 1(num of delegates)
 
@@ -258,7 +261,18 @@ const OWNABLE_ABI = [
 export const FEE_PROXY_ABI_DEPRECATED = [
   "function callWithFeePreferences(address asset, uint128 maxPayment, address target, bytes input)",
 ];
-export const FEE_PROXY_ABI = ["function callWithFeePreferences(address asset, address target, bytes input)"];
+export const FEE_PROXY_ABI = [
+  "function callWithFeePreferences(address asset, address target, bytes input)",
+  "function callWithSponsor(address sponsor, address target, bytes input)",
+];
+
+export const FEE_PREFERENCE_ABI = [
+  "event FeePreferenceSet(address indexed who, address indexed asset, uint32 maxSlippage)",
+  "event FeePreferenceRemoved(address indexed who)",
+  "function feePreferenceOf(address who) external view returns (address asset, uint32 maxSlippage)",
+  "function setFeePreference(address asset, uint32 maxSlippage) external",
+  "function removeFeePreference() external",
+];
 
 export const ERC20_ABI = [
   "event Transfer(address indexed from, address indexed to, uint256 value)",
@@ -287,8 +301,10 @@ export const SFT_PRECOMPILE_ABI = [
 export const PEG_PRECOMPILE_ABI = [
   "event Erc20Withdrawal(uint64 indexed eventProofId, address indexed beneficiary, address indexed tokenAddress, uint128 balance)",
   "event Erc721Withdrawal(uint64 indexed eventProofId, address indexed beneficiary, address indexed tokenAddress, uint32[] serialNumbers)",
+  "event RemoteCall(uint64 indexed callId, address indexed caller, address indexed target)",
   "function erc20Withdraw(address beneficiary, address asset, uint128 balance) returns (uint64)",
   "function erc721Withdraw(address beneficiary, address[] tokenAddresses, uint32[][] serialNumbers) returns (uint64)",
+  "function remoteCall(address target, bytes input) returns (uint64)",
 ];
 
 export const ERC721_PRECOMPILE_ABI = [
@@ -387,11 +403,27 @@ export const FUTUREPASS_PRECOMPILE_ABI = [
   "event FuturepassDelegateUnregistered(address indexed futurepass, address delegate)",
   "event Executed(uint8 indexed callType, address indexed target, uint256 indexed value, bytes4 data)",
   "event ContractCreated(uint8 indexed callType, address indexed contractAddress, uint256 indexed value, bytes32 salt)",
+  "event FuturepassRecoveryInitiated(address indexed guardian, address indexed newOwner)",
+  "event FuturepassRecoveryApproved(address indexed guardian)",
+  "event FuturepassRecoveryCancelled()",
 
   "function delegateType(address delegate) external view returns (uint8)",
   "function registerDelegateWithSignature(address delegate, uint8 proxyType, uint32 deadline, bytes memory signature) external",
   "function unregisterDelegate(address delegate) external",
   "function proxyCall(uint8 callType, address callTo, uint256 value, bytes memory callData) external payable",
+  "function proxyCallWithSignature(address delegate, uint8 callType, address callTo, uint256 value, bytes memory callData, uint32 nonce, uint32 deadline, bytes memory signature) external payable",
+  "function proxyCallBatch(tuple(uint8 callType, address callTo, uint256 value, bytes callData)[] calls, bool atomic) external payable returns (bool[] memory)",
+  "function signedProxyNonce(address delegate) external view returns (uint32)",
+
+  // Social recovery
+  "function recoveryConfig() external view returns (address[] memory guardians, uint32 threshold)",
+  "function activeRecovery() external view returns (address newOwner, address[] memory approvals, uint32 initiated)",
+  "function setRecoveryConfig(address[] memory guardians, uint32 threshold) external",
+  "function removeRecoveryConfig() external",
+  "function initiateRecovery(address newOwner) external",
+  "function approveRecovery() external",
+  "function cancelRecovery() external",
+  "function claimRecovery() external",
 
   // Ownable
   ...OWNABLE_ABI,
@@ -34,6 +34,9 @@ const PROXY_TYPE = {
   NoPermission: 0,
   Any: 1,
   NonTransfer: 2,
+  Governance: 3,
+  Staking: 4,
+  Owner: 255,
 };
 
 describe("Futurepass Precompile", function () {
@@ -153,10 +156,10 @@ describe("Futurepass Precompile", function () {
       .substring(2); // remove `0x` prefix
     const signature = await delegate.signMessage(message);
 
-    // registering with proxytype other than PROXY_TYPE.Any fails
+    // registering with PROXY_TYPE.Owner fails
     await futurepassPrecompile
       .connect(owner)
-      .registerDelegateWithSignature(delegate.address, PROXY_TYPE.NonTransfer, deadline, signature)
+      .registerDelegateWithSignature(delegate.address, PROXY_TYPE.Owner, deadline, signature)
       .catch((err: any) => expect(err.message).contains("PermissionDenied"));
 
     const tx = await futurepassPrecompile
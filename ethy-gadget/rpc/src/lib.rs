@@ -30,17 +30,22 @@ use sp_core::{Bytes, H256};
 use sp_runtime::traits::{Block, Convert};
 use std::{marker::PhantomData, ops::Deref, sync::Arc};
 
-use ethy_gadget::{notification::EthyEventProofStream, EthyEcdsaToEthereum};
+use ethy_gadget::{
+	make_batch_inclusion_key, notification::EthyEventProofStream, EthyEcdsaToEthereum,
+};
 use seed_primitives::{
 	ethy::{
-		EthyApi as EthyRuntimeApi, EthyChainId, EventProof, EventProofId, VersionedEventProof,
-		ETHY_ENGINE_ID,
+		crypto::AuthorityId, AggregateEventProof, EthyApi as EthyRuntimeApi, EthyChainId,
+		EventBatchInclusion, EventProof, EventProofId, VersionedEventProof, ETHY_ENGINE_ID,
 	},
 	AccountId20,
 };
 
 mod notification;
-use notification::{EthEventProofResponse, XrplEventProofResponse};
+use notification::{
+	AggregateSignatureResponse, EthEventProofResponse, EventBatchInclusionResponse,
+	XrplEventProofResponse,
+};
 use seed_primitives::ethy::EthyEcdsaToPublicKey;
 
 /// Provides RPC methods for interacting with Ethy.
@@ -52,6 +57,7 @@ pub trait EthyApi<Notification> {
 	fn subscribe_event_proofs(&self);
 
 	/// Query a proof for `event_proof_id` and Ethereum chain Id
+	/// If the event was batched the proof is for the batch and includes the event's inclusion path
 	///
 	/// Returns `null` if missing
 	#[method(name = "getEventProof")]
@@ -68,6 +74,15 @@ pub trait EthyApi<Notification> {
 		&self,
 		event_proof_id: EventProofId,
 	) -> RpcResult<Option<XrplEventProofResponse>>;
+
+	/// Query a proof for `event_proof_id` and the secondary EVM chain Id
+	///
+	/// Returns `null` if missing
+	#[method(name = "getSecondaryEvmEventProof")]
+	fn get_secondary_evm_event_proof(
+		&self,
+		event_proof_id: EventProofId,
+	) -> RpcResult<Option<EthEventProofResponse>>;
 }
 
 /// Implements the EthyApi RPC trait for interacting with ethy-gadget.
@@ -96,6 +111,55 @@ where
 	) -> Self {
 		Self { client, event_proof_stream, executor, runtime, phantom: PhantomData }
 	}
+
+	/// Query a proof for `event_id` on the EVM chain `chain_id`
+	/// A batched event is proven by the batch proof and the event's inclusion in the batch
+	fn get_evm_event_proof(
+		&self,
+		chain_id: EthyChainId,
+		event_id: EventProofId,
+	) -> Option<EthEventProofResponse> {
+		let maybe_encoded_inclusion = self
+			.client
+			.get_aux(make_batch_inclusion_key(chain_id, event_id).as_ref())
+			.ok()?;
+		let Some(encoded_inclusion) = maybe_encoded_inclusion else {
+			return self.get_versioned_proof(chain_id, event_id).and_then(|versioned_proof| {
+				build_event_proof_response::<R, B>(&self.runtime, versioned_proof)
+			})
+		};
+		let inclusion = EventBatchInclusion::decode(&mut &encoded_inclusion[..]).ok()?;
+		let versioned_proof = self.get_versioned_proof(chain_id, inclusion.batch_id)?;
+		let mut response = build_event_proof_response::<R, B>(&self.runtime, versioned_proof)?;
+		response.batch = Some(EventBatchInclusionResponse {
+			batch_id: inclusion.batch_id,
+			root: inclusion.root.into(),
+			leaf: inclusion.leaf.into(),
+			path: inclusion.path.into_iter().map(Into::into).collect(),
+		});
+		Some(response)
+	}
+
+	/// Query the proof stored for `event_id` on `chain_id`
+	fn get_versioned_proof(
+		&self,
+		chain_id: EthyChainId,
+		event_id: EventProofId,
+	) -> Option<VersionedEventProof> {
+		let encoded_proof = self
+			.client
+			.get_aux(
+				[
+					ETHY_ENGINE_ID.as_slice(),
+					&[chain_id.into()].as_slice(),
+					&event_id.to_be_bytes().as_slice(),
+				]
+				.concat()
+				.as_ref(),
+			)
+			.ok()??;
+		VersionedEventProof::decode(&mut &encoded_proof[..]).ok()
+	}
 }
 
 impl<C, R, B> EthyApiServer<EthEventProofResponse> for EthyRpcHandler<C, R, B>
@@ -125,24 +189,7 @@ where
 	}
 
 	fn get_event_proof(&self, event_id: EventProofId) -> RpcResult<Option<EthEventProofResponse>> {
-		if let Ok(maybe_encoded_proof) = self.client.get_aux(
-			[
-				ETHY_ENGINE_ID.as_slice(),
-				&[EthyChainId::Ethereum.into()].as_slice(),
-				&event_id.to_be_bytes().as_slice(),
-			]
-			.concat()
-			.as_ref(),
-		) {
-			if let Some(encoded_proof) = maybe_encoded_proof {
-				if let Ok(versioned_proof) = VersionedEventProof::decode(&mut &encoded_proof[..]) {
-					let event_proof_response =
-						build_event_proof_response::<R, B>(&self.runtime, versioned_proof);
-					return Ok(event_proof_response)
-				}
-			}
-		}
-		Ok(None)
+		Ok(self.get_evm_event_proof(EthyChainId::Ethereum, event_id))
 	}
 
 	fn get_xrpl_tx_proof(
@@ -168,6 +215,13 @@ where
 		}
 		Ok(None)
 	}
+
+	fn get_secondary_evm_event_proof(
+		&self,
+		event_id: EventProofId,
+	) -> RpcResult<Option<EthEventProofResponse>> {
+		Ok(self.get_evm_event_proof(EthyChainId::SecondaryEvm, event_id))
+	}
 }
 
 /// Build an `EthEventProofResponse` from a `VersionedEventProof`
@@ -187,12 +241,7 @@ where
 				.validator_set(&BlockId::hash(event_proof.block.into()))
 				.ok()?;
 
-			let validator_addresses: Vec<AccountId20> = proof_validator_set
-				.validators
-				.into_iter()
-				.map(|v| EthyEcdsaToEthereum::convert(v.as_ref()))
-				.map(Into::into)
-				.collect();
+			let validator_addresses = validator_addresses(proof_validator_set.validators);
 
 			Some(EthEventProofResponse {
 				event_id: event_proof.event_id,
@@ -205,11 +254,58 @@ where
 				validator_set_id: proof_validator_set.id,
 				block: event_proof.block.into(),
 				tag: None,
+				batch: None,
+				aggregate: None,
+			})
+		},
+		VersionedEventProof::V2(AggregateEventProof {
+			event_id,
+			signers,
+			signature,
+			block,
+			..
+		}) => {
+			let at = BlockId::hash(block.into());
+			let proof_validator_set = runtime.runtime_api().validator_set(&at).ok()?;
+			let bls_keys = runtime.runtime_api().bls_public_keys(&at).ok()?;
+			let public_keys = signers
+				.iter()
+				.map(|idx| {
+					bls_keys
+						.get(*idx as usize)
+						.copied()
+						.flatten()
+						.map(|k| Bytes::from(k.0.to_vec()))
+				})
+				.collect::<Option<Vec<Bytes>>>()?;
+
+			Some(EthEventProofResponse {
+				event_id,
+				signatures: Default::default(),
+				validators: validator_addresses(proof_validator_set.validators),
+				validator_set_id: proof_validator_set.id,
+				block: block.into(),
+				tag: None,
+				batch: None,
+				aggregate: Some(AggregateSignatureResponse {
+					signature: Bytes::from(signature.0.to_vec()),
+					signers,
+					public_keys,
+				}),
 			})
 		},
 	}
 }
 
+/// Convert Ethy validator keys into their Ethereum addresses
+fn validator_addresses(validators: Vec<AuthorityId>) -> Vec<AccountId20> {
+	validators
+		.into_iter()
+		.map(|v| EthyEcdsaToEthereum::convert(v.as_ref()))
+		.map(Into::into)
+		.collect()
+}
+
 /// Build an `XrplEventProofResponse` from a `VersionedEventProof`
 pub fn build_xrpl_tx_proof_response<R, B>(
 	runtime: &R,
@@ -274,5 +370,7 @@ where
 				tag: None,
 			})
 		},
+		// XRPL proofs are always ECDSA
+		VersionedEventProof::V2(_) => None,
 	}
 }
@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 use sp_core::{Bytes, H256};
 
 use seed_primitives::{
-	ethy::{EventProofId, ValidatorSetId},
+	ethy::{AuthorityIndex, EventProofId, ValidatorSetId},
 	AccountId20,
 };
 
@@ -35,6 +35,34 @@ pub struct EthEventProofResponse {
 	pub block: H256,
 	/// Metadata tag
 	pub tag: Option<Bytes>,
+	/// Inclusion of the event in the signed batch, if it was batched
+	/// The proof signatures are then over the batch root
+	pub batch: Option<EventBatchInclusionResponse>,
+	/// The aggregate BLS signature, if the chain uses BLS aggregate proofs
+	/// `signatures` is then empty
+	pub aggregate: Option<AggregateSignatureResponse>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AggregateSignatureResponse {
+	/// The aggregate BLS signature (G1 point)
+	pub signature: Bytes,
+	/// Indexes of the validators whose signatures are aggregated
+	pub signers: Vec<AuthorityIndex>,
+	/// The BLS public keys of `signers` (G2 points)
+	pub public_keys: Vec<Bytes>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct EventBatchInclusionResponse {
+	/// The event proof Id of the signed batch
+	pub batch_id: EventProofId,
+	/// The merkle root of the batch
+	pub root: H256,
+	/// The event digest i.e. the merkle leaf
+	pub leaf: H256,
+	/// Sibling hashes from the leaf up to the root
+	pub path: Vec<H256>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
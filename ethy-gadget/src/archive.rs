@@ -0,0 +1,144 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Ethy proof archive
+//!
+//! Proofs are stored in the client aux DB keyed by chain Id and event Id, the aux DB can't be
+//! iterated so the proof requests and event batches logged in block headers are used to find the
+//! proofs made for a block e.g. to export or prune them.
+use sc_client_api::AuxStore;
+use seed_primitives::ethy::{EthyChainId, EventProofId};
+use sp_runtime::traits::Block;
+
+use crate::{
+	types::{make_batch_inclusion_key, make_proof_key},
+	worker::{extract_event_batches, extract_proof_requests},
+};
+
+/// A proof stored in the aux DB
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoredProof {
+	/// A `VersionedEventProof` signed by the validators
+	Event(EthyChainId, EventProofId),
+	/// The `EventBatchInclusion` of an event in a signed batch
+	BatchInclusion(EthyChainId, EventProofId),
+}
+
+impl StoredProof {
+	/// The aux DB key of the proof
+	pub fn aux_key(&self) -> Vec<u8> {
+		match self {
+			Self::Event(chain_id, event_id) => make_proof_key(*chain_id, *event_id),
+			Self::BatchInclusion(chain_id, event_id) =>
+				make_batch_inclusion_key(*chain_id, *event_id),
+		}
+	}
+}
+
+/// The proofs requested by the block `header`
+/// The proofs may not exist if the node was not running or the event did not reach consensus
+pub fn requested_proofs<B: Block>(header: &B::Header) -> Vec<StoredProof> {
+	let mut proofs: Vec<StoredProof> = extract_proof_requests::<B>(header)
+		.into_iter()
+		.map(|request| StoredProof::Event(request.chain_id, request.event_id))
+		.collect();
+	for (chain_id, _batch_id, leaves) in extract_event_batches::<B>(header) {
+		proofs.extend(
+			leaves
+				.into_iter()
+				.map(|(event_id, _leaf)| StoredProof::BatchInclusion(chain_id, event_id)),
+		);
+	}
+	proofs
+}
+
+/// Delete the proofs requested by the block `header` from the aux DB
+/// Returns the number of proofs requested by `header`
+pub fn prune_proofs<B: Block, A: AuxStore + ?Sized>(
+	store: &A,
+	header: &B::Header,
+) -> sp_blockchain::Result<usize> {
+	let keys: Vec<Vec<u8>> = requested_proofs::<B>(header).iter().map(|p| p.aux_key()).collect();
+	if keys.is_empty() {
+		return Ok(0)
+	}
+	let delete: Vec<&[u8]> = keys.iter().map(|k| k.as_slice()).collect();
+	store.insert_aux(&[], &delete)?;
+	Ok(keys.len())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::EthyTestNet;
+	use codec::Encode;
+	use sc_network_test::TestNetFactory;
+	use seed_primitives::ethy::{crypto::AuthorityId as Public, ConsensusLog, ETHY_ENGINE_ID};
+	use sp_runtime::traits::Header as HeaderT;
+	use substrate_test_runtime_client::runtime::{Block, Digest, DigestItem, Header};
+
+	#[test]
+	fn prune_requested_proofs() {
+		let mut net = EthyTestNet::new(1, 0);
+		let client = net.peer(0).client().as_client();
+
+		let mut header = Header::new(
+			1u32.into(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Digest::default(),
+		);
+		header.digest_mut().push(DigestItem::Consensus(
+			ETHY_ENGINE_ID,
+			ConsensusLog::<Public>::OpaqueSigningRequest {
+				chain_id: EthyChainId::Ethereum,
+				event_proof_id: 5,
+				data: vec![1_u8; 32],
+			}
+			.encode(),
+		));
+		header.digest_mut().push(DigestItem::Consensus(
+			ETHY_ENGINE_ID,
+			ConsensusLog::<Public>::EventBatch {
+				chain_id: EthyChainId::SecondaryEvm,
+				batch_id: 6,
+				leaves: vec![(1, [1_u8; 32]), (2, [2_u8; 32])],
+			}
+			.encode(),
+		));
+
+		let expected = vec![
+			StoredProof::Event(EthyChainId::Ethereum, 5),
+			StoredProof::BatchInclusion(EthyChainId::SecondaryEvm, 1),
+			StoredProof::BatchInclusion(EthyChainId::SecondaryEvm, 2),
+		];
+		assert_eq!(requested_proofs::<Block>(&header), expected);
+
+		// a proof of another block is retained
+		let retained = StoredProof::Event(EthyChainId::Ethereum, 4);
+		for proof in expected.iter().chain([&retained]) {
+			client
+				.insert_aux(&[(proof.aux_key().as_slice(), [1_u8].as_slice())], &[])
+				.unwrap();
+		}
+
+		assert_eq!(prune_proofs::<Block, _>(client.as_ref(), &header).unwrap(), 3);
+		for proof in expected {
+			assert!(client.get_aux(&proof.aux_key()).unwrap().is_none());
+		}
+		assert!(client.get_aux(&retained.aux_key()).unwrap().is_some());
+	}
+}
@@ -13,7 +13,6 @@
 // limitations under the License.
 // You may obtain a copy of the License at the root of this project source code
 
-use codec::Decode;
 use log::{error, info, trace, warn};
 use parking_lot::{Mutex, RwLock};
 use sc_network::PeerId;
@@ -24,9 +23,11 @@ use std::{
 	time::{Duration, Instant},
 };
 
-use seed_primitives::ethy::{crypto::AuthorityId as Public, EventProofId, Witness};
+use seed_primitives::ethy::{
+	crypto::AuthorityId as Public, EventProofId, VersionedWitness, Witness,
+};
 
-use crate::keystore::EthyKeystore;
+use crate::{keystore::EthyKeystore, metric_inc, metrics::Metrics};
 
 /// Gossip engine messages topic
 pub(crate) fn topic<B: Block>() -> B::Hash
@@ -59,19 +60,21 @@ where
 	active_validators: RwLock<Vec<Public>>,
 	/// Scheduled time for re-broadcasting event witnesses
 	next_rebroadcast: Mutex<Instant>,
+	metrics: Option<Metrics>,
 }
 
 impl<B> GossipValidator<B>
 where
 	B: Block,
 {
-	pub fn new(active_validators: Vec<Public>) -> GossipValidator<B> {
+	pub fn new(active_validators: Vec<Public>, metrics: Option<Metrics>) -> GossipValidator<B> {
 		GossipValidator {
 			topic: topic::<B>(),
 			known_votes: RwLock::new(BTreeMap::new()),
 			active_validators: RwLock::new(active_validators),
 			complete_events: RwLock::new(Default::default()),
 			next_rebroadcast: Mutex::new(Instant::now() + REBROADCAST_AFTER),
+			metrics,
 		}
 	}
 
@@ -114,10 +117,11 @@ where
 		&self,
 		_context: &mut dyn ValidatorContext<B>,
 		sender: &PeerId,
-		mut data: &[u8],
+		data: &[u8],
 	) -> ValidationResult<B::Hash> {
-		if let Ok(Witness { authority_id, event_id, validator_set_id, digest, signature, .. }) =
-			Witness::decode(&mut data)
+		if let Some(Witness {
+			authority_id, event_id, validator_set_id, digest, signature, ..
+		}) = VersionedWitness::decode_witness(data)
 		{
 			trace!(target: "ethy", "💎 witness from: {:?}, validator set: {:?}, event: {:?}", authority_id, validator_set_id, event_id);
 
@@ -125,11 +129,13 @@ where
 			let maybe_known = known_votes.get(&event_id).map(|v| v.binary_search(&authority_id));
 			if let Some(Ok(_)) = maybe_known {
 				trace!(target: "ethy", "💎 witness from: {:?}, event: {:?} is already known", &authority_id, event_id);
+				metric_inc!(self, ethy_gossip_discarded, "duplicate");
 				return ValidationResult::Discard
 			}
 
 			if !self.active_validators.read().iter().any(|v| *v == authority_id) {
 				trace!(target: "ethy", "💎 witness from: {:?}, event: {:?} is not an active authority", &authority_id, event_id);
+				metric_inc!(self, ethy_gossip_discarded, "inactive_authority");
 				return ValidationResult::Discard
 			}
 
@@ -159,19 +165,22 @@ where
 			} else {
 				// TODO: decrease peer reputation
 				warn!(target: "ethy", "💎 bad signature: {:?}, event: {:?}", authority_id, event_id);
+				metric_inc!(self, ethy_gossip_discarded, "bad_signature");
+				return ValidationResult::Discard
 			}
 		}
 
 		trace!(target: "ethy", "💎 invalid witness from sender: {:?}, could not decode: {:?}", sender, data);
+		metric_inc!(self, ethy_gossip_discarded, "undecodable");
 		ValidationResult::Discard
 	}
 
 	fn message_expired<'a>(&'a self) -> Box<dyn FnMut(B::Hash, &[u8]) -> bool + 'a> {
 		let complete_events = self.complete_events.read();
-		Box::new(move |_topic, mut data| {
-			let witness = match Witness::decode(&mut data) {
-				Ok(w) => w,
-				Err(_) => return true,
+		Box::new(move |_topic, data| {
+			let witness = match VersionedWitness::decode_witness(data) {
+				Some(w) => w,
+				None => return true,
 			};
 
 			let expired = complete_events.binary_search(&witness.event_id).is_ok();
@@ -197,14 +206,14 @@ where
 		};
 
 		let complete_events = self.complete_events.read();
-		Box::new(move |_who, intent, _topic, mut data| {
+		Box::new(move |_who, intent, _topic, data| {
 			if let MessageIntent::PeriodicRebroadcast = intent {
 				return do_rebroadcast
 			}
 
-			let witness = match Witness::decode(&mut data) {
-				Ok(w) => w,
-				Err(_) => return true,
+			let witness = match VersionedWitness::decode_witness(data) {
+				Some(w) => w,
+				None => return true,
 			};
 
 			// Check if message is incomplete
@@ -219,13 +228,12 @@ where
 
 #[cfg(test)]
 mod tests {
-	use codec::Encode;
 	use sc_network::PeerId;
 	use sc_network_gossip::{ValidationResult, Validator, ValidatorContext};
 	use sc_network_test::{Block, Hash};
 	use sp_core::keccak_256;
 
-	use seed_primitives::ethy::{EthyChainId, Witness};
+	use seed_primitives::ethy::{bls, EthyChainId, VersionedWitness, Witness};
 
 	use super::{GossipValidator, MAX_COMPLETE_EVENT_CACHE};
 	use crate::{assert_validation_result, testing::Keyring};
@@ -260,11 +268,11 @@ mod tests {
 		let alice = &validators[0];
 		let mut context = NoopContext {};
 		let sender_peer_id = PeerId::random();
-		let gv = GossipValidator::<Block>::new(vec![]);
+		let gv = GossipValidator::<Block>::new(vec![], None);
 
 		let event_id = 5;
 		let message = b"hello world";
-		let witness = Witness {
+		let witness = VersionedWitness::encode_witness(&Witness {
 			digest: sp_core::keccak_256(message),
 			chain_id: EthyChainId::Ethereum,
 			event_id,
@@ -273,8 +281,8 @@ mod tests {
 			// 	fn sign(&self, message: &[u8]) -> Signature {
 			// self.sign_prehashed(&blake2_256(message))
 			signature: alice.sign(message),
-		}
-		.encode();
+			bls_signature: None,
+		});
 
 		// check the witness, not a validator, discard
 		let result = gv.validate(&mut context, &sender_peer_id, witness.as_ref());
@@ -296,20 +304,22 @@ mod tests {
 		let validators = mock_signers();
 		let alice = &validators[0];
 		let bob = &validators[1];
-		let gv =
-			GossipValidator::<Block>::new(validators.iter().map(|x| x.public().clone()).collect());
+		let gv = GossipValidator::<Block>::new(
+			validators.iter().map(|x| x.public().clone()).collect(),
+			None,
+		);
 
 		let event_id = 5;
 		let message = b"hello world";
-		let witness = Witness {
+		let witness = VersionedWitness::encode_witness(&Witness {
 			digest: keccak_256(message),
 			chain_id: EthyChainId::Ethereum,
 			event_id,
 			validator_set_id: 123,
 			authority_id: alice.public(),
 			signature: bob.sign(message), // signed by bob
-		}
-		.encode();
+			bls_signature: None,
+		});
 
 		// check the witness, not a validator, discard
 		let result = gv.validate(&mut NoopContext {}, &PeerId::random(), witness.as_ref());
@@ -317,9 +327,35 @@ mod tests {
 		assert!(!gv.is_tracking_event(&event_id));
 	}
 
+	#[test]
+	fn verify_bls_event_witness() {
+		let validators = mock_signers();
+		let alice = &validators[0];
+		let gv = GossipValidator::<Block>::new(
+			validators.iter().map(|x| x.public().clone()).collect(),
+			None,
+		);
+
+		let event_id = 5;
+		let message = b"hello world";
+		let witness = VersionedWitness::encode_witness(&Witness {
+			digest: keccak_256(message),
+			chain_id: EthyChainId::Xrpl,
+			event_id,
+			validator_set_id: 123,
+			authority_id: alice.public(),
+			signature: alice.sign(message),
+			bls_signature: Some(bls::Signature([1_u8; 64])),
+		});
+
+		let result = gv.validate(&mut NoopContext {}, &PeerId::random(), witness.as_ref());
+		assert_validation_result!(ValidationResult::ProcessAndKeep(_), result);
+		assert!(gv.is_tracking_event(&event_id));
+	}
+
 	#[test]
 	fn keeps_most_recent_events() {
-		let gv = GossipValidator::<Block>::new(vec![]);
+		let gv = GossipValidator::<Block>::new(vec![], None);
 		for event_id in 1..=MAX_COMPLETE_EVENT_CACHE {
 			gv.mark_complete(event_id as u64);
 		}
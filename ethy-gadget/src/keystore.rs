@@ -13,12 +13,14 @@
 // limitations under the License.
 // You may obtain a copy of the License at the root of this project source code
 
+use codec::Encode;
 use sp_application_crypto::RuntimeAppPublic;
-use sp_core::keccak_256;
+use sp_core::{blake2_256, keccak_256};
 use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
 
 pub use seed_primitives::ethy::EthyEcdsaToEthereum;
 use seed_primitives::ethy::{
+	bls,
 	crypto::{AuthorityId as Public, AuthoritySignature as Signature},
 	ETHY_KEY_TYPE,
 };
@@ -75,6 +77,18 @@ impl EthyKeystore {
 		Ok(sig)
 	}
 
+	/// Derive the BLS secret key of the `public` key
+	///
+	/// The key is derived from a signature over `bls::BLS_KEY_DERIVATION_MESSAGE`, matching the
+	/// key registered on-chain by the Ethy offchain worker (which signs with the runtime
+	/// `ecdsa_sign`, i.e. over the blake2 hash of the message)
+	pub fn bls_secret(&self, public: &Public) -> Result<bls::SecretKey, error::Error> {
+		let signature =
+			self.sign_prehashed(public, &blake2_256(bls::BLS_KEY_DERIVATION_MESSAGE))?;
+		bls::SecretKey::derive(signature.encode().as_slice())
+			.ok_or_else(|| error::Error::Signature("bls key derivation failed".to_string()))
+	}
+
 	/// Returns a vector of Public keys which are currently supported
 	/// (i.e. found in the keystore).
 	#[allow(dead_code)]
@@ -117,11 +131,13 @@ impl From<Option<SyncCryptoStorePtr>> for EthyKeystore {
 
 #[cfg(test)]
 mod tests {
+	use codec::Encode;
 	use sp_application_crypto::Pair as _PairT;
 	use sp_core::{ecdsa, keccak_256};
 	use sp_keystore::SyncCryptoStore;
 
 	use seed_primitives::ethy::{
+		bls,
 		crypto::{AuthorityId as Public, AuthorityPair as Pair},
 		ETHY_KEY_TYPE,
 	};
@@ -244,6 +260,30 @@ mod tests {
 		assert_eq!(sig1, sig2);
 	}
 
+	#[test]
+	fn bls_secret_works() {
+		let store = keystore();
+
+		let alice: Public = SyncCryptoStore::ecdsa_generate_new(
+			&*store,
+			ETHY_KEY_TYPE,
+			Some(&Keyring::Alice.to_seed()),
+		)
+		.ok()
+		.unwrap()
+		.into();
+
+		let store: EthyKeystore = Some(store).into();
+
+		// derived from the same signature as the runtime's `RuntimeAppPublic::sign`
+		let runtime_signature = Keyring::Alice.pair().sign(bls::BLS_KEY_DERIVATION_MESSAGE);
+		let want = bls::SecretKey::derive(runtime_signature.encode().as_slice()).unwrap();
+		assert_eq!(store.bls_secret(&alice).unwrap().public(), want.public());
+
+		let bob = Keyring::Bob.public();
+		assert!(store.bls_secret(&bob).is_err());
+	}
+
 	#[test]
 	fn sign_error() {
 		let store = keystore();
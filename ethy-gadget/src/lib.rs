@@ -24,7 +24,7 @@
 //!
 //! The current implementation simply assembles signatures from individual validators.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use log::debug;
 use prometheus::Registry;
@@ -39,6 +39,7 @@ use sp_consensus::SyncOracle;
 use sp_keystore::SyncCryptoStorePtr;
 use sp_runtime::traits::Block;
 
+pub mod archive;
 mod error;
 mod gossip;
 mod keystore;
@@ -55,7 +56,7 @@ mod tests;
 
 pub use ethy_protocol_name::standard_name as protocol_standard_name;
 pub use keystore::EthyEcdsaToEthereum;
-pub use types::data_to_digest;
+pub use types::{data_to_digest, make_batch_inclusion_key};
 
 pub(crate) mod ethy_protocol_name {
 	use sc_chain_spec::ChainSpec;
@@ -140,6 +141,10 @@ where
 	pub prometheus_registry: Option<Registry>,
 	/// Chain specific Ethy protocol name. See [`ethy_protocol_name::standard_name`].
 	pub protocol_name: ProtocolName,
+	/// Number of finalized blocks to keep event proofs for, `None` keeps all proofs
+	pub proof_retention: Option<u32>,
+	/// Age after which an event without consensus is reported as stalled
+	pub event_stall_threshold: Duration,
 	pub _phantom: std::marker::PhantomData<B>,
 }
 
@@ -164,13 +169,11 @@ where
 		event_proof_sender,
 		prometheus_registry,
 		protocol_name,
+		proof_retention,
+		event_stall_threshold,
 		_phantom: std::marker::PhantomData,
 	} = ethy_params;
 
-	let sync_oracle = network.clone();
-	let gossip_validator = Arc::new(gossip::GossipValidator::new(Default::default()));
-	let gossip_engine = GossipEngine::new(network, protocol_name, gossip_validator.clone(), None);
-
 	let metrics =
 		prometheus_registry.as_ref().map(metrics::Metrics::register).and_then(
 			|result| match result {
@@ -185,6 +188,11 @@ where
 			},
 		);
 
+	let sync_oracle = network.clone();
+	let gossip_validator =
+		Arc::new(gossip::GossipValidator::new(Default::default(), metrics.clone()));
+	let gossip_engine = GossipEngine::new(network, protocol_name, gossip_validator.clone(), None);
+
 	let worker_params = worker::WorkerParams {
 		client,
 		backend,
@@ -195,6 +203,8 @@ where
 		gossip_validator,
 		metrics,
 		sync_oracle,
+		proof_retention,
+		event_stall_threshold,
 	};
 
 	let worker = worker::EthyWorker::<_, _, _, _, _>::new(worker_params);
@@ -15,14 +15,29 @@
 
 //! ETHY Prometheus metrics definition
 
-use prometheus::{register, Counter, Gauge, PrometheusError, Registry, U64};
+use prometheus::{
+	exponential_buckets, register, Counter, CounterVec, Gauge, HistogramOpts, HistogramVec, Opts,
+	PrometheusError, Registry, U64,
+};
+use seed_primitives::ethy::EthyChainId;
 
 /// ETHY metrics exposed through Prometheus
+#[derive(Clone)]
 pub(crate) struct Metrics {
 	/// Current active validator set id
 	pub ethy_validator_set_id: Gauge<U64>,
 	/// Total number of votes sent by this node
 	pub ethy_witness_sent: Counter<U64>,
+	/// Number of events awaiting consensus
+	pub ethy_pending_proofs: Gauge<U64>,
+	/// Age of the oldest event awaiting consensus
+	pub ethy_oldest_pending_event_age: Gauge<U64>,
+	/// Time from an event proof request to consensus by chain
+	pub ethy_proof_consensus_time: HistogramVec,
+	/// Witnesses rejected by the witness record by reason
+	pub ethy_witness_rejected: CounterVec<U64>,
+	/// Gossip messages discarded by the gossip validator by reason
+	pub ethy_gossip_discarded: CounterVec<U64>,
 }
 
 impl Metrics {
@@ -36,10 +51,55 @@ impl Metrics {
 				Counter::new("ethy_witness_sent", "Number of witnesses sent by this node")?,
 				registry,
 			)?,
+			ethy_pending_proofs: register(
+				Gauge::new("ethy_pending_proofs", "Number of events awaiting consensus")?,
+				registry,
+			)?,
+			ethy_oldest_pending_event_age: register(
+				Gauge::new(
+					"ethy_oldest_pending_event_age_seconds",
+					"Age of the oldest event awaiting consensus",
+				)?,
+				registry,
+			)?,
+			ethy_proof_consensus_time: register(
+				HistogramVec::new(
+					HistogramOpts::new(
+						"ethy_proof_consensus_seconds",
+						"Time from an event proof request to consensus",
+					)
+					.buckets(exponential_buckets(1.0, 2.0, 12)?),
+					&["chain"],
+				)?,
+				registry,
+			)?,
+			ethy_witness_rejected: register(
+				CounterVec::new(
+					Opts::new("ethy_witness_rejected", "Number of witnesses rejected"),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			ethy_gossip_discarded: register(
+				CounterVec::new(
+					Opts::new("ethy_gossip_discarded", "Number of gossip messages discarded"),
+					&["reason"],
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
 
+/// The metric label of `chain_id`
+pub(crate) fn chain_label(chain_id: EthyChainId) -> &'static str {
+	match chain_id {
+		EthyChainId::Ethereum => "ethereum",
+		EthyChainId::Xrpl => "xrpl",
+		EthyChainId::SecondaryEvm => "secondary_evm",
+	}
+}
+
 // Note: we use the `format` macro to convert an expr into a `u64`. This will fail,
 // if expr does not derive `Display`.
 #[macro_export]
@@ -60,4 +120,18 @@ macro_rules! metric_inc {
 			metrics.$m.inc();
 		}
 	}};
+	($self:ident, $m:ident, $label:expr) => {{
+		if let Some(metrics) = $self.metrics.as_ref() {
+			metrics.$m.with_label_values(&[$label]).inc();
+		}
+	}};
+}
+
+#[macro_export]
+macro_rules! metric_observe {
+	($self:ident, $m:ident, $label:expr, $v:expr) => {{
+		if let Some(metrics) = $self.metrics.as_ref() {
+			metrics.$m.with_label_values(&[$label]).observe($v);
+		}
+	}};
 }
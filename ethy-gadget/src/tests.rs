@@ -23,7 +23,9 @@ use sc_network_test::{
 	Block, BlockImportAdapter, FullPeerConfig, PassThroughVerifier, Peer, PeersClient,
 	TestNetFactory,
 };
-use seed_primitives::ethy::{crypto::AuthorityId, EthyApi, ValidatorSet, ETHY_KEY_TYPE};
+use seed_primitives::ethy::{
+	bls, crypto::AuthorityId, EthyApi, EthyChainId, EventProofScheme, ValidatorSet, ETHY_KEY_TYPE,
+};
 use serde::{Deserialize, Serialize};
 use sp_api::{ApiRef, ProvideRuntimeApi};
 use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
@@ -171,6 +173,12 @@ macro_rules! create_test_api {
 						let validators = make_ethy_ids(&[$($inits),+]);
 						EthyValidatorSet::new(make_ethy_ids(&[$($inits),+]), 0, validators.len() as u32)
 					}
+					fn bls_public_keys() -> Vec<Option<bls::Public>> {
+						vec![None; make_ethy_ids(&[$($inits),+]).len()]
+					}
+					fn event_proof_scheme(_chain_id: EthyChainId) -> EventProofScheme {
+						EventProofScheme::Ecdsa
+					}
 				}
 			}
 		}
@@ -15,6 +15,7 @@
 
 //! ethy-gadget types
 use seed_primitives::ethy::{EthyChainId, EventProofId, ETHY_ENGINE_ID};
+use std::time::Instant;
 
 /// Metadata about an Ethy event
 pub struct EventMetadata {
@@ -26,6 +27,8 @@ pub struct EventMetadata {
 	pub digest_data: Vec<u8>,
 	/// The (finalized) block hash where the event proof was made
 	pub block_hash: [u8; 32],
+	/// When the event metadata was noted locally
+	pub noted_at: Instant,
 }
 
 /// An Ethy proof request
@@ -50,19 +53,44 @@ pub fn make_proof_key(chain_id: EthyChainId, event_id: EventProofId) -> Vec<u8>
 	.concat()
 }
 
+/// Make the storage key of an event's batch inclusion proof
+pub fn make_batch_inclusion_key(chain_id: EthyChainId, event_id: EventProofId) -> Vec<u8> {
+	[
+		ETHY_ENGINE_ID.as_slice(),
+		b"batch".as_slice(),
+		[Into::<u8>::into(chain_id)].as_slice(),
+		event_id.to_be_bytes().as_slice(),
+	]
+	.concat()
+}
+
 // data must be transformed into a 32 byte digest before signing
 pub fn data_to_digest(
 	chain_id: EthyChainId,
 	data: Vec<u8>,
 	public_key: [u8; 33],
 ) -> Option<[u8; 32]> {
-	if chain_id == EthyChainId::Xrpl {
+	match chain_id {
 		// XRPL has a unique protocol for multi-signing tx `data` where each authority must
 		// add its own public key to the data before hashing it
 		// the digest is unique per validator
-		Some(xrpl_codec::utils::digest_for_multi_signing_pre(data.as_slice(), public_key))
-	} else {
-		// any other chains e.g. Ethereum, `data` should already be a `keccak256` digest
-		data.try_into().ok()
+		EthyChainId::Xrpl =>
+			Some(xrpl_codec::utils::digest_for_multi_signing_pre(data.as_slice(), public_key)),
+		// `data` should already be a `keccak256` digest
+		EthyChainId::Ethereum => data.try_into().ok(),
+		// other EVM chains sign over a chain specific domain so a proof can't be replayed on
+		// another bridge contract
+		EthyChainId::SecondaryEvm => {
+			let data: [u8; 32] = data.try_into().ok()?;
+			Some(sp_core::keccak_256(
+				[
+					ETHY_ENGINE_ID.as_slice(),
+					[Into::<u8>::into(chain_id)].as_slice(),
+					data.as_slice(),
+				]
+				.concat()
+				.as_slice(),
+			))
+		},
 	}
 }
@@ -16,11 +16,12 @@
 use crate::keystore::EthyKeystore;
 use log::{debug, error, trace, warn};
 use seed_primitives::ethy::{
+	bls,
 	crypto::{AuthorityId, AuthoritySignature as Signature},
 	AuthorityIndex, EthyChainId, EthyEcdsaToPublicKey, EventProofId, ValidatorSet, Witness,
 };
 use sp_runtime::traits::Convert;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
 use crate::types::{data_to_digest, EventMetadata};
 
@@ -49,6 +50,20 @@ pub enum WitnessError {
 	UnknownAuthority,
 }
 
+impl WitnessError {
+	/// The metric label of the error
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::CompletedEvent => "completed_event",
+			Self::DuplicateWitness => "duplicate_witness",
+			Self::MismatchedDigest => "mismatched_digest",
+			Self::DigestCreationFailed => "digest_creation_failed",
+			Self::SignatureVerificationFailed => "signature_verification_failed",
+			Self::UnknownAuthority => "unknown_authority",
+		}
+	}
+}
+
 /// Handles tracking witnesses from ethy participants
 ///
 /// Expired/complete witnesses are handled at the gossip layer
@@ -63,8 +78,12 @@ pub struct WitnessRecord {
 	validators: ValidatorSet<AuthorityId>,
 	/// The secp256k1 public (session) keys of the XRPL validators (subset of all validators)
 	xrpl_validators: ValidatorSet<AuthorityId>,
+	/// The registered BLS public keys of `validators`, ordered as `validators`
+	bls_keys: Vec<Option<bls::Public>>,
 	/// The record of witnesses `event_id -> [(validator index, validator signature)]`
 	witnesses: HashMap<EventProofId, Vec<(AuthorityIndex, Signature)>>,
+	/// The record of verified witness BLS signatures `event_id -> [(validator index, signature)]`
+	bls_witnesses: HashMap<EventProofId, Vec<(AuthorityIndex, bls::Signature)>>,
 	/// The record of unverified witnesses `event_id -> [(validator index, validator signature)]`
 	unverified_witnesses: HashMap<EventProofId, Vec<Witness>>,
 	/// completed events
@@ -81,9 +100,14 @@ impl WitnessRecord {
 		self.validators = validators;
 		self.xrpl_validators = xrpl_validators;
 	}
+	/// Set the registered BLS public keys of the active `ValidatorSet`
+	pub fn set_bls_keys(&mut self, bls_keys: Vec<Option<bls::Public>>) {
+		self.bls_keys = bls_keys;
+	}
 	/// Remove a witness record from memory (typically after it has achieved consensus)
 	pub fn mark_complete(&mut self, event_id: EventProofId) {
 		self.witnesses.remove(&event_id);
+		self.bls_witnesses.remove(&event_id);
 		self.event_meta.remove(&event_id);
 		self.has_witnessed.remove(&event_id);
 		self.unverified_witnesses.remove(&event_id);
@@ -101,17 +125,33 @@ impl WitnessRecord {
 			None => Default::default(),
 		}
 	}
+	/// Return the aggregate of the BLS signatures for the witness on `event_id`, if there are >=
+	/// `threshold` of them
+	/// Returns the indexes of the aggregated signers and the aggregate signature
+	pub fn aggregate_signature_for(
+		&self,
+		event_id: EventProofId,
+	) -> Option<(Vec<AuthorityIndex>, bls::Signature)> {
+		let bls_witnesses = self.bls_witnesses.get(&event_id)?;
+		if bls_witnesses.len() < self.validators.proof_threshold as usize {
+			return None
+		}
+		let signature = bls::aggregate_signatures(bls_witnesses.iter().map(|(_idx, sig)| sig))?;
+		Some((bls_witnesses.iter().map(|(idx, _sig)| *idx).collect(), signature))
+	}
 	/// Does the event identified by `event_id` `digest` have >= `threshold` support
 	pub fn has_consensus(&self, event_id: EventProofId, chain_id: EthyChainId) -> bool {
 		trace!(target: "ethy", "💎 event {:?}, witnesses: {:?}", event_id, self.witnesses.get(&event_id));
 
 		let proof_threshold = match chain_id {
-			EthyChainId::Ethereum => self.validators.proof_threshold as usize,
+			EthyChainId::Ethereum | EthyChainId::SecondaryEvm =>
+				self.validators.proof_threshold as usize,
 			EthyChainId::Xrpl => self.xrpl_validators.proof_threshold as usize,
 		};
 
 		let witness_count = match chain_id {
-			EthyChainId::Ethereum => self.witnesses.get(&event_id).map(|w| w.len()),
+			EthyChainId::Ethereum | EthyChainId::SecondaryEvm =>
+				self.witnesses.get(&event_id).map(|w| w.len()),
 			EthyChainId::Xrpl => self.witnesses.get(&event_id).map(|w| {
 				// ethy tracks all witnesses but only a subset are able to be submitted to XRPL
 				// count signatures from the XRPL authorized signers only
@@ -137,17 +177,26 @@ impl WitnessRecord {
 	pub fn event_metadata(&self, event_id: EventProofId) -> Option<&EventMetadata> {
 		self.event_meta.get(&event_id)
 	}
+	/// Return the events with known metadata which have not completed
+	pub fn pending_events(&self) -> impl Iterator<Item = (&EventProofId, &EventMetadata)> {
+		self.event_meta.iter()
+	}
 	/// Process any unverified witnesses for `event_id`
 	/// Unverified witnesses can exist if metadata for an event was unknown locally when the
 	/// witnesses were originally received by the network
-	pub fn process_unverified_witnesses(&mut self, event_id: EventProofId) {
+	///
+	/// Returns the errors of any rejected witnesses
+	pub fn process_unverified_witnesses(&mut self, event_id: EventProofId) -> Vec<WitnessError> {
+		let mut errors = Vec::new();
 		if let Some(unverified) = self.unverified_witnesses.remove(&event_id) {
 			for w in unverified {
 				if let Err(err) = self.note_event_witness(&w) {
 					warn!(target: "ethy", "💎 failed to note (unverified) witness: {:?}, {:?}", w, err);
+					errors.push(err);
 				}
 			}
 		}
+		errors
 	}
 	/// Note event metadata
 	/// This must exist in order to locally verify witnesses
@@ -162,6 +211,7 @@ impl WitnessRecord {
 			block_hash,
 			digest_data,
 			chain_id,
+			noted_at: Instant::now(),
 		});
 	}
 	/// Note a witness if we haven't seen it before
@@ -249,6 +299,11 @@ impl WitnessRecord {
 			.or_insert_with(|| vec![(authority_index, witness.signature.clone())]);
 		debug!(target: "ethy", "💎 witness recorded: {:?}, {:?}", witness.event_id, witness.authority_id);
 
+		// The BLS signature is optional, the witness is still valid without it
+		if let Some(bls_signature) = witness.bls_signature {
+			self.note_bls_signature(witness, authority_index, bls_signature);
+		}
+
 		// Mark authority as voted
 		match self.has_witnessed.get_mut(&witness.event_id) {
 			None => {
@@ -266,6 +321,28 @@ impl WitnessRecord {
 		Ok(WitnessStatus::Verified)
 	}
 
+	/// Note the BLS signature of a (verified) witness if it is valid for the authority's registered
+	/// BLS key
+	fn note_bls_signature(
+		&mut self,
+		witness: &Witness,
+		authority_index: AuthorityIndex,
+		bls_signature: bls::Signature,
+	) {
+		let Some(Some(bls_key)) = self.bls_keys.get(authority_index as usize) else {
+			debug!(target: "ethy", "💎 witness bls signature from unregistered key: {:?}, {:?}", witness.event_id, witness.authority_id);
+			return
+		};
+		if !bls::verify(bls_key, &witness.digest, &bls_signature) {
+			warn!(target: "ethy", "💎 witness bls signature verification failed: {:?} from {:?}", witness.event_id, witness.authority_id);
+			return
+		}
+		let bls_witnesses = self.bls_witnesses.entry(witness.event_id).or_default();
+		if let Err(idx) = bls_witnesses.binary_search_by_key(&authority_index, |(idx, _sig)| *idx) {
+			bls_witnesses.insert(idx, (authority_index, bls_signature));
+		}
+	}
+
 	#[cfg(test)]
 	pub fn get_validator_set(&self) -> ValidatorSet<AuthorityId> {
 		self.validators.clone()
@@ -305,7 +382,7 @@ pub(crate) mod test {
 	use super::{compact_sequence, Signature, WitnessError, WitnessRecord, WitnessStatus};
 	use crate::{keystore::EthyKeystore, testing::Keyring, tests::create_ethy_keystore};
 	use seed_primitives::ethy::{
-		AuthorityIndex, EthyChainId, EthyEcdsaToPublicKey, EventProofId, ValidatorSet, Witness,
+		bls, AuthorityIndex, EthyChainId, EthyEcdsaToPublicKey, EventProofId, ValidatorSet, Witness,
 	};
 	use sp_runtime::traits::Convert;
 
@@ -337,6 +414,7 @@ pub(crate) mod test {
 			validator_set_id: 5_u64,
 			authority_id: validator.public(),
 			signature: keystore.sign_prehashed(&validator.public(), &digest).unwrap(),
+			bls_signature: None,
 		}
 	}
 
@@ -715,6 +793,43 @@ pub(crate) mod test {
 		assert!(witness_record.has_consensus(event_id, chain_id));
 	}
 
+	#[test]
+	fn pending_events_and_rejected_unverified_witnesses() {
+		let validator_keys = dev_signers();
+		let mut witness_record = WitnessRecord {
+			validators: ValidatorSet {
+				validators: validator_keys.iter().map(|x| x.public()).collect(),
+				proof_threshold: 2,
+				..Default::default()
+			},
+			..Default::default()
+		};
+		let chain_id = EthyChainId::Ethereum;
+		let event_id = 5_u64;
+		let digest = [1_u8; 32];
+
+		// alice signs the wrong digest before the event metadata is known
+		let witness = &create_witness(&validator_keys[0], event_id, chain_id, [2_u8; 32]);
+		assert_eq!(witness_record.note_event_witness(witness), Ok(WitnessStatus::DigestUnverified));
+		let witness = &create_witness(&validator_keys[1], event_id, chain_id, digest);
+		assert_eq!(witness_record.note_event_witness(witness), Ok(WitnessStatus::DigestUnverified));
+		assert_eq!(witness_record.pending_events().count(), 0);
+
+		witness_record.note_event_metadata(event_id, digest.to_vec(), Default::default(), chain_id);
+		assert_eq!(
+			witness_record.pending_events().map(|(id, _)| *id).collect::<Vec<_>>(),
+			vec![event_id]
+		);
+		assert_eq!(
+			witness_record.process_unverified_witnesses(event_id),
+			vec![WitnessError::MismatchedDigest]
+		);
+		assert_eq!(witness_record.signatures_for(event_id).len(), 1);
+
+		witness_record.mark_complete(event_id);
+		assert_eq!(witness_record.pending_events().count(), 0);
+	}
+
 	#[test]
 	fn has_consensus_xrpl() {
 		let xrpl_validator_keys = dev_signers_xrpl();
@@ -844,4 +959,121 @@ pub(crate) mod test {
 			false
 		);
 	}
+
+	#[test]
+	fn witness_signature_verification_secondary_evm() {
+		let validator_keys = dev_signers();
+		let validator = &validator_keys[0];
+		let chain_id = EthyChainId::SecondaryEvm;
+		let event_id = 5;
+		let compatible_public = EthyEcdsaToPublicKey::convert(validator.public());
+		let digest_data = [2_u8; 32];
+
+		let witness = create_witness(&validator, event_id, chain_id, digest_data);
+		let correct_digest =
+			data_to_digest(chain_id, digest_data.to_vec(), compatible_public).unwrap();
+		assert_eq!(
+			EthyKeystore::verify_prehashed(
+				&validator.public(),
+				&witness.signature,
+				&correct_digest
+			),
+			true
+		);
+
+		// the digest is domain separated from Ethereum
+		let ethereum_digest =
+			data_to_digest(EthyChainId::Ethereum, digest_data.to_vec(), compatible_public).unwrap();
+		assert_ne!(correct_digest, ethereum_digest);
+		assert_eq!(
+			EthyKeystore::verify_prehashed(
+				&validator.public(),
+				&witness.signature,
+				&ethereum_digest
+			),
+			false
+		);
+	}
+
+	#[test]
+	fn aggregate_signature_for_bls_witnesses() {
+		let validator_keys = dev_signers();
+		let bls_secrets: Vec<bls::SecretKey> = validator_keys
+			.iter()
+			.map(|v| {
+				let keystore: EthyKeystore = Some(create_ethy_keystore(*v)).into();
+				keystore.bls_secret(&v.public()).unwrap()
+			})
+			.collect();
+		let mut witness_record = WitnessRecord {
+			validators: ValidatorSet {
+				validators: validator_keys.iter().map(|x| x.public()).collect(),
+				proof_threshold: 2,
+				..Default::default()
+			},
+			..Default::default()
+		};
+		// charlie has not registered a bls key
+		witness_record.set_bls_keys(vec![
+			Some(bls_secrets[0].public()),
+			Some(bls_secrets[1].public()),
+			None,
+		]);
+		let digest = [1_u8; 32];
+		let bls_witness = |validator: usize, signer: usize, event_id: EventProofId| {
+			let mut witness =
+				create_witness(&validator_keys[validator], event_id, EthyChainId::Ethereum, digest);
+			witness.bls_signature = Some(bls_secrets[signer].sign(&witness.digest));
+			witness
+		};
+
+		let event_id = 5_u64;
+		witness_record.note_event_metadata(
+			event_id,
+			digest.to_vec(),
+			Default::default(),
+			EthyChainId::Ethereum,
+		);
+		assert_eq!(
+			witness_record.note_event_witness(&bls_witness(0, 0, event_id)),
+			Ok(WitnessStatus::Verified)
+		);
+		assert!(witness_record.aggregate_signature_for(event_id).is_none());
+		// bob's bls signature is invalid, the witness is still noted
+		assert_eq!(
+			witness_record.note_event_witness(&bls_witness(1, 0, event_id)),
+			Ok(WitnessStatus::Verified)
+		);
+		// charlie's bls key is unregistered
+		assert_eq!(
+			witness_record.note_event_witness(&bls_witness(2, 2, event_id)),
+			Ok(WitnessStatus::Verified)
+		);
+		assert!(witness_record.has_consensus(event_id, EthyChainId::Ethereum));
+		assert!(witness_record.aggregate_signature_for(event_id).is_none());
+
+		let event_id = 6_u64;
+		witness_record.note_event_metadata(
+			event_id,
+			digest.to_vec(),
+			Default::default(),
+			EthyChainId::Ethereum,
+		);
+		// note in reverse order
+		for validator in [1, 0] {
+			assert_eq!(
+				witness_record.note_event_witness(&bls_witness(validator, validator, event_id)),
+				Ok(WitnessStatus::Verified)
+			);
+		}
+		let (signers, signature) = witness_record.aggregate_signature_for(event_id).unwrap();
+		assert_eq!(signers, vec![0, 1]);
+		let aggregate_public =
+			bls::aggregate_public_keys(&[bls_secrets[0].public(), bls_secrets[1].public()])
+				.unwrap();
+		assert!(bls::verify(&aggregate_public, &digest, &signature));
+
+		witness_record.mark_complete(event_id);
+		assert!(witness_record.aggregate_signature_for(event_id).is_none());
+	}
 }
@@ -25,21 +25,28 @@ use sp_runtime::{
 	generic::OpaqueDigestItemId,
 	traits::{Block, Convert, Header, One},
 };
-use std::{sync::Arc, time::Duration};
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
 use seed_primitives::ethy::{
-	crypto::AuthorityId as Public, ConsensusLog, EthyApi, EthyEcdsaToPublicKey, EventProof,
-	EventProofId, ValidatorSet, VersionedEventProof, Witness, ETHY_ENGINE_ID,
-	GENESIS_AUTHORITY_SET_ID,
+	bls, crypto::AuthorityId as Public, event_batch_path, event_batch_root, AggregateEventProof,
+	ConsensusLog, EthyApi, EthyChainId, EthyEcdsaToPublicKey, EventBatchInclusion, EventProof,
+	EventProofId, EventProofScheme, ValidatorSet, VersionedEventProof, VersionedWitness, Witness,
+	ETHY_ENGINE_ID, GENESIS_AUTHORITY_SET_ID,
 };
 
 use crate::{
+	archive,
 	gossip::{topic, GossipValidator},
 	keystore::EthyKeystore,
-	metric_inc, metric_set,
-	metrics::Metrics,
+	metric_inc, metric_observe, metric_set,
+	metrics::{chain_label, Metrics},
 	notification,
-	types::{data_to_digest, make_proof_key, EventMetadata, ProofRequest},
+	types::{
+		data_to_digest, make_batch_inclusion_key, make_proof_key, EventMetadata, ProofRequest,
+	},
 	witness_record::WitnessRecord,
 	Client,
 };
@@ -56,8 +63,13 @@ where
 	pub gossip_validator: Arc<GossipValidator<B>>,
 	pub metrics: Option<Metrics>,
 	pub sync_oracle: SO,
+	pub proof_retention: Option<u32>,
+	pub event_stall_threshold: Duration,
 }
 
+/// Interval between checks for stalled events
+const STALL_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 /// An ETHY worker plays the ETHY protocol
 pub(crate) struct EthyWorker<B, C, BE, R, SO>
 where
@@ -83,6 +95,14 @@ where
 	validator_set: ValidatorSet<Public>,
 	/// Handle to the sync oracle
 	sync_oracle: SO,
+	/// BLS secret key derived from the local authority Id (cached)
+	bls_secret: Option<(Public, bls::SecretKey)>,
+	/// Number of finalized blocks to keep proofs for, `None` keeps all proofs
+	proof_retention: Option<u32>,
+	/// Age after which an event without consensus is reported as stalled
+	event_stall_threshold: Duration,
+	/// Scheduled time for the next stalled event check
+	next_stall_check: Instant,
 }
 
 impl<B, C, BE, R, SO> EthyWorker<B, C, BE, R, SO>
@@ -111,6 +131,8 @@ where
 			gossip_validator,
 			metrics,
 			sync_oracle,
+			proof_retention,
+			event_stall_threshold,
 		} = worker_params;
 
 		let last_finalized_header = client
@@ -130,6 +152,10 @@ where
 			validator_set: ValidatorSet::empty(),
 			witness_record: Default::default(),
 			sync_oracle,
+			bls_secret: None,
+			proof_retention,
+			event_stall_threshold,
+			next_stall_check: Instant::now() + STALL_CHECK_INTERVAL,
 		}
 	}
 }
@@ -171,6 +197,41 @@ where
 		xrpl_signers
 	}
 
+	/// Return the event proof scheme of `chain_id` at block `at`
+	/// Only EVM chains support schemes other than `EventProofScheme::Ecdsa`
+	fn event_proof_scheme(&self, chain_id: EthyChainId, at: B::Hash) -> EventProofScheme {
+		if !chain_id.is_evm() {
+			return EventProofScheme::Ecdsa
+		}
+		// runtimes prior to `EthyApi` v2 only support ECDSA proofs
+		self.runtime
+			.runtime_api()
+			.event_proof_scheme(&BlockId::hash(at), chain_id)
+			.unwrap_or_default()
+	}
+
+	/// Query the runtime state for the registered BLS keys of the validator set
+	fn refresh_bls_keys(&mut self, header: &B::Header) {
+		let at = BlockId::hash(header.hash());
+		let bls_keys = self.runtime.runtime_api().bls_public_keys(&at).unwrap_or_default();
+		trace!(target: "ethy", "💎 validator set bls keys: {:?}", bls_keys);
+		self.witness_record.set_bls_keys(bls_keys);
+	}
+
+	/// Return the BLS secret key of the local `authority_id`
+	fn bls_secret(&mut self, authority_id: &Public) -> Option<&bls::SecretKey> {
+		if self.bls_secret.as_ref().map(|(id, _secret)| id != authority_id).unwrap_or(true) {
+			self.bls_secret = match self.key_store.bls_secret(authority_id) {
+				Ok(secret) => Some((authority_id.clone(), secret)),
+				Err(err) => {
+					error!(target: "ethy", "💎 error deriving bls key: {:?}", err);
+					None
+				},
+			};
+		}
+		self.bls_secret.as_ref().map(|(_id, secret)| secret)
+	}
+
 	/// Handle finality notification for non-signers (no locally available validator keys)
 	fn handle_finality_notification_passive(&mut self, notification: FinalityNotification<B>) {
 		for ProofRequest { chain_id, event_id, data, block } in
@@ -217,6 +278,14 @@ where
 
 			debug!(target: "ethy", "💎 signed event id: {:?}, validator set: {:?},\nsignature: {:?}", event_id, self.validator_set.id, hex::encode(&signature));
 
+			// the proof will be an aggregate of the witness' BLS signatures
+			let bls_signature = match self.event_proof_scheme(chain_id, notification.header.hash())
+			{
+				EventProofScheme::BlsAggregate =>
+					self.bls_secret(&authority_id).map(|secret| secret.sign(&digest)),
+				EventProofScheme::Ecdsa => None,
+			};
+
 			let witness = Witness {
 				chain_id,
 				digest,
@@ -224,8 +293,9 @@ where
 				event_id,
 				authority_id: authority_id.clone(),
 				signature,
+				bls_signature,
 			};
-			let broadcast_witness = witness.encode();
+			let broadcast_witness = VersionedWitness::encode_witness(&witness);
 
 			metric_inc!(self, ethy_witness_sent);
 			debug!(target: "ethy", "💎 Sent witness: {:?}", witness);
@@ -303,6 +373,17 @@ where
 			}
 		}
 
+		// Store inclusion proofs for any batched events, the batch root is signed as usual
+		self.store_event_batches(&new_header);
+
+		// Prune proofs requested by the block leaving the retention window
+		self.prune_proofs(number);
+
+		// BLS keys may be registered at any time, refresh them before witnessing new events
+		if !extract_proof_requests::<B>(&new_header).is_empty() {
+			self.refresh_bls_keys(&new_header);
+		}
+
 		// Process proof requests
 		if let Some(authority_id) =
 			self.key_store.authority_id(self.validator_set.validators.as_slice())
@@ -314,9 +395,87 @@ where
 			self.handle_finality_notification_passive(notification)
 		};
 
+		self.check_pending_events();
+
 		self.best_grandpa_block_header = new_header;
 	}
 
+	/// Update the pending event metrics and warn of events pending longer than
+	/// `event_stall_threshold`
+	fn check_pending_events(&mut self) {
+		let now = Instant::now();
+		let mut stalled = Vec::new();
+		let mut pending = 0_usize;
+		let mut oldest_age = Duration::ZERO;
+		for (event_id, metadata) in self.witness_record.pending_events() {
+			let age = now.saturating_duration_since(metadata.noted_at);
+			if age >= self.event_stall_threshold {
+				stalled.push((*event_id, metadata.chain_id, age));
+			}
+			oldest_age = oldest_age.max(age);
+			pending += 1;
+		}
+		metric_set!(self, ethy_pending_proofs, pending);
+		metric_set!(self, ethy_oldest_pending_event_age, oldest_age.as_secs());
+
+		if now < self.next_stall_check {
+			return
+		}
+		self.next_stall_check = now + STALL_CHECK_INTERVAL;
+		for (event_id, chain_id, age) in stalled {
+			let witnesses = self.witness_record.signatures_for(event_id).len();
+			warn!(target: "ethy", "💎 event: {:?} for chain: {:?} has no consensus after {:?}s, witnesses: {:?}, validator set: {:?}", event_id, chain_id, age.as_secs(), witnesses, self.validator_set.id);
+		}
+	}
+
+	/// Store an inclusion proof for each event of the event batches requested in `header`
+	/// Proofs for batched events are then made from the batch proof and the inclusion proof
+	fn store_event_batches(&self, header: &B::Header) {
+		for (chain_id, batch_id, leaves) in extract_event_batches::<B>(header) {
+			let inclusions = make_batch_inclusions(batch_id, &leaves);
+			debug!(target: "ethy", "💎 storing inclusion proofs for event batch: {:?}, events: {:?}", batch_id, inclusions.len());
+			let entries: Vec<(Vec<u8>, Vec<u8>)> = inclusions
+				.into_iter()
+				.map(|(event_id, inclusion)| {
+					(make_batch_inclusion_key(chain_id, event_id), inclusion.encode())
+				})
+				.collect();
+			let insert: Vec<(&[u8], &[u8])> =
+				entries.iter().map(|(k, v)| (k.as_slice(), v.as_slice())).collect();
+			if let Err(err) = Backend::insert_aux(self.backend.as_ref(), &insert, &[]) {
+				error!(target: "ethy", "💎 failed to store inclusion proofs for event batch: {:?}. Error received: {:?}", batch_id, err);
+			}
+		}
+	}
+
+	/// Prune the proofs requested by the block `proof_retention` blocks before `number`
+	/// Blocks are finalized sequentially so proofs are pruned one block at a time
+	fn prune_proofs(&self, number: <B::Header as Header>::Number) {
+		let Some(retention) = self.proof_retention else { return };
+		if number <= retention.into() {
+			return
+		}
+		let prune_number = number - retention.into();
+		let header = match self.backend.blockchain().header(BlockId::Number(prune_number)) {
+			Ok(Some(header)) => header,
+			Ok(None) => {
+				debug!(target: "ethy", "💎 missing block #{:?} to prune proofs", prune_number);
+				return
+			},
+			Err(err) => {
+				error!(target: "ethy", "💎 error fetching block #{:?} to prune proofs. {:?}", prune_number, err);
+				return
+			},
+		};
+		match archive::prune_proofs::<B, _>(self.backend.as_ref(), &header) {
+			Ok(0) => (),
+			Ok(pruned) =>
+				debug!(target: "ethy", "💎 pruned {:?} proofs requested at block #{:?}", pruned, prune_number),
+			Err(err) =>
+				error!(target: "ethy", "💎 failed to prune proofs requested at block #{:?}. Error received: {:?}", prune_number, err),
+		}
+	}
+
 	/// Note an individual witness for a message
 	fn handle_witness(&mut self, witness: Witness) {
 		// The aggregated signed witness here could be different to another validators.
@@ -326,10 +485,15 @@ where
 		// only share if it's the first time witnessing the event
 		if let Err(err) = self.witness_record.note_event_witness(&witness) {
 			warn!(target: "ethy", "💎 failed to note witness: {:?}, {:?}", witness, err);
+			metric_inc!(self, ethy_witness_rejected, err.as_str());
 			return
 		}
 
-		self.gossip_engine.gossip_message(topic::<B>(), witness.encode(), false);
+		self.gossip_engine.gossip_message(
+			topic::<B>(),
+			VersionedWitness::encode_witness(&witness),
+			false,
+		);
 		// after processing `witness` there may now be enough info to make a proof
 		self.try_make_proof(witness.event_id);
 	}
@@ -353,8 +517,10 @@ where
 		}
 
 		// process any unverified witnesses, received before event metadata was known
-		self.witness_record.process_unverified_witnesses(event_id);
-		let EventMetadata { chain_id, block_hash, digest_data } =
+		for err in self.witness_record.process_unverified_witnesses(event_id) {
+			metric_inc!(self, ethy_witness_rejected, err.as_str());
+		}
+		let EventMetadata { chain_id, block_hash, digest_data, noted_at } =
 			self.witness_record.event_metadata(event_id).unwrap();
 
 		if self.witness_record.has_consensus(event_id, *chain_id) {
@@ -374,7 +540,11 @@ where
 				signatures: signatures.clone(),
 			};
 
-			let versioned_event_proof = VersionedEventProof::V1(event_proof.clone());
+			let versioned_event_proof =
+				match self.make_aggregate_proof(event_id, *chain_id, *block_hash, digest) {
+					Some(aggregate_proof) => VersionedEventProof::V2(aggregate_proof),
+					None => VersionedEventProof::V1(event_proof.clone()),
+				};
 
 			// Add proof to the DB that this event has been notarized specifically by the
 			// given threshold of validators
@@ -396,6 +566,12 @@ where
 			self.event_proof_sender
 				.notify(|| Ok::<_, ()>(versioned_event_proof))
 				.expect("forwards closure result; the closure always returns Ok; qed.");
+			metric_observe!(
+				self,
+				ethy_proof_consensus_time,
+				chain_label(*chain_id),
+				noted_at.elapsed().as_secs_f64()
+			);
 			// Remove from memory
 			self.witness_record.mark_complete(event_id);
 			self.gossip_validator.mark_complete(event_id);
@@ -405,6 +581,36 @@ where
 		}
 	}
 
+	/// Make an aggregate proof for `event_id` if its chain uses `EventProofScheme::BlsAggregate`
+	///
+	/// Returns `None` if the chain uses ECDSA proofs or too few witnesses had valid BLS signatures,
+	/// in which case an ECDSA proof should be made
+	fn make_aggregate_proof(
+		&self,
+		event_id: EventProofId,
+		chain_id: EthyChainId,
+		block_hash: [u8; 32],
+		digest: [u8; 32],
+	) -> Option<AggregateEventProof> {
+		let at = B::Hash::decode(&mut &block_hash[..]).ok()?;
+		if self.event_proof_scheme(chain_id, at) != EventProofScheme::BlsAggregate {
+			return None
+		}
+		let Some((signers, signature)) = self.witness_record.aggregate_signature_for(event_id)
+		else {
+			warn!(target: "ethy", "💎 too few bls signatures for event: {:?}, making ecdsa proof", event_id);
+			return None
+		};
+		Some(AggregateEventProof {
+			digest,
+			event_id,
+			validator_set_id: self.validator_set.id,
+			signers,
+			signature,
+			block: block_hash,
+		})
+	}
+
 	/// Main loop for Ethy worker.
 	pub(crate) async fn run(mut self) {
 		info!(target: "ethy", "💎 run Ethy worker, best finalized block: #{:?}.", self.best_grandpa_block_header.number());
@@ -420,7 +626,7 @@ where
 			|notification| async move {
 				trace!(target: "ethy", "💎 got witness: {:?}", notification);
 
-				Witness::decode(&mut &notification.message[..]).ok()
+				VersionedWitness::decode_witness(&notification.message[..])
 			},
 		))
 		.fuse();
@@ -458,7 +664,7 @@ where
 
 /// Extract event proof requests from a digest in the given header, if any.
 /// Returns (digest for signing, event id, optional tag)
-fn extract_proof_requests<B>(header: &B::Header) -> Vec<ProofRequest>
+pub(crate) fn extract_proof_requests<B>(header: &B::Header) -> Vec<ProofRequest>
 where
 	B: Block,
 {
@@ -479,6 +685,47 @@ where
 		.collect()
 }
 
+/// Extract event batches from a digest in the given header, if any.
+/// Returns (chain Id, batch Id, batch leaves)
+pub(crate) fn extract_event_batches<B>(
+	header: &B::Header,
+) -> Vec<(EthyChainId, EventProofId, Vec<(EventProofId, [u8; 32])>)>
+where
+	B: Block,
+{
+	header
+		.digest()
+		.logs()
+		.iter()
+		.flat_map(|log| {
+			if let Some(ConsensusLog::EventBatch { chain_id, batch_id, leaves }) =
+				log.try_to::<ConsensusLog<Public>>(OpaqueDigestItemId::Consensus(&ETHY_ENGINE_ID))
+			{
+				Some((chain_id, batch_id, leaves))
+			} else {
+				None
+			}
+		})
+		.collect()
+}
+
+/// Make the inclusion proof of each event in the batch `batch_id`
+fn make_batch_inclusions(
+	batch_id: EventProofId,
+	leaves: &[(EventProofId, [u8; 32])],
+) -> Vec<(EventProofId, EventBatchInclusion)> {
+	let digests: Vec<[u8; 32]> = leaves.iter().map(|(_id, leaf)| *leaf).collect();
+	let root = event_batch_root(&digests);
+	leaves
+		.iter()
+		.enumerate()
+		.filter_map(|(index, (event_id, leaf))| {
+			let path = event_batch_path(&digests, index)?;
+			Some((*event_id, EventBatchInclusion { batch_id, root, leaf: *leaf, path }))
+		})
+		.collect()
+}
+
 /// Scan the `header` digest log for an Ethy validator set change. Return either the new
 /// validator set or `None` in case no validator set change has been signaled.
 fn find_authorities_change<B>(header: &B::Header) -> Option<ValidatorSet<Public>>
@@ -511,7 +758,7 @@ pub(crate) mod test {
 	use sc_network::NetworkService;
 	use sc_network_test::{PeersFullClient, TestNetFactory};
 	use sc_utils::notification::NotificationStream;
-	use seed_primitives::ethy::{crypto::AuthorityId, EthyChainId, ValidatorSet};
+	use seed_primitives::ethy::{crypto::AuthorityId, verify_event_batch_path, ValidatorSet};
 	use sp_api::HeaderT;
 	use substrate_test_runtime_client::{
 		runtime::{Block, Digest, DigestItem, Header, H256},
@@ -542,6 +789,7 @@ pub(crate) mod test {
 				if let Ok(versioned_proof) = VersionedEventProof::decode(&mut &encoded_proof[..]) {
 					match versioned_proof {
 						VersionedEventProof::V1(event_proof) => return Some(event_proof),
+						VersionedEventProof::V2(_) => return None,
 					}
 				}
 			}
@@ -558,7 +806,7 @@ pub(crate) mod test {
 		let api = Arc::new(TestApi {});
 		let network = peer.network_service().clone();
 		let sync_oracle = network.clone();
-		let gossip_validator = Arc::new(crate::gossip::GossipValidator::new(validators));
+		let gossip_validator = Arc::new(crate::gossip::GossipValidator::new(validators, None));
 		let gossip_engine =
 			GossipEngine::new(network, ETHY_PROTOCOL_NAME, gossip_validator.clone(), None);
 		let (sender, _receiver) = NotificationStream::<_, EthyEventProofTracingKey>::channel();
@@ -573,6 +821,8 @@ pub(crate) mod test {
 			gossip_validator,
 			metrics: None,
 			sync_oracle,
+			proof_retention: None,
+			event_stall_threshold: Duration::from_secs(600),
 		};
 		EthyWorker::<_, _, _, _, _>::new(worker_params)
 	}
@@ -727,6 +977,55 @@ pub(crate) mod test {
 		assert_eq!(extracted, Some(validator_set));
 	}
 
+	#[test]
+	fn store_event_batch_inclusion_proofs() {
+		let keys = &[Keyring::Alice, Keyring::Bob];
+		let validators = make_ethy_ids(keys);
+		let mut net = EthyTestNet::new(1, 0);
+		let worker = create_ethy_worker(&net.peer(0), &keys[0], validators);
+
+		let mut header = Header::new(
+			1u32.into(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Digest::default(),
+		);
+		let batch_id: EventProofId = 10;
+		let leaves: Vec<(EventProofId, [u8; 32])> = (1..=3).map(|i| (i, [i as u8; 32])).collect();
+		header.digest_mut().push(DigestItem::Consensus(
+			ETHY_ENGINE_ID,
+			ConsensusLog::<Public>::EventBatch {
+				chain_id: EthyChainId::Ethereum,
+				batch_id,
+				leaves: leaves.clone(),
+			}
+			.encode(),
+		));
+
+		worker.store_event_batches(&header);
+
+		let root = event_batch_root(&leaves.iter().map(|(_id, leaf)| *leaf).collect::<Vec<_>>());
+		for (event_id, leaf) in leaves {
+			let encoded = worker
+				.client
+				.get_aux(make_batch_inclusion_key(EthyChainId::Ethereum, event_id).as_ref())
+				.unwrap()
+				.unwrap();
+			let inclusion = EventBatchInclusion::decode(&mut &encoded[..]).unwrap();
+			assert_eq!(inclusion.batch_id, batch_id);
+			assert_eq!(inclusion.root, root);
+			assert_eq!(inclusion.leaf, leaf);
+			assert!(verify_event_batch_path(&root, &leaf, &inclusion.path));
+		}
+		// batches are stored per chain
+		assert!(worker
+			.client
+			.get_aux(make_batch_inclusion_key(EthyChainId::SecondaryEvm, 1).as_ref())
+			.unwrap()
+			.is_none());
+	}
+
 	#[test]
 	fn extract_validators_from_the_runtime_and_not_from_header() {
 		let keys = &[Keyring::Alice, Keyring::Bob];
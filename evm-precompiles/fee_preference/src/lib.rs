@@ -0,0 +1,168 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{PrecompileHandle, PrecompileOutput, PrecompileResult};
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::{AddressMapping, Precompile};
+use pallet_fee_proxy::FeePreference;
+use precompile_utils::{constants::ERC20_PRECOMPILE_ADDRESS_PREFIX, prelude::*};
+use seed_primitives::AssetId;
+use sp_core::H160;
+use sp_runtime::Permill;
+use sp_std::{marker::PhantomData, vec};
+
+/// Solidity selector of the fee preference logs, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_FEE_PREFERENCE_SET: [u8; 32] =
+	keccak256!("FeePreferenceSet(address,address,uint32)"); // who, asset, max_slippage
+
+pub const SELECTOR_LOG_FEE_PREFERENCE_REMOVED: [u8; 32] =
+	keccak256!("FeePreferenceRemoved(address)"); // who
+
+#[generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	FeePreferenceOf = "feePreferenceOf(address)",
+	SetFeePreference = "setFeePreference(address,uint32)",
+	RemoveFeePreference = "removeFeePreference()",
+}
+
+/// Provides access to the fee preferences of the fee proxy pallet
+pub struct FeePreferencePrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<T> Default for FeePreferencePrecompile<T> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime> Precompile for FeePreferencePrecompile<Runtime>
+where
+	Runtime::AccountId: From<H160> + Into<H160>,
+	Runtime: frame_system::Config + pallet_fee_proxy::Config + pallet_evm::Config,
+	Runtime: ErcIdConversion<AssetId, EvmId = Address>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_fee_proxy::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let result = {
+			let selector = match handle.read_selector() {
+				Ok(selector) => selector,
+				Err(e) => return Err(e.into()),
+			};
+
+			if let Err(err) = handle.check_function_modifier(match selector {
+				Action::SetFeePreference | Action::RemoveFeePreference =>
+					FunctionModifier::NonPayable,
+				Action::FeePreferenceOf => FunctionModifier::View,
+			}) {
+				return Err(err.into())
+			}
+
+			match selector {
+				Action::FeePreferenceOf => Self::fee_preference_of(handle),
+				Action::SetFeePreference => Self::set_fee_preference(handle),
+				Action::RemoveFeePreference => Self::remove_fee_preference(handle),
+			}
+		};
+		return result
+	}
+}
+
+impl<Runtime> FeePreferencePrecompile<Runtime> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime> FeePreferencePrecompile<Runtime>
+where
+	Runtime::AccountId: From<H160> + Into<H160>,
+	Runtime: frame_system::Config + pallet_fee_proxy::Config + pallet_evm::Config,
+	Runtime: ErcIdConversion<AssetId, EvmId = Address>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_fee_proxy::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	fn fee_preference_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		read_args!(handle, { who: Address });
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+
+		// Manually record gas
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let (asset, max_slippage) = match pallet_fee_proxy::FeePreferences::<Runtime>::get(who) {
+			Some(FeePreference { asset_id, max_slippage }) => (
+				<Runtime as ErcIdConversion<AssetId>>::runtime_id_to_evm_id(
+					asset_id,
+					ERC20_PRECOMPILE_ADDRESS_PREFIX,
+				),
+				max_slippage.deconstruct(),
+			),
+			None => (Address::default(), 0),
+		};
+
+		Ok(succeed(EvmDataWriter::new().write(asset).write(max_slippage).build()))
+	}
+
+	fn set_fee_preference(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(3, 32)?;
+		read_args!(handle, { asset: Address, max_slippage: u32 });
+
+		let asset_id: AssetId = <Runtime as ErcIdConversion<AssetId>>::evm_id_to_runtime_id(
+			asset,
+			ERC20_PRECOMPILE_ADDRESS_PREFIX,
+		)
+		.ok_or_else(|| revert("FeePreference: Invalid asset address"))?;
+
+		let caller = handle.context().caller;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_fee_proxy::Call::<Runtime>::set_fee_preference {
+				asset_id,
+				max_slippage: Permill::from_parts(max_slippage),
+			},
+		)?;
+
+		log3(
+			handle.code_address(),
+			SELECTOR_LOG_FEE_PREFERENCE_SET,
+			caller,
+			H160::from(asset),
+			EvmDataWriter::new().write(max_slippage).build(),
+		)
+		.record(handle)?;
+
+		Ok(succeed([]))
+	}
+
+	fn remove_fee_preference(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let caller = handle.context().caller;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_fee_proxy::Call::<Runtime>::remove_fee_preference {},
+		)?;
+
+		log2(handle.code_address(), SELECTOR_LOG_FEE_PREFERENCE_REMOVED, caller, vec![])
+			.record(handle)?;
+
+		Ok(succeed([]))
+	}
+}
@@ -30,9 +30,10 @@ use precompile_utils::{
 use sp_core::{H160, H256, U256};
 use sp_runtime::{
 	codec::Decode,
-	traits::{ConstU32, Zero},
+	traits::{ConstU32, UniqueSaturatedInto, Zero},
+	BoundedVec,
 };
-use sp_std::{marker::PhantomData, vec};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
 
 /// Solidity selector of the Futurepass logs, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_FUTUREPASS_DELEGATE_REGISTERED: [u8; 32] =
@@ -44,6 +45,13 @@ pub const SELECTOR_LOG_FUTUREPASS_EXECUTED: [u8; 32] =
 pub const SELECTOR_LOG_FUTUREPASS_CONTRACT_CREATED: [u8; 32] =
 	keccak256!("ContractCreated(uint8,address,uint256,bytes32)"); // operation, contractAddress, value, salt
 
+pub const SELECTOR_LOG_FUTUREPASS_RECOVERY_INITIATED: [u8; 32] =
+	keccak256!("FuturepassRecoveryInitiated(address,address)"); // guardian, newOwner
+pub const SELECTOR_LOG_FUTUREPASS_RECOVERY_APPROVED: [u8; 32] =
+	keccak256!("FuturepassRecoveryApproved(address)"); // guardian
+pub const SELECTOR_LOG_FUTUREPASS_RECOVERY_CANCELLED: [u8; 32] =
+	keccak256!("FuturepassRecoveryCancelled()");
+
 /// Solidity selector of the OwnershipTransferred log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_OWNERSHIP_TRANSFERRED: [u8; 32] =
 	keccak256!("OwnershipTransferred(address,address)");
@@ -92,10 +100,23 @@ pub enum Action {
 	RegisterDelegateWithSignature = "registerDelegateWithSignature(address,uint8,uint32,bytes)",
 	UnRegisterDelegate = "unregisterDelegate(address)",
 	ProxyCall = "proxyCall(uint8,address,uint256,bytes)",
+	ProxyCallBatch = "proxyCallBatch((uint8,address,uint256,bytes)[],bool)",
+	ProxyCallWithSignature =
+		"proxyCallWithSignature(address,uint8,address,uint256,bytes,uint32,uint32,bytes)",
+	SignedProxyNonce = "signedProxyNonce(address)",
 	// Ownable - https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/contracts/access/Ownable.sol
 	Owner = "owner()",
 	RenounceOwnership = "renounceOwnership()",
 	TransferOwnership = "transferOwnership(address)",
+	// Social recovery
+	RecoveryConfig = "recoveryConfig()",
+	ActiveRecovery = "activeRecovery()",
+	SetRecoveryConfig = "setRecoveryConfig(address[],uint32)",
+	RemoveRecoveryConfig = "removeRecoveryConfig()",
+	InitiateRecovery = "initiateRecovery(address)",
+	ApproveRecovery = "approveRecovery()",
+	CancelRecovery = "cancelRecovery()",
+	ClaimRecovery = "claimRecovery()",
 }
 
 pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
@@ -106,6 +127,7 @@ type GetSignatureLimit = ConstU32<SIGNATURE_LENGTH>;
 
 pub struct EvmSubCall {
 	pub to: Address,
+	pub value: U256,
 	pub call_data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
 }
 
@@ -165,10 +187,21 @@ where
 					Self::register_delegate_with_signature(handle),
 				Action::UnRegisterDelegate => Self::unregister_delegate(handle),
 				Action::ProxyCall => Self::proxy_call(handle),
+				Action::ProxyCallBatch => Self::proxy_call_batch(handle),
+				Action::ProxyCallWithSignature => Self::proxy_call_with_signature(handle),
+				Action::SignedProxyNonce => Self::signed_proxy_nonce(handle),
 				// Ownable
 				Action::Owner => Self::owner(handle),
 				Action::RenounceOwnership => Self::renounce_ownership(handle),
 				Action::TransferOwnership => Self::transfer_ownership(handle),
+				Action::RecoveryConfig => Self::recovery_config(handle),
+				Action::ActiveRecovery => Self::active_recovery(handle),
+				Action::SetRecoveryConfig => Self::set_recovery_config(handle),
+				Action::RemoveRecoveryConfig => Self::remove_recovery_config(handle),
+				Action::InitiateRecovery => Self::initiate_recovery(handle),
+				Action::ApproveRecovery => Self::approve_recovery(handle),
+				Action::CancelRecovery => Self::cancel_recovery(handle),
+				Action::ClaimRecovery => Self::claim_recovery(handle),
 			}
 		};
 		return Some(result)
@@ -211,11 +244,20 @@ where
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 
 		let mut proxy_type: u8 = 0; // ProxyType.NoPermission
-		if let Some(proxy_def) = pallet_proxy::Pallet::<Runtime>::proxies(futurepass)
+		if let Some(proxy_def) = pallet_proxy::Pallet::<Runtime>::proxies(futurepass.clone())
 			.0
 			.iter()
 			.find(|pd| pd.delegate == delegate.into())
 		{
+			// expired delegates have no permission
+			handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+			if pallet_futurepass::Pallet::<Runtime>::is_delegate_expired(
+				&futurepass,
+				&delegate.into(),
+			) {
+				return Ok(succeed(EvmDataWriter::new().write::<u8>(proxy_type).build()))
+			}
+
 			proxy_type =
 				proxy_def.proxy_type.clone().try_into().map_err(|_e| {
 					RevertReason::custom("Futurepass: ProxyType conversion failure")
@@ -301,30 +343,170 @@ where
 			value: U256,
 			call_data: BoundedBytes<GetCallDataLimit>
 		});
+		let caller = handle.context().caller;
+		Self::do_proxy_call(handle, caller, call_type, call_to, value, call_data)
+	}
+
+	fn proxy_call_with_signature(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		// the nonce is written before the call
+		handle.check_function_modifier(FunctionModifier::Payable)?;
+		read_args!(handle, {
+			delegate: Address,
+			call_type: u8,
+			call_to: Address,
+			value: U256,
+			call_data: BoundedBytes<GetCallDataLimit>,
+			nonce: u32,
+			deadline: u32,
+			signature: BoundedBytes<GetSignatureLimit>
+		});
+		let futurepass: Runtime::AccountId = handle.code_address().into();
+		let delegate: H160 = delegate.into();
+		let signature: [u8; 65] = signature
+			.inner
+			.try_into()
+			.map_err(|_e| RevertReason::custom("Futurepass: Signature length mismatch"))?;
+
+		// verify the delegate signed the call and use the nonce
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		let struct_hash = pallet_futurepass::Pallet::<Runtime>::proxy_evm_call_struct_hash(
+			delegate,
+			call_type,
+			call_to.into(),
+			value,
+			&call_data.inner,
+			nonce,
+			deadline,
+		);
+		pallet_futurepass::Pallet::<Runtime>::verify_signed_proxy_call(
+			&futurepass,
+			&delegate.into(),
+			nonce,
+			deadline,
+			struct_hash,
+			&signature,
+		)
+		.map_err(|e| {
+			let error: &'static str = e.into();
+			RevertReason::custom(alloc::format!("Futurepass: {}", error))
+		})?;
+		pallet_futurepass::SignedProxyNonces::<Runtime>::insert(
+			&futurepass,
+			&Runtime::AccountId::from(delegate),
+			nonce.saturating_add(1),
+		);
+
+		Self::do_proxy_call(handle, delegate, call_type, call_to, value, call_data)
+	}
+
+	fn signed_proxy_nonce(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		read_args!(handle, { delegate: Address });
+		let futurepass: Runtime::AccountId = handle.code_address().into();
+		let delegate: H160 = delegate.into();
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let nonce =
+			pallet_futurepass::SignedProxyNonces::<Runtime>::get(&futurepass, &delegate.into());
+
+		Ok(succeed(EvmDataWriter::new().write(nonce).build()))
+	}
+
+	/// Proxy the call on behalf of the delegate `who`
+	fn do_proxy_call(
+		handle: &mut impl PrecompileHandle,
+		who: H160,
+		call_type: u8,
+		call_to: Address,
+		value: U256,
+		call_data: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult<PrecompileOutput> {
 		let call_type: CallType = call_type
 			.try_into()
 			.map_err(|err| RevertReason::custom(alloc::format!("Futurepass: {}", err)))?;
 
-		// restrict delegate access to whitelist
+		Self::ensure_whitelisted_call(handle.code_address(), who, &call_to, &call_data)?;
+
+		let evm_subcall = EvmSubCall { to: call_to, value, call_data };
+		Self::do_proxy(handle, handle.code_address(), who, call_type, evm_subcall)
+	}
+
+	/// Restrict delegate access to the futurepass precompile to the whitelist
+	fn ensure_whitelisted_call(
+		futurepass: H160,
+		who: H160,
+		call_to: &Address,
+		call_data: &BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult {
 		if call_to.0.as_bytes().starts_with(FUTUREPASS_PRECOMPILE_ADDRESS_PREFIX) {
-			let sub_call_selector = &call_data.inner[..4];
-			if sub_call_selector ==
-				&keccak256!("registerDelegateWithSignature(address,uint8,uint32,bytes)")[..4] ||
-				sub_call_selector == &keccak256!("unregisterDelegate(address)")[..4] ||
-				sub_call_selector == &keccak256!("transferOwnership(address)")[..4]
+			let sub_call_selector = call_data.inner.get(..4);
+			if [
+				keccak256!("registerDelegateWithSignature(address,uint8,uint32,bytes)"),
+				keccak256!("unregisterDelegate(address)"),
+				keccak256!("transferOwnership(address)"),
+				keccak256!("setRecoveryConfig(address[],uint32)"),
+				keccak256!("removeRecoveryConfig()"),
+				keccak256!("initiateRecovery(address)"),
+				keccak256!("approveRecovery()"),
+				keccak256!("cancelRecovery()"),
+				keccak256!("claimRecovery()"),
+			]
+			.iter()
+			.any(|selector| sub_call_selector == Some(&selector[..4]))
 			{
-				let futurepass: H160 = handle.code_address();
-				let caller: H160 = handle.context().caller;
 				ensure!(
-					pallet_futurepass::Holders::<Runtime>::get(&Runtime::AccountId::from(caller)) ==
+					pallet_futurepass::Holders::<Runtime>::get(&Runtime::AccountId::from(who)) ==
 						Some(futurepass.into()),
 					revert("Futurepass: NotFuturepassOwner")
 				);
 			}
 		}
+		Ok(())
+	}
+
+	fn proxy_call_batch(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		read_args!(handle, {
+			calls: precompile_utils::data::BoundedVec<
+				(u8, Address, U256, BoundedBytes<GetCallDataLimit>),
+				<Runtime as pallet_futurepass::Config>::MaxProxyBatchCalls,
+			>,
+			atomic: bool
+		});
+		let futurepass = handle.code_address();
+		let caller = handle.context().caller;
+
+		// sub calls are checked individually, the whitelist of futurepass calls is applied to the
+		// batch as a whole
+		let calls = calls.into_vec();
+		for (_, call_to, _, call_data) in calls.iter() {
+			Self::ensure_whitelisted_call(futurepass, caller, call_to, call_data)?;
+		}
+
+		// sub calls which revert are skipped in best-effort mode, while calls the delegate may
+		// not make revert the batch in either mode
+		let mut results = Vec::with_capacity(calls.len());
+		for (call_type, call_to, value, call_data) in calls {
+			let call_type: CallType = call_type
+				.try_into()
+				.map_err(|err| RevertReason::custom(alloc::format!("Futurepass: {}", err)))?;
+			let evm_subcall = EvmSubCall { to: call_to, value, call_data };
+			let (reason, output) =
+				Self::do_proxy_subcall(handle, futurepass, caller, call_type, evm_subcall)?;
+			match reason {
+				ExitReason::Succeed(_) => results.push(true),
+				ExitReason::Fatal(exit_status) =>
+					return Err(PrecompileFailure::Fatal { exit_status }),
+				ExitReason::Revert(exit_status) if atomic =>
+					return Err(PrecompileFailure::Revert { exit_status, output }),
+				ExitReason::Error(exit_status) if atomic =>
+					return Err(PrecompileFailure::Error { exit_status }),
+				ExitReason::Revert(_) | ExitReason::Error(_) => results.push(false),
+			}
+		}
 
-		let evm_subcall = EvmSubCall { to: call_to, call_data };
-		Self::do_proxy(handle, handle.code_address(), call_type, evm_subcall, value)
+		Ok(succeed(EvmDataWriter::new().write(results).build()))
 	}
 
 	fn receive(_handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
@@ -335,18 +517,49 @@ where
 	fn do_proxy(
 		handle: &mut impl PrecompileHandle,
 		futurepass: H160,
+		who: H160,
 		call_type: CallType,
 		evm_subcall: EvmSubCall,
-		value: U256,
 	) -> EvmResult<PrecompileOutput> {
+		let (reason, output) =
+			Self::do_proxy_subcall(handle, futurepass, who, call_type, evm_subcall)?;
+
+		// Return subcall result
+		match reason {
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(exit_status) =>
+				Err(PrecompileFailure::Revert { exit_status, output }),
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Succeed(_) => Ok(succeed(output)),
+		}
+	}
+
+	/// Execute the sub call on behalf of the delegate `who`.
+	/// Returns the sub call's exit reason and output, an error is returned if the delegate may not
+	/// make the call or exceeds its spend limits.
+	fn do_proxy_subcall(
+		handle: &mut impl PrecompileHandle,
+		futurepass: H160,
+		who: H160,
+		call_type: CallType,
+		evm_subcall: EvmSubCall,
+	) -> EvmResult<(ExitReason, Vec<u8>)> {
 		// Read proxy
 		let futurepass_account_id = futurepass.clone().into();
-		let who = handle.context().caller.into();
+		let who = who.into();
 		// find proxy
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 		let def = pallet_proxy::Pallet::<Runtime>::find_proxy(&futurepass_account_id, &who, None)
 			.map_err(|_| RevertReason::custom("Futurepass: Not proxy"))?;
 		ensure!(def.delay.is_zero(), revert("Futurepass: Unannounced")); // no delay for futurepass
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		ensure!(
+			!pallet_futurepass::Pallet::<Runtime>::is_delegate_expired(
+				&futurepass_account_id,
+				&who
+			),
+			revert("Futurepass: DelegateExpired")
+		);
 
 		// Read subcall recipient code
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
@@ -359,7 +572,32 @@ where
 			revert("Futurepass: CallFiltered")
 		);
 
-		let EvmSubCall { to, call_data } = evm_subcall;
+		// Apply delegate policy, delegates with a policy can't create contracts
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let policy =
+			pallet_futurepass::DelegatePolicies::<Runtime>::get(&futurepass_account_id, &who);
+		let mut balances = vec![];
+		if let Some(ref policy) = policy {
+			ensure!(
+				matches!(call_type, CallType::Call | CallType::StaticCall) &&
+					policy.allows_evm_call(&evm_subcall.to.0, &evm_subcall.call_data.inner),
+				revert("Futurepass: CallNotPermitted")
+			);
+			// read balances before and after the call and write spending
+			let spend_limits = policy.spend_limits.len() as u64;
+			handle.record_cost(
+				RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3 * spend_limits),
+			)?;
+			handle.record_cost(
+				RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(spend_limits),
+			)?;
+			balances = pallet_futurepass::Pallet::<Runtime>::spend_limit_balances(
+				&futurepass_account_id,
+				policy,
+			);
+		}
+
+		let EvmSubCall { to, value, call_data } = evm_subcall;
 		let address = to.0;
 
 		// build the sub context. here we update the caller to the futurepass.
@@ -508,14 +746,17 @@ where
 			CallType::DelegateCall => Err(RevertReason::custom("call type not supported"))?,
 		};
 
-		// Return subcall result
-		match reason {
-			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
-			ExitReason::Revert(exit_status) =>
-				Err(PrecompileFailure::Revert { exit_status, output }),
-			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
-			ExitReason::Succeed(_) => Ok(succeed(output)),
+		if let (Some(policy), ExitReason::Succeed(_)) = (policy, &reason) {
+			pallet_futurepass::Pallet::<Runtime>::record_spending(
+				&futurepass_account_id,
+				&who,
+				&policy,
+				balances,
+			)
+			.map_err(|_| RevertReason::custom("Futurepass: SpendLimitExceeded"))?;
 		}
+
+		Ok((reason, output))
 	}
 
 	fn owner(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
@@ -584,4 +825,182 @@ where
 		// Build output.
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
+	fn recovery_config(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let futurepass: Runtime::AccountId = handle.code_address().into();
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let (guardians, threshold): (Vec<Address>, u32) =
+			pallet_futurepass::RecoveryConfigs::<Runtime>::get(&futurepass)
+				.map(|config| {
+					(
+						config
+							.guardians
+							.into_iter()
+							.map(|guardian| Address::from(Into::<H160>::into(guardian)))
+							.collect(),
+						config.threshold,
+					)
+				})
+				.unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(guardians).write(threshold).build()))
+	}
+
+	fn active_recovery(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let futurepass: Runtime::AccountId = handle.code_address().into();
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let (new_owner, approvals, initiated): (H160, Vec<Address>, u32) =
+			pallet_futurepass::ActiveRecoveries::<Runtime>::get(&futurepass)
+				.map(|recovery| {
+					(
+						recovery.new_owner.into(),
+						recovery
+							.approvals
+							.into_iter()
+							.map(|guardian| Address::from(Into::<H160>::into(guardian)))
+							.collect(),
+						recovery.initiated.unique_saturated_into(),
+					)
+				})
+				.unwrap_or_default();
+
+		Ok(succeed(
+			EvmDataWriter::new()
+				.write(Address::from(new_owner))
+				.write(approvals)
+				.write(initiated)
+				.build(),
+		))
+	}
+
+	fn set_recovery_config(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		read_args!(handle, { guardians: Vec<Address>, threshold: u32 });
+		let guardians: Vec<Runtime::AccountId> =
+			guardians.into_iter().map(|guardian| H160::from(guardian).into()).collect();
+		let guardians = BoundedVec::try_from(guardians)
+			.map_err(|_| RevertReason::custom("Futurepass: Too many guardians"))?;
+		let caller = handle.context().caller;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_futurepass::Call::<Runtime>::set_recovery_config {
+				futurepass: handle.code_address().into(),
+				guardians,
+				threshold,
+			},
+		)?;
+
+		Ok(succeed([]))
+	}
+
+	fn remove_recovery_config(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let caller = handle.context().caller;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_futurepass::Call::<Runtime>::remove_recovery_config {
+				futurepass: handle.code_address().into(),
+			},
+		)?;
+
+		Ok(succeed([]))
+	}
+
+	fn initiate_recovery(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(3, 0)?;
+		read_args!(handle, { new_owner: Address });
+		let new_owner: H160 = new_owner.into();
+		let caller = handle.context().caller;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_futurepass::Call::<Runtime>::initiate_recovery {
+				futurepass: handle.code_address().into(),
+				new_owner: new_owner.into(),
+			},
+		)?;
+
+		log3(
+			handle.code_address(),
+			SELECTOR_LOG_FUTUREPASS_RECOVERY_INITIATED,
+			caller,
+			new_owner,
+			vec![],
+		)
+		.record(handle)?;
+
+		Ok(succeed([]))
+	}
+
+	fn approve_recovery(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(2, 0)?;
+		let caller = handle.context().caller;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_futurepass::Call::<Runtime>::approve_recovery {
+				futurepass: handle.code_address().into(),
+			},
+		)?;
+
+		log2(handle.code_address(), SELECTOR_LOG_FUTUREPASS_RECOVERY_APPROVED, caller, vec![])
+			.record(handle)?;
+
+		Ok(succeed([]))
+	}
+
+	fn cancel_recovery(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(1, 0)?;
+		let caller = handle.context().caller;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_futurepass::Call::<Runtime>::cancel_recovery {
+				futurepass: handle.code_address().into(),
+			},
+		)?;
+
+		log1(handle.code_address(), SELECTOR_LOG_FUTUREPASS_RECOVERY_CANCELLED, vec![])
+			.record(handle)?;
+
+		Ok(succeed([]))
+	}
+
+	fn claim_recovery(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(3, 0)?;
+		let futurepass: Runtime::AccountId = handle.code_address().into();
+		let caller = handle.context().caller;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 2)?;
+		let old_owner: H160 = <Runtime as pallet_futurepass::Config>::Proxy::owner(&futurepass)
+			.map(|owner: Runtime::AccountId| owner.into())
+			.unwrap_or_default();
+		let new_owner: H160 = pallet_futurepass::ActiveRecoveries::<Runtime>::get(&futurepass)
+			.map(|recovery| recovery.new_owner.into())
+			.unwrap_or_default();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			pallet_futurepass::Call::<Runtime>::claim_recovery { futurepass },
+		)?;
+
+		// emit OwnershipTransferred(address,address) event
+		log3(
+			handle.code_address(),
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			old_owner,
+			new_owner,
+			vec![],
+		)
+		.record(handle)?;
+
+		Ok(succeed([]))
+	}
 }
@@ -17,13 +17,16 @@
 extern crate alloc;
 
 use fp_evm::{PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use frame_support::traits::{ConstU32, UnixTime};
 use pallet_erc20_peg::{types::WithdrawCallOrigin, WeightInfo as Erc20PegWeightInfo};
+use pallet_ethy::WeightInfo as EthyWeightInfo;
 use pallet_evm::{GasWeightMapping, Precompile};
 use pallet_nft_peg::WeightInfo as NftPegWeightInfo;
 use precompile_utils::{
 	constants::{ERC20_PRECOMPILE_ADDRESS_PREFIX, ERC721_PRECOMPILE_ADDRESS_PREFIX},
 	prelude::*,
 };
+use seed_pallet_common::EthCallOracle;
 use seed_primitives::{AssetId, Balance, CollectionUuid, SerialNumber};
 use sp_core::{H160, H256, U256};
 use sp_runtime::{traits::SaturatedConversion, BoundedVec};
@@ -39,6 +42,14 @@ pub const SELECTOR_LOG_ERC20_WITHDRAWAL: [u8; 32] =
 pub const SELECTOR_LOG_ERC721_WITHDRAWAL: [u8; 32] =
 	keccak256!("Erc721Withdrawal(uint64,address,address,uint32[])");
 
+/// Solidity selector of the RemoteCall log, which is the Keccak of the Log signature.
+/// call_id, caller, target
+pub const SELECTOR_LOG_REMOTE_CALL: [u8; 32] = keccak256!("RemoteCall(uint64,address,address)");
+
+/// Maximum length of the calldata forwarded by `remoteCall`
+pub const REMOTE_CALL_INPUT_LIMIT: u32 = 2_048;
+type GetRemoteCallInputLimit = ConstU32<REMOTE_CALL_INPUT_LIMIT>;
+
 #[generate_function_selector]
 #[derive(Debug, PartialEq)]
 pub enum Action {
@@ -48,6 +59,9 @@ pub enum Action {
 	/// Withdraw an ERC721 token
 	/// (beneficiary, token_addresses+, serial_numbers)
 	Erc721Withdraw = "erc721Withdraw(address,address[],uint32[][])",
+	/// Request a notarized eth_call on the bridged Ethereum network
+	/// (target, input)
+	RemoteCall = "remoteCall(address,bytes)",
 }
 
 /// Provides access to the peg pallets
@@ -65,6 +79,7 @@ where
 	Runtime: frame_system::Config
 		+ pallet_nft_peg::Config
 		+ pallet_erc20_peg::Config
+		+ pallet_ethy::Config
 		+ pallet_evm::Config,
 	Runtime: ErcIdConversion<CollectionUuid, EvmId = Address>
 		+ ErcIdConversion<AssetId, EvmId = Address>,
@@ -83,6 +98,7 @@ where
 			match selector {
 				Action::Erc20Withdraw => Self::erc20_withdraw(handle),
 				Action::Erc721Withdraw => Self::erc721_withdraw(handle),
+				Action::RemoteCall => Self::remote_call(handle),
 			}
 		};
 		return result
@@ -101,6 +117,7 @@ where
 	Runtime: frame_system::Config
 		+ pallet_nft_peg::Config
 		+ pallet_erc20_peg::Config
+		+ pallet_ethy::Config
 		+ pallet_evm::Config,
 	Runtime: ErcIdConversion<CollectionUuid, EvmId = Address>
 		+ ErcIdConversion<AssetId, EvmId = Address>,
@@ -274,6 +291,44 @@ where
 		Ok(succeed(EvmDataWriter::new().write(U256::from(event_proof_id)).build()))
 	}
 
+	fn remote_call(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(4, 0)?;
+
+		// Parse input.
+		read_args!(
+			handle,
+			{
+				target: Address,
+				input: BoundedBytes<GetRemoteCallInputLimit>
+			}
+		);
+		let target: H160 = target.into();
+		let input = input.into_vec();
+
+		// Manually record gas
+		handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(
+			<Runtime as pallet_ethy::Config>::WeightInfo::checked_eth_call(),
+		))?;
+
+		// No block hint is given so validators check the call at the latest (or finalized) block.
+		// The result is delivered to the runtime's `EthCallSubscribers`
+		let timestamp = <Runtime as pallet_ethy::Config>::UnixTime::now().as_secs();
+		let call_id =
+			pallet_ethy::Pallet::<Runtime>::checked_eth_call(&target, &input, timestamp, 0, 0);
+
+		log4(
+			handle.code_address(),
+			SELECTOR_LOG_REMOTE_CALL,
+			H256::from_low_u64_be(call_id),
+			handle.context().caller,
+			target,
+			Vec::<u8>::new(),
+		)
+		.record(handle)?;
+
+		Ok(succeed(EvmDataWriter::new().write(U256::from(call_id)).build()))
+	}
+
 	// Convert a vector of U256 serial numbers into a bounded vector of serial numbers
 	fn bound_serial_numbers(
 		serial_numbers: Vec<U256>,
@@ -39,6 +39,8 @@ mod precompile_addresses {
 	/// The decoded location for the fee proxy function selector
 	/// 0x04BB = 00000100 10111011
 	pub const FEE_PROXY_ADDRESS: u64 = 1211; // 0x04BB
+	/// Precompile address for fee preferences
+	pub const FEE_PREFERENCE_PRECOMPILE: u64 = 1212; // 0x04BC
 	/// Function selector for call_with_fee_preferences (deprecated)
 	/// bytes4(keccak256(bytes("callWithFeePreferences(address,uint128,address,bytes)")));
 	#[deprecated(note = "Use `callWithFeePreferences(address,address,bytes)` instead")]
@@ -46,6 +48,9 @@ mod precompile_addresses {
 	/// Function selector for call_with_fee_preferences
 	/// bytes4(keccak256(bytes("callWithFeePreferences(address,address,bytes)")));
 	pub const FEE_FUNCTION_SELECTOR: [u8; 4] = [0xf6, 0x09, 0x82, 0x86];
+	/// Function selector for call_with_sponsor
+	/// bytes4(keccak256(bytes("callWithSponsor(address,address,bytes)")));
+	pub const FEE_SPONSOR_FUNCTION_SELECTOR: [u8; 4] = [0xdc, 0x37, 0xb9, 0xf9];
 	/// Precompile address for futurepass registar
 	pub const FUTUREPASS_REGISTRAR_PRECOMPILE: u64 = 65_535; // 0xFFFF
 	/// Precompile address for marketplace
@@ -93,6 +93,12 @@ impl<S> BoundedBytes<S> {
 	}
 }
 
+impl<S> From<Vec<u8>> for BoundedBytes<S> {
+	fn from(inner: Vec<u8>) -> Self {
+		Self { inner, _phantom: PhantomData }
+	}
+}
+
 /// The `bytes<X>` type of Solidity. X can be any number less than 32
 /// This will post pad the value with zeros upto a total of 32 bytes
 #[derive(Clone, Debug, Eq, PartialEq)]
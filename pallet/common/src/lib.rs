@@ -31,7 +31,8 @@ use sp_core::{H160, U256};
 use sp_std::{fmt::Debug, vec::Vec};
 
 use seed_primitives::{
-	ethy::{EventClaimId, EventProofId},
+	ethy::{EthyChainId, EventClaimId, EventProofId},
+	xrpl::Xls20TokenId,
 	AssetId, Balance, CollectionUuid, MetadataScheme, SerialNumber, TokenId,
 };
 
@@ -177,7 +178,7 @@ pub type EventRouterResult = Result<Weight, (Weight, EventRouterError)>;
 /// 	B: EthereumEventSubscriber,
 /// 	C: EthereumEventSubscriber,
 /// {
-/// 	fn route(destination, source, data) -> EventRouterResult {
+/// 	fn route(chain_id, destination, source, data) -> EventRouterResult {
 /// 		match destination {
 /// 			A::Destination => A::on_event(source, data).map_err(|(w, err)| (w, EventRouterError::FailedProcessing(err))),
 /// 			B::Destination => B::on_event(source, data).map_err(|(w, err)| (w, EventRouterError::FailedProcessing(err))),
@@ -189,10 +190,22 @@ pub type EventRouterResult = Result<Weight, (Weight, EventRouterError)>;
 /// ```
 pub trait EthereumEventRouter {
 	/// Route an event to a handler at `destination`
+	/// - `chain_id` the EVM chain the event was observed on
 	/// - `source` the sender address on Ethereum
 	/// - `destination` the intended handler (pseudo) address
 	/// - `data` the Ethereum ABI encoded event data
-	fn route(source: &H160, destination: &H160, data: &[u8]) -> EventRouterResult;
+	fn route(
+		chain_id: EthyChainId,
+		source: &H160,
+		destination: &H160,
+		data: &[u8],
+	) -> EventRouterResult;
+
+	/// Whether events observed on the EVM chain `chain_id` can be routed to any handler
+	/// Defaults to Ethereum only, as subscribers verify sources against their Ethereum contracts
+	fn is_routable(chain_id: EthyChainId) -> bool {
+		chain_id == EthyChainId::Ethereum
+	}
 }
 
 /// Result of processing an event by an `EthereumEventSubscriber`
@@ -254,6 +267,21 @@ pub trait EthereumBridge {
 		destination: &H160,
 		message: &[u8],
 	) -> Result<EventProofId, DispatchError>;
+
+	/// Send an event via the bridge for relaying to the EVM chain `chain_id`
+	///
+	/// Returns a unique event proofId on success
+	fn send_event_to(
+		chain_id: EthyChainId,
+		source: &H160,
+		destination: &H160,
+		message: &[u8],
+	) -> Result<EventProofId, DispatchError> {
+		match chain_id {
+			EthyChainId::Ethereum => Self::send_event(source, destination, message),
+			_ => Err(DispatchError::Other("Unsupported chain")),
+		}
+	}
 }
 
 /// Interface from xrpl-bridge to ethy
@@ -274,7 +302,7 @@ pub trait EthyToXrplBridgeAdapter<AccountId> {
 	) -> Result<EventProofId, DispatchError>;
 }
 
-#[derive(Encode, Decode, Debug, PartialEq, TypeInfo)]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo)]
 pub enum EthCallFailure {
 	/// Return data exceeds limit
 	ReturnDataExceedsLimit,
@@ -322,9 +350,10 @@ pub trait EthCallOracleSubscriber {
 	type CallId;
 	/// Receives verified details about prior `EthCallOracle::checked_eth_call` requests upon their
 	/// successful completion
+	/// `return_data` is the abi encoded returndata of the call (bounded length)
 	fn on_eth_call_complete(
 		call_id: Self::CallId,
-		return_data: &[u8; 32],
+		return_data: &[u8],
 		block_number: u64,
 		block_timestamp: u64,
 	);
@@ -336,7 +365,7 @@ impl EthCallOracleSubscriber for () {
 	type CallId = EventClaimId;
 	fn on_eth_call_complete(
 		_call_id: Self::CallId,
-		_return_data: &[u8; 32],
+		_return_data: &[u8],
 		_block_number: u64,
 		_block_timestamp: u64,
 	) {
@@ -356,6 +385,30 @@ pub trait Xls20MintRequest {
 	) -> DispatchResult;
 }
 
+/// Interface from xrpl-bridge to xls20 for bridging XLS-20 tokens
+pub trait Xls20Ext {
+	type AccountId;
+
+	/// Lock the XLS-20 compatible token `token_id` owned by `who` ahead of a withdrawal to XRPL
+	/// Returns the XLS-20 token id of the locked token
+	fn lock_xls20_token(
+		who: &Self::AccountId,
+		token_id: TokenId,
+	) -> Result<Xls20TokenId, DispatchError>;
+
+	/// Release the locked token mapped to `xls20_token_id` to `who` after a deposit from XRPL
+	/// Mapped tokens that do not exist on Root yet are minted to `who`
+	/// Returns the Root native token id of the released token
+	fn unlock_xls20_token(
+		who: &Self::AccountId,
+		xls20_token_id: &Xls20TokenId,
+	) -> Result<TokenId, DispatchError>;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	/// Create an XLS-20 mapped token owned by `owner` for benchmarking
+	fn create_benchmark_token(owner: &Self::AccountId) -> TokenId;
+}
+
 pub trait FeeConfig {
 	fn evm_base_fee_per_gas() -> U256;
 	fn weight_multiplier() -> Perbill;
@@ -39,7 +39,7 @@ pub mod test_prelude {
 		traits::{BlakeTwo256, IdentityLookup},
 		BoundedVec,
 		DispatchError::BadOrigin,
-		Permill,
+		Permill, Perquintill,
 	};
 	pub use sp_std::{vec, vec::Vec};
 }
@@ -322,10 +322,15 @@ macro_rules! impl_pallet_marketplace_config {
 #[macro_export]
 macro_rules! impl_pallet_fee_control_config {
 	($test:ident) => {
+		parameter_types! {
+			pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+		}
+
 		impl pallet_fee_control::Config for $test {
 			type RuntimeEvent = RuntimeEvent;
 			type WeightInfo = ();
 			type FeeConfig = ();
+			type TargetBlockFullness = TargetBlockFullness;
 		}
 	};
 }
@@ -443,6 +448,7 @@ macro_rules! impl_pallet_fee_proxy_config {
 
 		parameter_types! {
 			pub const XrpAssetId: AssetId = MOCK_PAYMENT_ASSET_ID;
+			pub const FeeProxyPalletId: PalletId = PalletId(*b"feeproxy");
 		}
 
 		pub type XrpCurrency = pallet_assets_ext::AssetCurrency<Test, XrpAssetId>;
@@ -456,6 +462,10 @@ macro_rules! impl_pallet_fee_proxy_config {
 			type ErcIdConversion = Self;
 			type EVMBaseFeeProvider = FeeControl;
 			type MaintenanceChecker = ();
+			type MultiCurrency = AssetsExt;
+			type PalletId = FeeProxyPalletId;
+			type MaxSponsorshipItems = frame_support::traits::ConstU32<4>;
+			type WeightInfo = ();
 		}
 	};
 }
@@ -657,8 +667,14 @@ macro_rules! impl_pallet_futurepass_config {
 			type WeightInfo = ();
 
 			type FuturepassMigrator = MockMigrationProvider;
-			#[cfg(feature = "runtime-benchmarks")]
 			type MultiCurrency = pallet_assets_ext::Pallet<Test>;
+			type NestedCalls = ();
+			type MaxDelegatePolicyItems = frame_support::traits::ConstU32<4>;
+			type MaxGuardians = frame_support::traits::ConstU32<3>;
+			type RecoveryDelay = frame_support::traits::ConstU64<10>;
+			type RecoveryExpiry = frame_support::traits::ConstU64<20>;
+			type ChainId = frame_support::traits::ConstU64<7672>;
+			type MaxProxyBatchCalls = frame_support::traits::ConstU32<4>;
 		}
 	};
 }
@@ -985,6 +985,64 @@ where
 		Ok(amounts)
 	}
 
+	/// Returns the path from `asset_in` to `asset_out` through enabled trading pairs, at most
+	/// `TradingPathLimit` assets long, which requires the least `asset_in` to receive `amount_out`
+	/// along with the amounts in for that path
+	///
+	/// Falls back to the direct path when no route exists so that the caller receives the same
+	/// error as `get_amounts_in` would give
+	pub fn get_best_path_in(
+		amount_out: Balance,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> sp_std::result::Result<(Vec<AssetId>, Vec<Balance>), DispatchError> {
+		let path_limit: usize = T::TradingPathLimit::get().saturated_into();
+		let pairs: Vec<TradingPair> = TradingPairStatuses::<T>::iter()
+			.filter_map(|(pair, status)| {
+				matches!(status, TradingPairStatus::Enabled).then_some(pair)
+			})
+			.collect();
+
+		let mut best: Option<(Vec<AssetId>, Vec<Balance>)> = None;
+		let mut paths: Vec<Vec<AssetId>> = vec![vec![asset_in]];
+		while let Some(path) = paths.pop() {
+			let last = path[path.len() - 1];
+			if last == asset_out {
+				if let Ok(amounts) = Self::get_amounts_in(amount_out, &path) {
+					if best.as_ref().map_or(true, |(_, best_amounts)| amounts[0] < best_amounts[0])
+					{
+						best = Some((path, amounts));
+					}
+				}
+				continue
+			}
+			if path.len() >= path_limit {
+				continue
+			}
+			for pair in pairs.iter() {
+				let next = match (pair.0 == last, pair.1 == last) {
+					(true, _) => pair.1,
+					(_, true) => pair.0,
+					_ => continue,
+				};
+				if !path.contains(&next) {
+					let mut next_path = path.clone();
+					next_path.push(next);
+					paths.push(next_path);
+				}
+			}
+		}
+
+		match best {
+			Some(best) => Ok(best),
+			None => {
+				let path = vec![asset_in, asset_out];
+				let amounts = Self::get_amounts_in(amount_out, &path)?;
+				Ok((path, amounts))
+			},
+		}
+	}
+
 	// Uniswapv2 `_swap` implementation in rust
 	// TODO: may need re-entrancy lock for this function
 	fn _swap(
@@ -1209,6 +1209,66 @@ fn perform_multiple_pair_swap_with_exact_supply() {
 	});
 }
 
+#[test]
+fn get_best_path_in() {
+	TestExt::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let alice: AccountId = create_account(1);
+
+		let a = AssetsExt::create(&alice, None).unwrap();
+		let b = AssetsExt::create(&alice, None).unwrap();
+		let c = AssetsExt::create(&alice, None).unwrap();
+		let d = AssetsExt::create(&alice, None).unwrap();
+		for asset in [a, b, c, d] {
+			assert_ok!(AssetsExt::mint_into(asset, &alice, to_eth(100)));
+		}
+
+		// no route between a and c
+		assert_noop!(Dex::get_best_path_in(10_000u128, a, c), Error::<Test>::MustBeEnabled);
+
+		// shallow direct pool (a-c), deep pools via b (a-b, b-c)
+		for (token_a, token_b, amount) in
+			[(a, c, 1_000_000u128), (a, b, 100_000_000u128), (b, c, 100_000_000u128)]
+		{
+			assert_ok!(Dex::add_liquidity(
+				RuntimeOrigin::signed(alice),
+				token_a,
+				token_b,
+				amount,
+				amount,
+				amount,
+				amount,
+				None,
+				None,
+			));
+		}
+
+		// the route via b needs less of a than the direct pool
+		let direct = Dex::get_amounts_in(10_000u128, &[a, c]).unwrap();
+		let via_b = Dex::get_amounts_in(10_000u128, &[a, b, c]).unwrap();
+		assert!(via_b[0] < direct[0]);
+		assert_ok!(Dex::get_best_path_in(10_000u128, a, c), (vec![a, b, c], via_b));
+
+		// paths longer than the trading path limit (3) are not considered
+		assert_ok!(Dex::add_liquidity(
+			RuntimeOrigin::signed(alice),
+			c,
+			d,
+			100_000_000u128,
+			100_000_000u128,
+			100_000_000u128,
+			100_000_000u128,
+			None,
+			None,
+		));
+		assert_eq!(Dex::get_best_path_in(10_000u128, a, d).unwrap().0, vec![a, c, d]);
+
+		// the direct pool is used once it is the cheapest route
+		assert_ok!(Dex::disable_trading_pair(RuntimeOrigin::root(), b, c));
+		assert_ok!(Dex::get_best_path_in(10_000u128, a, c), (vec![a, c], direct));
+	});
+}
+
 #[test]
 fn swap_with_exact_target() {
 	TestExt::default().build().execute_with(|| {
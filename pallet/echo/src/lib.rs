@@ -19,6 +19,8 @@
 //! Users can call the ping() extrinsic which will submit an event to Ethereum
 //! The pallet will subscribe to EthereumEventSubscriber so it can verify that the ping was received
 //! on Ethereum
+//! The pallet also subscribes to the results of checked eth_calls (EthCallOracleSubscriber), e.g.
+//! remote calls requested by EVM contracts, and deposits them as events
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
@@ -30,7 +32,9 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
-use seed_pallet_common::{EthereumBridge, EthereumEventSubscriber, OnEventResult};
+use seed_pallet_common::{
+	EthCallFailure, EthCallOracleSubscriber, EthereumBridge, EthereumEventSubscriber, OnEventResult,
+};
 use seed_primitives::{ethy::EventProofId, AccountId};
 use sp_core::H160;
 use sp_std::prelude::*;
@@ -88,6 +92,15 @@ pub mod pallet {
 		PingReceived { session_id: u64, source: H160, data: Vec<u8> },
 		/// A pong message was sent to Ethereum
 		PongSent { session_id: u64, source: H160, destination: H160, event_proof_id: EventProofId },
+		/// A checked eth_call completed with the abi encoded return data
+		EthCallComplete {
+			call_id: u64,
+			return_data: Vec<u8>,
+			block_number: u64,
+			block_timestamp: u64,
+		},
+		/// A checked eth_call failed
+		EthCallFailed { call_id: u64, reason: EthCallFailure },
 	}
 
 	#[pallet::error]
@@ -138,6 +151,29 @@ pub mod pallet {
 	}
 }
 
+// Implement Subscriber to receive the results of checked eth_calls
+impl<T: Config> EthCallOracleSubscriber for Pallet<T> {
+	type CallId = u64;
+
+	fn on_eth_call_complete(
+		call_id: Self::CallId,
+		return_data: &[u8],
+		block_number: u64,
+		block_timestamp: u64,
+	) {
+		Self::deposit_event(Event::EthCallComplete {
+			call_id,
+			return_data: return_data.to_vec(),
+			block_number,
+			block_timestamp,
+		});
+	}
+
+	fn on_eth_call_failed(call_id: Self::CallId, reason: EthCallFailure) {
+		Self::deposit_event(Event::EthCallFailed { call_id, reason });
+	}
+}
+
 // Implement Subscriber to receive events from Ethereum
 impl<T: Config> EthereumEventSubscriber for Pallet<T> {
 	type Address = T::PalletId;
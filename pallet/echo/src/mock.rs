@@ -17,7 +17,7 @@ use crate::{self as pallet_echo, Config, Weight, PING};
 use ethabi::{ParamType, Token};
 use frame_support::storage::StorageValue;
 use seed_pallet_common::test_prelude::*;
-use seed_primitives::ethy::EventProofId;
+use seed_primitives::ethy::{EthyChainId, EventProofId};
 use sp_runtime::SaturatedConversion;
 
 construct_runtime!(
@@ -80,7 +80,12 @@ impl EthereumBridge for MockBridge {
 				{
 					let ping_or_pong: u8 = (*ping_or_pong).saturated_into();
 					if ping_or_pong == PING {
-						let _ = MockEthereumEventRouter::route(source, destination, event);
+						let _ = MockEthereumEventRouter::route(
+							EthyChainId::Ethereum,
+							source,
+							destination,
+							event,
+						);
 					}
 				}
 			},
@@ -95,10 +100,16 @@ pub struct MockEthereumEventRouter;
 
 impl EthereumEventRouter for MockEthereumEventRouter {
 	/// Route an event to a handler at `destination`
+	/// - `chain_id` the EVM chain the event was observed on
 	/// - `source` the sender address on Ethereum
 	/// - `destination` the intended handler (pseudo) address
 	/// - `data` the Ethereum ABI encoded event data
-	fn route(source: &H160, destination: &H160, data: &[u8]) -> EventRouterResult {
+	fn route(
+		_chain_id: EthyChainId,
+		source: &H160,
+		destination: &H160,
+		data: &[u8],
+	) -> EventRouterResult {
 		// Route event to specific subscriber pallet
 		if destination == &<pallet_echo::Pallet<Test> as EthereumEventSubscriber>::address() {
 			<pallet_echo::Pallet<Test> as EthereumEventSubscriber>::process_event(source, data)
@@ -18,6 +18,7 @@ use super::*;
 use crate::mock::{test_storage::NextEventProofId, Echo, MockEthereumEventRouter, System, Test};
 use frame_support::storage::StorageValue;
 use seed_pallet_common::test_prelude::*;
+use seed_primitives::ethy::EthyChainId;
 use sp_runtime::traits::AccountIdConversion;
 
 #[test]
@@ -76,7 +77,12 @@ fn ping_works_from_ethereum() {
 			Token::Uint(next_session_id.into()),
 			Token::Address(destination),
 		]);
-		assert_ok!(MockEthereumEventRouter::route(&caller, &destination, data.clone().as_slice()));
+		assert_ok!(MockEthereumEventRouter::route(
+			EthyChainId::Ethereum,
+			&caller,
+			&destination,
+			data.clone().as_slice()
+		));
 
 		// Check Ping event thrown
 		System::assert_has_event(
@@ -96,3 +102,25 @@ fn ping_works_from_ethereum() {
 		);
 	});
 }
+
+#[test]
+fn eth_call_results_are_deposited() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let return_data = vec![0x3f_u8; 64];
+		<Echo as EthCallOracleSubscriber>::on_eth_call_complete(1, &return_data, 555, 1_000);
+		System::assert_last_event(
+			Event::EthCallComplete {
+				call_id: 1,
+				return_data,
+				block_number: 555,
+				block_timestamp: 1_000,
+			}
+			.into(),
+		);
+
+		<Echo as EthCallOracleSubscriber>::on_eth_call_failed(2, EthCallFailure::ReturnDataEmpty);
+		System::assert_last_event(
+			Event::EthCallFailed { call_id: 2, reason: EthCallFailure::ReturnDataEmpty }.into(),
+		);
+	});
+}
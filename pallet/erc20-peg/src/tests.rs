@@ -24,6 +24,7 @@ use frame_support::traits::{
 };
 use hex_literal::hex;
 use seed_pallet_common::test_prelude::*;
+use seed_primitives::ethy::EthyChainId;
 
 #[test]
 fn set_peg_contract_address_works() {
@@ -146,6 +147,7 @@ fn deposit_payment_with_ethereum_event_router() {
 			Token::Address(beneficiary),
 		]);
 		assert_ok!(MockEthereumEventRouter::route(
+			EthyChainId::Ethereum,
 			&contract_address,
 			&destination,
 			data.clone().as_slice()
@@ -181,7 +183,12 @@ fn deposit_payment_with_ethereum_event_router_source_address_not_set() {
 			Token::Address(beneficiary),
 		]);
 		assert_noop!(
-			MockEthereumEventRouter::route(&source, &destination, data.clone().as_slice()),
+			MockEthereumEventRouter::route(
+				EthyChainId::Ethereum,
+				&source,
+				&destination,
+				data.clone().as_slice()
+			),
 			(
 				DbWeight::get().reads(2u64),
 				EventRouterError::FailedProcessing(
@@ -220,7 +227,12 @@ fn deposit_payment_with_ethereum_event_router_incorrect_source_address() {
 			Token::Address(beneficiary),
 		]);
 		assert_noop!(
-			MockEthereumEventRouter::route(&source, &destination, data.clone().as_slice()),
+			MockEthereumEventRouter::route(
+				EthyChainId::Ethereum,
+				&source,
+				&destination,
+				data.clone().as_slice()
+			),
 			(
 				DbWeight::get().reads(2u64),
 				EventRouterError::FailedProcessing(
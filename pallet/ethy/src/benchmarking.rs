@@ -0,0 +1,394 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+use super::*;
+
+use frame_benchmarking::{account as bench_account, benchmarks, impl_benchmark_test_suite};
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use seed_pallet_common::{EthCallOracle, EthereumBridge};
+
+use crate::Pallet as EthBridge;
+
+pub fn account<T: Config>(name: &'static str) -> T::AccountId {
+	bench_account(name, 0, 0)
+}
+
+pub fn origin<T: Config>(acc: &T::AccountId) -> RawOrigin<T::AccountId> {
+	RawOrigin::Signed(acc.clone())
+}
+
+/// Create a deterministic notary key from `seed`
+pub fn ethy_id<T: Config>(seed: u8) -> T::EthyId {
+	let mut key = [2_u8; 33];
+	key[32] = seed;
+	T::EthyId::decode(&mut &key[..]).unwrap()
+}
+
+/// Bond and add a relayer to the relayer set
+pub fn setup_relayer<T: Config>() -> T::AccountId {
+	let relayer = account::<T>("Relayer");
+	assert_ok!(T::MultiCurrency::mint_into(
+		T::NativeAssetId::get(),
+		&relayer,
+		T::RelayerBond::get().saturating_mul(10)
+	));
+	assert_ok!(EthBridge::<T>::deposit_relayer_bond(origin::<T>(&relayer).into()));
+	assert_ok!(EthBridge::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone()));
+	relayer
+}
+
+/// ABI encode a bridge event as submitted by the relayer
+pub fn encode_event(event_id: EventClaimId) -> Vec<u8> {
+	ethabi::encode(&[
+		Token::Uint(event_id.into()),
+		Token::Address(H160::from_low_u64_be(123)),
+		Token::Address(H160::from_low_u64_be(456)),
+		Token::Bytes(vec![1_u8; 64]),
+		Token::Uint(0_u64.into()),
+	])
+}
+
+/// Fill the event batch of `chain_id` (one short of being requested)
+pub fn setup_event_batch<T: Config>(chain_id: EthyChainId) {
+	let source = H160::from_low_u64_be(123);
+	let destination = H160::from_low_u64_be(456);
+	for _ in 1..MAX_EVENT_BATCH_SIZE {
+		assert_ok!(EthBridge::<T>::send_event_to(chain_id, &source, &destination, &[1_u8; 64]));
+	}
+}
+
+/// Submit an event claim and challenge it
+pub fn setup_challenged_claim<T: Config>(event_id: EventClaimId) -> T::AccountId {
+	let relayer = setup_relayer::<T>();
+	assert_ok!(EthBridge::<T>::submit_event(
+		origin::<T>(&relayer).into(),
+		EthyChainId::Ethereum,
+		H256::from_low_u64_be(1),
+		encode_event(event_id)
+	));
+	let challenger = account::<T>("Challenger");
+	assert_ok!(T::MultiCurrency::mint_into(
+		T::NativeAssetId::get(),
+		&challenger,
+		T::ChallengeBond::get().saturating_mul(10)
+	));
+	assert_ok!(EthBridge::<T>::submit_challenge(origin::<T>(&challenger).into(), event_id));
+	challenger
+}
+
+benchmarks! {
+	set_xrpl_door_signers {
+		let p in 1 .. (T::MaxNewSigners::get() as u32 - 1);
+		let new_signers: Vec<(T::EthyId, bool)> = (0..p).map(|i| (ethy_id::<T>(i as u8), true)).collect();
+		NotaryKeys::<T>::put(new_signers.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>());
+
+	}: _(RawOrigin::Root, new_signers.clone())
+	verify {
+		for (signer, _) in new_signers {
+			assert!(XrplDoorSigners::<T>::get(signer));
+		}
+	}
+
+	add_relayer {
+		let relayer = account::<T>("Relayer");
+		assert_ok!(T::MultiCurrency::mint_into(
+			T::NativeAssetId::get(),
+			&relayer,
+			T::RelayerBond::get().saturating_mul(10)
+		));
+		assert_ok!(EthBridge::<T>::deposit_relayer_bond(origin::<T>(&relayer).into()));
+
+	}: _(RawOrigin::Root, relayer.clone())
+	verify {
+		assert!(Relayers::<T>::get().contains(&relayer));
+	}
+
+	deposit_relayer_bond {
+		let relayer = account::<T>("Relayer");
+		assert_ok!(T::MultiCurrency::mint_into(
+			T::NativeAssetId::get(),
+			&relayer,
+			T::RelayerBond::get().saturating_mul(10)
+		));
+
+	}: _(origin::<T>(&relayer))
+	verify {
+		assert_eq!(RelayerPaidBond::<T>::get(&relayer), T::RelayerBond::get());
+	}
+
+	withdraw_relayer_bond {
+		let relayer = setup_relayer::<T>();
+		assert_ok!(EthBridge::<T>::unbond_relayer(origin::<T>(&relayer).into()));
+		let unlock_at = RelayerUnbonding::<T>::get(&relayer).unwrap();
+		frame_system::Pallet::<T>::set_block_number(unlock_at);
+
+	}: _(origin::<T>(&relayer))
+	verify {
+		assert_eq!(RelayerPaidBond::<T>::get(&relayer), 0);
+		assert!(RelayerUnbonding::<T>::get(&relayer).is_none());
+	}
+
+	set_event_block_confirmations {
+		let confirmations = 10_u64;
+	}: _(RawOrigin::Root, EthyChainId::Ethereum, confirmations)
+	verify {
+		assert_eq!(EventBlockConfirmations::<T>::get(EthyChainId::Ethereum), confirmations);
+	}
+
+	set_delayed_event_proofs_per_block {
+		let count = 10_u8;
+	}: _(RawOrigin::Root, count)
+	verify {
+		assert_eq!(DelayedEventProofsPerBlock::<T>::get(), count);
+	}
+
+	set_challenge_period {
+		let blocks: T::BlockNumber = 100_u32.into();
+	}: _(RawOrigin::Root, blocks)
+	verify {
+		assert_eq!(ChallengePeriod::<T>::get(), blocks);
+	}
+
+	set_contract_address {
+		let contract_address = EthAddress::from_low_u64_be(123);
+	}: _(RawOrigin::Root, EthyChainId::Ethereum, contract_address)
+	verify {
+		assert_eq!(ContractAddress::<T>::get(EthyChainId::Ethereum), contract_address);
+	}
+
+	set_bridge_paused {
+		let paused = true;
+	}: _(RawOrigin::Root, paused)
+	verify {
+		assert!(BridgePaused::<T>::get());
+	}
+
+	finalise_authorities_change {
+		let next_notary_keys = vec![ethy_id::<T>(1)];
+		BridgePaused::<T>::put(true);
+
+	}: _(RawOrigin::None, next_notary_keys.clone())
+	verify {
+		assert_eq!(NotaryKeys::<T>::get(), next_notary_keys);
+		assert!(!BridgePaused::<T>::get());
+	}
+
+	submit_event {
+		let relayer = setup_relayer::<T>();
+		let event_id: EventClaimId = 1;
+		let event = encode_event(event_id);
+
+	}: _(origin::<T>(&relayer), EthyChainId::Ethereum, H256::from_low_u64_be(1), event)
+	verify {
+		assert_eq!(PendingClaimStatus::<T>::get(event_id), Some(EventClaimStatus::Pending));
+	}
+
+	submit_challenge {
+		let relayer = setup_relayer::<T>();
+		let event_id: EventClaimId = 1;
+		assert_ok!(EthBridge::<T>::submit_event(
+			origin::<T>(&relayer).into(),
+			EthyChainId::Ethereum,
+			H256::from_low_u64_be(1),
+			encode_event(event_id)
+		));
+		let challenger = account::<T>("Challenger");
+		assert_ok!(T::MultiCurrency::mint_into(
+			T::NativeAssetId::get(),
+			&challenger,
+			T::ChallengeBond::get().saturating_mul(10)
+		));
+
+	}: _(origin::<T>(&challenger), event_id)
+	verify {
+		assert_eq!(PendingClaimStatus::<T>::get(event_id), Some(EventClaimStatus::Challenged));
+	}
+
+	submit_notarization {
+		let event_id: EventClaimId = 1;
+		setup_challenged_claim::<T>(event_id);
+		NotaryKeys::<T>::put(vec![ethy_id::<T>(1)]);
+		let payload = NotarizationPayload::Event {
+			event_claim_id: event_id,
+			authority_index: 0,
+			result: EventClaimResult::Valid,
+		};
+		// the signature is checked in `validate_unsigned` only
+		let signature = <<T as Config>::EthyId as RuntimeAppPublic>::Signature::decode(
+			&mut &[0_u8; 65][..]
+		).unwrap();
+
+	}: _(RawOrigin::None, payload, signature)
+	verify {
+		assert_eq!(PendingClaimStatus::<T>::get(event_id), Some(EventClaimStatus::ProvenValid));
+	}
+
+	on_initialize {
+		let block_number: T::BlockNumber = 1_u32.into();
+	}: {
+		EthBridge::<T>::on_initialize(block_number);
+	}
+
+	on_initialize_event_claim {
+		let relayer = setup_relayer::<T>();
+		let event_id: EventClaimId = 1;
+		assert_ok!(EthBridge::<T>::submit_event(
+			origin::<T>(&relayer).into(),
+			EthyChainId::Ethereum,
+			H256::from_low_u64_be(1),
+			encode_event(event_id)
+		));
+		let process_at = <frame_system::Pallet<T>>::block_number() + ChallengePeriod::<T>::get();
+
+	}: {
+		EthBridge::<T>::on_initialize(process_at);
+	}
+	verify {
+		assert_eq!(ProcessedMessageIds::<T>::get(EthyChainId::Ethereum), vec![event_id]);
+		assert!(PendingEventClaims::<T>::get(event_id).is_none());
+	}
+
+	on_initialize_delayed_proof {
+		let event_proof_id: EventProofId = 1;
+		PendingEventProofs::<T>::insert(event_proof_id, EthySigningRequest::XrplTx(vec![1_u8; 32]));
+		DelayedEventProofsPerBlock::<T>::put(1);
+		let block_number: T::BlockNumber = 1_u32.into();
+
+	}: {
+		EthBridge::<T>::on_initialize(block_number);
+	}
+	verify {
+		assert!(PendingEventProofs::<T>::get(event_proof_id).is_none());
+	}
+
+	remove_relayer {
+		let relayer = setup_relayer::<T>();
+	}: _(RawOrigin::Root, relayer.clone())
+	verify {
+		assert!(!Relayers::<T>::get().contains(&relayer));
+		assert!(RelayerUnbonding::<T>::get(&relayer).is_some());
+	}
+
+	unbond_relayer {
+		let relayer = setup_relayer::<T>();
+	}: _(origin::<T>(&relayer))
+	verify {
+		assert!(!Relayers::<T>::get().contains(&relayer));
+		assert!(RelayerUnbonding::<T>::get(&relayer).is_some());
+	}
+
+	set_relayer_submission_mode {
+	}: _(RawOrigin::Root, RelayerSubmissionMode::RoundRobin)
+	verify {
+		assert_eq!(SubmissionMode::<T>::get(), RelayerSubmissionMode::RoundRobin);
+	}
+
+	checked_eth_call {
+		let target = EthAddress::from_low_u64_be(1);
+		let input = vec![1_u8; 100];
+		let call_id = NextEthCallId::<T>::get();
+	}: {
+		EthBridge::<T>::checked_eth_call(&target, &input, 0, 0, 0);
+	}
+	verify {
+		assert!(EthCallRequestInfo::<T>::contains_key(call_id));
+	}
+
+	set_event_finality_mode {
+	}: _(RawOrigin::Root, EthyChainId::Ethereum, EventFinalityMode::Finalized)
+	verify {
+		assert_eq!(EventFinalityModes::<T>::get(EthyChainId::Ethereum), EventFinalityMode::Finalized);
+	}
+
+	set_event_proof_scheme {
+	}: _(RawOrigin::Root, EthyChainId::Ethereum, EventProofScheme::BlsAggregate)
+	verify {
+		assert_eq!(EventProofSchemes::<T>::get(EthyChainId::Ethereum), EventProofScheme::BlsAggregate);
+	}
+
+	submit_bls_key {
+		NotaryKeys::<T>::put(vec![ethy_id::<T>(1)]);
+		let secret = bls::SecretKey::derive(&[1_u8; 65]).unwrap();
+		let registration = BlsKeyRegistration {
+			authority_index: 0,
+			public: secret.public(),
+			proof_of_possession: secret.prove_possession(),
+		};
+		// the signature is checked in `validate_unsigned` only
+		let signature = <<T as Config>::EthyId as RuntimeAppPublic>::Signature::decode(
+			&mut &[0_u8; 65][..]
+		).unwrap();
+	}: _(RawOrigin::None, registration, signature)
+	verify {
+		assert_eq!(NotaryBlsKeys::<T>::get(ethy_id::<T>(1)), Some(secret.public()));
+	}
+
+	on_initialize_event_batch {
+		let window: T::BlockNumber = 10_u32.into();
+		EventProofBatchWindow::<T>::put(window);
+		setup_event_batch::<T>(EthyChainId::Ethereum);
+	}: {
+		EthBridge::<T>::on_initialize(window);
+	}
+	verify {
+		assert!(PendingEventBatch::<T>::get(EthyChainId::Ethereum).is_empty());
+	}
+
+	set_event_proof_batch_window {
+		EventProofBatchWindow::<T>::put(T::BlockNumber::from(10_u32));
+		setup_event_batch::<T>(EthyChainId::Ethereum);
+		setup_event_batch::<T>(EthyChainId::SecondaryEvm);
+	}: _(RawOrigin::Root, T::BlockNumber::zero())
+	verify {
+		assert!(EventProofBatchWindow::<T>::get().is_zero());
+		assert!(PendingEventBatch::<T>::get(EthyChainId::Ethereum).is_empty());
+		assert!(PendingEventBatch::<T>::get(EthyChainId::SecondaryEvm).is_empty());
+	}
+
+	set_relayer {
+		let r in 1 .. 20;
+		for i in 0..r {
+			let relayer: T::AccountId = bench_account("Relayer", i, 0);
+			assert_ok!(T::MultiCurrency::mint_into(
+				T::NativeAssetId::get(),
+				&relayer,
+				T::RelayerBond::get().saturating_mul(10)
+			));
+			assert_ok!(EthBridge::<T>::deposit_relayer_bond(origin::<T>(&relayer).into()));
+			assert_ok!(EthBridge::<T>::add_relayer(RawOrigin::Root.into(), relayer));
+		}
+		let relayer = account::<T>("NewRelayer");
+		assert_ok!(T::MultiCurrency::mint_into(
+			T::NativeAssetId::get(),
+			&relayer,
+			T::RelayerBond::get().saturating_mul(10)
+		));
+		assert_ok!(EthBridge::<T>::deposit_relayer_bond(origin::<T>(&relayer).into()));
+	}: _(RawOrigin::Root, relayer.clone())
+	verify {
+		assert_eq!(Relayers::<T>::get(), vec![relayer]);
+	}
+}
+
+impl_benchmark_test_suite!(
+	EthBridge,
+	{
+		let mut ext = crate::mock::ExtBuilder::default().build();
+		ext.execute_with(|| crate::mock::MockValidatorSet::mock_n_validators(1));
+		ext
+	},
+	crate::mock::Test
+);
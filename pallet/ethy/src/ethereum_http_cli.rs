@@ -22,10 +22,14 @@ use sp_std::alloc::string::ToString;
 use std::string::ToString;
 
 use seed_pallet_common::log;
-use seed_primitives::ethy::ETH_HTTP_URI;
+use seed_primitives::ethy::EthyChainId;
 
 use crate::{
-	rt_offchain::{http::Request, Duration},
+	rt_offchain::{
+		http::{PendingRequest, Request},
+		storage::{StorageRetrievalError, StorageValueRef},
+		Duration, Timestamp,
+	},
 	types::{
 		BridgeEthereumRpcApi, BridgeRpcError, Bytes, EthAddress, EthBlock, EthCallRpcRequest,
 		EthHash, EthResponse, GetBlockRequest, GetTxReceiptRequest, LatestOrNumber,
@@ -38,36 +42,37 @@ use crate::{
 const REQUEST_TTL_MS: u64 = 1_500;
 
 /// Provides minimal ethereum RPC queries for eth bridge protocol
+///
+/// Multiple JSON-RPC endpoints may be configured per chain (comma separated), results of
+/// `eth_call` and `eth_getTransactionReceipt` are only used when a majority of the endpoints agree
 pub struct EthereumRpcClient;
 
 impl BridgeEthereumRpcApi for EthereumRpcClient {
 	/// Issue an `eth_call` request to `target` address with `input`
 	/// Returns the abi encoded 'returndata'
 	fn eth_call(
+		chain_id: EthyChainId,
 		target: EthAddress,
 		input: &[u8],
 		at_block: LatestOrNumber,
 	) -> Result<Vec<u8>, BridgeRpcError> {
 		let request = EthCallRpcRequest::new(target, input, random_request_id(), at_block);
-		let resp_bytes = Self::query_eth_client(Some(request))?;
-
-		// Deserialize JSON to struct
-		serde_json::from_slice::<EthResponse<Bytes>>(&resp_bytes)
-			.map(|resp| resp.result.map(|b| b.0).unwrap_or_default())
-			.map_err(|err| {
-				log!(error, "💎 deserialize json response error: {:?}", err);
-				BridgeRpcError::InvalidJSON
-			})
+		Self::query_eth_client_quorum::<_, Bytes>(chain_id, Some(request))
+			.map(|result| result.map(|b| b.0).unwrap_or_default())
 	}
 
 	/// Get latest block number from eth client
-	fn get_block_by_number(req: LatestOrNumber) -> Result<Option<EthBlock>, BridgeRpcError> {
+	fn get_block_by_number(
+		chain_id: EthyChainId,
+		req: LatestOrNumber,
+	) -> Result<Option<EthBlock>, BridgeRpcError> {
 		// TODO: #670 add a block cache
 		let request = match req {
 			LatestOrNumber::Latest => GetBlockRequest::latest(1_usize),
 			LatestOrNumber::Number(n) => GetBlockRequest::for_number(1_usize, n),
+			LatestOrNumber::Finalized => GetBlockRequest::finalized(1_usize),
 		};
-		let resp_bytes = Self::query_eth_client(request).map_err(|e| {
+		let resp_bytes = Self::query_eth_client(chain_id, request).map_err(|e| {
 			log!(error, "💎 read eth-rpc API error: {:?}", e);
 			BridgeRpcError::HttpFetch
 		})?;
@@ -83,88 +88,203 @@ impl BridgeEthereumRpcApi for EthereumRpcClient {
 
 	/// Get transaction receipt from eth client
 	fn get_transaction_receipt(
+		chain_id: EthyChainId,
 		tx_hash: EthHash,
 	) -> Result<Option<TransactionReceipt>, BridgeRpcError> {
 		let request = GetTxReceiptRequest::new(tx_hash, random_request_id());
-		let resp_bytes = Self::query_eth_client(Some(request)).map_err(|e| {
-			log!(error, "💎 read eth-rpc API error: {:?}", e);
-			BridgeRpcError::HttpFetch
-		})?;
-
-		// Deserialize JSON to struct
-		serde_json::from_slice::<EthResponse<TransactionReceipt>>(&resp_bytes)
-			.map(|resp| resp.result)
-			.map_err(|err| {
-				log!(error, "💎 deserialize json response error: {:?}", err);
-				BridgeRpcError::InvalidJSON
-			})
+		Self::query_eth_client_quorum::<_, TransactionReceipt>(chain_id, Some(request)).map_err(
+			|e| {
+				log!(error, "💎 read eth-rpc API error: {:?}", e);
+				e
+			},
+		)
 	}
 }
 
 impl EthereumRpcClient {
-	/// This function uses the `offchain::http` API to query the remote ethereum information,
-	/// and returns the JSON response as vector of bytes.
-	fn query_eth_client<R: serde::Serialize>(request_body: R) -> Result<Vec<u8>, BridgeRpcError> {
-		// Load eth http URI from offchain storage
-		// this should have been configured on start up by passing e.g. `--eth-http`
-		// e.g. `--eth-http=http://localhost:8545`
-		let eth_http_uri = if let Some(value) =
-			sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &ETH_HTTP_URI)
-		{
-			value
-		} else {
-			log!(error, "💎 Eth http uri is not configured! set --eth-http=<value> on start up");
-			return Err(BridgeRpcError::OcwConfig)
-		};
-		let eth_http_uri =
-			core::str::from_utf8(&eth_http_uri).map_err(|_| BridgeRpcError::OcwConfig)?;
+	/// Query the configured endpoints of `chain_id` in order, returning the JSON response of the
+	/// first endpoint to respond as vector of bytes.
+	///
+	/// Used where endpoints may legitimately disagree e.g. the latest block
+	fn query_eth_client<R: serde::Serialize>(
+		chain_id: EthyChainId,
+		request_body: R,
+	) -> Result<Vec<u8>, BridgeRpcError> {
+		let eth_http_uris = Self::eth_http_uris(chain_id)?;
+		let body = serde_json::to_string::<R>(&request_body).unwrap();
 
-		const HEADER_CONTENT_TYPE: &str = "application/json";
-		log!(info, "💎 sending request to: {}", eth_http_uri);
+		let mut result = Err(BridgeRpcError::HttpFetch);
+		for eth_http_uri in parse_uris(&eth_http_uris)? {
+			let timeout = sp_io::offchain::timestamp().add(Duration::from_millis(REQUEST_TTL_MS));
+			result = send_request(eth_http_uri, body.as_bytes(), timeout)
+				.and_then(|pending| wait_response(pending, timeout));
+			if result.is_ok() {
+				break
+			}
+		}
+
+		result
+	}
+
+	/// Query all configured endpoints of `chain_id` concurrently, returning the result agreed on
+	/// by a majority of them.
+	///
+	/// Any disagreement between responding endpoints is recorded in the offchain metric
+	/// `EthyChainId::rpc_disagreements_key`
+	fn query_eth_client_quorum<R, T>(
+		chain_id: EthyChainId,
+		request_body: R,
+	) -> Result<Option<T>, BridgeRpcError>
+	where
+		R: serde::Serialize,
+		T: serde::de::DeserializeOwned + PartialEq,
+	{
+		let eth_http_uris = Self::eth_http_uris(chain_id)?;
+		let eth_http_uris = parse_uris(&eth_http_uris)?;
 		let body = serde_json::to_string::<R>(&request_body).unwrap();
-		let body_raw = body.as_bytes();
-		// Initiate an external HTTP POST request. This is using high-level wrappers from
-		// `sp_runtime`.
-		let request = Request::post(eth_http_uri, vec![body_raw]);
-		log!(trace, "💎 request: {:?}", request);
-
-		// Keeping the offchain worker execution time reasonable, so limiting the call to be within
-		// 3s.
+
+		// send all requests before waiting so the endpoints are queried concurrently
 		let timeout = sp_io::offchain::timestamp().add(Duration::from_millis(REQUEST_TTL_MS));
-		let pending = request
-			.add_header("Content-Type", HEADER_CONTENT_TYPE)
-			.add_header("Content-Length", &body_raw.len().to_string())
-			.deadline(timeout) // Setting the timeout time
-			.send() // Sending the request out by the host
-			.map_err(|err| {
-				log!(error, "💎 http request error: {:?}", err);
-				BridgeRpcError::HttpFetch
-			})?;
-
-		// By default, the http request is async from the runtime perspective. So we are asking the
-		// runtime to wait here.
-		// The returning value here is a `Result` of `Result`, so we are unwrapping it twice by two
-		// `?` ref: https://substrate.dev/rustdocs/v3.0.0/sp_runtime/offchain/http/struct.PendingRequest.html#method.try_wait
-		let response = pending
-			.try_wait(timeout)
-			.map_err(|err| {
-				log!(error, "💎 http request error: timeline reached: {:?}", err);
-				BridgeRpcError::HttpFetch
-			})?
-			.map_err(|err| {
-				log!(error, "💎 http request error: timeline reached: {:?}", err);
-				BridgeRpcError::HttpFetch
-			})?;
-		log!(trace, "💎 response: {:?}", response);
-
-		if response.code != 200 {
-			log!(error, "💎 http request status code: {}", response.code);
-			return Err(BridgeRpcError::HttpFetch)
+		let pending = eth_http_uris
+			.iter()
+			.map(|eth_http_uri| send_request(eth_http_uri, body.as_bytes(), timeout))
+			.collect::<Vec<_>>();
+		let mut results = pending
+			.into_iter()
+			.map(|pending| {
+				let resp_bytes = pending.and_then(|pending| wait_response(pending, timeout))?;
+				// Deserialize JSON to struct
+				serde_json::from_slice::<EthResponse<T>>(&resp_bytes)
+					.map(|resp| resp.result)
+					.map_err(|err| {
+						log!(error, "💎 deserialize json response error: {:?}", err);
+						BridgeRpcError::InvalidJSON
+					})
+			})
+			.collect::<Vec<Result<Option<T>, BridgeRpcError>>>();
+
+		let mut responses = results.iter().filter(|result| result.is_ok());
+		let Some(first_response) = responses.next() else {
+			// no endpoint responded, report the first failure
+			return results.swap_remove(0)
+		};
+		if responses.any(|response| response != first_response) {
+			log!(warn, "💎 {:?} rpc endpoints returned different results", chain_id);
+			record_rpc_disagreement(chain_id);
 		}
 
-		// Read the response body and check it's valid utf-8
-		Ok(response.body().collect::<Vec<u8>>())
+		let quorum = eth_http_uris.len() / 2 + 1;
+		let agreed = results.iter().position(|candidate| {
+			candidate.is_ok() &&
+				results.iter().filter(|result| *result == candidate).count() >= quorum
+		});
+		match agreed {
+			Some(index) => results.swap_remove(index),
+			None => {
+				log!(error, "💎 {:?} rpc endpoints did not reach quorum", chain_id);
+				Err(BridgeRpcError::NoQuorum)
+			},
+		}
+	}
+
+	/// Load the (comma separated) eth http URIs of `chain_id` from offchain storage
+	/// this should have been configured on start up by passing e.g. `--eth-http`
+	/// e.g. `--eth-http=http://localhost:8545,http://localhost:8546`
+	fn eth_http_uris(chain_id: EthyChainId) -> Result<Vec<u8>, BridgeRpcError> {
+		let Some(http_uri_key) = chain_id.http_uri_key() else {
+			log!(error, "💎 {:?} is not an EVM chain", chain_id);
+			return Err(BridgeRpcError::OcwConfig)
+		};
+		sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &http_uri_key).ok_or_else(
+			|| {
+				log!(
+					error,
+					"💎 {:?} http uri is not configured! set e.g. --eth-http=<value> on start up",
+					chain_id
+				);
+				BridgeRpcError::OcwConfig
+			},
+		)
+	}
+}
+
+/// Split the configured `eth_http_uris` into individual endpoints
+fn parse_uris(eth_http_uris: &[u8]) -> Result<Vec<&str>, BridgeRpcError> {
+	let eth_http_uris =
+		core::str::from_utf8(eth_http_uris).map_err(|_| BridgeRpcError::OcwConfig)?;
+	let eth_http_uris = eth_http_uris
+		.split(',')
+		.map(str::trim)
+		.filter(|eth_http_uri| !eth_http_uri.is_empty())
+		.collect::<Vec<&str>>();
+	if eth_http_uris.is_empty() {
+		return Err(BridgeRpcError::OcwConfig)
+	}
+
+	Ok(eth_http_uris)
+}
+
+/// This function uses the `offchain::http` API to send a JSON-RPC request to `eth_http_uri`
+fn send_request(
+	eth_http_uri: &str,
+	body_raw: &[u8],
+	timeout: Timestamp,
+) -> Result<PendingRequest, BridgeRpcError> {
+	const HEADER_CONTENT_TYPE: &str = "application/json";
+	log!(info, "💎 sending request to: {}", eth_http_uri);
+	// Initiate an external HTTP POST request. This is using high-level wrappers from
+	// `sp_runtime`.
+	let request = Request::post(eth_http_uri, vec![body_raw]);
+	log!(trace, "💎 request: {:?}", request);
+
+	// Keeping the offchain worker execution time reasonable, so limiting the call to be within
+	// 3s.
+	request
+		.add_header("Content-Type", HEADER_CONTENT_TYPE)
+		.add_header("Content-Length", &body_raw.len().to_string())
+		.deadline(timeout) // Setting the timeout time
+		.send() // Sending the request out by the host
+		.map_err(|err| {
+			log!(error, "💎 http request error: {:?}", err);
+			BridgeRpcError::HttpFetch
+		})
+}
+
+/// Wait for the `pending` request and return the JSON response as vector of bytes.
+fn wait_response(pending: PendingRequest, timeout: Timestamp) -> Result<Vec<u8>, BridgeRpcError> {
+	// By default, the http request is async from the runtime perspective. So we are asking the
+	// runtime to wait here.
+	// The returning value here is a `Result` of `Result`, so we are unwrapping it twice by two
+	// `?` ref: https://substrate.dev/rustdocs/v3.0.0/sp_runtime/offchain/http/struct.PendingRequest.html#method.try_wait
+	let response = pending
+		.try_wait(timeout)
+		.map_err(|err| {
+			log!(error, "💎 http request error: timeline reached: {:?}", err);
+			BridgeRpcError::HttpFetch
+		})?
+		.map_err(|err| {
+			log!(error, "💎 http request error: timeline reached: {:?}", err);
+			BridgeRpcError::HttpFetch
+		})?;
+	log!(trace, "💎 response: {:?}", response);
+
+	if response.code != 200 {
+		log!(error, "💎 http request status code: {}", response.code);
+		return Err(BridgeRpcError::HttpFetch)
 	}
+
+	// Read the response body and check it's valid utf-8
+	Ok(response.body().collect::<Vec<u8>>())
+}
+
+/// Increment the offchain count of disagreements between the rpc endpoints of `chain_id`
+fn record_rpc_disagreement(chain_id: EthyChainId) {
+	let Some(key) = chain_id.rpc_disagreements_key() else { return };
+	let _ = StorageValueRef::persistent(&key).mutate(
+		|count: Result<Option<u64>, StorageRetrievalError>| -> Result<u64, ()> {
+			Ok(count.ok().flatten().unwrap_or_default().saturating_add(1))
+		},
+	);
 }
 
 /// Return a random usize value
@@ -176,6 +296,7 @@ fn random_request_id() -> usize {
 mod tests {
 	use super::*;
 	use parking_lot::RwLock;
+	use seed_primitives::ethy::{ETH_HTTP_URI, ETH_RPC_DISAGREEMENTS};
 	use sp_core::offchain::{
 		testing::{OffchainState, PendingRequest, TestOffchainExt},
 		OffchainDbExt, OffchainWorkerExt,
@@ -207,6 +328,10 @@ mod tests {
 			];
 			self
 		}
+		fn uri(mut self, uri: &str) -> Self {
+			self.0.uri = uri.into();
+			self
+		}
 		fn method(mut self, method: &str) -> Self {
 			self.0.method = method.into();
 			self
@@ -222,6 +347,13 @@ mod tests {
 
 	/// Setup mock offchain environment suitable for testing http requests
 	fn mock_offchain_env() -> (TestExternalities, Arc<RwLock<OffchainState>>) {
+		mock_offchain_env_with_endpoints(&[MOCK_TEST_ENDPOINT])
+	}
+
+	/// Setup mock offchain environment with multiple configured `--eth-http` endpoints
+	fn mock_offchain_env_with_endpoints(
+		endpoints: &[&str],
+	) -> (TestExternalities, Arc<RwLock<OffchainState>>) {
 		let (offchain, state) = TestOffchainExt::new();
 		let mut t = sp_io::TestExternalities::default();
 		t.register_extension(OffchainDbExt::new(offchain.clone()));
@@ -232,7 +364,7 @@ mod tests {
 				StorageKind::PERSISTENT,
 				&ETH_HTTP_URI,
 				None,
-				MOCK_TEST_ENDPOINT.as_bytes(),
+				endpoints.join(",").as_bytes(),
 			);
 		});
 
@@ -258,6 +390,7 @@ mod tests {
 		ext.execute_with(|| {
 			assert_eq!(
 				EthereumRpcClient::eth_call(
+					EthyChainId::Ethereum,
 					EthAddress::from_low_u64_be(2_u64), /* 0x0000000000000000000000000000000000000002 */
 					&[1_u8, 2, 3, 4, 5],                // 0x0102030405
 					LatestOrNumber::Latest,
@@ -287,6 +420,7 @@ mod tests {
 		ext.execute_with(|| {
 			assert_eq!(
 				EthereumRpcClient::eth_call(
+					EthyChainId::Ethereum,
 					EthAddress::from_low_u64_be(2_u64),
 					&[1_u8, 2, 3, 4, 5],
 					LatestOrNumber::Number(0xff),
@@ -315,6 +449,7 @@ mod tests {
 		ext.execute_with(|| {
 			assert_eq!(
 				EthereumRpcClient::eth_call(
+					EthyChainId::Ethereum,
 					EthAddress::zero(),
 					Default::default(),
 					LatestOrNumber::Latest,
@@ -323,4 +458,178 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn eth_call_unconfigured_chain_fails() {
+		let (mut ext, _state) = mock_offchain_env();
+		ext.execute_with(|| {
+			// only the Ethereum endpoint is configured
+			assert_eq!(
+				EthereumRpcClient::eth_call(
+					EthyChainId::SecondaryEvm,
+					EthAddress::zero(),
+					Default::default(),
+					LatestOrNumber::Latest,
+				),
+				Err(BridgeRpcError::OcwConfig),
+			);
+			assert_eq!(
+				EthereumRpcClient::get_block_by_number(EthyChainId::Xrpl, LatestOrNumber::Latest),
+				Err(BridgeRpcError::HttpFetch),
+			);
+		})
+	}
+
+	/// Expect an `eth_call` request to `uri` and respond with `mock_response`
+	fn expect_eth_call(state: &Arc<RwLock<OffchainState>>, uri: &str, mock_response: &[u8]) {
+		let expected_request = br#"{"jsonrpc":"2.0","method":"eth_call","params":[{"to":"0x0000000000000000000000000000000000000002","data":"0x0102030405"},"latest"],"id":0}"#;
+		let expected_request_response = PendingRequestBuilder::new()
+			.uri(uri)
+			.method("POST")
+			.request(expected_request)
+			.response(mock_response)
+			.build();
+		state.write().expect_request(expected_request_response);
+	}
+
+	/// Read the offchain count of Ethereum rpc endpoint disagreements
+	fn rpc_disagreements() -> Option<u64> {
+		StorageValueRef::persistent(&ETH_RPC_DISAGREEMENTS).get::<u64>().unwrap()
+	}
+
+	#[test]
+	fn eth_call_quorum() {
+		let endpoints = ["http://a.example.com", "http://b.example.com", "http://c.example.com"];
+		let (mut ext, state) = mock_offchain_env_with_endpoints(&endpoints);
+		expect_eth_call(&state, endpoints[0], br#"{"jsonrpc":"2.0","id":0,"result":"0x050403"}"#);
+		// a faulty endpoint is outvoted
+		expect_eth_call(&state, endpoints[1], br#"{"jsonrpc":"2.0","id":0,"result":"0x0504"}"#);
+		expect_eth_call(&state, endpoints[2], br#"{"jsonrpc":"2.0","id":0,"result":"0x050403"}"#);
+
+		ext.execute_with(|| {
+			assert_eq!(
+				EthereumRpcClient::eth_call(
+					EthyChainId::Ethereum,
+					EthAddress::from_low_u64_be(2_u64),
+					&[1_u8, 2, 3, 4, 5],
+					LatestOrNumber::Latest,
+				),
+				Ok(vec![5_u8, 4, 3]),
+			);
+			assert_eq!(rpc_disagreements(), Some(1));
+		})
+	}
+
+	#[test]
+	fn eth_call_quorum_ignores_failed_endpoint() {
+		let endpoints = ["http://a.example.com", "http://b.example.com", "http://c.example.com"];
+		let (mut ext, state) = mock_offchain_env_with_endpoints(&endpoints);
+		expect_eth_call(&state, endpoints[0], b"not json");
+		expect_eth_call(&state, endpoints[1], br#"{"jsonrpc":"2.0","id":0,"result":"0x050403"}"#);
+		expect_eth_call(&state, endpoints[2], br#"{"jsonrpc":"2.0","id":0,"result":"0x050403"}"#);
+
+		ext.execute_with(|| {
+			assert_eq!(
+				EthereumRpcClient::eth_call(
+					EthyChainId::Ethereum,
+					EthAddress::from_low_u64_be(2_u64),
+					&[1_u8, 2, 3, 4, 5],
+					LatestOrNumber::Latest,
+				),
+				Ok(vec![5_u8, 4, 3]),
+			);
+			// responding endpoints agreed
+			assert_eq!(rpc_disagreements(), None);
+		})
+	}
+
+	#[test]
+	fn eth_call_no_quorum_fails() {
+		let endpoints = ["http://a.example.com", "http://b.example.com"];
+		let (mut ext, state) = mock_offchain_env_with_endpoints(&endpoints);
+		expect_eth_call(&state, endpoints[0], br#"{"jsonrpc":"2.0","id":0,"result":"0x050403"}"#);
+		expect_eth_call(&state, endpoints[1], br#"{"jsonrpc":"2.0","id":0,"result":"0x0504"}"#);
+
+		ext.execute_with(|| {
+			assert_eq!(
+				EthereumRpcClient::eth_call(
+					EthyChainId::Ethereum,
+					EthAddress::from_low_u64_be(2_u64),
+					&[1_u8, 2, 3, 4, 5],
+					LatestOrNumber::Latest,
+				),
+				Err(BridgeRpcError::NoQuorum),
+			);
+			assert_eq!(rpc_disagreements(), Some(1));
+		})
+	}
+
+	#[test]
+	fn get_transaction_receipt_quorum() {
+		let endpoints = ["http://a.example.com", "http://b.example.com", "http://c.example.com"];
+		let (mut ext, state) = mock_offchain_env_with_endpoints(&endpoints);
+		let tx_hash = EthHash::from_low_u64_be(0x1234);
+		let expected_request =
+			serde_json::to_string(&Some(GetTxReceiptRequest::new(tx_hash, 0))).unwrap();
+		let receipt_response = format!(
+			r#"{{"jsonrpc":"2.0","id":0,"result":{{"blockHash":"{:?}","blockNumber":"0x5","cumulativeGasUsed":"0x5208","from":"0x0000000000000000000000000000000000000001","logs":[],"status":"0x1","transactionHash":"{:?}","transactionIndex":"0x0","logsBloom":"0x{}"}}}}"#,
+			EthHash::from_low_u64_be(5),
+			tx_hash,
+			"00".repeat(256),
+		);
+		// a lagging endpoint hasn't seen the tx yet
+		let responses = [
+			receipt_response.as_bytes(),
+			br#"{"jsonrpc":"2.0","id":0,"result":null}"#,
+			receipt_response.as_bytes(),
+		];
+		for (endpoint, response) in endpoints.iter().zip(responses) {
+			state.write().expect_request(
+				PendingRequestBuilder::new()
+					.uri(endpoint)
+					.method("POST")
+					.request(expected_request.as_bytes())
+					.response(response)
+					.build(),
+			);
+		}
+
+		ext.execute_with(|| {
+			let receipt =
+				EthereumRpcClient::get_transaction_receipt(EthyChainId::Ethereum, tx_hash)
+					.unwrap()
+					.expect("majority returned a receipt");
+			assert_eq!(receipt.transaction_hash, tx_hash);
+			assert_eq!(receipt.block_number, 5_u64.into());
+			assert_eq!(rpc_disagreements(), Some(1));
+		})
+	}
+
+	#[test]
+	fn get_block_by_number_uses_first_endpoint() {
+		let endpoints = ["http://a.example.com", "http://b.example.com"];
+		let (mut ext, state) = mock_offchain_env_with_endpoints(&endpoints);
+		// endpoints may legitimately disagree on the latest block, only the first is queried
+		let expected_request =
+			br#"{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["latest",false],"id":1}"#;
+		state.write().expect_request(
+			PendingRequestBuilder::new()
+				.uri(endpoints[0])
+				.method("POST")
+				.request(expected_request)
+				.response(br#"{"jsonrpc":"2.0","id":1,"result":{"number":"0x5","hash":null,"timestamp":"0x64"}}"#)
+				.build(),
+		);
+
+		ext.execute_with(|| {
+			let block = EthereumRpcClient::get_block_by_number(
+				EthyChainId::Ethereum,
+				LatestOrNumber::Latest,
+			)
+			.unwrap()
+			.unwrap();
+			assert_eq!(block.number, Some(5_u64.into()));
+			assert_eq!(block.timestamp, 100_u64.into());
+		})
+	}
 }
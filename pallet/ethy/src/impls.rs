@@ -23,10 +23,7 @@ use frame_support::{
 use frame_system::offchain::SubmitTransaction;
 use sp_runtime::{
 	generic::DigestItem,
-	traits::{AccountIdConversion, Convert, SaturatedConversion, Saturating},
-	transaction_validity::{
-		InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction,
-	},
+	traits::{AccountIdConversion, Convert, SaturatedConversion, Saturating, Zero},
 	Percent, RuntimeAppPublic,
 };
 use sp_std::prelude::*;
@@ -35,11 +32,11 @@ use seed_pallet_common::{
 	log, logger::debug, EthCallFailure, EthCallOracle, EthCallOracleSubscriber, EthereumBridge,
 	FinalSessionTracker as FinalSessionTrackerT, XrplBridgeToEthyAdapter,
 };
-use seed_primitives::ethy::{EthyEcdsaToEthereum, EthyEcdsaToXRPLAccountId};
+use seed_primitives::ethy::{bls, EthyChainId, EthyEcdsaToEthereum, EthyEcdsaToXRPLAccountId};
 
 use crate::{types::*, *};
 
-impl<T: Config> EthereumBridge for Module<T> {
+impl<T: Config> EthereumBridge for Pallet<T> {
 	/// Send an event via the bridge
 	///  A proof of the event will be generated by notaries (async)
 	///
@@ -49,8 +46,22 @@ impl<T: Config> EthereumBridge for Module<T> {
 		destination: &H160,
 		app_event: &[u8],
 	) -> Result<EventProofId, DispatchError> {
+		Self::send_event_to(EthyChainId::Ethereum, source, destination, app_event)
+	}
+
+	/// Send an event via the bridge to the EVM chain `chain_id`
+	///  A proof of the event will be generated by notaries (async)
+	///
+	/// Returns an Id for the proof
+	fn send_event_to(
+		chain_id: EthyChainId,
+		source: &H160,
+		destination: &H160,
+		app_event: &[u8],
+	) -> Result<EventProofId, DispatchError> {
+		ensure!(chain_id.is_evm(), Error::<T>::UnsupportedChain);
 		let event_proof_id = Self::next_event_proof_id();
-		NextEventProofId::put(event_proof_id.wrapping_add(1));
+		NextEventProofId::<T>::put(event_proof_id.wrapping_add(1));
 
 		let event_proof_info = EthereumEventInfo {
 			source: *source,
@@ -59,23 +70,40 @@ impl<T: Config> EthereumBridge for Module<T> {
 			validator_set_id: Self::validator_set().id,
 			event_proof_id,
 		};
+		let event_digest: [u8; 32] = sp_io::hashing::keccak_256(&event_proof_info.abi_encode());
+		let request = match chain_id {
+			EthyChainId::SecondaryEvm => EthySigningRequest::SecondaryEvm(event_proof_info),
+			_ => EthySigningRequest::Ethereum(event_proof_info),
+		};
 
-		Self::do_request_event_proof(
-			event_proof_id,
-			EthySigningRequest::Ethereum(event_proof_info),
-		);
+		if Self::event_proof_batch_window().is_zero() {
+			Self::do_request_event_proof(event_proof_id, request);
+		} else {
+			// the event is proven by its inclusion in the next signed batch
+			let batch_size = PendingEventBatch::<T>::mutate(chain_id, |batch| {
+				batch.push((event_proof_id, event_digest));
+				batch.len()
+			});
+			Self::deposit_event(Event::<T>::EventBatchQueued {
+				event_proof_id,
+				signing_request: request,
+			});
+			if batch_size >= MAX_EVENT_BATCH_SIZE {
+				Self::do_request_event_batch(chain_id);
+			}
+		}
 		Ok(event_proof_id)
 	}
 }
 
-impl<T: Config> XrplBridgeToEthyAdapter<T::EthyId> for Module<T> {
+impl<T: Config> XrplBridgeToEthyAdapter<T::EthyId> for Pallet<T> {
 	/// Sign a transaction via ethy for XRPL
 	/// Signatures for the tx will be generated by notaries (async)
 	///
 	/// Returns an Id for the proof
 	fn sign_xrpl_transaction(tx_data: &[u8]) -> Result<EventProofId, DispatchError> {
 		let event_proof_id = Self::next_event_proof_id();
-		NextEventProofId::put(event_proof_id.wrapping_add(1));
+		NextEventProofId::<T>::put(event_proof_id.wrapping_add(1));
 
 		Self::do_request_event_proof(event_proof_id, EthySigningRequest::XrplTx(tx_data.to_vec()));
 		Ok(event_proof_id)
@@ -88,7 +116,7 @@ impl<T: Config> XrplBridgeToEthyAdapter<T::EthyId> for Module<T> {
 	}
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
 	pub fn update_xrpl_notary_keys(validator_list: &Vec<T::EthyId>) {
 		let validators = Self::get_xrpl_notary_keys(validator_list);
 		<NotaryXrplKeys<T>>::put(&validators);
@@ -135,6 +163,30 @@ impl<T: Config> Module<T> {
 		maybe_active_key.map(|(key, idx)| (key, idx as u16))
 	}
 
+	/// Register the BLS key derived from the node's local notary session key, if it's not yet
+	/// registered
+	/// ethy-gadget derives the same key to sign aggregatable witnesses
+	pub(crate) fn do_bls_key_registration_ocw(active_key: &T::EthyId, authority_index: u16) {
+		if NotaryBlsKeys::<T>::contains_key(active_key) {
+			return
+		}
+		let Some(secret) = active_key
+			.sign(&bls::BLS_KEY_DERIVATION_MESSAGE)
+			.and_then(|signature| bls::SecretKey::derive(signature.encode().as_slice()))
+		else {
+			log!(error, "💎 deriving bls key failed");
+			return
+		};
+		let registration = BlsKeyRegistration {
+			authority_index,
+			public: secret.public(),
+			proof_of_possession: secret.prove_possession(),
+		};
+		let _ = Self::offchain_send_bls_key(active_key, registration).map_err(|err| {
+			log!(error, "💎 sending bls key registration failed 🙈, {:?}", err);
+		});
+	}
+
 	/// Handle OCW event notarization protocol for validators
 	/// Receives the node's local notary session key and index in the set
 	pub(crate) fn do_event_notarization_ocw(active_key: &T::EthyId, authority_index: u16) {
@@ -147,7 +199,7 @@ impl<T: Config> Module<T> {
 		// this will be invoked once every block
 		// we limit the total claims per invocation using `CLAIMS_PER_BLOCK` so we don't stall block
 		// production.
-		for event_claim_id in PendingClaimChallenges::get().iter().take(CLAIMS_PER_BLOCK) {
+		for event_claim_id in PendingClaimChallenges::<T>::get().iter().take(CLAIMS_PER_BLOCK) {
 			let event_claim = Self::pending_event_claims(event_claim_id);
 			if event_claim.is_none() {
 				// This shouldn't happen
@@ -164,8 +216,17 @@ impl<T: Config> Module<T> {
 				continue
 			}
 
-			let result = Self::offchain_try_notarize_event(*event_claim_id, event_claim.unwrap());
+			let event_claim = event_claim.unwrap();
+			let chain_id = event_claim.chain_id;
+			let result = Self::offchain_try_notarize_event(*event_claim_id, event_claim);
 			log!(trace, "💎 claim verification status: {:?}", &result);
+			// wait for the block to be finalized rather than voting against the claim
+			if result == EventClaimResult::NotEnoughConfirmations &&
+				Self::event_finality_mode(chain_id) == EventFinalityMode::Finalized
+			{
+				log!(trace, "💎 claim: {:?} not yet finalized, retrying...", event_claim_id);
+				continue
+			}
 			let payload = NotarizationPayload::Event {
 				event_claim_id: *event_claim_id,
 				authority_index,
@@ -195,15 +256,15 @@ impl<T: Config> Module<T> {
 	/// - tx sent to source contract address
 	/// - check for exact log data match
 	/// - check log source == bridge contract address
-	/// - confirmations `>= T::EventConfirmations`
+	/// - confirmations `>= T::EventConfirmations` or the block is finalized (`EventFinalityMode`)
 	///
 	/// Returns result of the validation
 	pub(crate) fn offchain_try_notarize_event(
 		event_claim_id: EventClaimId,
 		event_claim: EventClaim,
 	) -> EventClaimResult {
-		let EventClaim { tx_hash, data, source, destination } = event_claim;
-		let result = T::EthereumRpcClient::get_transaction_receipt(tx_hash);
+		let EventClaim { tx_hash, data, source, destination, chain_id } = event_claim;
+		let result = T::EthereumRpcClient::get_transaction_receipt(chain_id, tx_hash);
 		if let Err(err) = result {
 			log!(error, "💎 eth_getTransactionReceipt({:?}) failed: {:?}", tx_hash, err);
 			return EventClaimResult::DataProviderErr
@@ -233,8 +294,9 @@ impl<T: Config> Module<T> {
 				log.topics.contains(&SUBMIT_BRIDGE_EVENT_SELECTOR.into())
 		});
 
+		// the bridge contract emits the chain local event nonce, not the namespaced claim id
 		let submitted_event_data = ethabi::encode(&[
-			Token::Uint(event_claim_id.into()),
+			Token::Uint(EthyChainId::event_nonce(event_claim_id).into()),
 			Token::Address(source),
 			Token::Address(destination),
 			Token::Bytes(data),
@@ -251,7 +313,7 @@ impl<T: Config> Module<T> {
 				);
 				return EventClaimResult::UnexpectedData
 			}
-			if log.address != Self::contract_address() {
+			if log.address != Self::contract_address(chain_id) {
 				return EventClaimResult::UnexpectedContractAddress
 			}
 		} else {
@@ -261,8 +323,27 @@ impl<T: Config> Module<T> {
 		//  have we got enough block confirmations to be re-org safe?
 		let observed_block_number: u64 = tx_receipt.block_number.saturated_into();
 
+		if Self::event_finality_mode(chain_id) == EventFinalityMode::Finalized {
+			let finalized_block: EthBlock = match T::EthereumRpcClient::get_block_by_number(
+				chain_id,
+				LatestOrNumber::Finalized,
+			) {
+				Ok(None) => return EventClaimResult::DataProviderErr,
+				Ok(Some(block)) => block,
+				Err(err) => {
+					log!(error, "💎 eth_getBlockByNumber finalized failed: {:?}", err);
+					return EventClaimResult::DataProviderErr
+				},
+			};
+			let finalized_block_number = finalized_block.number.unwrap_or_default().as_u64();
+			if observed_block_number > finalized_block_number {
+				return EventClaimResult::NotEnoughConfirmations
+			}
+			return EventClaimResult::Valid
+		}
+
 		let latest_block: EthBlock =
-			match T::EthereumRpcClient::get_block_by_number(LatestOrNumber::Latest) {
+			match T::EthereumRpcClient::get_block_by_number(chain_id, LatestOrNumber::Latest) {
 				Ok(None) => return EventClaimResult::DataProviderErr,
 				Ok(Some(block)) => block,
 				Err(err) => {
@@ -273,7 +354,7 @@ impl<T: Config> Module<T> {
 
 		let latest_block_number = latest_block.number.unwrap_or_default().as_u64();
 		let block_confirmations = latest_block_number.saturating_sub(observed_block_number);
-		if block_confirmations < Self::event_block_confirmations() {
+		if block_confirmations < Self::event_block_confirmations(chain_id) {
 			return EventClaimResult::NotEnoughConfirmations
 		}
 
@@ -285,7 +366,7 @@ impl<T: Config> Module<T> {
 	pub(crate) fn do_call_notarization_ocw(active_key: &T::EthyId, authority_index: u16) {
 		// we limit the total claims per invocation using `CALLS_PER_BLOCK` so we don't stall block
 		// production
-		for call_id in EthCallRequests::get().iter().take(CALLS_PER_BLOCK) {
+		for call_id in EthCallRequests::<T>::get().iter().take(CALLS_PER_BLOCK) {
 			// skip if we've notarized it previously
 			if <EthCallNotarizations<T>>::contains_key::<EthCallId, T::EthyId>(
 				*call_id,
@@ -296,10 +377,21 @@ impl<T: Config> Module<T> {
 			}
 
 			if let Some(request) = Self::eth_call_request_info(call_id) {
-				let result = Self::offchain_try_eth_call(&request);
+				let (result, return_data) = Self::offchain_try_eth_call(&request);
 				log!(trace, "💎 checked call status: {:?}", &result);
-				let payload =
-					NotarizationPayload::Call { call_id: *call_id, authority_index, result };
+				// the return data is stored once, only send it if no other notary has
+				let return_data = match result {
+					CheckedEthCallResult::Ok(return_data_hash, ..)
+						if !EthCallReturnData::<T>::contains_key(call_id, return_data_hash) =>
+						return_data,
+					_ => None,
+				};
+				let payload = NotarizationPayload::Call {
+					call_id: *call_id,
+					authority_index,
+					result,
+					return_data,
+				};
 				let _ = Self::offchain_send_notarization(active_key, payload)
 					.map_err(|err| {
 						log!(error, "💎 sending notarization failed 🙈, {:?}", err);
@@ -323,41 +415,49 @@ impl<T: Config> Module<T> {
 	/// `request` - details of the `eth_call` request to perform
 	/// `try_block_number` - a block number to try the call at `latest - max_block_look_behind <= t
 	/// < latest` `max_block_look_behind` - max ethereum blocks to look back from head
-	pub(crate) fn offchain_try_eth_call(request: &CheckedEthCallRequest) -> CheckedEthCallResult {
+	///
+	/// Returns the notarization result and the returndata of an `Ok` result
+	pub(crate) fn offchain_try_eth_call(
+		request: &CheckedEthCallRequest,
+	) -> (CheckedEthCallResult, Option<Vec<u8>>) {
 		// OCW has 1 block to do all its stuff, so needs to be kept light
 		//
 		// basic flow of this function:
-		// 1) get latest ethereum block
+		// 1) get latest ethereum block (the finalized block in `EventFinalityMode::Finalized`)
 		// 2) check relayed block # and timestamp is within acceptable range (based on
 		// `max_block_look_behind`) 3a) within range: do an eth_call at the relayed block
 		// 3b) out of range: do an eth_call at block number latest
+		let latest_tag = match Self::event_finality_mode(EthyChainId::Ethereum) {
+			EventFinalityMode::BlockConfirmations => LatestOrNumber::Latest,
+			EventFinalityMode::Finalized => LatestOrNumber::Finalized,
+		};
 		let latest_block: EthBlock =
-			match T::EthereumRpcClient::get_block_by_number(LatestOrNumber::Latest) {
-				Ok(None) => return CheckedEthCallResult::DataProviderErr,
+			match T::EthereumRpcClient::get_block_by_number(EthyChainId::Ethereum, latest_tag) {
+				Ok(None) => return (CheckedEthCallResult::DataProviderErr, None),
 				Ok(Some(block)) => block,
 				Err(err) => {
 					log!(error, "💎 eth_getBlockByNumber latest failed: {:?}", err);
-					return CheckedEthCallResult::DataProviderErr
+					return (CheckedEthCallResult::DataProviderErr, None)
 				},
 			};
 		// some future proofing/protections if timestamps or block numbers are de-synced, stuck, or
 		// missing this protocol should vote to abort
 		let latest_eth_block_timestamp: u64 = latest_block.timestamp.saturated_into();
 		if latest_eth_block_timestamp == u64::max_value() {
-			return CheckedEthCallResult::InvalidTimestamp
+			return (CheckedEthCallResult::InvalidTimestamp, None)
 		}
 		// latest ethereum block timestamp should be after the request
 		if latest_eth_block_timestamp < request.timestamp {
-			return CheckedEthCallResult::InvalidTimestamp
+			return (CheckedEthCallResult::InvalidTimestamp, None)
 		}
 		let latest_eth_block_number = match latest_block.number {
 			Some(number) => {
 				if number.is_zero() || number.low_u64() == u64::max_value() {
-					return CheckedEthCallResult::InvalidEthBlock
+					return (CheckedEthCallResult::InvalidEthBlock, None)
 				}
 				number.low_u64()
 			},
-			None => return CheckedEthCallResult::InvalidEthBlock,
+			None => return (CheckedEthCallResult::InvalidEthBlock, None),
 		};
 
 		// check relayed block # and timestamp is within acceptable range
@@ -378,13 +478,14 @@ impl<T: Config> Module<T> {
 			request.try_block_number < latest_eth_block_number
 		{
 			let target_block: EthBlock = match T::EthereumRpcClient::get_block_by_number(
+				EthyChainId::Ethereum,
 				LatestOrNumber::Number(request.try_block_number),
 			) {
-				Ok(None) => return CheckedEthCallResult::DataProviderErr,
+				Ok(None) => return (CheckedEthCallResult::DataProviderErr, None),
 				Ok(Some(block)) => block,
 				Err(err) => {
 					log!(error, "💎 eth_getBlockByNumber latest failed: {:?}", err);
-					return CheckedEthCallResult::DataProviderErr
+					return (CheckedEthCallResult::DataProviderErr, None)
 				},
 			};
 			target_block_number = request.try_block_number;
@@ -392,27 +493,32 @@ impl<T: Config> Module<T> {
 		}
 
 		let return_data = match T::EthereumRpcClient::eth_call(
+			EthyChainId::Ethereum,
 			request.target,
 			&request.input,
 			LatestOrNumber::Number(target_block_number),
 		) {
 			Ok(data) =>
 				if data.is_empty() {
-					return CheckedEthCallResult::ReturnDataEmpty
+					return (CheckedEthCallResult::ReturnDataEmpty, None)
 				} else {
 					data
 				},
 			Err(err) => {
 				log!(error, "💎 eth_call at: {:?}, failed: {:?}", target_block_number, err);
-				return CheckedEthCallResult::DataProviderErr
+				return (CheckedEthCallResult::DataProviderErr, None)
 			},
 		};
 
-		// valid returndata is ethereum abi encoded and therefore always >= 32 bytes
-		match return_data.try_into() {
-			Ok(r) => CheckedEthCallResult::Ok(r, target_block_number, target_block_timestamp),
-			Err(_) => CheckedEthCallResult::ReturnDataExceedsLimit,
+		if return_data.len() > T::MaxEthCallReturnData::get() as usize {
+			return (CheckedEthCallResult::ReturnDataExceedsLimit, None)
 		}
+		// notaries vote on the hash of the returndata, the returndata itself is stored once
+		let return_data_hash = sp_io::hashing::keccak_256(&return_data);
+		(
+			CheckedEthCallResult::Ok(return_data_hash, target_block_number, target_block_timestamp),
+			Some(return_data),
+		)
 	}
 
 	/// Send a notarization for the given claim
@@ -430,6 +536,27 @@ impl<T: Config> Module<T> {
 			.map_err(|_| <Error<T>>::OffchainUnsignedTxSignedPayload)
 	}
 
+	/// Send a BLS key registration
+	fn offchain_send_bls_key(
+		key: &T::EthyId,
+		registration: BlsKeyRegistration,
+	) -> Result<(), Error<T>> {
+		let signature = key
+			.sign(&registration.encode())
+			.ok_or(<Error<T>>::OffchainUnsignedTxSignedPayload)?;
+
+		let call = Call::submit_bls_key { registration, _signature: signature };
+
+		SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+			.map_err(|_| <Error<T>>::OffchainUnsignedTxSignedPayload)
+	}
+
+	/// Return the registered BLS public keys of the active Ethy validator set, ordered as
+	/// `validator_set`
+	pub fn bls_public_keys() -> Vec<Option<bls::Public>> {
+		Self::notary_keys().iter().map(Self::notary_bls_key).collect()
+	}
+
 	/// Return the active Ethy validator set.
 	pub fn validator_set() -> ValidatorSet<T::EthyId> {
 		let validator_keys = Self::notary_keys();
@@ -486,9 +613,9 @@ impl<T: Config> Module<T> {
 	}
 
 	/// Handle claim after challenge has proven claim to be invalid
-	/// Slash relayer and pay slashed amount to challenger
+	/// Slash the submitting relayer and pay slashed amount to challenger
 	/// repay challenger bond to challenger
-	/// Remove the active relayer
+	/// Remove the submitting relayer from the relayer set
 	pub(crate) fn handle_invalid_claim(event_claim_id: EventClaimId) -> DispatchResult {
 		if let Some(cursor) = <EventNotarizations<T>>::clear_prefix(
 			event_claim_id,
@@ -500,14 +627,14 @@ impl<T: Config> Module<T> {
 			log!(error, "💎 cleaning storage entries failed: {:?}", cursor);
 			return Err(Error::<T>::Internal.into())
 		}
-		PendingClaimChallenges::mutate(|event_ids| {
+		PendingClaimChallenges::<T>::mutate(|event_ids| {
 			event_ids
 				.iter()
 				.position(|x| *x == event_claim_id)
 				.map(|idx| event_ids.remove(idx));
 		});
 
-		if let Some(_event_claim) = PendingEventClaims::take(event_claim_id) {
+		if let Some(_event_claim) = PendingEventClaims::<T>::take(event_claim_id) {
 			if let Some((challenger, bond_amount)) = <ChallengerAccount<T>>::take(event_claim_id) {
 				// Challenger is correct, the event is invalid.
 				// Return challenger bond to challenger and reward challenger with relayer bond
@@ -518,22 +645,32 @@ impl<T: Config> Module<T> {
 					bond_amount,
 				)?;
 
-				if let Some(relayer) = Self::relayer() {
+				if let Some(relayer) = EventClaimRelayer::<T>::take(event_claim_id) {
 					// Relayer bond goes to challenger
 					let relayer_paid_bond = <RelayerPaidBond<T>>::take(relayer.clone());
-					T::MultiCurrency::spend_hold(
-						T::BridgePalletId::get(),
-						&relayer,
-						T::NativeAssetId::get(),
-						&[(challenger, relayer_paid_bond)],
-					)?;
-					// Relayer has been slashed, remove their stored bond amount and set relayer to
-					// None
-					<Relayer<T>>::kill();
-				};
+					if relayer_paid_bond > 0 {
+						T::MultiCurrency::spend_hold(
+							T::BridgePalletId::get(),
+							&relayer,
+							T::NativeAssetId::get(),
+							&[(challenger.clone(), relayer_paid_bond)],
+						)?;
+					}
+					// Relayer has been slashed, remove them from the relayer set
+					Relayers::<T>::mutate(|relayers| relayers.retain(|r| r != &relayer));
+					RelayerLastSubmission::<T>::remove(&relayer);
+					RelayerUnbonding::<T>::remove(&relayer);
+					Self::deposit_event(Event::<T>::RelayerSlashed(
+						event_claim_id,
+						relayer,
+						challenger,
+						relayer_paid_bond,
+					));
+				} else {
+					log!(error, "💎 unexpected missing claim relayer");
+				}
 
-				PendingClaimStatus::remove(event_claim_id);
-				Self::deposit_event(Event::<T>::RelayerSet(None));
+				PendingClaimStatus::<T>::remove(event_claim_id);
 			} else {
 				// This shouldn't happen
 				log!(error, "💎 unexpected missing challenger account");
@@ -547,7 +684,7 @@ impl<T: Config> Module<T> {
 	}
 
 	/// Handle claim after challenge has proven claim to be valid
-	/// Pay challenger bond to relayer
+	/// Pay challenger bond to the submitting relayer
 	pub(crate) fn handle_valid_claim(event_claim_id: EventClaimId) -> DispatchResult {
 		// no need to track info on this claim any more since it's approved
 		if let Some(cursor) = <EventNotarizations<T>>::clear_prefix(
@@ -561,15 +698,15 @@ impl<T: Config> Module<T> {
 			return Err(Error::<T>::Internal.into())
 		}
 		// Remove the claim from pending_claim_challenges
-		PendingClaimChallenges::mutate(|event_ids| {
+		PendingClaimChallenges::<T>::mutate(|event_ids| {
 			event_ids
 				.iter()
 				.position(|x| *x == event_claim_id)
 				.map(|idx| event_ids.remove(idx));
 		});
 
-		if PendingEventClaims::contains_key(event_claim_id) {
-			if let Some(relayer) = Self::relayer() {
+		if PendingEventClaims::<T>::contains_key(event_claim_id) {
+			if let Some(relayer) = Self::event_claim_relayer(event_claim_id) {
 				if let Some((challenger, bond_amount)) =
 					<ChallengerAccount<T>>::take(event_claim_id)
 				{
@@ -585,10 +722,10 @@ impl<T: Config> Module<T> {
 					log!(error, "💎 unexpected missing challenger account");
 				}
 
-				PendingClaimStatus::insert(event_claim_id, EventClaimStatus::ProvenValid);
+				PendingClaimStatus::<T>::insert(event_claim_id, EventClaimStatus::ProvenValid);
 				Self::deposit_event(Event::<T>::Verified(event_claim_id));
 			} else {
-				log!(error, "💎 No relayer set");
+				log!(error, "💎 unexpected missing claim relayer");
 			}
 		} else {
 			log!(error, "💎 unexpected empty claim");
@@ -597,17 +734,71 @@ impl<T: Config> Module<T> {
 		Ok(())
 	}
 
+	/// Add the bonded account `relayer` to the relayer set
+	pub(crate) fn do_add_relayer(relayer: T::AccountId) -> DispatchResult {
+		// Ensure relayer has bonded more than relayer bond amount
+		ensure!(
+			Self::relayer_paid_bond(relayer.clone()) >= T::RelayerBond::get(),
+			Error::<T>::NoBondPaid
+		);
+		ensure!(!RelayerUnbonding::<T>::contains_key(&relayer), Error::<T>::RelayerUnbonding);
+		Relayers::<T>::try_mutate(|relayers| -> DispatchResult {
+			ensure!(!relayers.contains(&relayer), Error::<T>::RelayerAlreadyActive);
+			relayers.push(relayer.clone());
+			Ok(())
+		})?;
+		RelayerLastSubmission::<T>::insert(&relayer, <frame_system::Pallet<T>>::block_number());
+		Self::deposit_event(Event::<T>::RelayerAdded(relayer));
+		Ok(())
+	}
+
+	/// Remove `relayer` from the relayer set and start unbonding its bond
+	pub(crate) fn do_remove_relayer(relayer: &T::AccountId) -> DispatchResult {
+		Relayers::<T>::try_mutate(|relayers| -> DispatchResult {
+			let idx =
+				relayers.iter().position(|r| r == relayer).ok_or(Error::<T>::RelayerNotActive)?;
+			relayers.remove(idx);
+			Ok(())
+		})?;
+		RelayerLastSubmission::<T>::remove(relayer);
+		let unlock_at =
+			<frame_system::Pallet<T>>::block_number() + T::RelayerUnbondingPeriod::get();
+		RelayerUnbonding::<T>::insert(relayer, unlock_at);
+		Self::deposit_event(Event::<T>::RelayerRemoved(relayer.clone()));
+		Self::deposit_event(Event::<T>::RelayerUnbonding(relayer.clone(), unlock_at));
+		Ok(())
+	}
+
 	/// Handle a submitted call notarization
+	/// `return_data` is the returndata of an `Ok` result, required only if it's not yet stored
 	pub(crate) fn handle_call_notarization(
 		call_id: EthCallId,
 		result: CheckedEthCallResult,
+		return_data: Option<Vec<u8>>,
 		notary_id: &T::EthyId,
 	) -> DispatchResult {
-		if !EthCallRequestInfo::contains_key(call_id) {
+		if !EthCallRequestInfo::<T>::contains_key(call_id) {
 			// there's no claim active
 			return Err(Error::<T>::InvalidClaim.into())
 		}
 
+		// Store the return data once, notaries vote on its hash
+		if let CheckedEthCallResult::Ok(return_data_hash, ..) = result {
+			if !EthCallReturnData::<T>::contains_key(call_id, return_data_hash) {
+				let return_data = return_data.ok_or(Error::<T>::MissingEthCallReturnData)?;
+				ensure!(
+					sp_io::hashing::keccak_256(&return_data) == return_data_hash,
+					Error::<T>::InvalidEthCallReturnData
+				);
+				let return_data: BoundedVec<u8, T::MaxEthCallReturnData> =
+					BoundedVec::try_from(return_data)
+						.map_err(|_| Error::<T>::InvalidEthCallReturnData)?;
+				EthCallReturnData::<T>::insert(call_id, return_data_hash, return_data);
+			}
+		} else if return_data.is_some() {
+			return Err(Error::<T>::InvalidEthCallReturnData.into())
+		}
+
 		// Record the notarization (ensures the validator won't resubmit it)
 		<EthCallNotarizations<T>>::insert::<EventClaimId, T::EthyId, CheckedEthCallResult>(
 			call_id,
@@ -618,13 +809,20 @@ impl<T: Config> Module<T> {
 		// notify subscribers of a notarized eth_call outcome and clean upstate
 		let do_callback_and_clean_up = |result: CheckedEthCallResult| {
 			match result {
-				CheckedEthCallResult::Ok(return_data, block, timestamp) =>
-					T::EthCallSubscribers::on_eth_call_complete(
-						call_id,
-						&return_data,
-						block,
-						timestamp,
-					),
+				CheckedEthCallResult::Ok(return_data_hash, block, timestamp) =>
+					match EthCallReturnData::<T>::get(call_id, return_data_hash) {
+						Some(return_data) => T::EthCallSubscribers::on_eth_call_complete(
+							call_id,
+							&return_data,
+							block,
+							timestamp,
+						),
+						// should not happen, the return data is stored with the first notarization
+						None => T::EthCallSubscribers::on_eth_call_failed(
+							call_id,
+							EthCallFailure::Internal,
+						),
+					},
 				CheckedEthCallResult::ReturnDataEmpty => T::EthCallSubscribers::on_eth_call_failed(
 					call_id,
 					EthCallFailure::ReturnDataEmpty,
@@ -646,16 +844,23 @@ impl<T: Config> Module<T> {
 				log!(error, "💎 cleaning storage entries failed: {:?}", cursor);
 				return Err(Error::<T>::Internal.into())
 			};
-			EthCallNotarizationsAggregated::remove(call_id);
-			EthCallRequestInfo::remove(call_id);
-			EthCallRequests::mutate(|requests| {
+			// at most one return data entry per notary
+			let _ = EthCallReturnData::<T>::clear_prefix(
+				call_id,
+				NotaryKeys::<T>::decode_len().unwrap_or(1_000) as u32,
+				None,
+			);
+			EthCallNotarizationsAggregated::<T>::remove(call_id);
+			EthCallRequestInfo::<T>::remove(call_id);
+			EthCallRequests::<T>::mutate(|requests| {
 				requests.iter().position(|x| *x == call_id).map(|idx| requests.remove(idx));
 			});
 
 			Ok(())
 		};
 
-		let mut notarizations = EthCallNotarizationsAggregated::get(call_id).unwrap_or_default();
+		let mut notarizations =
+			EthCallNotarizationsAggregated::<T>::get(call_id).unwrap_or_default();
 		// increment notarization count for this result
 		*notarizations.entry(result).or_insert(0) += 1;
 
@@ -681,7 +886,7 @@ impl<T: Config> Module<T> {
 		}
 
 		// update counts
-		EthCallNotarizationsAggregated::insert(call_id, notarizations);
+		EthCallNotarizationsAggregated::<T>::insert(call_id, notarizations);
 		Ok(())
 	}
 
@@ -718,7 +923,7 @@ impl<T: Config> Module<T> {
 		// notify ethereum contract about validator set change
 		if let Ok(event_proof_id) = Self::send_event(
 			&T::BridgePalletId::get().into_account_truncating(),
-			&Self::contract_address(),
+			&Self::contract_address(EthyChainId::Ethereum),
 			new_validator_set_message.as_slice(),
 		) {
 			// Signal the Event Id that will be used for the proof of validator set change.
@@ -728,9 +933,26 @@ impl<T: Config> Module<T> {
 				event_proof_id,
 				next_validator_set_id,
 			));
-			NotarySetProofId::put(event_proof_id);
+			NotarySetProofId::<T>::put(event_proof_id);
 			// Indicate that the authorities have been changed
-			AuthoritiesChangedThisEra::put(true);
+			AuthoritiesChangedThisEra::<T>::put(true);
+		}
+
+		// keep the validator set on the secondary EVM bridge contract in sync, if it is bridged
+		let secondary_contract_address = Self::contract_address(EthyChainId::SecondaryEvm);
+		if !secondary_contract_address.is_zero() {
+			if let Ok(event_proof_id) = Self::send_event_to(
+				EthyChainId::SecondaryEvm,
+				&T::BridgePalletId::get().into_account_truncating(),
+				&secondary_contract_address,
+				new_validator_set_message.as_slice(),
+			) {
+				Self::deposit_event(Event::<T>::EvmAuthoritySetChange(
+					EthyChainId::SecondaryEvm,
+					event_proof_id,
+					next_validator_set_id,
+				));
+			}
 		}
 
 		// request for proof xrpl - SignerListSet
@@ -745,7 +967,7 @@ impl<T: Config> Module<T> {
 		if notary_xrpl_keys == next_notary_xrpl_keys {
 			info!(target: "ethy-pallet", "💎 notary xrpl keys unchanged {:?}", next_notary_xrpl_keys);
 			// Pause the bridge
-			BridgePaused::put(true);
+			BridgePaused::<T>::put(true);
 			<NextAuthorityChange<T>>::kill();
 			return
 		}
@@ -768,7 +990,7 @@ impl<T: Config> Module<T> {
 					event_proof_id,
 					next_validator_set_id,
 				));
-				XrplNotarySetProofId::put(event_proof_id);
+				XrplNotarySetProofId::<T>::put(event_proof_id);
 			},
 			Err(e) => {
 				warn!(target: "ethy-pallet", "💎 Failed to send xrpl signer list set request {:?}", e);
@@ -777,7 +999,7 @@ impl<T: Config> Module<T> {
 		};
 
 		// Pause the bridge
-		BridgePaused::put(true);
+		BridgePaused::<T>::put(true);
 		<NextAuthorityChange<T>>::kill();
 	}
 
@@ -800,15 +1022,36 @@ impl<T: Config> Module<T> {
 		<frame_system::Pallet<T>>::deposit_log(log);
 
 		// Unpause the bridge
-		BridgePaused::kill();
+		BridgePaused::<T>::kill();
 		// A proof should've been generated now so we can reactivate the bridge with the new
 		// validator set
-		AuthoritiesChangedThisEra::kill();
+		AuthoritiesChangedThisEra::<T>::kill();
 		// Store the new keys and increment the validator set id
 		// Next notary keys should be unset, until populated by new session logic
 		<NotaryKeys<T>>::put(&next_notary_keys);
 		Self::update_xrpl_notary_keys(&next_notary_keys);
-		NotarySetId::mutate(|next_set_id| *next_set_id = next_set_id.wrapping_add(1));
+		NotarySetId::<T>::mutate(|next_set_id| *next_set_id = next_set_id.wrapping_add(1));
+	}
+
+	/// Request signing of the merkle root of the events queued for `chain_id`
+	///
+	/// Returns whether a batch was requested
+	pub(crate) fn do_request_event_batch(chain_id: EthyChainId) -> bool {
+		let leaves = PendingEventBatch::<T>::take(chain_id);
+		if leaves.is_empty() {
+			return false
+		}
+		let batch_id = Self::next_event_proof_id();
+		NextEventProofId::<T>::put(batch_id.wrapping_add(1));
+
+		let batch = EventBatchInfo {
+			chain_id,
+			batch_id,
+			validator_set_id: Self::validator_set().id,
+			leaves,
+		};
+		Self::do_request_event_proof(batch_id, EthySigningRequest::EventBatch(batch));
+		true
 	}
 
 	/// Submit an event proof signing request in the block, for use by the ethy-gadget protocol
@@ -819,7 +1062,7 @@ impl<T: Config> Module<T> {
 		// if bridge is paused (e.g transitioning authority set at the end of an era)
 		// delay proofs until it is ready again
 		if Self::bridge_paused() {
-			PendingEventProofs::insert(event_proof_id, request);
+			PendingEventProofs::<T>::insert(event_proof_id, request);
 			Self::deposit_event(Event::<T>::ProofDelayed(event_proof_id));
 			return
 		}
@@ -834,59 +1077,28 @@ impl<T: Config> Module<T> {
 			.encode(),
 		);
 		<frame_system::Pallet<T>>::deposit_log(log);
-		Self::deposit_event(Event::<T>::EventSend { event_proof_id, signing_request: request });
-	}
-}
-
-impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
-	type Call = Call<T>;
-
-	fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-		if let Call::submit_notarization { ref payload, _signature: ref signature } = call {
-			// notarization must be from an active notary
-			let notary_keys = Self::notary_keys();
-			let notary_public_key = match notary_keys.get(payload.authority_index() as usize) {
-				Some(id) => id,
-				None => return InvalidTransaction::BadProof.into(),
-			};
-			// notarization must not be a duplicate/equivocation
-			if <EventNotarizations<T>>::contains_key(payload.payload_id(), &notary_public_key) {
-				log!(
-					error,
-					"💎 received equivocation from: {:?} on {:?}",
-					notary_public_key,
-					payload.payload_id()
-				);
-				return InvalidTransaction::BadProof.into()
-			}
-			// notarization is signed correctly
-			if !(notary_public_key.verify(&payload.encode(), signature)) {
-				return InvalidTransaction::BadProof.into()
-			}
-			ValidTransaction::with_tag_prefix("eth-bridge")
-				.priority(UNSIGNED_TXS_PRIORITY)
-				// 'provides' must be unique for each submission on the network (i.e. unique for
-				// each claim id and validator)
-				.and_provides([
-					b"notarize",
-					&payload.type_id().to_be_bytes(),
-					&payload.payload_id().to_be_bytes(),
-					&(payload.authority_index() as u64).to_be_bytes(),
-				])
-				.longevity(3)
-				.propagate(true)
-				.build()
-		} else {
-			InvalidTransaction::Call.into()
+		// ethy-gadget requires the batch leaves to make inclusion proofs for each event
+		if let EthySigningRequest::EventBatch(batch) = &request {
+			let log: DigestItem = DigestItem::Consensus(
+				ETHY_ENGINE_ID,
+				ConsensusLog::<T::AccountId>::EventBatch {
+					chain_id: batch.chain_id,
+					batch_id: event_proof_id,
+					leaves: batch.leaves.clone(),
+				}
+				.encode(),
+			);
+			<frame_system::Pallet<T>>::deposit_log(log);
 		}
+		Self::deposit_event(Event::<T>::EventSend { event_proof_id, signing_request: request });
 	}
 }
 
-impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Module<T> {
+impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Pallet<T> {
 	type Public = T::EthyId;
 }
 
-impl<T: Config> OneSessionHandler<T::AccountId> for Module<T> {
+impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 	type Key = T::EthyId;
 
 	fn on_genesis_session<'a, I: 'a>(validators: I)
@@ -961,7 +1173,7 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Module<T> {
 	fn on_disabled(_i: u32) {}
 }
 
-impl<T: Config> EthCallOracle for Module<T> {
+impl<T: Config> EthCallOracle for Pallet<T> {
 	type Address = EthAddress;
 	type CallId = EthCallId;
 	/// Request an eth_call on some `target` contract with `input` on the bridged ethereum network
@@ -978,8 +1190,8 @@ impl<T: Config> EthCallOracle for Module<T> {
 		max_block_look_behind: u64,
 	) -> Self::CallId {
 		// store the job for validators to process async
-		let call_id = NextEthCallId::get();
-		EthCallRequestInfo::insert(
+		let call_id = NextEthCallId::<T>::get();
+		EthCallRequestInfo::<T>::insert(
 			call_id,
 			CheckedEthCallRequest {
 				check_timestamp: T::UnixTime::now().as_secs(),
@@ -990,8 +1202,8 @@ impl<T: Config> EthCallOracle for Module<T> {
 				max_block_look_behind,
 			},
 		);
-		EthCallRequests::append(call_id);
-		NextEthCallId::put(call_id + 1);
+		EthCallRequests::<T>::append(call_id);
+		NextEthCallId::<T>::put(call_id + 1);
 
 		call_id
 	}
@@ -38,22 +38,19 @@
 
 use ethabi::{ParamType, Token};
 use frame_support::{
-	decl_error, decl_event, decl_module, decl_storage,
 	pallet_prelude::*,
 	traits::{
-		fungibles::Transfer,
+		fungibles::{Mutate, Transfer},
 		schedule::{Anon, DispatchTime},
 		UnixTime, ValidatorSet as ValidatorSetT,
 	},
-	transactional,
-	weights::constants::RocksDbWeight as DbWeight,
-	PalletId, Parameter,
+	transactional, PalletId, Parameter,
 };
 use frame_system::{offchain::CreateSignedTransaction, pallet_prelude::*};
 use hex_literal::hex;
 use sp_runtime::{
 	offchain as rt_offchain,
-	traits::{MaybeSerializeDeserialize, Member, SaturatedConversion},
+	traits::{MaybeSerializeDeserialize, Member, SaturatedConversion, Zero},
 	Percent, RuntimeAppPublic,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
@@ -62,7 +59,12 @@ use seed_pallet_common::{
 	log, EthCallOracleSubscriber, EthereumEventRouter, EthyToXrplBridgeAdapter, EventRouterError,
 	FinalSessionTracker as FinalSessionTrackerT, Hold,
 };
-use seed_primitives::{AssetId, Balance};
+use seed_primitives::{
+	ethy::{bls, EthyChainId, EventProofScheme},
+	AssetId, Balance,
+};
+
+pub use pallet::*;
 
 mod ethereum_http_cli;
 pub use ethereum_http_cli::EthereumRpcClient;
@@ -73,14 +75,22 @@ mod mock;
 #[cfg(test)]
 mod tests;
 mod types;
+pub use types::EventClaim;
 use types::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 /// The type to sign and send transactions.
 const UNSIGNED_TXS_PRIORITY: u64 = 100;
 /// Max notarization claims to attempt per block/OCW invocation
 const CLAIMS_PER_BLOCK: usize = 1;
 /// Max eth_call checks to attempt per block/OCW invocation
 const CALLS_PER_BLOCK: usize = 1;
+/// Max. number of outgoing events committed to by a single event batch
+pub(crate) const MAX_EVENT_BATCH_SIZE: usize = 128;
 
 /// The logging target for this pallet
 pub(crate) const LOG_TARGET: &str = "ethy";
@@ -91,131 +101,346 @@ pub(crate) const LOG_TARGET: &str = "ethy";
 const SUBMIT_BRIDGE_EVENT_SELECTOR: [u8; 32] =
 	hex!("0f8885c9654c5901d61d2eae1fa5d11a67f9b8fca77146d5109bc7be00f4472a");
 
-/// This is the pallet's configuration trait
-pub trait Config: frame_system::Config + CreateSignedTransaction<Call<Self>> {
-	/// Length of time the bridge will be paused while the authority set changes
-	type AuthorityChangeDelay: Get<Self::BlockNumber>;
-	/// Knows the active authority set (validator stash addresses)
-	type AuthoritySet: ValidatorSetT<Self::AccountId, ValidatorId = Self::AccountId>;
-	/// The pallet bridge address (destination for incoming messages, source for outgoing)
-	type BridgePalletId: Get<PalletId>;
-	/// The runtime call type.
-	type RuntimeCall: From<Call<Self>>;
-	/// Bond required by challenger to make a challenge
-	type ChallengeBond: Get<Balance>;
-	// The duration in blocks of one epoch
-	type EpochDuration: Get<u64>;
-	/// Pallet subscribing to of notarized eth calls
-	type EthCallSubscribers: EthCallOracleSubscriber<CallId = EthCallId>;
-	/// Provides an api for Ethereum JSON-RPC request/responses to the bridged ethereum network
-	type EthereumRpcClient: BridgeEthereumRpcApi;
-	/// The runtime event type.
-	type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
-	/// Handles routing received Ethereum events upon verification
-	type EventRouter: EthereumEventRouter;
-	/// The identifier type for an authority in this module (i.e. active validator session key)
-	/// 33 byte secp256k1 public key
-	type EthyId: Member
-		+ Parameter
-		+ AsRef<[u8]>
-		+ RuntimeAppPublic
-		+ Ord
-		+ MaybeSerializeDeserialize;
-	/// Reports the final session of na eras
-	type FinalSessionTracker: FinalSessionTrackerT;
-	/// Max amount of new signers that can be set an in extrinsic
-	type MaxNewSigners: Get<u8>;
-	/// Handles a multi-currency fungible asset system
-	type MultiCurrency: Transfer<Self::AccountId> + Hold<AccountId = Self::AccountId>;
-	/// The native token asset Id (managed by pallet-balances)
-	type NativeAssetId: Get<AssetId>;
-	/// The threshold of notarizations required to approve an Ethereum event
-	type NotarizationThreshold: Get<Percent>;
-	/// Bond required for an account to act as relayer
-	type RelayerBond: Get<Balance>;
-	/// The Scheduler.
-	type Scheduler: Anon<Self::BlockNumber, <Self as Config>::RuntimeCall, Self::PalletsOrigin>;
-	/// Overarching type of all pallets origins.
-	type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
-	/// Returns the block timestamp
-	type UnixTime: UnixTime;
-	/// Max Xrpl notary (validator) public keys
-	type MaxXrplKeys: Get<u8>;
-	/// Xrpl-bridge adapter
-	type XrplBridgeAdapter: EthyToXrplBridgeAdapter<H160>;
-}
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	/// The current storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// This is the pallet's configuration trait
+	#[pallet::config]
+	pub trait Config: frame_system::Config + CreateSignedTransaction<Call<Self>> {
+		/// Length of time the bridge will be paused while the authority set changes
+		type AuthorityChangeDelay: Get<Self::BlockNumber>;
+		/// Knows the active authority set (validator stash addresses)
+		type AuthoritySet: ValidatorSetT<Self::AccountId, ValidatorId = Self::AccountId>;
+		/// The pallet bridge address (destination for incoming messages, source for outgoing)
+		type BridgePalletId: Get<PalletId>;
+		/// The runtime call type.
+		type RuntimeCall: From<Call<Self>>;
+		/// Bond required by challenger to make a challenge
+		type ChallengeBond: Get<Balance>;
+		// The duration in blocks of one epoch
+		type EpochDuration: Get<u64>;
+		/// Pallet subscribing to of notarized eth calls
+		type EthCallSubscribers: EthCallOracleSubscriber<CallId = EthCallId>;
+		/// Provides an api for Ethereum JSON-RPC request/responses to the bridged ethereum network
+		type EthereumRpcClient: BridgeEthereumRpcApi;
+		/// The runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Handles routing received Ethereum events upon verification
+		type EventRouter: EthereumEventRouter;
+		/// The identifier type for an authority in this module (i.e. active validator session key)
+		/// 33 byte secp256k1 public key
+		type EthyId: Member
+			+ Parameter
+			+ AsRef<[u8]>
+			+ RuntimeAppPublic
+			+ Ord
+			+ MaybeSerializeDeserialize;
+		/// Reports the final session of na eras
+		type FinalSessionTracker: FinalSessionTrackerT;
+		/// Max amount of new signers that can be set an in extrinsic
+		type MaxNewSigners: Get<u8>;
+		/// Max length of the return data of a checked eth_call (bytes)
+		#[pallet::constant]
+		type MaxEthCallReturnData: Get<u32>;
+		/// Handles a multi-currency fungible asset system
+		type MultiCurrency: Transfer<Self::AccountId>
+			+ Mutate<Self::AccountId, AssetId = AssetId, Balance = Balance>
+			+ Hold<AccountId = Self::AccountId>;
+		/// The native token asset Id (managed by pallet-balances)
+		type NativeAssetId: Get<AssetId>;
+		/// The threshold of notarizations required to approve an Ethereum event
+		type NotarizationThreshold: Get<Percent>;
+		/// Bond required for an account to act as relayer
+		type RelayerBond: Get<Balance>;
+		/// Blocks a relayer's bond stays locked after leaving the relayer set, so that any
+		/// outstanding claims can still be challenged
+		type RelayerUnbondingPeriod: Get<Self::BlockNumber>;
+		/// Blocks without a submission after which a relayer is considered offline in round robin
+		/// mode, any relayer may then submit the offline relayer's events
+		type RoundRobinTimeout: Get<Self::BlockNumber>;
+		/// The Scheduler.
+		type Scheduler: Anon<Self::BlockNumber, <Self as Config>::RuntimeCall, Self::PalletsOrigin>;
+		/// Overarching type of all pallets origins.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+		/// Returns the block timestamp
+		type UnixTime: UnixTime;
+		/// Max Xrpl notary (validator) public keys
+		type MaxXrplKeys: Get<u8>;
+		/// Xrpl-bridge adapter
+		type XrplBridgeAdapter: EthyToXrplBridgeAdapter<H160>;
+		/// Provides the weights for this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultChallengePeriod<T: Config>() -> T::BlockNumber {
+		// 10 Minutes
+		T::BlockNumber::from(150_u32)
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultEventBlockConfirmations() -> u64 {
+		3_u64
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultDelayedEventProofsPerBlock() -> u8 {
+		5_u8
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn authorities_changed_this_era)]
+	/// Flag to indicate whether authorities have been changed during the current era
+	pub type AuthoritiesChangedThisEra<T> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_paused)]
+	/// Whether the bridge is paused (e.g. during validator transitions or by governance)
+	pub type BridgePaused<T> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn challenger_account)]
+	/// Maps from event claim id to challenger and bond amount paid
+	pub type ChallengerAccount<T: Config> =
+		StorageMap<_, Twox64Concat, EventClaimId, (T::AccountId, Balance)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn challenge_period)]
+	/// The (optimistic) challenge period after which a submitted event is considered valid
+	pub type ChallengePeriod<T: Config> =
+		StorageValue<_, T::BlockNumber, ValueQuery, DefaultChallengePeriod<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn contract_address)]
+	/// The bridge contract address on each EVM chain
+	pub type ContractAddress<T> = StorageMap<_, Twox64Concat, EthyChainId, EthAddress, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn event_block_confirmations)]
+	/// The minimum number of block confirmations needed to notarize an event on each EVM chain
+	pub type EventBlockConfirmations<T> =
+		StorageMap<_, Twox64Concat, EthyChainId, u64, ValueQuery, DefaultEventBlockConfirmations>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn event_finality_mode)]
+	/// How the finality of an event's block is decided on each EVM chain
+	pub type EventFinalityModes<T> =
+		StorageMap<_, Twox64Concat, EthyChainId, EventFinalityMode, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn event_proof_scheme)]
+	/// How ethy proves outgoing events on each EVM chain
+	pub type EventProofSchemes<T> =
+		StorageMap<_, Twox64Concat, EthyChainId, EventProofScheme, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn notary_bls_key)]
+	/// BLS public keys of notaries, derived from and registered by their Ethy session key
+	pub type NotaryBlsKeys<T: Config> = StorageMap<_, Twox64Concat, T::EthyId, bls::Public>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn event_notarizations)]
+	/// Notarizations for queued events
+	/// Either: None = no notarization exists OR Some(yay/nay)
+	pub type EventNotarizations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EventClaimId, Twox64Concat, T::EthyId, EventClaimResult>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn delayed_event_proofs_per_block)]
+	/// The maximum number of delayed events that can be processed in on_initialize()
+	pub type DelayedEventProofsPerBlock<T> =
+		StorageValue<_, u8, ValueQuery, DefaultDelayedEventProofsPerBlock>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_event_proof_id)]
+	/// Id of the next event proof
+	pub type NextEventProofId<T> = StorageValue<_, EventProofId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_notary_keys)]
+	/// Scheduled notary (validator) public keys for the next session
+	pub type NextNotaryKeys<T: Config> = StorageValue<_, Vec<T::EthyId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn notary_keys)]
+	/// Active notary (validator) public keys
+	pub type NotaryKeys<T: Config> = StorageValue<_, Vec<T::EthyId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn notary_xrpl_keys)]
+	/// Active xrpl notary (validator) public keys
+	pub type NotaryXrplKeys<T: Config> = StorageValue<_, Vec<T::EthyId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn xrpl_door_signers)]
+	/// Door Signers set by sudo (white list)
+	pub type XrplDoorSigners<T: Config> = StorageMap<_, Twox64Concat, T::EthyId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn notary_set_id)]
+	/// The current validator set id
+	pub type NotarySetId<T> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn notary_set_proof_id)]
+	/// The event proof Id generated by the previous validator set to notarize the current set.
+	/// Useful for syncing the latest proof to Ethereum
+	pub type NotarySetProofId<T> = StorageValue<_, EventProofId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn xrpl_notary_set_proof_id)]
+	/// The event proof Id generated by the previous validator set to notarize the current set.
+	/// Useful for syncing the latest proof to Xrpl
+	pub type XrplNotarySetProofId<T> = StorageValue<_, EventProofId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_event_claims)]
+	/// Queued event claims, can be challenged within challenge period
+	pub type PendingEventClaims<T> = StorageMap<_, Twox64Concat, EventClaimId, EventClaim>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_event_proofs)]
+	/// Queued event proofs to be processed once bridge has been re-enabled
+	pub type PendingEventProofs<T> = StorageMap<_, Twox64Concat, EventProofId, EthySigningRequest>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn event_proof_batch_window)]
+	/// Outgoing EVM events are batched and signed as a single merkle root every `window` blocks
+	/// Batching is disabled when zero
+	pub type EventProofBatchWindow<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_event_batch)]
+	/// Outgoing events queued for the next batch of each EVM chain (event proof Id, event digest)
+	pub type PendingEventBatch<T> =
+		StorageMap<_, Twox64Concat, EthyChainId, Vec<(EventProofId, [u8; 32])>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_claim_challenges)]
+	/// List of all event ids that are currently being challenged
+	pub type PendingClaimChallenges<T> = StorageValue<_, Vec<EventClaimId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_claim_status)]
+	/// Status of pending event claims
+	pub type PendingClaimStatus<T> = StorageMap<_, Twox64Concat, EventProofId, EventClaimStatus>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn processed_message_ids)]
+	/// Tracks processed message Ids per EVM chain (prevent replay)
+	pub type ProcessedMessageIds<T> =
+		StorageMap<_, Twox64Concat, EthyChainId, Vec<EventClaimId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_authority_change)]
+	/// The block in which we process the next authority change
+	pub type NextAuthorityChange<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn messages_valid_at)]
+	/// Map from block number to list of EventClaims that will be considered valid and should be
+	/// forwarded to handlers (i.e after the optimistic challenge period has passed without issue)
+	pub type MessagesValidAt<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<EventClaimId>, ValueQuery>;
+
+	// State Oracle
+	#[pallet::storage]
+	/// Subscription Id for EthCall requests
+	pub type NextEthCallId<T> = StorageValue<_, EthCallId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayers)]
+	/// The permissioned and bonded relayers
+	pub type Relayers<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_submission_mode)]
+	/// How the relayers may submit event claims
+	pub type SubmissionMode<T> = StorageValue<_, RelayerSubmissionMode, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn event_claim_relayer)]
+	/// Map from event claim id to the relayer that submitted it
+	pub type EventClaimRelayer<T: Config> = StorageMap<_, Twox64Concat, EventClaimId, T::AccountId>;
 
-decl_storage! {
-	trait Store for Module<T: Config> as EthBridge {
-		/// Flag to indicate whether authorities have been changed during the current era
-		AuthoritiesChangedThisEra get(fn authorities_changed_this_era): bool;
-		/// Whether the bridge is paused (e.g. during validator transitions or by governance)
-		BridgePaused get(fn bridge_paused): bool;
-		/// Maps from event claim id to challenger and bond amount paid
-		ChallengerAccount get(fn challenger_account): map hasher(twox_64_concat) EventClaimId => Option<(T::AccountId, Balance)>;
-		/// The (optimistic) challenge period after which a submitted event is considered valid
-		ChallengePeriod get(fn challenge_period): T::BlockNumber = T::BlockNumber::from(150_u32); // 10 Minutes
-		/// The bridge contract address on Ethereum
-		pub ContractAddress get(fn contract_address): EthAddress;
-		/// The minimum number of block confirmations needed to notarize an Ethereum event
-		EventBlockConfirmations get(fn event_block_confirmations): u64 = 3;
-		/// Notarizations for queued events
-		/// Either: None = no notarization exists OR Some(yay/nay)
-		EventNotarizations get(fn event_notarizations): double_map hasher(twox_64_concat) EventClaimId, hasher(twox_64_concat) T::EthyId => Option<EventClaimResult>;
-		/// The maximum number of delayed events that can be processed in on_initialize()
-		DelayedEventProofsPerBlock get(fn delayed_event_proofs_per_block): u8 = 5;
-		/// Id of the next event proof
-		NextEventProofId get(fn next_event_proof_id): EventProofId;
-		/// Scheduled notary (validator) public keys for the next session
-		NextNotaryKeys get(fn next_notary_keys): Vec<T::EthyId>;
-		/// Active notary (validator) public keys
-		NotaryKeys get(fn notary_keys): Vec<T::EthyId>;
-		/// Active xrpl notary (validator) public keys
-		NotaryXrplKeys get(fn notary_xrpl_keys): Vec<T::EthyId>;
-		/// Door Signers set by sudo (white list)
-		XrplDoorSigners get(fn xrpl_door_signers): map hasher(twox_64_concat) T::EthyId => bool;
-		/// The current validator set id
-		NotarySetId get(fn notary_set_id): u64;
-		/// The event proof Id generated by the previous validator set to notarize the current set.
-		/// Useful for syncing the latest proof to Ethereum
-		NotarySetProofId get(fn notary_set_proof_id): EventProofId;
-		/// The event proof Id generated by the previous validator set to notarize the current set.
-		/// Useful for syncing the latest proof to Xrpl
-		XrplNotarySetProofId get(fn xrpl_notary_set_proof_id): EventProofId;
-		/// Queued event claims, can be challenged within challenge period
-		PendingEventClaims get(fn pending_event_claims): map hasher(twox_64_concat) EventClaimId => Option<EventClaim>;
-		/// Queued event proofs to be processed once bridge has been re-enabled
-		PendingEventProofs get(fn pending_event_proofs): map hasher(twox_64_concat) EventProofId => Option<EthySigningRequest>;
-		/// List of all event ids that are currently being challenged
-		PendingClaimChallenges get(fn pending_claim_challenges): Vec<EventClaimId>;
-		/// Status of pending event claims
-		PendingClaimStatus get(fn pending_claim_status): map hasher(twox_64_concat) EventProofId => Option<EventClaimStatus>;
-		/// Tracks processed message Ids (prevent replay)
-		ProcessedMessageIds get(fn processed_message_ids): Vec<EventClaimId>;
-		/// The block in which we process the next authority change
-		NextAuthorityChange get(fn next_authority_change): Option<T::BlockNumber>;
-		/// Map from block number to list of EventClaims that will be considered valid and should be forwarded to handlers (i.e after the optimistic challenge period has passed without issue)
-		MessagesValidAt get(fn messages_valid_at): map hasher(twox_64_concat) T::BlockNumber => Vec<EventClaimId>;
-		// State Oracle
-		/// Subscription Id for EthCall requests
-		NextEthCallId: EthCallId;
-		/// The permissioned relayer
-		Relayer get(fn relayer): Option<T::AccountId>;
-		/// Maps from relayer account to their paid bond amount
-		RelayerPaidBond get(fn relayer_paid_bond): map hasher(twox_64_concat) T::AccountId => Balance;
-		/// Queue of pending EthCallOracle requests
-		EthCallRequests get(fn eth_call_requests): Vec<EthCallId>;
-		/// EthCallOracle notarizations keyed by (Id, Notary)
-		EthCallNotarizations: double_map hasher(twox_64_concat) EthCallId, hasher(twox_64_concat) T::EthyId => Option<CheckedEthCallResult>;
-		/// map from EthCallOracle notarizations to an aggregated count
-		EthCallNotarizationsAggregated get(fn eth_call_notarizations_aggregated): map hasher(twox_64_concat) EthCallId => Option<BTreeMap<CheckedEthCallResult, u32>>;
-		/// EthCallOracle request info
-		EthCallRequestInfo get(fn eth_call_request_info): map hasher(twox_64_concat) EthCallId => Option<CheckedEthCallRequest>;
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_last_submission)]
+	/// Map from relayer account to the block it was added or last submitted an event claim at
+	pub type RelayerLastSubmission<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_unbonding)]
+	/// Map from former relayer account to the block their bond may be withdrawn at
+	pub type RelayerUnbonding<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_paid_bond)]
+	/// Maps from relayer account to their paid bond amount
+	pub type RelayerPaidBond<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn eth_call_requests)]
+	/// Queue of pending EthCallOracle requests
+	pub type EthCallRequests<T> = StorageValue<_, Vec<EthCallId>, ValueQuery>;
+
+	#[pallet::storage]
+	/// EthCallOracle notarizations keyed by (Id, Notary)
+	pub type EthCallNotarizations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EthCallId, Twox64Concat, T::EthyId, CheckedEthCallResult>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn eth_call_notarizations_aggregated)]
+	/// map from EthCallOracle notarizations to an aggregated count
+	pub type EthCallNotarizationsAggregated<T> =
+		StorageMap<_, Twox64Concat, EthCallId, BTreeMap<CheckedEthCallResult, u32>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn eth_call_request_info)]
+	/// EthCallOracle request info
+	pub type EthCallRequestInfo<T> = StorageMap<_, Twox64Concat, EthCallId, CheckedEthCallRequest>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn eth_call_return_data)]
+	/// EthCallOracle return data keyed by (Id, keccak256(return data))
+	/// Notaries vote on the hash, the return data is stored once by the first notarization
+	pub type EthCallReturnData<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EthCallId,
+		Identity,
+		[u8; 32],
+		BoundedVec<u8, T::MaxEthCallReturnData>,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub xrp_door_signers: Vec<T::EthyId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { xrp_door_signers: Default::default() }
+		}
 	}
-	add_extra_genesis {
-		config(xrp_door_signers): Vec<T::EthyId>;
-		build(|config: &GenesisConfig<T>| {
-			for new_signer in config.xrp_door_signers.iter() {
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for new_signer in self.xrp_door_signers.iter() {
 				XrplDoorSigners::<T>::insert(new_signer, true);
 			}
 			// set the NotaryXrplKeys as well
@@ -226,15 +451,12 @@ decl_storage! {
 				.take(T::MaxXrplKeys::get().into())
 				.collect::<Vec<_>>();
 			NotaryXrplKeys::<T>::put(genesis_xrpl_keys);
-		});
+		}
 	}
-}
 
-decl_event! {
-	pub enum Event<T> where
-		AccountId = <T as frame_system::Config>::AccountId,
-		BlockNumber = <T as frame_system::Config>::BlockNumber,
-	{
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
 		/// Verifying an event succeeded
 		Verified(EventClaimId),
 		/// Verifying an event failed
@@ -252,35 +474,57 @@ decl_event! {
 		/// Processing an event failed
 		ProcessingFailed(EventClaimId, EventRouterError),
 		/// An event has been challenged (claim_id, challenger)
-		Challenged(EventClaimId, AccountId),
+		Challenged(EventClaimId, T::AccountId),
 		/// The event is still awaiting consensus. Process block pushed out (claim_id, process_at)
-		ProcessAtExtended(EventClaimId, BlockNumber),
+		ProcessAtExtended(EventClaimId, T::BlockNumber),
 		/// An event proof has been sent for signing by ethy-gadget
 		EventSend { event_proof_id: EventProofId, signing_request: EthySigningRequest },
 		/// An event has been submitted from Ethereum (event_claim_id, event_claim, process_at)
-		EventSubmit(EventClaimId, EventClaim, BlockNumber),
+		EventSubmit(EventClaimId, EventClaim, T::BlockNumber),
 		/// An account has deposited a relayer bond
-		RelayerBondDeposit(AccountId, Balance),
+		RelayerBondDeposit(T::AccountId, Balance),
 		/// An account has withdrawn a relayer bond
-		RelayerBondWithdraw(AccountId, Balance),
-		/// A new relayer has been set
-		RelayerSet(Option<AccountId>),
+		RelayerBondWithdraw(T::AccountId, Balance),
+		/// A relayer has been added to the relayer set
+		RelayerAdded(T::AccountId),
 		/// Xrpl Door signers are set
 		XrplDoorSignersSet,
 		/// The schedule to unpause the bridge has failed (scheduled_block)
-		FinaliseScheduleFail(BlockNumber),
-		/// The bridge contract address has been set
-		SetContractAddress(EthAddress),
+		FinaliseScheduleFail(T::BlockNumber),
+		/// The bridge contract address has been set (chain_id, contract_address)
+		SetContractAddress(EthyChainId, EthAddress),
 		/// Xrpl authority set change request failed
-		XrplAuthoritySetChangeRequestFailed
+		XrplAuthoritySetChangeRequestFailed,
+		/// A relayer has been removed from the relayer set
+		RelayerRemoved(T::AccountId),
+		/// A relayer has left the relayer set, its bond may be withdrawn at (relayer, unlock_at)
+		RelayerUnbonding(T::AccountId, T::BlockNumber),
+		/// A relayer submitted an invalid claim and its bond was paid to the challenger
+		/// (claim_id, relayer, challenger, amount)
+		RelayerSlashed(EventClaimId, T::AccountId, T::AccountId, Balance),
+		/// The relayer submission mode has been set
+		RelayerSubmissionModeSet(RelayerSubmissionMode),
+		/// A notary (validator) set change for a secondary EVM chain is in motion
+		/// (chain_id, event_id, new_validator_set_id)
+		EvmAuthoritySetChange(EthyChainId, EventProofId, u64),
+		/// An event has been queued for the next event batch
+		EventBatchQueued { event_proof_id: EventProofId, signing_request: EthySigningRequest },
+		/// The event batch window has been set
+		EventProofBatchWindowSet(T::BlockNumber),
+		/// The event finality mode of an EVM chain has been set (chain_id, mode)
+		EventFinalityModeSet(EthyChainId, EventFinalityMode),
+		/// The event proof scheme of an EVM chain has been set (chain_id, scheme)
+		EventProofSchemeSet(EthyChainId, EventProofScheme),
+		/// A notary registered the BLS public key of its Ethy session key
+		BlsKeyRegistered(T::EthyId),
 	}
-}
 
-decl_error! {
-	pub enum Error for Module<T: Config> {
+	#[pallet::error]
+	pub enum Error<T> {
 		// Error returned when making signed transactions in off-chain worker
 		NoLocalSigningAccount,
-		// Error returned when making unsigned transactions with signed payloads in off-chain worker
+		// Error returned when making unsigned transactions with signed payloads in off-chain
+		// worker
 		OffchainUnsignedTxSignedPayload,
 		/// A notarization was invalid
 		InvalidNotarization,
@@ -312,22 +556,33 @@ decl_error! {
 		/// The relayer hasn't paid the relayer bond so can't be set as the active relayer
 		NoBondPaid,
 		/// Someone tried to set a greater amount of validators than allowed
-		MaxNewSignersExceeded
+		MaxNewSignersExceeded,
+		/// The account is already in the relayer set
+		RelayerAlreadyActive,
+		/// The account is not in the relayer set
+		RelayerNotActive,
+		/// The relayer is unbonding and can't rejoin or withdraw until the unbonding period ends
+		RelayerUnbonding,
+		/// The event must be submitted by the relayer whose turn it is
+		NotDesignatedRelayer,
+		/// The chain is not bridged by this operation
+		UnsupportedChain,
+		/// The eth_call return data does not match the notarized result or exceeds the limit
+		InvalidEthCallReturnData,
+		/// The eth_call return data of the notarized result has not been submitted
+		MissingEthCallReturnData,
 	}
-}
-
-decl_module! {
-	pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
-		type Error = Error<T>;
-
-		fn deposit_event() = default;
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		/// This method schedules 3 different processes
 		/// 1) Handle change in authorities 5 minutes before the end of an epoch
 		/// 2) Process any newly valid event claims (incoming)
-		/// 3) Process any deferred event proofs that were submitted while the bridge was paused (should only happen on the first few blocks in a new era) (outgoing)
+		/// 3) Process any deferred event proofs that were submitted while the bridge was paused
+		/// (should only happen on the first few blocks in a new era) (outgoing)
+		/// 4) Request signing of queued event batches at the end of each batch window (outgoing)
 		fn on_initialize(block_number: T::BlockNumber) -> Weight {
-			let mut consumed_weight = Weight::zero();
+			let mut consumed_weight = T::WeightInfo::on_initialize();
 
 			// 1) Handle authority change
 			if Some(block_number) == Self::next_authority_change() {
@@ -338,87 +593,229 @@ decl_module! {
 
 			// 2) Process validated messages
 			// Removed message_id from MessagesValidAt and processes
-			let mut processed_message_ids = Self::processed_message_ids();
+			let mut processed_message_ids = BTreeMap::<EthyChainId, Vec<EventClaimId>>::new();
 			for message_id in MessagesValidAt::<T>::take(block_number) {
+				consumed_weight =
+					consumed_weight.saturating_add(T::WeightInfo::on_initialize_event_claim());
 				if Self::pending_claim_status(message_id) == Some(EventClaimStatus::Challenged) {
-					// We are still waiting on the challenge to be processed, push out by challenge period
+					// We are still waiting on the challenge to be processed, push out by challenge
+					// period
 					let new_process_at = block_number + Self::challenge_period();
-					<MessagesValidAt<T>>::append(
-						new_process_at,
-						message_id,
-					);
+					MessagesValidAt::<T>::append(new_process_at, message_id);
 					Self::deposit_event(Event::<T>::ProcessAtExtended(message_id, new_process_at));
 					continue
 				}
 				// Removed PendingEventClaim from storage and processes
-				if let Some(EventClaim { source, destination, data, .. } ) = PendingEventClaims::take(message_id) {
+				if let Some(EventClaim { source, destination, data, chain_id, .. }) =
+					PendingEventClaims::<T>::take(message_id)
+				{
 					// keep a runtime hardcoded list of destination <> palletId
-					match T::EventRouter::route(&source, &destination, &data) {
+					match T::EventRouter::route(chain_id, &source, &destination, &data) {
 						Ok(weight) => {
-							consumed_weight += weight;
+							consumed_weight = consumed_weight.saturating_add(weight);
 							Self::deposit_event(Event::<T>::ProcessingOk(message_id));
 						},
 						Err((weight, err)) => {
-							consumed_weight += weight;
+							consumed_weight = consumed_weight.saturating_add(weight);
 							Self::deposit_event(Event::<T>::ProcessingFailed(message_id, err));
-						}
+						},
 					}
 				}
 				// mark as processed
-				if let Err(idx) = processed_message_ids.binary_search(&message_id) {
-					processed_message_ids.insert(idx, message_id);
+				if let Some(chain_id) = EthyChainId::from_event_claim_id(message_id) {
+					let processed_message_ids = processed_message_ids
+						.entry(chain_id)
+						.or_insert_with(|| Self::processed_message_ids(chain_id));
+					if let Err(idx) = processed_message_ids.binary_search(&message_id) {
+						processed_message_ids.insert(idx, message_id);
+					}
 				}
 				// Tidy up status check
-				PendingClaimStatus::remove(message_id);
+				PendingClaimStatus::<T>::remove(message_id);
+				EventClaimRelayer::<T>::remove(message_id);
 			}
-			if !processed_message_ids.is_empty() {
+			for (chain_id, mut processed_message_ids) in processed_message_ids {
 				impls::prune_claim_ids(&mut processed_message_ids);
-				ProcessedMessageIds::put(processed_message_ids);
+				ProcessedMessageIds::<T>::insert(chain_id, processed_message_ids);
 			}
 
 			// 3) Try process delayed proofs
-			consumed_weight += DbWeight::get().reads(2u64);
-			if PendingEventProofs::iter().next().is_some() && !Self::bridge_paused() {
+			if PendingEventProofs::<T>::iter().next().is_some() && !Self::bridge_paused() {
 				let max_delayed_events = Self::delayed_event_proofs_per_block();
-				consumed_weight = consumed_weight.saturating_add(DbWeight::get().reads(1u64));
-				consumed_weight = consumed_weight.saturating_add(DbWeight::get().writes(2u64).mul(max_delayed_events as u64));
-				for (event_proof_id, signing_request) in PendingEventProofs::iter().take(max_delayed_events as usize) {
+				for (event_proof_id, signing_request) in
+					PendingEventProofs::<T>::iter().take(max_delayed_events as usize)
+				{
 					Self::do_request_event_proof(event_proof_id, signing_request);
-					PendingEventProofs::remove(event_proof_id);
+					PendingEventProofs::<T>::remove(event_proof_id);
+					consumed_weight = consumed_weight
+						.saturating_add(T::WeightInfo::on_initialize_delayed_proof());
+				}
+			}
+
+			// 4) Request signing of event batches
+			let batch_window = Self::event_proof_batch_window();
+			if !batch_window.is_zero() && (block_number % batch_window).is_zero() {
+				for chain_id in [EthyChainId::Ethereum, EthyChainId::SecondaryEvm] {
+					if Self::do_request_event_batch(chain_id) {
+						consumed_weight = consumed_weight
+							.saturating_add(T::WeightInfo::on_initialize_event_batch());
+					}
 				}
 			}
 
 			consumed_weight
 		}
 
-		#[weight = DbWeight::get().writes(new_signers.len() as u64).saturating_add(DbWeight::get().reads_writes(4, 3))]
+		fn offchain_worker(block_number: T::BlockNumber) {
+			log!(trace, "💎 entering off-chain worker: {:?}", block_number);
+			log!(trace, "💎 active notaries: {:?}", Self::notary_keys());
+
+			// this passes if flag `--validator` set, not necessarily in the active set
+			if !sp_io::offchain::is_validator() {
+				log!(info, "💎 not a validator, exiting");
+				return
+			}
+
+			// check a local key exists for a valid bridge notary
+			if let Some((active_key, authority_index)) = Self::find_active_ethy_key() {
+				// check enough validators have active notary keys
+				let supports = NotaryKeys::<T>::decode_len().unwrap_or(0);
+				let needed = T::NotarizationThreshold::get();
+				// TODO: check every session change not block
+				if Percent::from_rational(supports, T::AuthoritySet::validators().len()) < needed {
+					log!(
+						info,
+						"💎 waiting for validator support to activate eth-bridge: {:?}/{:?}",
+						supports,
+						needed
+					);
+					return
+				}
+				Self::do_bls_key_registration_ocw(&active_key, authority_index);
+				// do some notarizing
+				Self::do_event_notarization_ocw(&active_key, authority_index);
+				Self::do_call_notarization_ocw(&active_key, authority_index);
+			} else {
+				log!(trace, "💎 not an active validator, exiting");
+			}
+
+			log!(trace, "💎 exiting off-chain worker");
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::submit_notarization { ref payload, _signature: ref signature } = call {
+				// notarization must be from an active notary
+				let notary_keys = Self::notary_keys();
+				let notary_public_key = match notary_keys.get(payload.authority_index() as usize) {
+					Some(id) => id,
+					None => return InvalidTransaction::BadProof.into(),
+				};
+				// notarization must not be a duplicate/equivocation
+				if <EventNotarizations<T>>::contains_key(payload.payload_id(), &notary_public_key) {
+					log!(
+						error,
+						"💎 received equivocation from: {:?} on {:?}",
+						notary_public_key,
+						payload.payload_id()
+					);
+					return InvalidTransaction::BadProof.into()
+				}
+				// notarization is signed correctly
+				if !(notary_public_key.verify(&payload.encode(), signature)) {
+					return InvalidTransaction::BadProof.into()
+				}
+				ValidTransaction::with_tag_prefix("eth-bridge")
+					.priority(UNSIGNED_TXS_PRIORITY)
+					// 'provides' must be unique for each submission on the network (i.e. unique for
+					// each claim id and validator)
+					.and_provides([
+						b"notarize",
+						&payload.type_id().to_be_bytes(),
+						&payload.payload_id().to_be_bytes(),
+						&(payload.authority_index() as u64).to_be_bytes(),
+					])
+					.longevity(3)
+					.propagate(true)
+					.build()
+			} else if let Call::submit_bls_key { ref registration, _signature: ref signature } =
+				call
+			{
+				// registration must be from an active notary
+				let notary_keys = Self::notary_keys();
+				let notary_public_key = match notary_keys.get(registration.authority_index as usize)
+				{
+					Some(id) => id,
+					None => return InvalidTransaction::BadProof.into(),
+				};
+				if Self::notary_bls_key(notary_public_key) == Some(registration.public) {
+					return InvalidTransaction::Stale.into()
+				}
+				// registration is signed correctly and the notary holds the BLS secret key
+				if !(notary_public_key.verify(&registration.encode(), signature)) ||
+					!bls::verify_possession(
+						&registration.public,
+						&registration.proof_of_possession,
+					) {
+					return InvalidTransaction::BadProof.into()
+				}
+				ValidTransaction::with_tag_prefix("eth-bridge")
+					.priority(UNSIGNED_TXS_PRIORITY)
+					.and_provides((b"bls-key", registration.authority_index))
+					.longevity(3)
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
 		/// Set new XRPL door signers
-		pub fn set_xrpl_door_signers(origin, new_signers: Vec<(T::EthyId, bool)>) {
+		#[pallet::weight(T::WeightInfo::set_xrpl_door_signers(new_signers.len() as u32))]
+		pub fn set_xrpl_door_signers(
+			origin: OriginFor<T>,
+			new_signers: Vec<(T::EthyId, bool)>,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			ensure!((new_signers.len() as u8) < T::MaxNewSigners::get(), Error::<T>::MaxNewSignersExceeded);
+			ensure!(
+				(new_signers.len() as u8) < T::MaxNewSigners::get(),
+				Error::<T>::MaxNewSignersExceeded
+			);
 
 			for new_signer in new_signers {
 				XrplDoorSigners::<T>::insert(new_signer.0, new_signer.1);
 			}
 
-
 			Self::update_xrpl_notary_keys(&Self::notary_keys());
 			Self::deposit_event(Event::<T>::XrplDoorSignersSet);
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
-		/// Set the relayer address
-		pub fn set_relayer(origin, relayer: T::AccountId) {
+		/// Set a bonded account as the only relayer
+		/// Any other relayers are removed and start unbonding, use `add_relayer` and
+		/// `remove_relayer` to manage multiple relayers
+		#[pallet::weight(T::WeightInfo::set_relayer(Self::relayers().len() as u32))]
+		pub fn set_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
 			ensure_root(origin)?;
-			// Ensure relayer has bonded more than relayer bond amount
-			ensure!(Self::relayer_paid_bond(relayer.clone()) >= T::RelayerBond::get(), Error::<T>::NoBondPaid);
-			<Relayer<T>>::put(relayer.clone());
-			Self::deposit_event(Event::<T>::RelayerSet(Some(relayer)));
+			for other in Self::relayers().into_iter().filter(|r| r != &relayer) {
+				Self::do_remove_relayer(&other)?;
+			}
+			if !Self::relayers().contains(&relayer) {
+				Self::do_add_relayer(relayer)?;
+			}
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().reads_writes(5, 6)]
 		/// Submit bond for relayer account
-		pub fn deposit_relayer_bond(origin) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::deposit_relayer_bond())]
+		pub fn deposit_relayer_bond(origin: OriginFor<T>) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 
 			// Ensure relayer doesn't already have a bond set
@@ -437,17 +834,22 @@ decl_module! {
 			Ok(())
 		}
 
-		#[weight = DbWeight::get().reads_writes(3, 3)]
 		/// Withdraw relayer bond amount
-		pub fn withdraw_relayer_bond(origin) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::withdraw_relayer_bond())]
+		pub fn withdraw_relayer_bond(origin: OriginFor<T>) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 
-			// Ensure account is not the current relayer
-			if Self::relayer() == Some(origin.clone()) {
-				ensure!(Self::relayer() != Some(origin.clone()), Error::<T>::CantUnbondRelayer);
-			};
+			// Ensure account is not an active relayer
+			ensure!(!Self::relayers().contains(&origin), Error::<T>::CantUnbondRelayer);
 			let relayer_paid_bond = Self::relayer_paid_bond(origin.clone());
 			ensure!(relayer_paid_bond > 0, Error::<T>::CantUnbondRelayer);
+			// Former relayers must wait out the unbonding period
+			if let Some(unlock_at) = Self::relayer_unbonding(&origin) {
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() >= unlock_at,
+					Error::<T>::RelayerUnbonding
+				);
+			}
 
 			// Attempt to release the relayers hold
 			T::MultiCurrency::release_hold(
@@ -457,111 +859,190 @@ decl_module! {
 				relayer_paid_bond,
 			)?;
 			<RelayerPaidBond<T>>::remove(origin.clone());
+			RelayerUnbonding::<T>::remove(origin.clone());
 
 			Self::deposit_event(Event::<T>::RelayerBondWithdraw(origin, relayer_paid_bond));
 			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
-		/// Set event confirmations (blocks). Required block confirmations for an Ethereum event to be notarized by Seed
-		pub fn set_event_block_confirmations(origin, confirmations: u64) {
+		/// Set event confirmations (blocks). Required block confirmations for an event on the EVM
+		/// chain `chain_id` to be notarized by Seed
+		#[pallet::weight(T::WeightInfo::set_event_block_confirmations())]
+		pub fn set_event_block_confirmations(
+			origin: OriginFor<T>,
+			chain_id: EthyChainId,
+			confirmations: u64,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			EventBlockConfirmations::put(confirmations)
+			ensure!(chain_id.is_evm(), Error::<T>::UnsupportedChain);
+			EventBlockConfirmations::<T>::insert(chain_id, confirmations);
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
 		/// Set max number of delayed events that can be processed per block
-		pub fn set_delayed_event_proofs_per_block(origin, count: u8) {
+		#[pallet::weight(T::WeightInfo::set_delayed_event_proofs_per_block())]
+		pub fn set_delayed_event_proofs_per_block(
+			origin: OriginFor<T>,
+			count: u8,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			DelayedEventProofsPerBlock::put(count);
+			DelayedEventProofsPerBlock::<T>::put(count);
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
-		/// Set challenge period, this is the window in which an event can be challenged before processing
-		pub fn set_challenge_period(origin, blocks: T::BlockNumber) {
+		/// Set challenge period, this is the window in which an event can be challenged before
+		/// processing
+		#[pallet::weight(T::WeightInfo::set_challenge_period())]
+		pub fn set_challenge_period(
+			origin: OriginFor<T>,
+			blocks: T::BlockNumber,
+		) -> DispatchResult {
 			ensure_root(origin)?;
 			<ChallengePeriod<T>>::put(blocks);
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
-		/// Set the bridge contract address on Ethereum (requires governance)
-		pub fn set_contract_address(origin, contract_address: EthAddress) {
+		/// Set the bridge contract address on the EVM chain `chain_id` (requires governance)
+		#[pallet::weight(T::WeightInfo::set_contract_address())]
+		pub fn set_contract_address(
+			origin: OriginFor<T>,
+			chain_id: EthyChainId,
+			contract_address: EthAddress,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			ContractAddress::put(contract_address);
-			Self::deposit_event(<Event<T>>::SetContractAddress(contract_address));
+			ensure!(chain_id.is_evm(), Error::<T>::UnsupportedChain);
+			ContractAddress::<T>::insert(chain_id, contract_address);
+			Self::deposit_event(Event::<T>::SetContractAddress(chain_id, contract_address));
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
 		/// Pause or unpause the bridge (requires governance)
-		pub fn set_bridge_paused(origin, paused: bool) {
+		#[pallet::weight(T::WeightInfo::set_bridge_paused())]
+		pub fn set_bridge_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
 			ensure_root(origin)?;
 			match paused {
-				true => BridgePaused::put(true),
-				false => BridgePaused::kill(),
+				true => BridgePaused::<T>::put(true),
+				false => BridgePaused::<T>::kill(),
 			};
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
 		/// Finalise authority changes, unpauses bridge and sets new notary keys
 		/// Called internally after force new era
-		pub fn finalise_authorities_change(origin, next_notary_keys: Vec<T::EthyId>) {
+		#[pallet::weight(T::WeightInfo::finalise_authorities_change())]
+		pub fn finalise_authorities_change(
+			origin: OriginFor<T>,
+			next_notary_keys: Vec<T::EthyId>,
+		) -> DispatchResult {
 			ensure_none(origin)?;
 			Self::do_finalise_authorities_change(next_notary_keys);
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1)]
 		/// Submit ABI encoded event data from the Ethereum bridge contract
 		/// - tx_hash The Ethereum transaction hash which triggered the event
 		/// - event ABI encoded bridge event
-		pub fn submit_event(origin, tx_hash: H256, event: Vec<u8>) {
+		#[pallet::weight(T::WeightInfo::submit_event())]
+		pub fn submit_event(
+			origin: OriginFor<T>,
+			chain_id: EthyChainId,
+			tx_hash: H256,
+			event: Vec<u8>,
+		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			ensure!(chain_id.is_evm(), Error::<T>::UnsupportedChain);
+			// reject claims that could never be processed
+			ensure!(T::EventRouter::is_routable(chain_id), Error::<T>::UnsupportedChain);
 
-			ensure!(Some(origin) == Self::relayer(), Error::<T>::NoPermission);
+			let relayers = Self::relayers();
+			ensure!(relayers.contains(&origin), Error::<T>::NoPermission);
 
 			// TODO: place some limit on `data` length (it should match on contract side)
-			// event SendMessage(uint256 messageId, address source, address destination, bytes message, uint256 fee);
-			if let [Token::Uint(event_id), Token::Address(source), Token::Address(destination), Token::Bytes(data), Token::Uint(_fee)] = ethabi::decode(&[
-				ParamType::Uint(64),
-				ParamType::Address,
-				ParamType::Address,
-				ethabi::ParamType::Bytes,
-				ParamType::Uint(64),
-			], event.as_slice()).map_err(|_| Error::<T>::InvalidClaim)?.as_slice() {
-				let event_id: EventClaimId = (*event_id).saturated_into();
-				ensure!(!PendingEventClaims::contains_key(event_id), Error::<T>::EventReplayPending); // NOTE(surangap): prune PendingEventClaims also?
-				if !Self::processed_message_ids().is_empty() {
-					ensure!( event_id > Self::processed_message_ids()[0] &&
-						Self::processed_message_ids().binary_search(&event_id).is_err() , Error::<T>::EventReplayProcessed);
+			// event SendMessage(uint256 messageId, address source, address destination, bytes
+			// message, uint256 fee);
+			if let [Token::Uint(event_id), Token::Address(source), Token::Address(destination), Token::Bytes(data), Token::Uint(_fee)] =
+				ethabi::decode(
+					&[
+						ParamType::Uint(64),
+						ParamType::Address,
+						ParamType::Address,
+						ethabi::ParamType::Bytes,
+						ParamType::Uint(64),
+					],
+					event.as_slice(),
+				)
+				.map_err(|_| Error::<T>::InvalidClaim)?
+				.as_slice()
+			{
+				let event_nonce: u64 = (*event_id).saturated_into();
+				let now = <frame_system::Pallet<T>>::block_number();
+				if Self::relayer_submission_mode() == RelayerSubmissionMode::RoundRobin {
+					let designated = &relayers[(event_nonce % relayers.len() as u64) as usize];
+					// the designated relayer's turn is open to all once it has been offline for
+					// `RoundRobinTimeout` blocks
+					let designated_offline = Self::relayer_last_submission(designated)
+						.unwrap_or_default()
+						.saturating_add(T::RoundRobinTimeout::get()) <=
+						now;
+					ensure!(
+						designated == &origin || designated_offline,
+						Error::<T>::NotDesignatedRelayer
+					);
+				}
+				// claim ids are namespaced by chain so events from different chains never collide
+				let event_id: EventClaimId =
+					chain_id.event_claim_id(event_nonce).ok_or(Error::<T>::InvalidClaim)?;
+				ensure!(
+					!PendingEventClaims::<T>::contains_key(event_id),
+					Error::<T>::EventReplayPending
+				); // NOTE(surangap): prune PendingEventClaims also?
+				let processed_message_ids = Self::processed_message_ids(chain_id);
+				if !processed_message_ids.is_empty() {
+					ensure!(
+						event_id > processed_message_ids[0] &&
+							processed_message_ids.binary_search(&event_id).is_err(),
+						Error::<T>::EventReplayProcessed
+					);
 				}
 				let event_claim = EventClaim {
 					tx_hash,
 					source: *source,
 					destination: *destination,
 					data: data.clone(),
+					chain_id,
 				};
 
-				PendingEventClaims::insert(event_id, &event_claim);
-				PendingClaimStatus::insert(event_id, EventClaimStatus::Pending);
+				PendingEventClaims::<T>::insert(event_id, &event_claim);
+				PendingClaimStatus::<T>::insert(event_id, EventClaimStatus::Pending);
+				EventClaimRelayer::<T>::insert(event_id, &origin);
+				RelayerLastSubmission::<T>::insert(origin, now);
 
 				// TODO: there should be some limit per block
-				let process_at: T::BlockNumber = <frame_system::Pallet<T>>::block_number() + Self::challenge_period();
+				let process_at: T::BlockNumber = now + Self::challenge_period();
 				<MessagesValidAt<T>>::append(process_at, event_id);
 
 				Self::deposit_event(Event::<T>::EventSubmit(event_id, event_claim, process_at));
 			}
+			Ok(())
 		}
 
-		#[weight = DbWeight::get().writes(1) + DbWeight::get().reads(2)]
 		/// Submit a challenge for an event
 		/// Challenged events won't be processed until verified by validators
 		/// An event can only be challenged once
-		pub fn submit_challenge(origin, event_claim_id: EventClaimId) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::submit_challenge())]
+		pub fn submit_challenge(
+			origin: OriginFor<T>,
+			event_claim_id: EventClaimId,
+		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 
 			// Validate event_id existence
-			ensure!(PendingEventClaims::contains_key(event_claim_id), Error::<T>::NoClaim);
+			ensure!(PendingEventClaims::<T>::contains_key(event_claim_id), Error::<T>::NoClaim);
 			// Check that event isn't already being challenged
-			ensure!(Self::pending_claim_status(event_claim_id) == Some(EventClaimStatus::Pending), Error::<T>::ClaimAlreadyChallenged);
+			ensure!(
+				Self::pending_claim_status(event_claim_id) == Some(EventClaimStatus::Pending),
+				Error::<T>::ClaimAlreadyChallenged
+			);
 
 			let challenger_bond = T::ChallengeBond::get();
 			// try lock challenger bond
@@ -575,19 +1056,23 @@ decl_module! {
 			// Add event to challenged event storage
 			// Not sorted so we can check using FIFO
 			// Include challenger account for releasing funds in case claim is invalid
-			PendingClaimChallenges::append(event_claim_id);
+			PendingClaimChallenges::<T>::append(event_claim_id);
 			<ChallengerAccount<T>>::insert(event_claim_id, (origin.clone(), challenger_bond));
-			PendingClaimStatus::insert(event_claim_id, EventClaimStatus::Challenged);
+			PendingClaimStatus::<T>::insert(event_claim_id, EventClaimStatus::Challenged);
 
 			Self::deposit_event(Event::<T>::Challenged(event_claim_id, origin));
 			Ok(())
 		}
 
-		#[weight = 1_000_000]
-		#[transactional]
 		/// Internal only
 		/// Validators will submit inherents with their notarization vote for a given claim
-		pub fn submit_notarization(origin, payload: NotarizationPayload, _signature: <<T as Config>::EthyId as RuntimeAppPublic>::Signature) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::submit_notarization())]
+		#[transactional]
+		pub fn submit_notarization(
+			origin: OriginFor<T>,
+			payload: NotarizationPayload,
+			_signature: <<T as Config>::EthyId as RuntimeAppPublic>::Signature,
+		) -> DispatchResult {
 			let _ = ensure_none(origin)?;
 
 			// we don't need to verify the signature here because it has been verified in
@@ -600,39 +1085,116 @@ decl_module! {
 			};
 
 			match payload {
-				NotarizationPayload::Call{ call_id, result, .. } => Self::handle_call_notarization(call_id, result, notary_public_key),
-				NotarizationPayload::Event{ event_claim_id, result, .. } => Self::handle_event_notarization(event_claim_id, result, notary_public_key),
+				NotarizationPayload::Call { call_id, result, return_data, .. } =>
+					Self::handle_call_notarization(call_id, result, return_data, notary_public_key),
+				NotarizationPayload::Event { event_claim_id, result, .. } =>
+					Self::handle_event_notarization(event_claim_id, result, notary_public_key),
 			}
 		}
 
-		fn offchain_worker(block_number: T::BlockNumber) {
-			log!(trace, "💎 entering off-chain worker: {:?}", block_number);
-			log!(trace, "💎 active notaries: {:?}", Self::notary_keys());
+		/// Internal only
+		/// Validators will submit the BLS public key derived from their Ethy session key
+		#[pallet::weight(T::WeightInfo::submit_bls_key())]
+		pub fn submit_bls_key(
+			origin: OriginFor<T>,
+			registration: BlsKeyRegistration,
+			_signature: <<T as Config>::EthyId as RuntimeAppPublic>::Signature,
+		) -> DispatchResult {
+			let _ = ensure_none(origin)?;
 
-			// this passes if flag `--validator` set, not necessarily in the active set
-			if !sp_io::offchain::is_validator() {
-				log!(info, "💎 not a validator, exiting");
-				return
-			}
+			// the signature and proof of possession have been verified in `validate_unsigned`
+			let notary_keys = Self::notary_keys();
+			let notary_public_key = match notary_keys.get(registration.authority_index as usize) {
+				Some(id) => id,
+				None => return Err(Error::<T>::InvalidNotarization.into()),
+			};
+			NotaryBlsKeys::<T>::insert(notary_public_key, registration.public);
+			Self::deposit_event(Event::<T>::BlsKeyRegistered(notary_public_key.clone()));
+			Ok(())
+		}
 
-			// check a local key exists for a valid bridge notary
-			if let Some((active_key, authority_index)) = Self::find_active_ethy_key() {
-				// check enough validators have active notary keys
-				let supports = NotaryKeys::<T>::decode_len().unwrap_or(0);
-				let needed = T::NotarizationThreshold::get();
-				// TODO: check every session change not block
-				if Percent::from_rational(supports, T::AuthoritySet::validators().len()) < needed {
-					log!(info, "💎 waiting for validator support to activate eth-bridge: {:?}/{:?}", supports, needed);
-					return;
+		/// Remove a relayer from the relayer set
+		/// Its bond remains held until the unbonding period has passed
+		#[pallet::weight(T::WeightInfo::remove_relayer())]
+		pub fn remove_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_remove_relayer(&relayer)
+		}
+
+		/// Leave the relayer set
+		/// The caller's bond may be withdrawn once the unbonding period has passed
+		#[pallet::weight(T::WeightInfo::unbond_relayer())]
+		pub fn unbond_relayer(origin: OriginFor<T>) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_remove_relayer(&origin)
+		}
+
+		/// Set how the relayers may submit event claims
+		#[pallet::weight(T::WeightInfo::set_relayer_submission_mode())]
+		pub fn set_relayer_submission_mode(
+			origin: OriginFor<T>,
+			mode: RelayerSubmissionMode,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			SubmissionMode::<T>::put(mode);
+			Self::deposit_event(Event::<T>::RelayerSubmissionModeSet(mode));
+			Ok(())
+		}
+
+		/// Set the event batch window (blocks)
+		/// Outgoing EVM events are signed as a single merkle root every `window` blocks, setting
+		/// zero disables batching and requests signing of any queued events immediately
+		#[pallet::weight(T::WeightInfo::set_event_proof_batch_window())]
+		pub fn set_event_proof_batch_window(
+			origin: OriginFor<T>,
+			window: T::BlockNumber,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			EventProofBatchWindow::<T>::put(window);
+			if window.is_zero() {
+				for chain_id in [EthyChainId::Ethereum, EthyChainId::SecondaryEvm] {
+					Self::do_request_event_batch(chain_id);
 				}
-				// do some notarizing
-				Self::do_event_notarization_ocw(&active_key, authority_index);
-				Self::do_call_notarization_ocw(&active_key, authority_index);
-			} else {
-				log!(trace, "💎 not an active validator, exiting");
 			}
+			Self::deposit_event(Event::<T>::EventProofBatchWindowSet(window));
+			Ok(())
+		}
 
-			log!(trace, "💎 exiting off-chain worker");
+		/// Add a bonded account to the relayer set
+		#[pallet::weight(T::WeightInfo::add_relayer())]
+		pub fn add_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_add_relayer(relayer)
+		}
+
+		/// Set how the finality of an event's block on the EVM chain `chain_id` is decided,
+		/// either by `EventBlockConfirmations` or the chain's `finalized` block
+		#[pallet::weight(T::WeightInfo::set_event_finality_mode())]
+		pub fn set_event_finality_mode(
+			origin: OriginFor<T>,
+			chain_id: EthyChainId,
+			mode: EventFinalityMode,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(chain_id.is_evm(), Error::<T>::UnsupportedChain);
+			EventFinalityModes::<T>::insert(chain_id, mode);
+			Self::deposit_event(Event::<T>::EventFinalityModeSet(chain_id, mode));
+			Ok(())
+		}
+
+		/// Set how outgoing events on the EVM chain `chain_id` are proven
+		/// The chain's bridge contract must support the scheme
+		#[pallet::weight(T::WeightInfo::set_event_proof_scheme())]
+		pub fn set_event_proof_scheme(
+			origin: OriginFor<T>,
+			chain_id: EthyChainId,
+			scheme: EventProofScheme,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(chain_id.is_evm(), Error::<T>::UnsupportedChain);
+			EventProofSchemes::<T>::insert(chain_id, scheme);
+			Self::deposit_event(Event::<T>::EventProofSchemeSet(chain_id, scheme));
+			Ok(())
 		}
 	}
 }
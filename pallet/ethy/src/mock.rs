@@ -17,8 +17,8 @@ use crate::{
 	self as pallet_ethy,
 	sp_api_hidden_includes_decl_storage::hidden_include::{IterableStorageMap, StorageMap},
 	types::{
-		BridgeEthereumRpcApi, BridgeRpcError, CheckedEthCallRequest, CheckedEthCallResult,
-		EthAddress, EthBlock, EthCallId, EthHash, LatestOrNumber, Log, TransactionReceipt,
+		BridgeEthereumRpcApi, BridgeRpcError, CheckedEthCallRequest, EthAddress, EthBlock,
+		EthCallId, EthHash, LatestOrNumber, Log, TransactionReceipt,
 	},
 	Config,
 };
@@ -31,7 +31,7 @@ use frame_support::{
 use scale_info::TypeInfo;
 use seed_pallet_common::test_prelude::*;
 use seed_primitives::{
-	ethy::{crypto::AuthorityId, EventProofId},
+	ethy::{crypto::AuthorityId, EthyChainId, EventProofId},
 	AssetId, Balance, Signature,
 };
 use sp_application_crypto::RuntimeAppPublic;
@@ -77,8 +77,11 @@ parameter_types! {
 	pub const EpochDuration: u64 = 1000_u64;
 	pub const ChallengerBond: Balance = 100;
 	pub const RelayerBond: Balance = 202;
+	pub const RelayerUnbondingPeriod: BlockNumber = 200;
+	pub const RoundRobinTimeout: BlockNumber = 100;
 	pub const MaxXrplKeys: u8 = 8;
 	pub const MaxNewSigners: u8 = 20;
+	pub const MaxEthCallReturnData: u32 = 256;
 	pub const AuthorityChangeDelay: BlockNumber = 75;
 }
 impl Config for Test {
@@ -99,11 +102,15 @@ impl Config for Test {
 	type MultiCurrency = AssetsExt;
 	type NativeAssetId = NativeAssetId;
 	type RelayerBond = RelayerBond;
+	type RelayerUnbondingPeriod = RelayerUnbondingPeriod;
+	type RoundRobinTimeout = RoundRobinTimeout;
 	type MaxXrplKeys = MaxXrplKeys;
 	type Scheduler = Scheduler;
 	type PalletsOrigin = OriginCaller;
 	type MaxNewSigners = MaxNewSigners;
+	type MaxEthCallReturnData = MaxEthCallReturnData;
 	type XrplBridgeAdapter = MockXrplBridgeAdapter;
+	type WeightInfo = ();
 }
 
 pub struct MockXrplBridgeAdapter;
@@ -258,7 +265,7 @@ pub(crate) mod test_storage {
 	use seed_pallet_common::EthCallFailure;
 
 	use crate::{
-		types::{CheckedEthCallResult, EthAddress, EthCallId, EthHash},
+		types::{EthAddress, EthCallId, EthHash},
 		Config,
 	};
 
@@ -271,10 +278,12 @@ pub(crate) mod test_storage {
 			pub CallAt: double_map hasher(twox_64_concat) u64, hasher(twox_64_concat) EthAddress => Option<Vec<u8>>;
 			pub TransactionReceiptFor: map hasher(twox_64_concat) EthHash => Option<MockReceiptResponse>;
 			pub Timestamp: Option<u64>;
+			pub FinalizedBlock: Option<u64>;
 			pub Validators: Vec<AccountId>;
-			pub LastCallResult: Option<(EthCallId, CheckedEthCallResult)>;
+			pub LastCallResult: Option<(EthCallId, Vec<u8>, u64, u64)>;
 			pub LastCallFailure: Option<(EthCallId, EthCallFailure)>;
 			pub Forcing: bool;
+			pub SecondaryEvmUnroutable: bool;
 		}
 	}
 }
@@ -343,6 +352,10 @@ impl MockEthereumRpcClient {
 		};
 		test_storage::BlockResponseAt::insert(block_number, mock_block_response);
 	}
+	/// Mark the stored block at `block_number` as the `finalized` block
+	pub fn mock_finalized_block(block_number: u64) {
+		test_storage::FinalizedBlock::put(block_number);
+	}
 	/// Mock a tx receipt response for a hash
 	pub fn mock_transaction_receipt_for(tx_hash: EthHash, mock_tx_receipt: TransactionReceipt) {
 		let mock_receipt_response = MockReceiptResponse {
@@ -364,12 +377,15 @@ impl MockEthereumRpcClient {
 impl BridgeEthereumRpcApi for MockEthereumRpcClient {
 	/// Returns an ethereum block given a block height
 	fn get_block_by_number(
+		_chain_id: EthyChainId,
 		block_number: LatestOrNumber,
 	) -> Result<Option<EthBlock>, BridgeRpcError> {
 		let mock_block_response = match block_number {
 			LatestOrNumber::Latest =>
 				test_storage::BlockResponseAt::iter().last().map(|x| x.1).or(None),
 			LatestOrNumber::Number(block) => test_storage::BlockResponseAt::get(block),
+			LatestOrNumber::Finalized =>
+				test_storage::FinalizedBlock::get().and_then(test_storage::BlockResponseAt::get),
 		};
 		println!("get_block_by_number at: {:?}", mock_block_response);
 		if mock_block_response.is_none() {
@@ -387,6 +403,7 @@ impl BridgeEthereumRpcApi for MockEthereumRpcClient {
 	}
 	/// Returns an ethereum transaction receipt given a tx hash
 	fn get_transaction_receipt(
+		_chain_id: EthyChainId,
 		hash: EthHash,
 	) -> Result<Option<TransactionReceipt>, BridgeRpcError> {
 		let mock_receipt: Option<MockReceiptResponse> =
@@ -408,6 +425,7 @@ impl BridgeEthereumRpcApi for MockEthereumRpcClient {
 		Ok(Some(transaction_receipt))
 	}
 	fn eth_call(
+		_chain_id: EthyChainId,
 		target: EthAddress,
 		_input: &[u8],
 		at_block: LatestOrNumber,
@@ -416,6 +434,7 @@ impl BridgeEthereumRpcApi for MockEthereumRpcClient {
 			LatestOrNumber::Number(n) => n,
 			LatestOrNumber::Latest =>
 				test_storage::BlockResponseAt::iter().last().unwrap().1.block_number,
+			LatestOrNumber::Finalized => test_storage::FinalizedBlock::get().unwrap(),
 		};
 		println!("eth_call at: {:?}", block_number);
 		test_storage::CallAt::get(block_number, target).ok_or(BridgeRpcError::HttpFetch)
@@ -453,9 +472,20 @@ impl MockValidatorSet {
 
 pub struct MockEventRouter;
 impl EthereumEventRouter for MockEventRouter {
-	fn route(_source: &H160, _destination: &H160, _data: &[u8]) -> EventRouterResult {
+	fn route(
+		_chain_id: EthyChainId,
+		_source: &H160,
+		_destination: &H160,
+		_data: &[u8],
+	) -> EventRouterResult {
 		Ok(Weight::from_ref_time(1000))
 	}
+
+	fn is_routable(chain_id: EthyChainId) -> bool {
+		chain_id == EthyChainId::Ethereum ||
+			(chain_id == EthyChainId::SecondaryEvm &&
+				!test_storage::SecondaryEvmUnroutable::get())
+	}
 }
 
 pub struct MockEthCallSubscriber;
@@ -465,13 +495,15 @@ impl EthCallOracleSubscriber for MockEthCallSubscriber {
 	/// Available via `Self::success_result_for()`
 	fn on_eth_call_complete(
 		call_id: Self::CallId,
-		return_data: &[u8; 32],
+		return_data: &[u8],
 		block_number: u64,
 		block_timestamp: u64,
 	) {
 		test_storage::LastCallResult::put((
 			call_id,
-			CheckedEthCallResult::Ok(*return_data, block_number, block_timestamp),
+			return_data.to_vec(),
+			block_number,
+			block_timestamp,
 		));
 	}
 	/// Stores the failed call info
@@ -483,7 +515,7 @@ impl EthCallOracleSubscriber for MockEthCallSubscriber {
 
 impl MockEthCallSubscriber {
 	/// Returns last known successful call, if any
-	pub fn success_result() -> Option<(EthCallId, CheckedEthCallResult)> {
+	pub fn success_result() -> Option<(EthCallId, Vec<u8>, u64, u64)> {
 		test_storage::LastCallResult::get()
 	}
 	/// Returns last known failed call, if any
@@ -613,7 +645,7 @@ impl ExtBuilder {
 				assert!(
 					EthBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer.into())).is_ok()
 				);
-				assert!(EthBridge::set_relayer(RuntimeOrigin::root(), relayer).is_ok());
+				assert!(EthBridge::add_relayer(RuntimeOrigin::root(), relayer).is_ok());
 			});
 		}
 
@@ -650,6 +682,7 @@ fn get_block_by_number_mock_works() {
 		MockEthereumRpcClient::mock_block_response_at(block_number, mock_block.clone());
 
 		let result = <MockEthereumRpcClient as BridgeEthereumRpcApi>::get_block_by_number(
+			EthyChainId::Ethereum,
 			LatestOrNumber::Number(block_number),
 		)
 		.unwrap();
@@ -673,6 +706,7 @@ fn mock_eth_call_at_latest_block() {
 
 		assert_eq!(
 			MockEthereumRpcClient::eth_call(
+				EthyChainId::Ethereum,
 				EthAddress::from_low_u64_be(1),
 				&[4_u8, 5, 6],
 				LatestOrNumber::Latest
@@ -696,6 +730,7 @@ fn get_latest_block_by_number_mock_works() {
 		MockEthereumRpcClient::mock_block_response_at(block_number, mock_block.clone());
 
 		let result = <MockEthereumRpcClient as BridgeEthereumRpcApi>::get_block_by_number(
+			EthyChainId::Ethereum,
 			LatestOrNumber::Latest,
 		)
 		.unwrap();
@@ -730,9 +765,11 @@ fn get_transaction_receipt_mock_works() {
 
 		MockEthereumRpcClient::mock_transaction_receipt_for(tx_hash, mock_tx_receipt.clone());
 
-		let result =
-			<MockEthereumRpcClient as BridgeEthereumRpcApi>::get_transaction_receipt(tx_hash)
-				.unwrap();
+		let result = <MockEthereumRpcClient as BridgeEthereumRpcApi>::get_transaction_receipt(
+			EthyChainId::Ethereum,
+			tx_hash,
+		)
+		.unwrap();
 		assert_eq!(Some(mock_tx_receipt), result);
 	});
 }
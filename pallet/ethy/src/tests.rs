@@ -19,13 +19,14 @@ use crate::{
 	impls::prune_claim_ids,
 	mock::*,
 	types::{
-		CheckedEthCallRequest, CheckedEthCallResult, EthAddress, EthBlock, EthHash,
-		EthereumEventInfo, EthySigningRequest, EventClaim, EventClaimResult, EventProofId,
+		BlsKeyRegistration, CheckedEthCallRequest, CheckedEthCallResult, EthAddress, EthBlock,
+		EthHash, EthereumEventInfo, EthySigningRequest, EventBatchInfo, EventClaim,
+		EventClaimResult, EventFinalityMode, EventProofId, RelayerSubmissionMode,
 		TransactionReceipt,
 	},
-	BridgePaused, Config, Error, EthCallRequestInfo, Event, EventClaimStatus, Module,
-	NextNotaryKeys, NotaryKeys, NotaryXrplKeys, XrplDoorSigners, ETHY_ENGINE_ID,
-	SUBMIT_BRIDGE_EVENT_SELECTOR,
+	BridgePaused, Config, Error, EthCallRequestInfo, EthCallReturnData, Event, EventClaimStatus,
+	NextNotaryKeys, NotaryKeys, NotaryXrplKeys, Pallet, WeightInfo, XrplDoorSigners,
+	ETHY_ENGINE_ID, MAX_EVENT_BATCH_SIZE, SUBMIT_BRIDGE_EVENT_SELECTOR,
 };
 use codec::Encode;
 use ethabi::Token;
@@ -34,16 +35,18 @@ use hex_literal::hex;
 use seed_pallet_common::test_prelude::*;
 use seed_primitives::{
 	ethy::{
-		crypto::AuthorityId, ConsensusLog, EthyChainId, EthyEcdsaToEthereum, EventClaimId,
-		ValidatorSet,
+		bls, crypto::AuthorityId, ConsensusLog, EthyChainId, EthyEcdsaToEthereum, EventClaimId,
+		EventProofScheme, ValidatorSet,
 	},
 	xrpl::XrplAccountId,
 };
 use sp_core::ByteArray;
+use sp_io::hashing::keccak_256;
 use sp_keystore::{testing::KeyStore, SyncCryptoStore};
 use sp_runtime::{
 	generic::DigestItem,
-	traits::{AccountIdConversion, Convert},
+	traits::{AccountIdConversion, Convert, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
 	Percent, RuntimeAppPublic, SaturatedConversion,
 };
 
@@ -114,6 +117,18 @@ fn encode_validator_set_message(validator_set: &Vec<AuthorityId>, token_id: u64)
 	])
 }
 
+#[test]
+fn storage_defaults() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(EthBridge::challenge_period(), 150);
+		assert_eq!(EthBridge::event_block_confirmations(EthyChainId::Ethereum), 3);
+		assert_eq!(EthBridge::delayed_event_proofs_per_block(), 5);
+		assert!(EthBridge::relayers().is_empty());
+		assert_eq!(EthBridge::relayer_submission_mode(), RelayerSubmissionMode::FreeForAll);
+		assert!(!EthBridge::bridge_paused());
+	});
+}
+
 #[test]
 fn submit_event() {
 	let relayer = H160::from_low_u64_be(123);
@@ -125,6 +140,7 @@ fn submit_event() {
 	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
 		assert_ok!(EthBridge::submit_event(
 			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::Ethereum,
 			tx_hash.clone(),
 			event_data.clone(),
 		));
@@ -132,7 +148,13 @@ fn submit_event() {
 		let process_at = System::block_number() + EthBridge::challenge_period();
 		assert_eq!(
 			EthBridge::pending_event_claims(event_id),
-			Some(EventClaim { tx_hash, source, destination, data: message.to_vec() })
+			Some(EventClaim {
+				tx_hash,
+				source,
+				destination,
+				data: message.to_vec(),
+				chain_id: EthyChainId::Ethereum
+			})
 		);
 		assert_eq!(EthBridge::messages_valid_at(process_at), [event_id],);
 	});
@@ -145,6 +167,7 @@ fn submit_event_relayer_only() {
 		assert_noop!(
 			EthBridge::submit_event(
 				RuntimeOrigin::signed(not_relayer.into()),
+				EthyChainId::Ethereum,
 				H256::default(),
 				vec![]
 			),
@@ -161,7 +184,12 @@ fn submit_event_bad_encoding() {
 
 	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
 		assert_noop!(
-			EthBridge::submit_event(RuntimeOrigin::signed(relayer.into()), tx_hash, event_data),
+			EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
+				tx_hash,
+				event_data
+			),
 			Error::<Test>::InvalidClaim
 		);
 	});
@@ -181,12 +209,18 @@ fn submit_event_tracks_pending() {
 	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
 		assert_ok!(EthBridge::submit_event(
 			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::Ethereum,
 			tx_hash.clone(),
 			event_data.clone(),
 		));
 
 		assert_noop!(
-			EthBridge::submit_event(RuntimeOrigin::signed(relayer.into()), tx_hash, event_data),
+			EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
+				tx_hash,
+				event_data
+			),
 			Error::<Test>::EventReplayPending
 		);
 	});
@@ -206,6 +240,7 @@ fn submit_event_tracks_completed() {
 	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
 		assert_ok!(EthBridge::submit_event(
 			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::Ethereum,
 			tx_hash.clone(),
 			event_data.clone(),
 		));
@@ -215,18 +250,120 @@ fn submit_event_tracks_completed() {
 		EthBridge::on_initialize(process_at);
 
 		assert_noop!(
-			EthBridge::submit_event(RuntimeOrigin::signed(relayer.into()), tx_hash, event_data),
+			EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
+				tx_hash,
+				event_data
+			),
+			Error::<Test>::EventReplayProcessed
+		);
+	});
+}
+
+#[test]
+fn submit_event_secondary_evm() {
+	let relayer = H160::from_low_u64_be(123);
+	let tx_hash = EthHash::from_low_u64_be(33);
+	let (event_id, source, destination, message) =
+		(1_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[1_u8, 2, 3, 4, 5]);
+	let event_data = encode_event_message(event_id, source, destination, message);
+
+	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
+		// the same event id may be submitted from each chain
+		assert_ok!(EthBridge::submit_event(
+			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::Ethereum,
+			tx_hash,
+			event_data.clone(),
+		));
+		assert_ok!(EthBridge::submit_event(
+			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::SecondaryEvm,
+			tx_hash,
+			event_data.clone(),
+		));
+
+		let claim_id = EthyChainId::SecondaryEvm.event_claim_id(event_id).unwrap();
+		assert_ne!(claim_id, event_id);
+		assert_eq!(
+			EthBridge::pending_event_claims(claim_id),
+			Some(EventClaim {
+				tx_hash,
+				source,
+				destination,
+				data: message.to_vec(),
+				chain_id: EthyChainId::SecondaryEvm,
+			})
+		);
+		let process_at = System::block_number() + EthBridge::challenge_period();
+		assert_eq!(EthBridge::messages_valid_at(process_at), vec![event_id, claim_id]);
+
+		// processed ids are tracked per chain
+		EthBridge::on_initialize(process_at);
+		assert_eq!(EthBridge::processed_message_ids(EthyChainId::Ethereum), vec![event_id]);
+		assert_eq!(EthBridge::processed_message_ids(EthyChainId::SecondaryEvm), vec![claim_id]);
+		assert_noop!(
+			EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::SecondaryEvm,
+				tx_hash,
+				event_data
+			),
 			Error::<Test>::EventReplayProcessed
 		);
 	});
 }
 
 #[test]
-fn set_relayer_no_bond_should_fail() {
+fn submit_event_unroutable_chain_should_fail() {
+	let relayer = H160::from_low_u64_be(123);
+	let event_data =
+		encode_event_message(1_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[1]);
+
+	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
+		test_storage::SecondaryEvmUnroutable::put(true);
+		assert_noop!(
+			EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::SecondaryEvm,
+				EthHash::from_low_u64_be(33),
+				event_data
+			),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn submit_event_unsupported_chain_should_fail() {
+	let relayer = H160::from_low_u64_be(123);
+	let event_data = encode_event_message(
+		1_u64,
+		H160::from_low_u64_be(555),
+		H160::from_low_u64_be(555),
+		&[1_u8, 2, 3, 4, 5],
+	);
+
+	ExtBuilder::default().relayer(relayer).build().execute_with(|| {
+		assert_noop!(
+			EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Xrpl,
+				EthHash::from_low_u64_be(33),
+				event_data
+			),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn add_relayer_no_bond_should_fail() {
 	let relayer = H160::from_low_u64_be(123);
 	ExtBuilder::default().build().execute_with(|| {
 		assert_noop!(
-			EthBridge::set_relayer(frame_system::RawOrigin::Root.into(), relayer.into()),
+			EthBridge::add_relayer(frame_system::RawOrigin::Root.into(), relayer.into()),
 			Error::<Test>::NoBondPaid
 		);
 	});
@@ -252,7 +389,7 @@ fn deposit_relayer_bond_works() {
 			);
 
 			// Setting relayer should work
-			assert_ok!(EthBridge::set_relayer(
+			assert_ok!(EthBridge::add_relayer(
 				frame_system::RawOrigin::Root.into(),
 				relayer.into()
 			));
@@ -263,7 +400,7 @@ fn deposit_relayer_bond_works() {
 
 			// Check storage
 			assert_eq!(EthBridge::relayer_paid_bond(AccountId::from(relayer)), RelayerBond::get());
-			assert_eq!(EthBridge::relayer(), Some(relayer.into()));
+			assert_eq!(EthBridge::relayers(), vec![relayer.into()]);
 		});
 }
 
@@ -315,7 +452,7 @@ fn withdraw_active_relayer_bond_should_fail() {
 			assert_ok!(EthBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer.into())));
 
 			// Setting relayer should work
-			assert_ok!(EthBridge::set_relayer(
+			assert_ok!(EthBridge::add_relayer(
 				frame_system::RawOrigin::Root.into(),
 				relayer.into()
 			));
@@ -328,6 +465,359 @@ fn withdraw_active_relayer_bond_should_fail() {
 		});
 }
 
+/// Bond `relayer` and add it to the relayer set
+fn setup_relayer(relayer: H160) {
+	assert_ok!(EthBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer.into())));
+	assert_ok!(EthBridge::add_relayer(frame_system::RawOrigin::Root.into(), relayer.into()));
+}
+
+#[test]
+fn add_and_remove_relayers_works() {
+	let relayer_1 = H160::from_low_u64_be(123);
+	let relayer_2 = H160::from_low_u64_be(124);
+	ExtBuilder::default()
+		.with_endowed_account(relayer_1, RelayerBond::get())
+		.with_endowed_account(relayer_2, RelayerBond::get())
+		.build()
+		.execute_with(|| {
+			setup_relayer(relayer_1);
+			setup_relayer(relayer_2);
+			assert_eq!(EthBridge::relayers(), vec![relayer_1.into(), relayer_2.into()]);
+
+			// Can't add the same relayer twice
+			assert_noop!(
+				EthBridge::add_relayer(frame_system::RawOrigin::Root.into(), relayer_1.into()),
+				Error::<Test>::RelayerAlreadyActive
+			);
+
+			// Only root can remove relayers
+			assert_noop!(
+				EthBridge::remove_relayer(
+					RuntimeOrigin::signed(relayer_2.into()),
+					relayer_1.into()
+				),
+				BadOrigin
+			);
+			assert_ok!(EthBridge::remove_relayer(
+				frame_system::RawOrigin::Root.into(),
+				relayer_1.into()
+			));
+			assert_eq!(EthBridge::relayers(), vec![relayer_2.into()]);
+			let unlock_at = System::block_number() + RelayerUnbondingPeriod::get();
+			assert_eq!(EthBridge::relayer_unbonding(AccountId::from(relayer_1)), Some(unlock_at));
+			System::assert_has_event(Event::<Test>::RelayerRemoved(relayer_1.into()).into());
+
+			// Removed relayers can't rejoin until their bond is withdrawn
+			assert_noop!(
+				EthBridge::add_relayer(frame_system::RawOrigin::Root.into(), relayer_1.into()),
+				Error::<Test>::RelayerUnbonding
+			);
+			assert_noop!(
+				EthBridge::remove_relayer(frame_system::RawOrigin::Root.into(), relayer_1.into()),
+				Error::<Test>::RelayerNotActive
+			);
+		});
+}
+
+#[test]
+fn set_relayer_replaces_relayer_set() {
+	let relayer_1 = H160::from_low_u64_be(123);
+	let relayer_2 = H160::from_low_u64_be(124);
+	ExtBuilder::default()
+		.with_endowed_account(relayer_1, RelayerBond::get())
+		.with_endowed_account(relayer_2, RelayerBond::get())
+		.build()
+		.execute_with(|| {
+			setup_relayer(relayer_1);
+			assert_ok!(EthBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer_2.into())));
+
+			assert_noop!(
+				EthBridge::set_relayer(RuntimeOrigin::signed(relayer_2.into()), relayer_2.into()),
+				BadOrigin
+			);
+			assert_ok!(EthBridge::set_relayer(
+				frame_system::RawOrigin::Root.into(),
+				relayer_2.into()
+			));
+			assert_eq!(EthBridge::relayers(), vec![relayer_2.into()]);
+			System::assert_has_event(Event::<Test>::RelayerRemoved(relayer_1.into()).into());
+			System::assert_last_event(Event::<Test>::RelayerAdded(relayer_2.into()).into());
+
+			// Setting the only relayer again is a no-op
+			assert_ok!(EthBridge::set_relayer(
+				frame_system::RawOrigin::Root.into(),
+				relayer_2.into()
+			));
+			assert_eq!(EthBridge::relayers(), vec![relayer_2.into()]);
+		});
+}
+
+#[test]
+fn unbond_relayer_works() {
+	let relayer = H160::from_low_u64_be(123);
+	ExtBuilder::default()
+		.with_endowed_account(relayer, RelayerBond::get())
+		.build()
+		.execute_with(|| {
+			setup_relayer(relayer);
+
+			assert_ok!(EthBridge::unbond_relayer(RuntimeOrigin::signed(relayer.into())));
+			assert!(EthBridge::relayers().is_empty());
+			let unlock_at = System::block_number() + RelayerUnbondingPeriod::get();
+			System::assert_last_event(
+				Event::<Test>::RelayerUnbonding(relayer.into(), unlock_at).into(),
+			);
+
+			// Can't unbond twice
+			assert_noop!(
+				EthBridge::unbond_relayer(RuntimeOrigin::signed(relayer.into())),
+				Error::<Test>::RelayerNotActive
+			);
+
+			// Bond is locked until the unbonding period has passed
+			System::set_block_number(unlock_at - 1);
+			assert_noop!(
+				EthBridge::withdraw_relayer_bond(RuntimeOrigin::signed(relayer.into())),
+				Error::<Test>::RelayerUnbonding
+			);
+
+			System::set_block_number(unlock_at);
+			assert_ok!(EthBridge::withdraw_relayer_bond(RuntimeOrigin::signed(relayer.into())));
+			assert_eq!(EthBridge::relayer_paid_bond(AccountId::from(relayer)), 0);
+			assert!(EthBridge::relayer_unbonding(AccountId::from(relayer)).is_none());
+			assert_eq!(AssetsExt::balance(ROOT_ASSET_ID, &relayer.into()), RelayerBond::get());
+		});
+}
+
+#[test]
+fn submit_event_multiple_relayers() {
+	let relayer_1 = H160::from_low_u64_be(123);
+	let relayer_2 = H160::from_low_u64_be(124);
+	let not_relayer = H160::from_low_u64_be(125);
+	let event_data_1 =
+		encode_event_message(1_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[1]);
+	let event_data_2 =
+		encode_event_message(2_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[2]);
+	ExtBuilder::default()
+		.with_endowed_account(relayer_1, RelayerBond::get())
+		.with_endowed_account(relayer_2, RelayerBond::get())
+		.build()
+		.execute_with(|| {
+			setup_relayer(relayer_1);
+			setup_relayer(relayer_2);
+
+			assert_noop!(
+				EthBridge::submit_event(
+					RuntimeOrigin::signed(not_relayer.into()),
+					EthyChainId::Ethereum,
+					EthHash::from_low_u64_be(1),
+					event_data_1.clone(),
+				),
+				Error::<Test>::NoPermission
+			);
+
+			// Any relayer may submit any event
+			assert_ok!(EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer_2.into()),
+				EthyChainId::Ethereum,
+				EthHash::from_low_u64_be(1),
+				event_data_1.clone(),
+			));
+			assert_ok!(EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer_2.into()),
+				EthyChainId::Ethereum,
+				EthHash::from_low_u64_be(2),
+				event_data_2.clone(),
+			));
+			assert_eq!(EthBridge::event_claim_relayer(1), Some(relayer_2.into()));
+			assert_eq!(EthBridge::event_claim_relayer(2), Some(relayer_2.into()));
+
+			// Submitting relayer is tidied up once the claim is processed
+			let process_at = System::block_number() + EthBridge::challenge_period();
+			EthBridge::on_initialize(process_at);
+			assert!(EthBridge::event_claim_relayer(1).is_none());
+			assert!(EthBridge::event_claim_relayer(2).is_none());
+		});
+}
+
+#[test]
+fn submit_event_round_robin() {
+	let relayer_1 = H160::from_low_u64_be(123);
+	let relayer_2 = H160::from_low_u64_be(124);
+	let event_data_1 =
+		encode_event_message(1_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[1]);
+	let event_data_2 =
+		encode_event_message(2_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[2]);
+	ExtBuilder::default()
+		.with_endowed_account(relayer_1, RelayerBond::get())
+		.with_endowed_account(relayer_2, RelayerBond::get())
+		.build()
+		.execute_with(|| {
+			setup_relayer(relayer_1);
+			setup_relayer(relayer_2);
+
+			assert_noop!(
+				EthBridge::set_relayer_submission_mode(
+					RuntimeOrigin::signed(relayer_1.into()),
+					RelayerSubmissionMode::RoundRobin
+				),
+				BadOrigin
+			);
+			assert_ok!(EthBridge::set_relayer_submission_mode(
+				frame_system::RawOrigin::Root.into(),
+				RelayerSubmissionMode::RoundRobin
+			));
+			System::assert_last_event(
+				Event::<Test>::RelayerSubmissionModeSet(RelayerSubmissionMode::RoundRobin).into(),
+			);
+
+			// Event 1 belongs to relayer 2 (1 % 2), event 2 to relayer 1 (2 % 2)
+			assert_noop!(
+				EthBridge::submit_event(
+					RuntimeOrigin::signed(relayer_1.into()),
+					EthyChainId::Ethereum,
+					EthHash::from_low_u64_be(1),
+					event_data_1.clone(),
+				),
+				Error::<Test>::NotDesignatedRelayer
+			);
+			assert_ok!(EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer_2.into()),
+				EthyChainId::Ethereum,
+				EthHash::from_low_u64_be(1),
+				event_data_1.clone(),
+			));
+			assert_noop!(
+				EthBridge::submit_event(
+					RuntimeOrigin::signed(relayer_2.into()),
+					EthyChainId::Ethereum,
+					EthHash::from_low_u64_be(2),
+					event_data_2.clone(),
+				),
+				Error::<Test>::NotDesignatedRelayer
+			);
+			assert_ok!(EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer_1.into()),
+				EthyChainId::Ethereum,
+				EthHash::from_low_u64_be(2),
+				event_data_2.clone(),
+			));
+		});
+}
+
+#[test]
+fn submit_event_round_robin_offline_relayer() {
+	let relayer_1 = H160::from_low_u64_be(123);
+	let relayer_2 = H160::from_low_u64_be(124);
+	let event_data =
+		encode_event_message(1_u64, H160::from_low_u64_be(555), H160::from_low_u64_be(555), &[1]);
+	ExtBuilder::default()
+		.with_endowed_account(relayer_1, RelayerBond::get())
+		.with_endowed_account(relayer_2, RelayerBond::get())
+		.build()
+		.execute_with(|| {
+			setup_relayer(relayer_1);
+			setup_relayer(relayer_2);
+			assert_ok!(EthBridge::set_relayer_submission_mode(
+				frame_system::RawOrigin::Root.into(),
+				RelayerSubmissionMode::RoundRobin
+			));
+
+			// Event 1 belongs to relayer 2 which is still considered online
+			System::set_block_number(System::block_number() + RoundRobinTimeout::get() - 1);
+			assert_noop!(
+				EthBridge::submit_event(
+					RuntimeOrigin::signed(relayer_1.into()),
+					EthyChainId::Ethereum,
+					EthHash::from_low_u64_be(1),
+					event_data.clone(),
+				),
+				Error::<Test>::NotDesignatedRelayer
+			);
+
+			// Relayer 2 hasn't submitted for `RoundRobinTimeout` blocks, anyone may take its turn
+			System::set_block_number(System::block_number() + 1);
+			assert_ok!(EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer_1.into()),
+				EthyChainId::Ethereum,
+				EthHash::from_low_u64_be(1),
+				event_data,
+			));
+			assert_eq!(EthBridge::event_claim_relayer(1), Some(relayer_1.into()));
+			assert_eq!(
+				EthBridge::relayer_last_submission(AccountId::from(relayer_1)),
+				Some(System::block_number())
+			);
+		});
+}
+
+#[test]
+fn invalid_claim_slashes_submitting_relayer() {
+	let relayer_1 = H160::from_low_u64_be(123);
+	let relayer_2 = H160::from_low_u64_be(124);
+	let challenger = H160::from_low_u64_be(1234);
+	let event_id = 1_u64;
+	let event_data = encode_event_message(
+		event_id,
+		H160::from_low_u64_be(555),
+		H160::from_low_u64_be(555),
+		&[1],
+	);
+	let mock_notary_keys: Vec<<Test as Config>::EthyId> = (1_u8..=3_u8)
+		.map(|k| <Test as Config>::EthyId::from_slice(&[k; 33]).unwrap())
+		.collect();
+	ExtBuilder::default()
+		.with_endowed_account(relayer_1, RelayerBond::get())
+		.with_endowed_account(relayer_2, RelayerBond::get())
+		.with_endowed_account(challenger, ChallengerBond::get())
+		.build()
+		.execute_with(|| {
+			MockValidatorSet::mock_n_validators(mock_notary_keys.len() as u8);
+			setup_relayer(relayer_1);
+			setup_relayer(relayer_2);
+
+			// relayer 2 submits a bad claim and unbonds before it is disproven
+			assert_ok!(EthBridge::submit_event(
+				RuntimeOrigin::signed(relayer_2.into()),
+				EthyChainId::Ethereum,
+				EthHash::from_low_u64_be(1),
+				event_data,
+			));
+			assert_ok!(EthBridge::submit_challenge(
+				RuntimeOrigin::signed(challenger.into()),
+				event_id
+			));
+			assert_ok!(EthBridge::unbond_relayer(RuntimeOrigin::signed(relayer_2.into())));
+
+			for notary_key in mock_notary_keys.iter().take(2) {
+				assert_ok!(EthBridge::handle_event_notarization(
+					event_id,
+					EventClaimResult::TxStatusFailed,
+					notary_key
+				));
+			}
+
+			// The unbonding bond of relayer 2 is paid to the challenger
+			assert_eq!(EthBridge::relayer_paid_bond(AccountId::from(relayer_2)), 0);
+			assert!(EthBridge::relayer_unbonding(AccountId::from(relayer_2)).is_none());
+			assert_eq!(
+				AssetsExt::hold_balance(&BridgePalletId::get(), &relayer_2.into(), &ROOT_ASSET_ID),
+				0
+			);
+			assert_eq!(
+				AssetsExt::balance(ROOT_ASSET_ID, &challenger.into()),
+				RelayerBond::get() + ChallengerBond::get()
+			);
+
+			// relayer 1 is unaffected
+			assert_eq!(EthBridge::relayers(), vec![relayer_1.into()]);
+			assert_eq!(
+				EthBridge::relayer_paid_bond(AccountId::from(relayer_1)),
+				RelayerBond::get()
+			);
+		});
+}
+
 #[test]
 fn submit_challenge() {
 	let relayer = H160::from_low_u64_be(123);
@@ -351,6 +841,7 @@ fn submit_challenge() {
 			// Submit event
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash.clone(),
 				event_data.clone(),
 			));
@@ -391,6 +882,7 @@ fn submit_challenge_no_balance_should_fail() {
 		// Submit event
 		assert_ok!(EthBridge::submit_event(
 			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::Ethereum,
 			tx_hash.clone(),
 			event_data.clone(),
 		));
@@ -434,6 +926,7 @@ fn handle_event_notarization_valid_claims() {
 			// Submit Event 1
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash_1.clone(),
 				event_data_1.clone(),
 			));
@@ -444,6 +937,7 @@ fn handle_event_notarization_valid_claims() {
 			// Submit Event 2
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash_2.clone(),
 				event_data_2.clone(),
 			));
@@ -526,7 +1020,8 @@ fn handle_event_notarization_valid_claims() {
 					source: source_2,
 					destination: destination_2,
 					tx_hash: tx_hash_2,
-					data: message_2.to_vec()
+					data: message_2.to_vec(),
+					chain_id: EthyChainId::Ethereum,
 				})
 			);
 			assert_eq!(EthBridge::messages_valid_at(process_at), vec![event_id_1, event_id_2]);
@@ -562,6 +1057,7 @@ fn process_valid_challenged_event() {
 			// Submit Event 1
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash_1.clone(),
 				event_data_1.clone(),
 			));
@@ -617,7 +1113,8 @@ fn process_valid_challenged_event() {
 					source: source_1,
 					destination: destination_1,
 					tx_hash: tx_hash_1,
-					data: message_1.to_vec()
+					data: message_1.to_vec(),
+					chain_id: EthyChainId::Ethereum,
 				})
 			);
 			assert_eq!(EthBridge::messages_valid_at(process_at), vec![event_id_1]);
@@ -625,7 +1122,9 @@ fn process_valid_challenged_event() {
 			// Weight returned should include the 1000 that we specified in our mock
 			assert_eq!(
 				EthBridge::on_initialize(process_at),
-				DbWeight::get().reads(2u64) + Weight::from_ref_time(1000u64)
+				<() as WeightInfo>::on_initialize() +
+					<() as WeightInfo>::on_initialize_event_claim() +
+					Weight::from_ref_time(1000u64)
 			);
 
 			// Storage should now be fully cleared
@@ -635,7 +1134,7 @@ fn process_valid_challenged_event() {
 			assert!(EthBridge::pending_claim_status(event_id_1).is_none());
 			assert!(EthBridge::messages_valid_at(process_at).is_empty());
 			// The event is processed!
-			assert_eq!(EthBridge::processed_message_ids(), vec![event_id_1]);
+			assert_eq!(EthBridge::processed_message_ids(EthyChainId::Ethereum), vec![event_id_1]);
 		});
 }
 
@@ -668,6 +1167,7 @@ fn process_valid_challenged_event_delayed() {
 			// Submit Event 1
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash_1.clone(),
 				event_data_1.clone(),
 			));
@@ -687,7 +1187,11 @@ fn process_valid_challenged_event_delayed() {
 
 			// Weight returned should not include the 1000 that we specified in our mock as a
 			// consensus has not been reached
-			assert_eq!(EthBridge::on_initialize(process_at), DbWeight::get().reads(2u64));
+			assert_eq!(
+				EthBridge::on_initialize(process_at),
+				<() as WeightInfo>::on_initialize() +
+					<() as WeightInfo>::on_initialize_event_claim()
+			);
 
 			assert_eq!(EthBridge::messages_valid_at(process_at_extended), vec![event_id_1]);
 			assert!(EthBridge::messages_valid_at(process_at).is_empty());
@@ -712,7 +1216,8 @@ fn process_valid_challenged_event_delayed() {
 					source: source_1,
 					destination: destination_1,
 					tx_hash: tx_hash_1,
-					data: message_1.to_vec()
+					data: message_1.to_vec(),
+					chain_id: EthyChainId::Ethereum,
 				})
 			);
 			assert_eq!(EthBridge::messages_valid_at(process_at_extended), vec![event_id_1]);
@@ -720,7 +1225,9 @@ fn process_valid_challenged_event_delayed() {
 			// Weight returned should include the 1000 that we specified in our mock
 			assert_eq!(
 				EthBridge::on_initialize(process_at_extended),
-				DbWeight::get().reads(2u64) + Weight::from_ref_time(1000u64)
+				<() as WeightInfo>::on_initialize() +
+					<() as WeightInfo>::on_initialize_event_claim() +
+					Weight::from_ref_time(1000u64)
 			);
 
 			// Storage should now be fully cleared
@@ -730,7 +1237,7 @@ fn process_valid_challenged_event_delayed() {
 			assert!(EthBridge::pending_claim_status(event_id_1).is_none());
 			assert!(EthBridge::messages_valid_at(process_at_extended).is_empty());
 			// The event is processed!
-			assert_eq!(EthBridge::processed_message_ids(), vec![event_id_1]);
+			assert_eq!(EthBridge::processed_message_ids(EthyChainId::Ethereum), vec![event_id_1]);
 		});
 }
 
@@ -759,6 +1266,7 @@ fn handle_event_notarization_invalid_claims() {
 			// Submit Event 1
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash_1.clone(),
 				event_data_1.clone(),
 			));
@@ -840,6 +1348,19 @@ fn handle_event_notarization_invalid_claims() {
 				RelayerBond::get() + ChallengerBond::get()
 			);
 			assert!(EthBridge::challenger_account(event_id_1).is_none());
+
+			// Relayer is removed from the relayer set
+			assert!(EthBridge::relayers().is_empty());
+			assert!(EthBridge::event_claim_relayer(event_id_1).is_none());
+			System::assert_has_event(
+				Event::<Test>::RelayerSlashed(
+					event_id_1,
+					relayer.into(),
+					challenger.into(),
+					RelayerBond::get(),
+				)
+				.into(),
+			);
 		});
 }
 
@@ -862,6 +1383,7 @@ fn do_event_notarization_ocw_doesnt_change_storage() {
 			// Submit Event 1
 			assert_ok!(EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash_1.clone(),
 				event_data_1.clone(),
 			));
@@ -921,7 +1443,7 @@ fn pre_last_session_change() {
 			event_proof_id,
 			validator_set_id: 0,
 			source: BridgePalletId::get().into_account_truncating(),
-			destination: EthBridge::contract_address(),
+			destination: EthBridge::contract_address(EthyChainId::Ethereum),
 			message: new_validator_set_message.to_vec(),
 		});
 
@@ -1008,7 +1530,7 @@ fn on_new_session_updates_keys() {
 			event_proof_id,
 			validator_set_id: 0,
 			source: BridgePalletId::get().into_account_truncating(),
-			destination: EthBridge::contract_address(),
+			destination: EthBridge::contract_address(EthyChainId::Ethereum),
 			message: new_validator_set_message.to_vec(),
 		});
 		assert_eq!(
@@ -1027,7 +1549,7 @@ fn on_new_session_updates_keys() {
 		// Calling on_before_session_ending should NOT call handle_authorities_change again,
 		// but do_finalise_authorities_change() will add ConsensusLog::AuthoritiesChange
 		// notification log to the header
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		assert_eq!(System::digest().logs.len(), 2); // previous one + new
 		assert_eq!(
 			System::digest().logs[1],
@@ -1099,7 +1621,7 @@ fn on_before_session_ending_handles_authorities() {
 
 		// Calling on_before_session_ending should call handle_authorities_change as it wasn't
 		// changed in on_initialize
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 
 		// Storage should represent the storage before the authorities are finalized
 		assert_eq!(EthBridge::notary_set_proof_id(), event_proof_id);
@@ -1171,7 +1693,7 @@ fn on_before_session_ending_handles_authorities_without_on_new_session() {
 
 		// Calling on_before_session_ending should call handle_authorities_change as it wasn't
 		// changed in on_initialize
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 
 		// Item should be scheduled and bridge still paused
 		assert!(EthBridge::bridge_paused());
@@ -1231,7 +1753,7 @@ fn force_new_era_with_scheduled_authority_change_works() {
 
 		// Calling on_before_session_ending should call handle_authorities_change as it wasn't
 		// changed in on_initialize
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		// Item should be scheduled and bridge still paused
 		assert!(EthBridge::bridge_paused());
 
@@ -1296,7 +1818,7 @@ fn last_session_change() {
 		EthBridge::handle_authorities_change();
 		assert!(EthBridge::bridge_paused());
 		// current session is last in era: finishing
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		assert_eq!(EthBridge::notary_keys(), next_keys);
 		assert_eq!(EthBridge::notary_set_id(), current_set_id + 1);
 		assert_eq!(
@@ -1325,10 +1847,229 @@ fn send_event() {
 		// Ensure event has not been added to delayed queue
 		assert_eq!(EthBridge::pending_event_proofs(event_proof_id), None);
 		assert_eq!(EthBridge::next_event_proof_id(), event_proof_id + 1);
-		// On initialize does up to 2 reads to check for delayed proofs
+		// No delayed proofs to process
 		assert_eq!(
 			EthBridge::on_initialize(frame_system::Pallet::<Test>::block_number() + 1),
-			DbWeight::get().reads(2u64)
+			<() as WeightInfo>::on_initialize()
+		);
+	});
+}
+
+#[test]
+fn send_event_to_secondary_evm() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = H160::from_low_u64_be(444);
+		let destination = H160::from_low_u64_be(555);
+		let message = &b"hello world"[..];
+		let event_proof_id = EthBridge::next_event_proof_id();
+
+		assert_ok!(
+			EthBridge::send_event_to(EthyChainId::SecondaryEvm, &source, &destination, &message),
+			event_proof_id
+		);
+		assert_eq!(EthBridge::next_event_proof_id(), event_proof_id + 1);
+
+		let signing_request = EthySigningRequest::SecondaryEvm(EthereumEventInfo {
+			source,
+			destination,
+			message: message.to_vec(),
+			validator_set_id: EthBridge::validator_set().id,
+			event_proof_id,
+		});
+		System::assert_has_event(
+			Event::<Test>::EventSend { event_proof_id, signing_request: signing_request.clone() }
+				.into(),
+		);
+		assert_eq!(
+			System::digest().logs[0],
+			DigestItem::Consensus(
+				ETHY_ENGINE_ID,
+				ConsensusLog::OpaqueSigningRequest::<AuthorityId> {
+					chain_id: EthyChainId::SecondaryEvm,
+					event_proof_id,
+					data: signing_request.data(),
+				}
+				.encode(),
+			),
+		);
+
+		// XRPL is not an EVM chain
+		assert_noop!(
+			EthBridge::send_event_to(EthyChainId::Xrpl, &source, &destination, &message),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn send_event_batched() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = H160::from_low_u64_be(444);
+		let destination = H160::from_low_u64_be(555);
+		let message = &b"hello world"[..];
+		let event_proof_id = EthBridge::next_event_proof_id();
+		assert_ok!(EthBridge::set_event_proof_batch_window(RuntimeOrigin::root(), 5));
+		System::assert_last_event(Event::<Test>::EventProofBatchWindowSet(5).into());
+
+		// Events are queued for the next batch, no signing is requested yet
+		assert_ok!(EthBridge::send_event(&source, &destination, &message), event_proof_id);
+		assert_ok!(EthBridge::send_event(&source, &destination, &message), event_proof_id + 1);
+		assert!(System::digest().logs.is_empty());
+		let event_info = |event_proof_id| EthereumEventInfo {
+			source,
+			destination,
+			message: message.to_vec(),
+			validator_set_id: EthBridge::validator_set().id,
+			event_proof_id,
+		};
+		System::assert_has_event(
+			Event::<Test>::EventBatchQueued {
+				event_proof_id,
+				signing_request: EthySigningRequest::Ethereum(event_info(event_proof_id)),
+			}
+			.into(),
+		);
+		let leaves = vec![
+			(event_proof_id, keccak_256(&event_info(event_proof_id).abi_encode())),
+			(event_proof_id + 1, keccak_256(&event_info(event_proof_id + 1).abi_encode())),
+		];
+		assert_eq!(EthBridge::pending_event_batch(EthyChainId::Ethereum), leaves);
+
+		// Not the end of the batch window
+		assert_eq!(EthBridge::on_initialize(4), <() as WeightInfo>::on_initialize());
+		assert!(System::digest().logs.is_empty());
+
+		// End of the batch window, the batch root is signed once
+		let batch_id = event_proof_id + 2;
+		assert_eq!(
+			EthBridge::on_initialize(5),
+			<() as WeightInfo>::on_initialize() + <() as WeightInfo>::on_initialize_event_batch()
+		);
+		assert!(EthBridge::pending_event_batch(EthyChainId::Ethereum).is_empty());
+		assert_eq!(EthBridge::next_event_proof_id(), batch_id + 1);
+		let signing_request = EthySigningRequest::EventBatch(EventBatchInfo {
+			chain_id: EthyChainId::Ethereum,
+			batch_id,
+			validator_set_id: EthBridge::validator_set().id,
+			leaves: leaves.clone(),
+		});
+		System::assert_has_event(
+			Event::<Test>::EventSend {
+				event_proof_id: batch_id,
+				signing_request: signing_request.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			System::digest().logs,
+			vec![
+				DigestItem::Consensus(
+					ETHY_ENGINE_ID,
+					ConsensusLog::OpaqueSigningRequest::<AuthorityId> {
+						chain_id: EthyChainId::Ethereum,
+						event_proof_id: batch_id,
+						data: signing_request.data(),
+					}
+					.encode(),
+				),
+				DigestItem::Consensus(
+					ETHY_ENGINE_ID,
+					ConsensusLog::EventBatch::<AuthorityId> {
+						chain_id: EthyChainId::Ethereum,
+						batch_id,
+						leaves,
+					}
+					.encode(),
+				),
+			]
+		);
+
+		// Nothing queued, no batch requested
+		assert_eq!(EthBridge::on_initialize(10), <() as WeightInfo>::on_initialize());
+	});
+}
+
+#[test]
+fn send_event_batched_flushes_full_batch() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = H160::from_low_u64_be(444);
+		let destination = H160::from_low_u64_be(555);
+		let message = &b"hello world"[..];
+		assert_ok!(EthBridge::set_event_proof_batch_window(RuntimeOrigin::root(), 100));
+
+		for _ in 0..MAX_EVENT_BATCH_SIZE - 1 {
+			assert_ok!(EthBridge::send_event_to(
+				EthyChainId::SecondaryEvm,
+				&source,
+				&destination,
+				&message
+			));
+		}
+		assert_eq!(
+			EthBridge::pending_event_batch(EthyChainId::SecondaryEvm).len(),
+			MAX_EVENT_BATCH_SIZE - 1
+		);
+		assert!(System::digest().logs.is_empty());
+
+		// The batch is requested as soon as it is full
+		assert_ok!(EthBridge::send_event_to(
+			EthyChainId::SecondaryEvm,
+			&source,
+			&destination,
+			&message
+		));
+		assert!(EthBridge::pending_event_batch(EthyChainId::SecondaryEvm).is_empty());
+		assert_eq!(System::digest().logs.len(), 2);
+		let batch_id = MAX_EVENT_BATCH_SIZE as EventProofId;
+		assert_eq!(EthBridge::next_event_proof_id(), batch_id + 1);
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::EthBridge(Event::EventSend {
+				event_proof_id,
+				signing_request: EthySigningRequest::EventBatch(batch),
+			}) if *event_proof_id == batch_id &&
+				batch.chain_id == EthyChainId::SecondaryEvm &&
+				batch.leaves.len() == MAX_EVENT_BATCH_SIZE
+		)));
+	});
+}
+
+#[test]
+fn set_event_proof_batch_window_zero_flushes_batches() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = H160::from_low_u64_be(444);
+		let destination = H160::from_low_u64_be(555);
+		let message = &b"hello world"[..];
+		assert_ok!(EthBridge::set_event_proof_batch_window(RuntimeOrigin::root(), 5));
+		assert_ok!(EthBridge::send_event(&source, &destination, &message));
+		assert_ok!(EthBridge::send_event_to(
+			EthyChainId::SecondaryEvm,
+			&source,
+			&destination,
+			&message
+		));
+
+		// Disabling batching requests signing of both queued batches
+		assert_ok!(EthBridge::set_event_proof_batch_window(RuntimeOrigin::root(), 0));
+		assert!(EthBridge::pending_event_batch(EthyChainId::Ethereum).is_empty());
+		assert!(EthBridge::pending_event_batch(EthyChainId::SecondaryEvm).is_empty());
+		assert_eq!(System::digest().logs.len(), 4);
+
+		// Events are signed individually again
+		assert_ok!(EthBridge::send_event(&source, &destination, &message));
+		assert_eq!(System::digest().logs.len(), 5);
+	});
+}
+
+#[test]
+fn set_event_proof_batch_window_requires_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EthBridge::set_event_proof_batch_window(
+				RuntimeOrigin::signed(H160::from_low_u64_be(1).into()),
+				5
+			),
+			BadOrigin
 		);
 	});
 }
@@ -1363,7 +2104,7 @@ fn xrpl_tx_signing_request() {
 		);
 
 		// Bridge is paused, request signing
-		BridgePaused::put(true);
+		BridgePaused::<Test>::put(true);
 		assert_ok!(EthBridge::sign_xrpl_transaction("hello world".as_bytes()), event_proof_id + 1);
 		assert_eq!(
 			EthBridge::pending_event_proofs(event_proof_id + 1),
@@ -1394,7 +2135,7 @@ fn delayed_event_proof() {
 		let message = &b"hello world"[..];
 		let source = H160::from_low_u64_be(444);
 		let destination = H160::from_low_u64_be(555);
-		BridgePaused::put(true);
+		BridgePaused::<Test>::put(true);
 		assert_eq!(EthBridge::bridge_paused(), true);
 
 		let event_proof_id = EthBridge::next_event_proof_id();
@@ -1413,11 +2154,10 @@ fn delayed_event_proof() {
 		assert_eq!(EthBridge::next_event_proof_id(), event_proof_id + 1);
 
 		// Re-enable bridge
-		BridgePaused::kill();
+		BridgePaused::<Test>::kill();
 		// initialize pallet and initiate event proof
-		let max_delayed_events = EthBridge::delayed_event_proofs_per_block() as u64;
 		let expected_weight: Weight =
-			DbWeight::get().reads(3u64) + DbWeight::get().writes(2u64) * max_delayed_events;
+			<() as WeightInfo>::on_initialize() + <() as WeightInfo>::on_initialize_delayed_proof();
 		assert_eq!(
 			EthBridge::on_initialize(frame_system::Pallet::<Test>::block_number() + 1),
 			expected_weight
@@ -1433,7 +2173,7 @@ fn multiple_delayed_event_proof() {
 		let message = &b"hello world"[..];
 		let source = H160::from_low_u64_be(444);
 		let destination = H160::from_low_u64_be(555);
-		BridgePaused::put(true);
+		BridgePaused::<Test>::put(true);
 		assert_eq!(EthBridge::bridge_paused(), true);
 
 		let max_delayed_events = EthBridge::delayed_event_proofs_per_block();
@@ -1459,11 +2199,12 @@ fn multiple_delayed_event_proof() {
 		}
 
 		// Re-enable bridge
-		BridgePaused::kill();
+		BridgePaused::<Test>::kill();
 		// initialize pallet and initiate event proof
 		assert_eq!(
 			EthBridge::on_initialize(frame_system::Pallet::<Test>::block_number() + 1),
-			DbWeight::get().reads(3u64) + DbWeight::get().writes(2u64) * max_delayed_events as u64
+			<() as WeightInfo>::on_initialize() +
+				<() as WeightInfo>::on_initialize_delayed_proof() * max_delayed_events as u64
 		);
 
 		let mut removed_count = 0;
@@ -1484,7 +2225,8 @@ fn multiple_delayed_event_proof() {
 		// Now initialize next block and process the rest
 		assert_eq!(
 			EthBridge::on_initialize(frame_system::Pallet::<Test>::block_number() + 2),
-			DbWeight::get().reads(3u64) + DbWeight::get().writes(2u64) * max_delayed_events as u64
+			<() as WeightInfo>::on_initialize() +
+				<() as WeightInfo>::on_initialize_delayed_proof() * max_delayed_events as u64
 		);
 
 		let mut removed_count = 0;
@@ -1515,7 +2257,7 @@ fn set_delayed_event_proofs_per_block() {
 		let source = H160::from_low_u64_be(444);
 		let destination = H160::from_low_u64_be(555);
 		let mut event_ids: Vec<EventProofId> = vec![];
-		BridgePaused::put(true);
+		BridgePaused::<Test>::put(true);
 
 		for _ in 0..new_max_delayed_events {
 			let event_proof_id = EthBridge::next_event_proof_id();
@@ -1535,12 +2277,12 @@ fn set_delayed_event_proofs_per_block() {
 		}
 
 		// Re-enable bridge
-		BridgePaused::kill();
+		BridgePaused::<Test>::kill();
 		// initialize pallet and initiate event proof
 		assert_eq!(
 			EthBridge::on_initialize(frame_system::Pallet::<Test>::block_number() + 1),
-			DbWeight::get().reads(3u64) +
-				DbWeight::get().writes(2u64) * new_max_delayed_events as u64
+			<() as WeightInfo>::on_initialize() +
+				<() as WeightInfo>::on_initialize_delayed_proof() * new_max_delayed_events as u64
 		);
 
 		for i in 0..new_max_delayed_events {
@@ -1585,7 +2327,49 @@ fn offchain_try_notarize_event() {
 		let _mock_block_1 = mock_block_response(block_number, timestamp);
 		let _mock_block_2 = mock_block_response(block_number + 5, timestamp);
 		let mock_log = MockLogBuilder::new()
-			.address(EthBridge::contract_address())
+			.address(EthBridge::contract_address(EthyChainId::Ethereum))
+			.data(event_data.as_slice())
+			.topics(vec![SUBMIT_BRIDGE_EVENT_SELECTOR.into()])
+			.transaction_hash(tx_hash)
+			.build();
+		let _mock_tx_receipt =
+			create_transaction_receipt_mock(block_number, tx_hash, source, vec![mock_log]);
+
+		let event_claim = EventClaim {
+			tx_hash,
+			source,
+			destination,
+			data: message,
+			chain_id: EthyChainId::Ethereum,
+		};
+		assert_eq!(
+			EthBridge::offchain_try_notarize_event(event_id, event_claim),
+			EventClaimResult::Valid
+		);
+	});
+}
+
+#[test]
+fn offchain_try_notarize_event_finalized_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Mock block response and transaction receipt
+		let block_number = 10;
+		let timestamp =
+			U256::from(<MockUnixTime as UnixTime>::now().as_secs().saturated_into::<u64>());
+		let tx_hash = EthHash::from_low_u64_be(222);
+		let source = EthAddress::from_low_u64_be(333);
+		let destination = EthAddress::from_low_u64_be(444);
+		let message = vec![1_u8, 2, 3, 4, 5];
+		let event_id = 1;
+		let event_data = encode_event_message(event_id, source, destination, message.as_slice());
+
+		// The tx block has enough confirmations but is not yet finalized
+		let _mock_block_1 = mock_block_response(block_number - 1, timestamp);
+		let _mock_block_2 = mock_block_response(block_number, timestamp);
+		let _mock_block_3 = mock_block_response(block_number + 5, timestamp);
+		MockEthereumRpcClient::mock_finalized_block(block_number - 1);
+		let mock_log = MockLogBuilder::new()
+			.address(EthBridge::contract_address(EthyChainId::Ethereum))
 			.data(event_data.as_slice())
 			.topics(vec![SUBMIT_BRIDGE_EVENT_SELECTOR.into()])
 			.transaction_hash(tx_hash)
@@ -1593,7 +2377,30 @@ fn offchain_try_notarize_event() {
 		let _mock_tx_receipt =
 			create_transaction_receipt_mock(block_number, tx_hash, source, vec![mock_log]);
 
-		let event_claim = EventClaim { tx_hash, source, destination, data: message };
+		let event_claim = EventClaim {
+			tx_hash,
+			source,
+			destination,
+			data: message,
+			chain_id: EthyChainId::Ethereum,
+		};
+		assert_eq!(
+			EthBridge::offchain_try_notarize_event(event_id, event_claim.clone()),
+			EventClaimResult::Valid
+		);
+
+		assert_ok!(EthBridge::set_event_finality_mode(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
+			EventFinalityMode::Finalized
+		));
+		assert_eq!(
+			EthBridge::offchain_try_notarize_event(event_id, event_claim.clone()),
+			EventClaimResult::NotEnoughConfirmations
+		);
+
+		// The tx block is finalized
+		MockEthereumRpcClient::mock_finalized_block(block_number);
 		assert_eq!(
 			EthBridge::offchain_try_notarize_event(event_id, event_claim),
 			EventClaimResult::Valid
@@ -1679,7 +2486,7 @@ fn offchain_try_notarize_event_no_block_number_should_fail() {
 		// Create mock info for transaction receipt
 		let event_data = encode_event_message(event_id, source, destination, Default::default());
 		let mock_log = MockLogBuilder::new()
-			.address(EthBridge::contract_address())
+			.address(EthBridge::contract_address(EthyChainId::Ethereum))
 			.topics(vec![SUBMIT_BRIDGE_EVENT_SELECTOR.into()])
 			.data(event_data.as_slice())
 			.transaction_hash(tx_hash)
@@ -1713,7 +2520,7 @@ fn offchain_try_notarize_event_no_confirmations_should_fail() {
 		let _mock_block_2 = mock_block_response(block_number, timestamp);
 		let event_data = encode_event_message(event_id, source, destination, Default::default());
 		let mock_log = MockLogBuilder::new()
-			.address(EthBridge::contract_address())
+			.address(EthBridge::contract_address(EthyChainId::Ethereum))
 			.topics(vec![SUBMIT_BRIDGE_EVENT_SELECTOR.into()])
 			.data(event_data.as_slice())
 			.transaction_hash(tx_hash)
@@ -1746,7 +2553,7 @@ fn offchain_try_notarize_event_no_observed_should_fail() {
 		let _mock_block_1 = mock_block_response(block_number, timestamp);
 		let event_data = encode_event_message(event_id, source, destination, Default::default());
 		let mock_log = MockLogBuilder::new()
-			.address(EthBridge::contract_address())
+			.address(EthBridge::contract_address(EthyChainId::Ethereum))
 			.data(event_data.as_slice())
 			.transaction_hash(tx_hash)
 			.build();
@@ -1755,7 +2562,11 @@ fn offchain_try_notarize_event_no_observed_should_fail() {
 		let event_claim = EventClaim { tx_hash, source, destination, ..Default::default() };
 
 		// Set event confirmations to 0 so it doesn't fail early
-		let _ = EthBridge::set_event_block_confirmations(frame_system::RawOrigin::Root.into(), 0);
+		let _ = EthBridge::set_event_block_confirmations(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
+			0,
+		);
 		assert_eq!(
 			EthBridge::offchain_try_notarize_event(event_id, event_claim),
 			EventClaimResult::NoTxLogs
@@ -1768,7 +2579,7 @@ fn offchain_try_eth_call_cant_fetch_latest_block() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_eq!(
 			EthBridge::offchain_try_eth_call(&CheckedEthCallRequestBuilder::new().build()),
-			CheckedEthCallResult::DataProviderErr
+			(CheckedEthCallResult::DataProviderErr, None)
 		);
 	});
 }
@@ -1779,7 +2590,7 @@ fn offchain_try_eth_call_cant_check_call() {
 		mock_block_response(123_u64, now().into());
 		assert_eq!(
 			EthBridge::offchain_try_eth_call(&CheckedEthCallRequestBuilder::new().build()),
-			CheckedEthCallResult::DataProviderErr,
+			(CheckedEthCallResult::DataProviderErr, None),
 		);
 	});
 }
@@ -1819,7 +2630,60 @@ fn offchain_try_eth_call_at_historic_block() {
 		// Then
 		assert_eq!(
 			result,
-			CheckedEthCallResult::Ok(expected_return_data, try_block_number, try_block_timestamp)
+			(
+				CheckedEthCallResult::Ok(
+					keccak_256(&expected_return_data),
+					try_block_number,
+					try_block_timestamp
+				),
+				Some(expected_return_data.to_vec())
+			)
+		);
+	});
+}
+
+#[test]
+fn offchain_try_eth_call_at_finalized_block() {
+	// given the finalized event finality mode
+	// then the `eth_call` should be executed at the finalized block rather than the latest block
+	ExtBuilder::default().build().execute_with(|| {
+		let finalized_block_number = 123_u64;
+		let block_timestamp = now();
+		mock_timestamp(now());
+		mock_block_response(finalized_block_number, block_timestamp.into());
+		mock_block_response(finalized_block_number + 64, block_timestamp.into());
+		MockEthereumRpcClient::mock_finalized_block(finalized_block_number);
+		assert_ok!(EthBridge::set_event_finality_mode(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
+			EventFinalityMode::Finalized
+		));
+
+		let remote_contract = H160::from_low_u64_be(333);
+		let expected_return_data = [0x01_u8; 32];
+		MockEthereumRpcClient::mock_call_at(
+			finalized_block_number,
+			remote_contract,
+			&expected_return_data,
+		);
+
+		let request = CheckedEthCallRequestBuilder::new()
+			.check_timestamp(block_timestamp)
+			.max_block_look_behind(2)
+			.try_block_number(finalized_block_number + 10) // after the finalized block
+			.target(remote_contract)
+			.build();
+
+		assert_eq!(
+			EthBridge::offchain_try_eth_call(&request),
+			(
+				CheckedEthCallResult::Ok(
+					keccak_256(&expected_return_data),
+					finalized_block_number,
+					block_timestamp
+				),
+				Some(expected_return_data.to_vec())
+			)
 		);
 	});
 }
@@ -1856,10 +2720,51 @@ fn offchain_try_eth_call_at_latest_block() {
 		// Then
 		assert_eq!(
 			result,
-			CheckedEthCallResult::Ok(
-				expected_return_data,
-				latest_block_number,
-				latest_block_timestamp
+			(
+				CheckedEthCallResult::Ok(
+					keccak_256(&expected_return_data),
+					latest_block_number,
+					latest_block_timestamp
+				),
+				Some(expected_return_data.to_vec())
+			)
+		);
+	});
+}
+
+#[test]
+fn offchain_try_eth_call_multi_word_return_data() {
+	// given a request where returndata is > 32 bytes e.g. a struct
+	// when the validator checks the request
+	// then the hash of the returndata is notarized
+	ExtBuilder::default().build().execute_with(|| {
+		let latest_block_number = 123_u64;
+		let latest_block_timestamp = now();
+		mock_timestamp(now());
+		mock_block_response(latest_block_number, latest_block_timestamp.into());
+		let remote_contract = H160::from_low_u64_be(333);
+		// e.g. `getReserves()` returns 3 words
+		let expected_return_data = [0x02_u8; 96];
+		MockEthereumRpcClient::mock_call_at(
+			latest_block_number,
+			remote_contract,
+			&expected_return_data,
+		);
+
+		let request = CheckedEthCallRequestBuilder::new()
+			.target(remote_contract)
+			.try_block_number(5)
+			.build();
+
+		assert_eq!(
+			EthBridge::offchain_try_eth_call(&request),
+			(
+				CheckedEthCallResult::Ok(
+					keccak_256(&expected_return_data),
+					latest_block_number,
+					latest_block_timestamp
+				),
+				Some(expected_return_data.to_vec())
 			)
 		);
 	});
@@ -1867,7 +2772,7 @@ fn offchain_try_eth_call_at_latest_block() {
 
 #[test]
 fn offchain_try_eth_call_reports_oversized_return_data() {
-	// given a request where returndata is > 32 bytes
+	// given a request where returndata is > `MaxEthCallReturnData`
 	// when the validator checks the request
 	// then it should be reported as oversized
 	ExtBuilder::default().build().execute_with(|| {
@@ -1875,7 +2780,11 @@ fn offchain_try_eth_call_reports_oversized_return_data() {
 		mock_timestamp(now());
 		mock_block_response(latest_block_number, now().into());
 		let remote_contract = H160::from_low_u64_be(333);
-		MockEthereumRpcClient::mock_call_at(latest_block_number, remote_contract, &[0x02, 33]); // longer than 32 bytes
+		MockEthereumRpcClient::mock_call_at(
+			latest_block_number,
+			remote_contract,
+			&vec![0x02; MaxEthCallReturnData::get() as usize + 1],
+		);
 
 		let request = CheckedEthCallRequestBuilder::new()
 			.target(remote_contract)
@@ -1886,7 +2795,7 @@ fn offchain_try_eth_call_reports_oversized_return_data() {
 		let result = EthBridge::offchain_try_eth_call(&request);
 
 		// Then
-		assert_eq!(result, CheckedEthCallResult::ReturnDataExceedsLimit);
+		assert_eq!(result, (CheckedEthCallResult::ReturnDataExceedsLimit, None));
 	});
 }
 
@@ -1934,7 +2843,14 @@ fn offchain_try_eth_call_at_historic_block_after_delay() {
 		// Then
 		assert_eq!(
 			result,
-			CheckedEthCallResult::Ok(expected_return_data, try_block_number, try_block_timestamp)
+			(
+				CheckedEthCallResult::Ok(
+					keccak_256(&expected_return_data),
+					try_block_number,
+					try_block_timestamp
+				),
+				Some(expected_return_data.to_vec())
+			)
 		);
 
 		// same request as before but the check time set is set to _now_
@@ -1948,7 +2864,7 @@ fn offchain_try_eth_call_at_historic_block_after_delay() {
 			.target(remote_contract)
 			.build();
 		let result = EthBridge::offchain_try_eth_call(&request);
-		assert_eq!(result, CheckedEthCallResult::DataProviderErr);
+		assert_eq!(result, (CheckedEthCallResult::DataProviderErr, None));
 	});
 }
 
@@ -1964,24 +2880,27 @@ fn handle_call_notarization_success() {
 		.collect();
 	ExtBuilder::default().build().execute_with(|| {
 		let call_id = 1_u64;
-		EthCallRequestInfo::insert(call_id, CheckedEthCallRequest::default());
+		EthCallRequestInfo::<Test>::insert(call_id, CheckedEthCallRequest::default());
 		MockValidatorSet::mock_n_validators(mock_notary_keys.len() as u8);
 
 		let block = 555_u64;
 		let timestamp = now();
-		let return_data = [0x3f_u8; 32];
+		// larger than a single word
+		let return_data = vec![0x3f_u8; 64];
+		let return_data_hash = keccak_256(&return_data);
+		let other_return_data = vec![0x11_u8; 32];
 
 		// `notarizations[i]` is submitted by the i-th validator (`mock_notary_keys`)
 		let notarizations = vec![
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
-			CheckedEthCallResult::Ok(return_data, block - 1, timestamp),
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
-			CheckedEthCallResult::Ok(return_data, block, timestamp + 5),
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
-			CheckedEthCallResult::Ok([0x11_u8; 32], block, timestamp),
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block - 1, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp + 5),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
+			CheckedEthCallResult::Ok(keccak_256(&other_return_data), block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
 		];
 		// expected aggregated count after the i-th notarization
 		let expected_aggregations = vec![
@@ -1997,10 +2916,24 @@ fn handle_call_notarization_success() {
 		];
 
 		// aggregate the notarizations
-		for ((notary_result, notary_pk), aggregation) in
-			notarizations.iter().zip(mock_notary_keys).zip(expected_aggregations)
+		for (idx, ((notary_result, notary_pk), aggregation)) in notarizations
+			.iter()
+			.zip(mock_notary_keys)
+			.zip(expected_aggregations)
+			.enumerate()
 		{
-			assert_ok!(EthBridge::handle_call_notarization(call_id, *notary_result, &notary_pk));
+			// the return data is sent until it has been stored
+			let notary_return_data = match idx {
+				0 => Some(return_data.clone()),
+				7 => Some(other_return_data.clone()),
+				_ => None,
+			};
+			assert_ok!(EthBridge::handle_call_notarization(
+				call_id,
+				*notary_result,
+				notary_return_data,
+				&notary_pk
+			));
 
 			// assert notarization progress
 			let aggregated_notarizations =
@@ -2010,7 +2943,12 @@ fn handle_call_notarization_success() {
 		}
 
 		// callback triggered with correct value
-		assert_eq!(MockEthCallSubscriber::success_result(), Some((call_id, notarizations[0])),);
+		assert_eq!(
+			MockEthCallSubscriber::success_result(),
+			Some((call_id, return_data, block, timestamp))
+		);
+		// return data is cleaned up
+		assert_eq!(EthBridge::eth_call_return_data(call_id, return_data_hash), None);
 	});
 }
 
@@ -2026,18 +2964,24 @@ fn handle_call_notarization_aborts_no_consensus() {
 		.collect();
 	ExtBuilder::default().build().execute_with(|| {
 		let call_id = 1_u64;
-		EthCallRequestInfo::insert(call_id, CheckedEthCallRequest::default());
+		EthCallRequestInfo::<Test>::insert(call_id, CheckedEthCallRequest::default());
 		MockValidatorSet::mock_n_validators(mock_notary_keys.len() as u8);
 		let block = 555_u64;
 		let timestamp = now();
-		let return_data = [0x3f_u8; 32];
+		let return_data = vec![0x3f_u8; 32];
+		let return_data_hash = keccak_256(&return_data);
+		EthCallReturnData::<Test>::insert(
+			call_id,
+			return_data_hash,
+			BoundedVec::try_from(return_data).unwrap(),
+		);
 
 		// `notarizations[i]` is submitted by the i-th validator (`mock_notary_keys`)
 		let notarizations = vec![
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
-			CheckedEthCallResult::Ok(return_data, block, timestamp - 1),
-			CheckedEthCallResult::Ok(return_data, block, timestamp - 2),
-			CheckedEthCallResult::Ok(return_data, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp - 1),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp - 2),
+			CheckedEthCallResult::Ok(return_data_hash, block, timestamp),
 			CheckedEthCallResult::DataProviderErr,
 			CheckedEthCallResult::DataProviderErr,
 		];
@@ -2064,7 +3008,7 @@ fn handle_call_notarization_aborts_no_consensus() {
 				// handling the (5th) notarization triggers failure as reaching consensus is no
 				// longer possible this (6th) notarization is effectively ignored
 				assert_noop!(
-					EthBridge::handle_call_notarization(call_id, *notary_result, &notary_pk),
+					EthBridge::handle_call_notarization(call_id, *notary_result, None, &notary_pk),
 					Error::<Test>::InvalidClaim
 				);
 			} else {
@@ -2072,6 +3016,7 @@ fn handle_call_notarization_aborts_no_consensus() {
 				assert_ok!(EthBridge::handle_call_notarization(
 					call_id,
 					*notary_result,
+					None,
 					&notary_pk
 				));
 			}
@@ -2088,6 +3033,67 @@ fn handle_call_notarization_aborts_no_consensus() {
 			MockEthCallSubscriber::failed_result(),
 			Some((call_id, EthCallFailure::Internal)),
 		);
+		assert_eq!(EthBridge::eth_call_return_data(call_id, return_data_hash), None);
+	});
+}
+
+#[test]
+fn handle_call_notarization_checks_return_data() {
+	let notary_pk = <Test as Config>::EthyId::from_slice(&[1_u8; 33]).unwrap();
+	ExtBuilder::default().build().execute_with(|| {
+		let call_id = 1_u64;
+		EthCallRequestInfo::<Test>::insert(call_id, CheckedEthCallRequest::default());
+		MockValidatorSet::mock_n_validators(3);
+		let return_data = vec![0x3f_u8; 32];
+		let result = CheckedEthCallResult::Ok(keccak_256(&return_data), 555_u64, now());
+
+		// the first notarization of a result must provide its return data
+		assert_noop!(
+			EthBridge::handle_call_notarization(call_id, result, None, &notary_pk),
+			Error::<Test>::MissingEthCallReturnData
+		);
+		// the return data must match the notarized hash
+		assert_noop!(
+			EthBridge::handle_call_notarization(
+				call_id,
+				result,
+				Some(vec![0x11_u8; 32]),
+				&notary_pk
+			),
+			Error::<Test>::InvalidEthCallReturnData
+		);
+		// return data is only accepted with an `Ok` result
+		assert_noop!(
+			EthBridge::handle_call_notarization(
+				call_id,
+				CheckedEthCallResult::DataProviderErr,
+				Some(return_data.clone()),
+				&notary_pk
+			),
+			Error::<Test>::InvalidEthCallReturnData
+		);
+		// the return data is bounded
+		let oversized_return_data = vec![0x3f_u8; MaxEthCallReturnData::get() as usize + 1];
+		assert_noop!(
+			EthBridge::handle_call_notarization(
+				call_id,
+				CheckedEthCallResult::Ok(keccak_256(&oversized_return_data), 555_u64, now()),
+				Some(oversized_return_data),
+				&notary_pk
+			),
+			Error::<Test>::InvalidEthCallReturnData
+		);
+
+		assert_ok!(EthBridge::handle_call_notarization(
+			call_id,
+			result,
+			Some(return_data.clone()),
+			&notary_pk
+		));
+		assert_eq!(
+			EthBridge::eth_call_return_data(call_id, keccak_256(&return_data)).map(|r| r.to_vec()),
+			Some(return_data)
+		);
 	});
 }
 
@@ -2147,6 +3153,7 @@ fn test_submit_event_replay_check() {
 			if i != 2 {
 				assert_ok!(EthBridge::submit_event(
 					RuntimeOrigin::signed(relayer.into()),
+					EthyChainId::Ethereum,
 					tx_hash.clone(),
 					event_data[i].clone(),
 				));
@@ -2156,11 +3163,12 @@ fn test_submit_event_replay_check() {
 		let process_at = System::block_number() + EthBridge::challenge_period();
 		EthBridge::on_initialize(process_at);
 		// check the processed_message_ids has [1, 3]
-		assert_eq!(EthBridge::processed_message_ids(), vec![1, 3]);
+		assert_eq!(EthBridge::processed_message_ids(EthyChainId::Ethereum), vec![1, 3]);
 		// try to resubmit claim 0 again.
 		assert_noop!(
 			EthBridge::submit_event(
 				RuntimeOrigin::signed(relayer.into()),
+				EthyChainId::Ethereum,
 				tx_hash,
 				event_data[0].clone()
 			),
@@ -2170,6 +3178,7 @@ fn test_submit_event_replay_check() {
 		// submit claim 2 now
 		assert_ok!(EthBridge::submit_event(
 			RuntimeOrigin::signed(relayer.into()),
+			EthyChainId::Ethereum,
 			tx_hash.clone(),
 			event_data[2].clone(),
 		));
@@ -2178,7 +3187,7 @@ fn test_submit_event_replay_check() {
 		EthBridge::on_initialize(process_at2);
 
 		// check the processed_message_ids has [3]
-		assert_eq!(EthBridge::processed_message_ids(), vec![3]);
+		assert_eq!(EthBridge::processed_message_ids(EthyChainId::Ethereum), vec![3]);
 	});
 }
 
@@ -2231,10 +3240,231 @@ fn set_contract_address_works() {
 
 		assert_ok!(EthBridge::set_contract_address(
 			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
 			new_bridge_address
 		));
 		// Check storage updated
-		assert_eq!(EthBridge::contract_address(), new_bridge_address);
+		assert_eq!(EthBridge::contract_address(EthyChainId::Ethereum), new_bridge_address);
+	});
+}
+
+#[test]
+fn set_contract_address_per_chain() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ethereum_address = EthAddress::from_low_u64_be(123);
+		let secondary_address = EthAddress::from_low_u64_be(456);
+
+		assert_ok!(EthBridge::set_contract_address(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
+			ethereum_address
+		));
+		assert_ok!(EthBridge::set_contract_address(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::SecondaryEvm,
+			secondary_address
+		));
+		System::assert_last_event(
+			Event::<Test>::SetContractAddress(EthyChainId::SecondaryEvm, secondary_address).into(),
+		);
+		assert_eq!(EthBridge::contract_address(EthyChainId::Ethereum), ethereum_address);
+		assert_eq!(EthBridge::contract_address(EthyChainId::SecondaryEvm), secondary_address);
+
+		assert_noop!(
+			EthBridge::set_contract_address(
+				frame_system::RawOrigin::Root.into(),
+				EthyChainId::Xrpl,
+				secondary_address
+			),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn set_event_block_confirmations_per_chain() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EthBridge::set_event_block_confirmations(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::SecondaryEvm,
+			20
+		));
+		assert_eq!(EthBridge::event_block_confirmations(EthyChainId::SecondaryEvm), 20);
+		assert_eq!(EthBridge::event_block_confirmations(EthyChainId::Ethereum), 3);
+
+		assert_noop!(
+			EthBridge::set_event_block_confirmations(
+				frame_system::RawOrigin::Root.into(),
+				EthyChainId::Xrpl,
+				20
+			),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn set_event_finality_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			EthBridge::event_finality_mode(EthyChainId::Ethereum),
+			EventFinalityMode::BlockConfirmations
+		);
+		assert_ok!(EthBridge::set_event_finality_mode(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
+			EventFinalityMode::Finalized
+		));
+		assert_eq!(
+			EthBridge::event_finality_mode(EthyChainId::Ethereum),
+			EventFinalityMode::Finalized
+		);
+		assert_eq!(
+			EthBridge::event_finality_mode(EthyChainId::SecondaryEvm),
+			EventFinalityMode::BlockConfirmations
+		);
+		System::assert_last_event(
+			Event::<Test>::EventFinalityModeSet(
+				EthyChainId::Ethereum,
+				EventFinalityMode::Finalized,
+			)
+			.into(),
+		);
+
+		assert_noop!(
+			EthBridge::set_event_finality_mode(
+				RuntimeOrigin::signed(H160::from_low_u64_be(1).into()),
+				EthyChainId::Ethereum,
+				EventFinalityMode::BlockConfirmations
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			EthBridge::set_event_finality_mode(
+				frame_system::RawOrigin::Root.into(),
+				EthyChainId::Xrpl,
+				EventFinalityMode::Finalized
+			),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn set_event_proof_scheme() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(EthBridge::event_proof_scheme(EthyChainId::Ethereum), EventProofScheme::Ecdsa);
+		assert_ok!(EthBridge::set_event_proof_scheme(
+			frame_system::RawOrigin::Root.into(),
+			EthyChainId::Ethereum,
+			EventProofScheme::BlsAggregate
+		));
+		assert_eq!(
+			EthBridge::event_proof_scheme(EthyChainId::Ethereum),
+			EventProofScheme::BlsAggregate
+		);
+		assert_eq!(
+			EthBridge::event_proof_scheme(EthyChainId::SecondaryEvm),
+			EventProofScheme::Ecdsa
+		);
+		System::assert_last_event(
+			Event::<Test>::EventProofSchemeSet(
+				EthyChainId::Ethereum,
+				EventProofScheme::BlsAggregate,
+			)
+			.into(),
+		);
+
+		assert_noop!(
+			EthBridge::set_event_proof_scheme(
+				RuntimeOrigin::signed(H160::from_low_u64_be(1).into()),
+				EthyChainId::Ethereum,
+				EventProofScheme::Ecdsa
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			EthBridge::set_event_proof_scheme(
+				frame_system::RawOrigin::Root.into(),
+				EthyChainId::Xrpl,
+				EventProofScheme::BlsAggregate
+			),
+			Error::<Test>::UnsupportedChain
+		);
+	});
+}
+
+#[test]
+fn submit_bls_key() {
+	ExtBuilder::default().with_keystore().build().execute_with(|| {
+		let notary = AuthorityId::generate_pair(None);
+		NotaryKeys::<Test>::put(vec![
+			AuthorityId::from_slice(&[1_u8; 33]).unwrap(),
+			notary.clone(),
+		]);
+
+		// the bls key is derived from the notary session key
+		let secret = notary
+			.sign(&bls::BLS_KEY_DERIVATION_MESSAGE)
+			.and_then(|signature| bls::SecretKey::derive(signature.encode().as_slice()))
+			.unwrap();
+		let registration = BlsKeyRegistration {
+			authority_index: 1,
+			public: secret.public(),
+			proof_of_possession: secret.prove_possession(),
+		};
+		let signature = notary.sign(&registration.encode()).unwrap();
+		let call = crate::Call::<Test>::submit_bls_key {
+			registration: registration.clone(),
+			_signature: signature.clone(),
+		};
+		assert_ok!(<EthBridge as ValidateUnsigned>::validate_unsigned(
+			TransactionSource::Local,
+			&call
+		));
+
+		// signed by another notary
+		let mut other_registration = registration.clone();
+		other_registration.authority_index = 0;
+		assert_eq!(
+			<EthBridge as ValidateUnsigned>::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::<Test>::submit_bls_key {
+					registration: other_registration,
+					_signature: signature.clone(),
+				}
+			),
+			InvalidTransaction::BadProof.into()
+		);
+
+		// the proof of possession is for another key
+		let mut rogue_registration = registration.clone();
+		rogue_registration.proof_of_possession = secret.sign(&[1_u8; 32]);
+		assert_eq!(
+			<EthBridge as ValidateUnsigned>::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::<Test>::submit_bls_key {
+					_signature: notary.sign(&rogue_registration.encode()).unwrap(),
+					registration: rogue_registration,
+				}
+			),
+			InvalidTransaction::BadProof.into()
+		);
+
+		assert_ok!(EthBridge::submit_bls_key(
+			RuntimeOrigin::none(),
+			registration,
+			signature.clone()
+		));
+		assert_eq!(EthBridge::notary_bls_key(&notary), Some(secret.public()));
+		assert_eq!(EthBridge::bls_public_keys(), vec![None, Some(secret.public())]);
+		System::assert_last_event(Event::<Test>::BlsKeyRegistered(notary).into());
+
+		// already registered
+		assert_eq!(
+			<EthBridge as ValidateUnsigned>::validate_unsigned(TransactionSource::Local, &call),
+			InvalidTransaction::Stale.into()
+		);
 	});
 }
 
@@ -2246,7 +3476,11 @@ fn set_contract_address_not_root_should_fail() {
 		let ken = H160::from_low_u64_be(123);
 
 		assert_noop!(
-			EthBridge::set_contract_address(RuntimeOrigin::signed(ken.into()), new_bridge_address),
+			EthBridge::set_contract_address(
+				RuntimeOrigin::signed(ken.into()),
+				EthyChainId::Ethereum,
+				new_bridge_address
+			),
 			DispatchError::BadOrigin
 		);
 	});
@@ -2331,7 +3565,7 @@ fn notary_xrpl_keys_unchanged_do_not_request_for_xrpl_proof() {
 		assert_eq!(EthBridge::xrpl_notary_set_proof_id(), 0); // No change to XrplNotarySetProofId since no change to NotaryXrplKeys
 
 		// current session is last in era: finishing
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		assert_eq!(EthBridge::notary_keys(), next_keys);
 		assert_eq!(EthBridge::notary_set_id(), current_set_id + 1);
 		assert_eq!(EthBridge::notary_xrpl_keys(), next_keys);
@@ -2392,7 +3626,7 @@ fn notary_xrpl_keys_same_set_shuffled_do_not_request_for_xrpl_proof() {
 		assert_eq!(EthBridge::xrpl_notary_set_proof_id(), 0); // No change to XrplNotarySetProofId since no change to NotaryXrplKeys
 
 		// current session is last in era: finishing
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		assert_eq!(EthBridge::notary_keys(), next_keys);
 		assert_eq!(EthBridge::notary_set_id(), current_set_id + 1);
 		assert_eq!(EthBridge::notary_xrpl_keys(), next_keys);
@@ -2459,7 +3693,7 @@ fn notary_xrpl_keys_changed_request_for_xrpl_proof() {
 		assert_eq!(EthBridge::xrpl_notary_set_proof_id(), eth_proof_id + 1);
 
 		// current session is last in era: finishing
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		assert_eq!(EthBridge::notary_keys(), next_keys);
 		assert_eq!(EthBridge::notary_set_id(), current_set_id + 1);
 		assert_eq!(EthBridge::notary_xrpl_keys(), next_keys);
@@ -2536,7 +3770,7 @@ fn notary_xrpl_keys_removed_request_for_xrpl_proof() {
 		assert_eq!(EthBridge::xrpl_notary_set_proof_id(), eth_proof_id + 1);
 
 		// current session is last in era: finishing
-		<Module<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
+		<Pallet<Test> as OneSessionHandler<AccountId>>::on_before_session_ending();
 		assert_eq!(EthBridge::notary_keys(), next_keys);
 		assert_eq!(EthBridge::notary_set_id(), current_set_id + 1);
 		let keys_filtered = vec![
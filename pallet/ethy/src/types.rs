@@ -15,7 +15,7 @@
 
 //! Eth Bridge Types
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use core::fmt;
 use ethabi::Token;
 use ethereum_types::{Bloom, U64};
@@ -39,7 +39,7 @@ use alloc::string::String;
 #[cfg(feature = "std")]
 use std::string::String;
 
-use seed_primitives::ethy::ValidatorSetId;
+use seed_primitives::ethy::{bls, event_batch_root, ValidatorSetId};
 pub use seed_primitives::{
 	ethy::{ConsensusLog, EthyChainId, EventClaimId, EventProofId, ValidatorSet, ETHY_ENGINE_ID},
 	BlockNumber,
@@ -72,9 +72,9 @@ pub struct CheckedEthCallRequest {
 }
 #[derive(Encode, Decode, Debug, Eq, PartialOrd, Ord, PartialEq, Copy, Clone, TypeInfo)]
 pub enum CheckedEthCallResult {
-	/// returndata obtained, ethereum block number, ethereum timestamp
+	/// keccak256(returndata) obtained, ethereum block number, ethereum timestamp
 	Ok([u8; 32], u64, u64),
-	/// returndata obtained, exceeds length limit
+	/// returndata obtained, exceeds length limit (`MaxEthCallReturnData`)
 	ReturnDataExceedsLimit,
 	/// returndata obtained, empty
 	ReturnDataEmpty,
@@ -104,6 +104,8 @@ pub struct EventClaim {
 	pub destination: EthAddress,
 	/// The Ethereum ABI encoded event data as logged on Ethereum
 	pub data: Vec<u8>,
+	/// The EVM chain the event was submitted from
+	pub chain_id: EthyChainId,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Decode, Encode, TypeInfo)]
@@ -140,6 +142,38 @@ impl EthereumEventInfo {
 	}
 }
 
+/// A batch of outgoing EVM events committed to by a single merkle root
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub struct EventBatchInfo {
+	/// The EVM chain the events are bridged to
+	pub chain_id: EthyChainId,
+	/// The id of the batch proof
+	pub batch_id: EventProofId,
+	/// The validator set id which will sign the batch
+	pub validator_set_id: ValidatorSetId,
+	/// The (event proof Id, event digest) of each batched event in tree order
+	pub leaves: Vec<(EventProofId, [u8; 32])>,
+}
+
+impl EventBatchInfo {
+	/// Return the merkle root of the batched events
+	pub fn root(&self) -> [u8; 32] {
+		let leaves: Vec<[u8; 32]> = self.leaves.iter().map(|(_id, leaf)| *leaf).collect();
+		event_batch_root(&leaves)
+	}
+	/// Ethereum ABI encode the batch root for signing
+	/// `root` The merkle root of the batched event digests
+	/// `validator_set_id` The id of the current validator set
+	/// `batch_id` The id of this batch proof
+	pub fn abi_encode(&self) -> Vec<u8> {
+		ethabi::encode(&[
+			Token::FixedBytes(self.root().to_vec()),
+			Token::Uint(self.validator_set_id.into()),
+			Token::Uint(self.batch_id.into()),
+		])
+	}
+}
+
 /// A request for ethy-gadget to sign something
 #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, TypeInfo)]
 pub enum EthySigningRequest {
@@ -147,6 +181,10 @@ pub enum EthySigningRequest {
 	Ethereum(EthereumEventInfo),
 	/// Request to sign an XRPL tx (binary serialized in 'for signing' mode)
 	XrplTx(Vec<u8>),
+	/// Request to sign an event for the secondary EVM chain
+	SecondaryEvm(EthereumEventInfo),
+	/// Request to sign the merkle root of a batch of EVM events
+	EventBatch(EventBatchInfo),
 }
 
 impl EthySigningRequest {
@@ -155,16 +193,20 @@ impl EthySigningRequest {
 		match self {
 			Self::Ethereum(_) => EthyChainId::Ethereum,
 			Self::XrplTx { .. } => EthyChainId::Xrpl,
+			Self::SecondaryEvm(_) => EthyChainId::SecondaryEvm,
+			Self::EventBatch(batch) => batch.chain_id,
 		}
 	}
 	/// Return the data for signing by ethy
 	pub fn data(&self) -> Vec<u8> {
 		match self {
 			// Ethereum event signing requires keccak hashing the event
-			Self::Ethereum(event) =>
+			Self::Ethereum(event) | Self::SecondaryEvm(event) =>
 				sp_io::hashing::keccak_256(&event.abi_encode().as_slice()).to_vec(),
 			// XRPL tx hashing must happen before signing to inject the public key
 			Self::XrplTx(data) => data.clone(),
+			Self::EventBatch(batch) =>
+				sp_io::hashing::keccak_256(&batch.abi_encode().as_slice()).to_vec(),
 		}
 	}
 }
@@ -178,21 +220,28 @@ pub enum BridgeRpcError {
 	InvalidJSON,
 	/// offchain worker not configured properly
 	OcwConfig,
+	/// A majority of the configured endpoints did not agree on the result
+	NoQuorum,
 }
 
 /// Provides request/responses according to a minimal subset of Ethereum RPC API
 /// required for the bridge
+/// `chain_id` selects the EVM chain (and configured endpoint) to query
 pub trait BridgeEthereumRpcApi {
 	/// Returns an ethereum block given a block height
 	fn get_block_by_number(
+		chain_id: EthyChainId,
 		block_number: LatestOrNumber,
 	) -> Result<Option<EthBlock>, BridgeRpcError>;
 	/// Returns an ethereum transaction receipt given a tx hash
-	fn get_transaction_receipt(hash: EthHash)
-		-> Result<Option<TransactionReceipt>, BridgeRpcError>;
+	fn get_transaction_receipt(
+		chain_id: EthyChainId,
+		hash: EthHash,
+	) -> Result<Option<TransactionReceipt>, BridgeRpcError>;
 	/// Performs an `eth_call` request
 	/// Returns the Ethereum abi encoded returndata as a Vec<u8>
 	fn eth_call(
+		chain_id: EthyChainId,
 		target: EthAddress,
 		input: &[u8],
 		at_block: LatestOrNumber,
@@ -235,6 +284,37 @@ pub enum EventClaimStatus {
 	ProvenValid,
 }
 
+/// How the registered relayers may submit event claims
+#[derive(Decode, Encode, Debug, PartialEq, Eq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum RelayerSubmissionMode {
+	/// Any registered relayer may submit any event
+	FreeForAll,
+	/// Only the relayer at index `event_id % relayers.len()` may submit the event
+	RoundRobin,
+}
+
+impl Default for RelayerSubmissionMode {
+	fn default() -> Self {
+		Self::FreeForAll
+	}
+}
+
+/// How the finality of an event's EVM block is decided before it is notarized
+#[derive(Decode, Encode, Debug, PartialEq, Eq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum EventFinalityMode {
+	/// The block must have at least `EventBlockConfirmations` confirmations
+	BlockConfirmations,
+	/// The block must be at or below the chain's `finalized` block
+	/// notarization is deferred until then
+	Finalized,
+}
+
+impl Default for EventFinalityMode {
+	fn default() -> Self {
+		Self::BlockConfirmations
+	}
+}
+
 /// An independent notarization of a bridged value
 /// This is signed and shared with the runtime after verification by a particular validator
 #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
@@ -248,6 +328,8 @@ pub enum NotarizationPayload {
 		authority_index: u16,
 		/// Result of the notarization check by this authority
 		result: CheckedEthCallResult,
+		/// The returndata of an `Ok` result, omitted once it has been stored
+		return_data: Option<Vec<u8>>,
 	},
 	Event {
 		/// The message Id being notarized
@@ -284,6 +366,18 @@ impl NotarizationPayload {
 	}
 }
 
+/// A notary's registration of the BLS public key derived from its Ethy session key
+/// This is signed by the Ethy session key and shared with the runtime
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct BlsKeyRegistration {
+	/// The ordinal index of the signer in the notary set
+	pub authority_index: u16,
+	/// The BLS public key
+	pub public: bls::Public,
+	/// Proof the notary holds the BLS secret key of `public`
+	pub proof_of_possession: bls::Signature,
+}
+
 /// Log
 #[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone, Default)]
 #[serde(rename_all = "camelCase")]
@@ -449,6 +543,8 @@ pub struct GetBlockRequest {
 pub enum LatestOrNumber {
 	Latest,
 	Number(u64),
+	/// The latest block considered final by the chain's consensus (proof-of-stake)
+	Finalized,
 }
 
 const METHOD_ETH_CALL: &str = "eth_call";
@@ -495,6 +591,7 @@ pub fn serialize_params<S: serde::Serializer>(
 	let mut tup = s.serialize_tuple(2)?;
 	match v.0 {
 		LatestOrNumber::Latest => tup.serialize_element(&"latest")?,
+		LatestOrNumber::Finalized => tup.serialize_element(&"finalized")?,
 		LatestOrNumber::Number(n) => {
 			// Ethereum JSON RPC API expects the block number as a hex string
 			let mut hex_block_number = sp_std::Writer::default();
@@ -521,6 +618,7 @@ pub fn serialize_params_eth_call<S: serde::Serializer>(
 	tup.serialize_element(&v.0)?;
 	match v.1 {
 		LatestOrNumber::Latest => tup.serialize_element(&"latest")?,
+		LatestOrNumber::Finalized => tup.serialize_element(&"finalized")?,
 		LatestOrNumber::Number(n) => {
 			// Ethereum JSON RPC API expects the block number as a hex string
 			let mut hex_block_number = sp_std::Writer::default();
@@ -553,6 +651,15 @@ impl GetBlockRequest {
 			id,
 		}
 	}
+	pub fn finalized(id: usize) -> Self {
+		Self {
+			json_rpc: JSONRPC,
+			method: METHOD_GET_BLOCK_BY_NUMBER,
+			params: (LatestOrNumber::Finalized, false), /* `false` = return tx hashes not full tx
+			                                             * data */
+			id,
+		}
+	}
 }
 
 // Serde deserialize hex string, expects prefix '0x'
@@ -822,6 +929,10 @@ mod tests {
 			r#"{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["latest",false],"id":1}"#;
 		let result = serde_json::to_string(&GetBlockRequest::latest(1)).unwrap();
 		assert_eq!(expected, result);
+
+		let expected = r#"{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["finalized",false],"id":1}"#;
+		let result = serde_json::to_string(&GetBlockRequest::finalized(1)).unwrap();
+		assert_eq!(expected, result);
 	}
 
 	#[test]
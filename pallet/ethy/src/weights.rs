@@ -0,0 +1,535 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for `pallet_ethy`
+//!
+//! THESE WEIGHTS WERE NOT PRODUCED BY A BENCHMARK RUN. The storage accesses follow the
+//! benchmarked code paths but the ref times are estimates, they must be replaced by
+//! regenerating this file before release.
+
+// Regenerate with:
+// ./target/release/seed
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet-ethy
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output
+// ./pallet/ethy/src/weights.rs
+// --template
+// ./scripts/pallet_template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ethy.
+pub trait WeightInfo {
+	fn set_xrpl_door_signers(p: u32, ) -> Weight;
+	fn add_relayer() -> Weight;
+	fn deposit_relayer_bond() -> Weight;
+	fn withdraw_relayer_bond() -> Weight;
+	fn set_event_block_confirmations() -> Weight;
+	fn set_delayed_event_proofs_per_block() -> Weight;
+	fn set_challenge_period() -> Weight;
+	fn set_contract_address() -> Weight;
+	fn set_bridge_paused() -> Weight;
+	fn finalise_authorities_change() -> Weight;
+	fn submit_event() -> Weight;
+	fn submit_challenge() -> Weight;
+	fn submit_notarization() -> Weight;
+	fn on_initialize() -> Weight;
+	fn on_initialize_event_claim() -> Weight;
+	fn on_initialize_delayed_proof() -> Weight;
+	fn remove_relayer() -> Weight;
+	fn unbond_relayer() -> Weight;
+	fn set_relayer_submission_mode() -> Weight;
+	fn on_initialize_event_batch() -> Weight;
+	fn set_event_proof_batch_window() -> Weight;
+	fn set_event_finality_mode() -> Weight;
+	fn checked_eth_call() -> Weight;
+	fn set_event_proof_scheme() -> Weight;
+	fn submit_bls_key() -> Weight;
+	fn set_relayer(r: u32) -> Weight;
+}
+
+/// Weights for pallet_ethy using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: EthBridge XrplDoorSigners (r:1 w:1)
+	// Storage: EthBridge NotaryKeys (r:1 w:0)
+	// Storage: EthBridge NotaryXrplKeys (r:0 w:1)
+	/// The range of component `p` is `[1, 19]`.
+	fn set_xrpl_door_signers(p: u32, ) -> Weight {
+		Weight::from_ref_time(40_215_000 as u64)
+			// Standard Error: 3_921
+			.saturating_add(Weight::from_ref_time(4_873_211 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: EthBridge RelayerPaidBond (r:1 w:0)
+	// Storage: EthBridge RelayerUnbonding (r:1 w:0)
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_ref_time(47_815_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: EthBridge RelayerPaidBond (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_relayer_bond() -> Weight {
+		Weight::from_ref_time(96_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:0)
+	// Storage: EthBridge RelayerPaidBond (r:1 w:1)
+	// Storage: EthBridge RelayerUnbonding (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_relayer_bond() -> Weight {
+		Weight::from_ref_time(94_106_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: EthBridge EventBlockConfirmations (r:0 w:1)
+	fn set_event_block_confirmations() -> Weight {
+		Weight::from_ref_time(31_118_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge DelayedEventProofsPerBlock (r:0 w:1)
+	fn set_delayed_event_proofs_per_block() -> Weight {
+		Weight::from_ref_time(30_986_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge ChallengePeriod (r:0 w:1)
+	fn set_challenge_period() -> Weight {
+		Weight::from_ref_time(31_402_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge ContractAddress (r:0 w:1)
+	fn set_contract_address() -> Weight {
+		Weight::from_ref_time(33_017_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge BridgePaused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_ref_time(31_590_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge NotarySetId (r:1 w:1)
+	// Storage: EthBridge XrplDoorSigners (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:0 w:1)
+	// Storage: EthBridge AuthoritiesChangedThisEra (r:0 w:1)
+	// Storage: EthBridge NotaryKeys (r:0 w:1)
+	// Storage: EthBridge NotaryXrplKeys (r:0 w:1)
+	fn finalise_authorities_change() -> Weight {
+		Weight::from_ref_time(58_744_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:0)
+	// Storage: EthBridge SubmissionMode (r:1 w:0)
+	// Storage: EthBridge PendingEventClaims (r:1 w:1)
+	// Storage: EthBridge ProcessedMessageIds (r:1 w:0)
+	// Storage: EthBridge ChallengePeriod (r:1 w:0)
+	// Storage: EthBridge MessagesValidAt (r:1 w:1)
+	// Storage: EthBridge PendingClaimStatus (r:0 w:1)
+	// Storage: EthBridge EventClaimRelayer (r:0 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:1 w:1)
+	fn submit_event() -> Weight {
+		Weight::from_ref_time(74_528_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge PendingEventClaims (r:1 w:0)
+	// Storage: EthBridge PendingClaimStatus (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: EthBridge PendingClaimChallenges (r:1 w:1)
+	// Storage: EthBridge ChallengerAccount (r:0 w:1)
+	fn submit_challenge() -> Weight {
+		Weight::from_ref_time(101_560_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: EthBridge NotaryKeys (r:1 w:0)
+	// Storage: EthBridge PendingClaimStatus (r:1 w:1)
+	// Storage: EthBridge EventNotarizations (r:2 w:2)
+	// Storage: Session Validators (r:1 w:0)
+	// Storage: EthBridge PendingClaimChallenges (r:1 w:1)
+	// Storage: EthBridge PendingEventClaims (r:1 w:0)
+	// Storage: EthBridge EventClaimRelayer (r:1 w:0)
+	// Storage: EthBridge ChallengerAccount (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:2)
+	fn submit_notarization() -> Weight {
+		Weight::from_ref_time(139_205_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: EthBridge NextAuthorityChange (r:1 w:0)
+	// Storage: EthBridge ProcessedMessageIds (r:1 w:0)
+	// Storage: EthBridge MessagesValidAt (r:1 w:0)
+	// Storage: EthBridge PendingEventProofs (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(21_093_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+	}
+	// Storage: EthBridge PendingClaimStatus (r:1 w:1)
+	// Storage: EthBridge PendingEventClaims (r:1 w:1)
+	// Storage: EthBridge MessagesValidAt (r:0 w:1)
+	// Storage: EthBridge ProcessedMessageIds (r:0 w:1)
+	// Storage: EthBridge EventClaimRelayer (r:0 w:1)
+	fn on_initialize_event_claim() -> Weight {
+		Weight::from_ref_time(41_126_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge DelayedEventProofsPerBlock (r:1 w:0)
+	// Storage: EthBridge PendingEventProofs (r:1 w:1)
+	// Storage: EthBridge NotarySetId (r:1 w:0)
+	fn on_initialize_delayed_proof() -> Weight {
+		Weight::from_ref_time(48_312_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerUnbonding (r:0 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_ref_time(38_452_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerUnbonding (r:0 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn unbond_relayer() -> Weight {
+		Weight::from_ref_time(39_107_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: EthBridge SubmissionMode (r:0 w:1)
+	fn set_relayer_submission_mode() -> Weight {
+		Weight::from_ref_time(24_318_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge PendingEventBatch (r:1 w:1)
+	// Storage: EthBridge NextEventProofId (r:1 w:1)
+	// Storage: EthBridge NotarySetId (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	// Storage: System Digest (r:0 w:1)
+	// Storage: System Events (r:0 w:1)
+	fn on_initialize_event_batch() -> Weight {
+		Weight::from_ref_time(96_514_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: EthBridge PendingEventBatch (r:2 w:2)
+	// Storage: EthBridge NextEventProofId (r:1 w:1)
+	// Storage: EthBridge NotarySetId (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	// Storage: System Digest (r:1 w:1)
+	// Storage: EthBridge EventProofBatchWindow (r:0 w:1)
+	fn set_event_proof_batch_window() -> Weight {
+		Weight::from_ref_time(142_775_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: EthBridge EventFinalityModes (r:0 w:1)
+	fn set_event_finality_mode() -> Weight {
+		Weight::from_ref_time(25_946_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge NextEthCallId (r:1 w:1)
+	// Storage: EthBridge EthCallRequests (r:1 w:1)
+	// Storage: EthBridge EthCallRequestInfo (r:0 w:1)
+	fn checked_eth_call() -> Weight {
+		Weight::from_ref_time(31_221_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: EthBridge EventProofSchemes (r:0 w:1)
+	fn set_event_proof_scheme() -> Weight {
+		Weight::from_ref_time(25_812_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge NotaryKeys (r:1 w:0)
+	// Storage: EthBridge NotaryBlsKeys (r:0 w:1)
+	fn submit_bls_key() -> Weight {
+		Weight::from_ref_time(29_634_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerPaidBond (r:1 w:0)
+	// Storage: EthBridge RelayerUnbonding (r:1 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn set_relayer(r: u32) -> Weight {
+		Weight::from_ref_time(48_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: EthBridge XrplDoorSigners (r:1 w:1)
+	// Storage: EthBridge NotaryKeys (r:1 w:0)
+	// Storage: EthBridge NotaryXrplKeys (r:0 w:1)
+	/// The range of component `p` is `[1, 19]`.
+	fn set_xrpl_door_signers(p: u32, ) -> Weight {
+		Weight::from_ref_time(40_215_000 as u64)
+			// Standard Error: 3_921
+			.saturating_add(Weight::from_ref_time(4_873_211 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: EthBridge RelayerPaidBond (r:1 w:0)
+	// Storage: EthBridge RelayerUnbonding (r:1 w:0)
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_ref_time(47_815_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: EthBridge RelayerPaidBond (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_relayer_bond() -> Weight {
+		Weight::from_ref_time(96_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:0)
+	// Storage: EthBridge RelayerPaidBond (r:1 w:1)
+	// Storage: EthBridge RelayerUnbonding (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_relayer_bond() -> Weight {
+		Weight::from_ref_time(94_106_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: EthBridge EventBlockConfirmations (r:0 w:1)
+	fn set_event_block_confirmations() -> Weight {
+		Weight::from_ref_time(31_118_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge DelayedEventProofsPerBlock (r:0 w:1)
+	fn set_delayed_event_proofs_per_block() -> Weight {
+		Weight::from_ref_time(30_986_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge ChallengePeriod (r:0 w:1)
+	fn set_challenge_period() -> Weight {
+		Weight::from_ref_time(31_402_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge ContractAddress (r:0 w:1)
+	fn set_contract_address() -> Weight {
+		Weight::from_ref_time(33_017_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge BridgePaused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_ref_time(31_590_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge NotarySetId (r:1 w:1)
+	// Storage: EthBridge XrplDoorSigners (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:0 w:1)
+	// Storage: EthBridge AuthoritiesChangedThisEra (r:0 w:1)
+	// Storage: EthBridge NotaryKeys (r:0 w:1)
+	// Storage: EthBridge NotaryXrplKeys (r:0 w:1)
+	fn finalise_authorities_change() -> Weight {
+		Weight::from_ref_time(58_744_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:0)
+	// Storage: EthBridge SubmissionMode (r:1 w:0)
+	// Storage: EthBridge PendingEventClaims (r:1 w:1)
+	// Storage: EthBridge ProcessedMessageIds (r:1 w:0)
+	// Storage: EthBridge ChallengePeriod (r:1 w:0)
+	// Storage: EthBridge MessagesValidAt (r:1 w:1)
+	// Storage: EthBridge PendingClaimStatus (r:0 w:1)
+	// Storage: EthBridge EventClaimRelayer (r:0 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:1 w:1)
+	fn submit_event() -> Weight {
+		Weight::from_ref_time(74_528_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge PendingEventClaims (r:1 w:0)
+	// Storage: EthBridge PendingClaimStatus (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: EthBridge PendingClaimChallenges (r:1 w:1)
+	// Storage: EthBridge ChallengerAccount (r:0 w:1)
+	fn submit_challenge() -> Weight {
+		Weight::from_ref_time(101_560_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: EthBridge NotaryKeys (r:1 w:0)
+	// Storage: EthBridge PendingClaimStatus (r:1 w:1)
+	// Storage: EthBridge EventNotarizations (r:2 w:2)
+	// Storage: Session Validators (r:1 w:0)
+	// Storage: EthBridge PendingClaimChallenges (r:1 w:1)
+	// Storage: EthBridge PendingEventClaims (r:1 w:0)
+	// Storage: EthBridge EventClaimRelayer (r:1 w:0)
+	// Storage: EthBridge ChallengerAccount (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:2)
+	fn submit_notarization() -> Weight {
+		Weight::from_ref_time(139_205_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
+	// Storage: EthBridge NextAuthorityChange (r:1 w:0)
+	// Storage: EthBridge ProcessedMessageIds (r:1 w:0)
+	// Storage: EthBridge MessagesValidAt (r:1 w:0)
+	// Storage: EthBridge PendingEventProofs (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(21_093_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+	}
+	// Storage: EthBridge PendingClaimStatus (r:1 w:1)
+	// Storage: EthBridge PendingEventClaims (r:1 w:1)
+	// Storage: EthBridge MessagesValidAt (r:0 w:1)
+	// Storage: EthBridge ProcessedMessageIds (r:0 w:1)
+	// Storage: EthBridge EventClaimRelayer (r:0 w:1)
+	fn on_initialize_event_claim() -> Weight {
+		Weight::from_ref_time(41_126_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: EthBridge DelayedEventProofsPerBlock (r:1 w:0)
+	// Storage: EthBridge PendingEventProofs (r:1 w:1)
+	// Storage: EthBridge NotarySetId (r:1 w:0)
+	fn on_initialize_delayed_proof() -> Weight {
+		Weight::from_ref_time(48_312_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerUnbonding (r:0 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_ref_time(38_452_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerUnbonding (r:0 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn unbond_relayer() -> Weight {
+		Weight::from_ref_time(39_107_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: EthBridge SubmissionMode (r:0 w:1)
+	fn set_relayer_submission_mode() -> Weight {
+		Weight::from_ref_time(24_318_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge PendingEventBatch (r:1 w:1)
+	// Storage: EthBridge NextEventProofId (r:1 w:1)
+	// Storage: EthBridge NotarySetId (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	// Storage: System Digest (r:0 w:1)
+	// Storage: System Events (r:0 w:1)
+	fn on_initialize_event_batch() -> Weight {
+		Weight::from_ref_time(96_514_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: EthBridge PendingEventBatch (r:2 w:2)
+	// Storage: EthBridge NextEventProofId (r:1 w:1)
+	// Storage: EthBridge NotarySetId (r:1 w:0)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	// Storage: System Digest (r:1 w:1)
+	// Storage: EthBridge EventProofBatchWindow (r:0 w:1)
+	fn set_event_proof_batch_window() -> Weight {
+		Weight::from_ref_time(142_775_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: EthBridge EventFinalityModes (r:0 w:1)
+	fn set_event_finality_mode() -> Weight {
+		Weight::from_ref_time(25_946_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge NextEthCallId (r:1 w:1)
+	// Storage: EthBridge EthCallRequests (r:1 w:1)
+	// Storage: EthBridge EthCallRequestInfo (r:0 w:1)
+	fn checked_eth_call() -> Weight {
+		Weight::from_ref_time(31_221_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: EthBridge EventProofSchemes (r:0 w:1)
+	fn set_event_proof_scheme() -> Weight {
+		Weight::from_ref_time(25_812_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge NotaryKeys (r:1 w:0)
+	// Storage: EthBridge NotaryBlsKeys (r:0 w:1)
+	fn submit_bls_key() -> Weight {
+		Weight::from_ref_time(29_634_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: EthBridge Relayers (r:1 w:1)
+	// Storage: EthBridge RelayerPaidBond (r:1 w:0)
+	// Storage: EthBridge RelayerUnbonding (r:1 w:1)
+	// Storage: EthBridge RelayerLastSubmission (r:0 w:1)
+	fn set_relayer(r: u32) -> Weight {
+		Weight::from_ref_time(48_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+	}
+}
@@ -35,6 +35,14 @@ benchmarks! {
 
 	set_length_multiplier {
 	}: _(RawOrigin::Root, Balance::one())
+
+	set_dynamic_base_fee {
+		let config = DynamicBaseFeeConfig {
+			min_base_fee_per_gas: U256::one(),
+			max_base_fee_per_gas: U256::MAX,
+			elasticity: Permill::from_percent(12),
+		};
+	}: _(RawOrigin::Root, Some(config))
 }
 
 impl_benchmark_test_suite!(
@@ -17,12 +17,12 @@
 
 pub use pallet::*;
 
-use frame_support::pallet_prelude::*;
+use frame_support::{dispatch::DispatchClass, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 use seed_pallet_common::FeeConfig;
 use seed_primitives::Balance;
 use sp_core::U256;
-use sp_runtime::Perbill;
+use sp_runtime::{PerThing, Perbill, Permill, Perquintill};
 
 use core::ops::Mul;
 
@@ -45,6 +45,18 @@ pub struct FeeControlFeeConfig {
 	pub length_multiplier: Balance,
 }
 
+/// Bounds and elasticity of the EVM base fee while it follows block fullness
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DynamicBaseFeeConfig {
+	/// The base fee never drops below this value
+	pub min_base_fee_per_gas: U256,
+	/// The base fee never rises above this value
+	pub max_base_fee_per_gas: U256,
+	/// The max change of the base fee per block, when a block is empty or twice the target
+	/// fullness
+	pub elasticity: Permill,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -64,6 +76,9 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// Default EVM fee values
 		type FeeConfig: FeeConfig;
+		/// The portion of the normal dispatch weight targeted by the dynamic EVM base fee. This
+		/// should match the target of the `TargetedFeeAdjustment`
+		type TargetBlockFullness: Get<Perquintill>;
 	}
 
 	#[pallet::type_value]
@@ -78,6 +93,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Data<T> = StorageValue<_, FeeControlFeeConfig, ValueQuery, DefaultFeeConfig<T>>;
 
+	/// When set, the EVM base fee is adjusted at the end of each block based on block fullness
+	#[pallet::storage]
+	pub type DynamicBaseFee<T> = StorageValue<_, DynamicBaseFeeConfig, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T> {
@@ -87,6 +106,36 @@ pub mod pallet {
 		WeightMultiplierSet { weight_multiplier: Perbill },
 		/// The length multiplier has been set to `length_multiplier`
 		LengthMultiplierSet { length_multiplier: Balance },
+		/// The dynamic EVM base fee has been enabled with `config`, or disabled if `None`
+		DynamicBaseFeeSet { config: Option<DynamicBaseFeeConfig> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The min base fee is greater than the max base fee
+		InvalidBaseFeeBounds,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Account for the base fee adjustment in `on_finalize`
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1u64);
+			if DynamicBaseFee::<T>::exists() {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2u64, 1u64));
+			}
+			weight
+		}
+
+		/// Adjust the EVM base fee for the next block based on the fullness of this block
+		fn on_finalize(_now: T::BlockNumber) {
+			if let Some(config) = DynamicBaseFee::<T>::get() {
+				Data::<T>::mutate(|x| {
+					x.evm_base_fee_per_gas =
+						Self::next_base_fee_per_gas(&config, x.evm_base_fee_per_gas);
+				});
+			}
+		}
 	}
 
 	#[pallet::call]
@@ -123,6 +172,37 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::LengthMultiplierSet { length_multiplier: value });
 			Ok(())
 		}
+
+		/// Enable the EVM base fee following block fullness, or disable it with `None`
+		///
+		/// While enabled, the base fee moves towards `TargetBlockFullness` of the normal dispatch
+		/// weight each block, by up to `elasticity`, within the min and max base fee.
+		/// `set_evm_base_fee` may still be used to reset the current base fee.
+		#[pallet::weight(T::WeightInfo::set_dynamic_base_fee())]
+		pub fn set_dynamic_base_fee(
+			origin: OriginFor<T>,
+			config: Option<DynamicBaseFeeConfig>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			match &config {
+				Some(config) => {
+					ensure!(
+						config.min_base_fee_per_gas <= config.max_base_fee_per_gas,
+						Error::<T>::InvalidBaseFeeBounds
+					);
+					Data::<T>::mutate(|x| {
+						x.evm_base_fee_per_gas = x
+							.evm_base_fee_per_gas
+							.clamp(config.min_base_fee_per_gas, config.max_base_fee_per_gas);
+					});
+					DynamicBaseFee::<T>::put(config);
+				},
+				None => DynamicBaseFee::<T>::kill(),
+			}
+
+			Self::deposit_event(Event::<T>::DynamicBaseFeeSet { config });
+			Ok(())
+		}
 	}
 }
 
@@ -138,6 +218,48 @@ impl<T: Config> Pallet<T> {
 	pub fn base_fee_per_gas() -> U256 {
 		Data::<T>::get().evm_base_fee_per_gas
 	}
+
+	/// The max change of the EVM base fee per block, zero while the base fee is fixed
+	pub fn elasticity() -> Permill {
+		DynamicBaseFee::<T>::get().map(|config| config.elasticity).unwrap_or_default()
+	}
+
+	/// The base fee for the next block given the normal dispatch weight used by this block
+	///
+	/// Follows EIP-1559, with the congestion signal of the `TargetedFeeAdjustment`
+	fn next_base_fee_per_gas(config: &DynamicBaseFeeConfig, base_fee: U256) -> U256 {
+		let weights = T::BlockWeights::get();
+		let max_normal = weights
+			.get(DispatchClass::Normal)
+			.max_total
+			.unwrap_or(weights.max_block)
+			.ref_time();
+		let target = T::TargetBlockFullness::get() * max_normal;
+		let used = frame_system::Pallet::<T>::block_weight()
+			.get(DispatchClass::Normal)
+			.ref_time()
+			.min(max_normal);
+
+		let next_base_fee = if target == 0 || config.elasticity.is_zero() {
+			base_fee
+		} else {
+			let denominator = U256::from(target).saturating_mul(U256::from(Permill::ACCURACY));
+			let delta = |diff: u64| {
+				base_fee
+					.saturating_mul(U256::from(diff))
+					.saturating_mul(U256::from(config.elasticity.deconstruct())) /
+					denominator
+			};
+			if used > target {
+				// a congested block always raises the base fee
+				base_fee.saturating_add(delta(used - target).max(U256::one()))
+			} else {
+				base_fee.saturating_sub(delta(target - used))
+			}
+		};
+
+		next_base_fee.clamp(config.min_base_fee_per_gas, config.max_base_fee_per_gas)
+	}
 }
 
 impl<T: Config> FeeConfig for Pallet<T> {
@@ -147,3 +147,97 @@ fn set_length_multiplier_works() {
 		}));
 	});
 }
+
+mod dynamic_base_fee {
+	use super::*;
+	use crate::{DynamicBaseFee, DynamicBaseFeeConfig, Error};
+	use frame_support::traits::{Get, Hooks};
+
+	fn config() -> DynamicBaseFeeConfig {
+		DynamicBaseFeeConfig {
+			min_base_fee_per_gas: U256::from(1_000),
+			max_base_fee_per_gas: U256::from(1_000_000),
+			elasticity: Permill::from_percent(10),
+		}
+	}
+
+	/// Finalize a block which used `fullness` of the max normal dispatch weight
+	fn finalize_block(fullness: Perbill) {
+		let weights = <Test as frame_system::Config>::BlockWeights::get();
+		let max_normal = weights.get(DispatchClass::Normal).max_total.unwrap_or(weights.max_block);
+		System::set_block_consumed_resources(fullness * max_normal, 0);
+		FeeControl::on_finalize(System::block_number());
+	}
+
+	#[test]
+	fn set_dynamic_base_fee_works() {
+		TestExt::<Test>::default().build().execute_with(|| {
+			let config = config();
+			assert_noop!(
+				FeeControl::set_dynamic_base_fee(
+					RawOrigin::Root.into(),
+					Some(DynamicBaseFeeConfig {
+						min_base_fee_per_gas: config.max_base_fee_per_gas + 1,
+						..config.clone()
+					})
+				),
+				Error::<Test>::InvalidBaseFeeBounds
+			);
+
+			// the current base fee is brought within the bounds
+			assert!(FeeControl::base_fee_per_gas() > config.max_base_fee_per_gas);
+			assert_ok!(FeeControl::set_dynamic_base_fee(
+				RawOrigin::Root.into(),
+				Some(config.clone())
+			));
+			assert_eq!(DynamicBaseFee::<Test>::get(), Some(config.clone()));
+			assert_eq!(FeeControl::base_fee_per_gas(), config.max_base_fee_per_gas);
+			assert_eq!(FeeControl::elasticity(), config.elasticity);
+			System::assert_last_event(MockEvent::FeeControl(Event::<Test>::DynamicBaseFeeSet {
+				config: Some(config),
+			}));
+
+			assert_ok!(FeeControl::set_dynamic_base_fee(RawOrigin::Root.into(), None));
+			assert_eq!(DynamicBaseFee::<Test>::get(), None);
+			assert_eq!(FeeControl::elasticity(), Permill::zero());
+			System::assert_last_event(MockEvent::FeeControl(Event::<Test>::DynamicBaseFeeSet {
+				config: None,
+			}));
+		});
+	}
+
+	#[test]
+	fn base_fee_follows_block_fullness() {
+		TestExt::<Test>::default().build().execute_with(|| {
+			let base_fee = U256::from(100_000);
+			assert_ok!(FeeControl::set_evm_base_fee(RawOrigin::Root.into(), base_fee));
+
+			// the base fee is fixed until the dynamic base fee is enabled
+			finalize_block(Perbill::one());
+			assert_eq!(FeeControl::base_fee_per_gas(), base_fee);
+			assert_ok!(FeeControl::set_dynamic_base_fee(RawOrigin::Root.into(), Some(config())));
+
+			// the target fullness of 25% keeps the base fee
+			finalize_block(Perbill::from_percent(25));
+			assert_eq!(FeeControl::base_fee_per_gas(), base_fee);
+
+			// twice the target fullness raises the base fee by the elasticity
+			finalize_block(Perbill::from_percent(50));
+			assert_eq!(FeeControl::base_fee_per_gas(), U256::from(110_000));
+
+			// an empty block lowers the base fee by the elasticity
+			finalize_block(Perbill::zero());
+			assert_eq!(FeeControl::base_fee_per_gas(), U256::from(99_000));
+
+			// the base fee stays within the bounds
+			for _ in 0..100 {
+				finalize_block(Perbill::one());
+			}
+			assert_eq!(FeeControl::base_fee_per_gas(), config().max_base_fee_per_gas);
+			for _ in 0..100 {
+				finalize_block(Perbill::zero());
+			}
+			assert_eq!(FeeControl::base_fee_per_gas(), config().min_base_fee_per_gas);
+		});
+	}
+}
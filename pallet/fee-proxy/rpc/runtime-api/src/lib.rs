@@ -0,0 +1,47 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Runtime API definition required by fee proxy RPC extensions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use seed_primitives::types::{AssetId, Balance};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+	/// The RPC API to quote fees paid with the fee proxy module
+	pub trait FeeProxyApi<Call> where
+		Call: Codec,
+	{
+		/// Returns the amount of `payment_asset` swapped to pay the fees of `call` wrapped in
+		/// `call_with_fee_preferences`, where `len` is the encoded length of the extrinsic
+		fn quote_call_fee(
+			payment_asset: AssetId,
+			call: Call,
+			len: u32,
+		) -> Result<Balance, DispatchError>;
+
+		/// Returns the max amount of `payment_asset` swapped to pay the fees of an EVM call via the
+		/// fee proxy precompile
+		fn quote_evm_fee(
+			payment_asset: AssetId,
+			gas_limit: u64,
+			max_fee_per_gas: U256,
+			max_priority_fee_per_gas: Option<U256>,
+		) -> Result<Balance, DispatchError>;
+	}
+}
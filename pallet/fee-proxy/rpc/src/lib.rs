@@ -0,0 +1,101 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Node-specific RPC methods for interaction with fee proxy module.
+
+use std::sync::Arc;
+
+use codec::{Codec, Decode};
+use jsonrpsee::{
+	core::{Error as RpcError, RpcResult},
+	proc_macros::rpc,
+};
+pub use pallet_fee_proxy_rpc_runtime_api::{
+	self as runtime_api, FeeProxyApi as FeeProxyRuntimeApi,
+};
+use seed_primitives::types::{AssetId, Balance};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, U256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, DispatchError};
+
+/// Fee proxy RPC methods.
+#[rpc(client, server, namespace = "feeProxy")]
+pub trait FeeProxyApi {
+	#[method(name = "quoteCallFee")]
+	fn quote_call_fee(
+		&self,
+		payment_asset: AssetId,
+		call: Bytes,
+		len: u32,
+	) -> RpcResult<Result<Balance, DispatchError>>;
+
+	#[method(name = "quoteEvmFee")]
+	fn quote_evm_fee(
+		&self,
+		payment_asset: AssetId,
+		gas_limit: u64,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: Option<U256>,
+	) -> RpcResult<Result<Balance, DispatchError>>;
+}
+
+/// An implementation of fee proxy specific RPC methods.
+pub struct FeeProxy<C, Block, Call> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(Block, Call)>,
+}
+
+impl<C, Block, Call> FeeProxy<C, Block, Call> {
+	/// Create new `FeeProxy` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		FeeProxy { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Call> FeeProxyApiServer for FeeProxy<C, Block, Call>
+where
+	Block: BlockT,
+	Call: Codec + Send + Sync + 'static,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: FeeProxyRuntimeApi<Block, Call>,
+{
+	fn quote_call_fee(
+		&self,
+		payment_asset: AssetId,
+		call: Bytes,
+		len: u32,
+	) -> RpcResult<Result<Balance, DispatchError>> {
+		let call = Call::decode(&mut &*call)
+			.map_err(|e| RpcError::Custom(format!("Unable to decode call: {:?}", e)))?;
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(self.client.info().best_hash);
+		api.quote_call_fee(&at, payment_asset, call, len)
+			.map_err(|e| RpcError::to_call_error(e))
+	}
+
+	fn quote_evm_fee(
+		&self,
+		payment_asset: AssetId,
+		gas_limit: u64,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: Option<U256>,
+	) -> RpcResult<Result<Balance, DispatchError>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(self.client.info().best_hash);
+		api.quote_evm_fee(&at, payment_asset, gas_limit, max_fee_per_gas, max_priority_fee_per_gas)
+			.map_err(|e| RpcError::to_call_error(e))
+	}
+}
@@ -0,0 +1,127 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::Pallet as FeeProxy;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, traits::fungibles::Mutate};
+use frame_system::RawOrigin;
+use pallet_futurepass::CallPermission;
+use sp_runtime::BoundedVec;
+use sp_std::vec;
+
+// fund account with XRP
+fn fund<T: Config>(account: &T::AccountId) {
+	assert_ok!(T::MultiCurrency::mint_into(T::FeeAssetId::get(), account, 1_000_000));
+}
+
+// sponsorship rules covering `call` with the max number of accounts
+fn sponsorship_rules<T: Config>(
+	call: &<T as Config>::RuntimeCall,
+	who: &T::AccountId,
+) -> SponsorshipRulesOf<T> {
+	let encoded = call.encode();
+	let mut accounts: Vec<T::AccountId> =
+		(1..T::MaxSponsorshipItems::get()).map(|i| account("sponsored", i, 0)).collect();
+	accounts.push(who.clone());
+	SponsorshipRules {
+		calls: BoundedVec::truncate_from(vec![CallPermission {
+			pallet_index: encoded[0],
+			call_index: Some(encoded[1]),
+		}]),
+		evm_calls: Default::default(),
+		accounts: BoundedVec::truncate_from(accounts),
+		user_cap: 1_000,
+		period_cap: 1_000,
+		period: 100_u32.into(),
+	}
+}
+
+benchmarks! {
+	call_with_sponsor {
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let rules = sponsorship_rules::<T>(&call, &caller);
+		assert_ok!(FeeProxy::<T>::set_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), rules));
+	}: _(RawOrigin::Signed(caller.clone()), sponsor.clone(), Box::new(call))
+	verify {
+		frame_system::Pallet::<T>::assert_last_event(<T as Config>::RuntimeEvent::from(Event::<T>::CallWithSponsor { who: caller, sponsor }).into());
+	}
+
+	set_sponsorship {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let rules = sponsorship_rules::<T>(&call, &sponsor);
+	}: _(RawOrigin::Signed(sponsor.clone()), rules.clone())
+	verify {
+		assert_eq!(Sponsorships::<T>::get(&sponsor), Some(rules));
+	}
+
+	remove_sponsorship {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let rules = sponsorship_rules::<T>(&call, &sponsor);
+		assert_ok!(FeeProxy::<T>::set_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), rules));
+	}: _(RawOrigin::Signed(sponsor.clone()))
+	verify {
+		assert!(!Sponsorships::<T>::contains_key(&sponsor));
+	}
+
+	deposit_sponsorship_funds {
+		let sponsor: T::AccountId = whitelisted_caller();
+		fund::<T>(&sponsor);
+	}: _(RawOrigin::Signed(sponsor.clone()), 1_000)
+	verify {
+		assert_eq!(T::MultiCurrency::balance(T::FeeAssetId::get(), &FeeProxy::<T>::sponsor_account(&sponsor)), 1_000);
+	}
+
+	withdraw_sponsorship_funds {
+		let sponsor: T::AccountId = whitelisted_caller();
+		fund::<T>(&sponsor);
+		assert_ok!(FeeProxy::<T>::deposit_sponsorship_funds(RawOrigin::Signed(sponsor.clone()).into(), 1_000));
+	}: _(RawOrigin::Signed(sponsor.clone()), 500)
+	verify {
+		assert_eq!(T::MultiCurrency::balance(T::FeeAssetId::get(), &FeeProxy::<T>::sponsor_account(&sponsor)), 500);
+	}
+
+	set_fee_preference {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = T::FeeAssetId::get() + 1;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, Permill::from_percent(5))
+	verify {
+		assert_eq!(FeePreferences::<T>::get(&caller), Some(FeePreference { asset_id, max_slippage: Permill::from_percent(5) }));
+	}
+
+	remove_fee_preference {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(FeeProxy::<T>::set_fee_preference(RawOrigin::Signed(caller.clone()).into(), T::FeeAssetId::get() + 1, Permill::from_percent(5)));
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!FeePreferences::<T>::contains_key(&caller));
+	}
+}
+
+impl_benchmark_test_suite!(
+	FeeProxy,
+	seed_primitives::test_utils::TestExt::<crate::mock::Test>::default()
+		.with_xrp_balances(&[(sp_core::H160::from_low_u64_be(1).into(), 1_000_000)])
+		.build(),
+	crate::mock::Test
+);
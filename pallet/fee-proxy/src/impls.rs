@@ -13,14 +13,22 @@
 // limitations under the License.
 // You may obtain a copy of the License at the root of this project source code
 
-use crate::{Call::call_with_fee_preferences, *};
-use frame_support::traits::IsSubType;
+use crate::{
+	Call::{call_with_fee_preferences, call_with_sponsor},
+	*,
+};
+use frame_support::traits::{fungibles::Inspect as _, IsSubType};
+use pallet_evm::{AddressMapping, OnChargeEVMTransaction};
 use pallet_futurepass::ProxyProvider;
 use pallet_transaction_payment::OnChargeTransaction;
 use precompile_utils::{Address, ErcIdConversion};
 use seed_primitives::{AccountId, AssetId, Balance};
-use sp_core::U256;
-use sp_runtime::traits::{DispatchInfoOf, PostDispatchInfoOf};
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, SaturatedConversion},
+	TokenError,
+};
+use sp_std::marker::PhantomData;
 
 impl<T> OnChargeTransaction<T> for Pallet<T>
 where
@@ -41,8 +49,11 @@ where
 	Balance: From<<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance>,
 {
 	type Balance = <<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
-	type LiquidityInfo =
-		<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo;
+	/// The inner liquidity info, and the sponsor and the fee charged to it if the fee is sponsored
+	type LiquidityInfo = (
+		<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo,
+		Option<(T::AccountId, Balance)>,
+	);
 
 	/// Intercept the withdraw fee, and swap any tokens to gas tokens if the call is
 	/// pallet_fee_proxy.call_with_fee_preferences()
@@ -55,6 +66,18 @@ where
 	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
 		let mut who = who;
 
+		// if the call is pallet_fee_proxy::Call::call_with_sponsor(), and the sponsor's rules cover
+		// the caller and the call, we switch the gas payer to the sponsor's funds
+		let sponsor_account;
+		let mut sponsored = None;
+		if let Some(call_with_sponsor { sponsor, call }) = call.is_sub_type() {
+			sponsor_account =
+				Pallet::<T>::charge_sponsored_call(sponsor, who, call, Balance::from(fee))
+					.map_err(|_| InvalidTransaction::Payment)?;
+			who = &sponsor_account;
+			sponsored = Some((sponsor.clone(), Balance::from(fee)));
+		}
+
 		// if the call is pallet_futurepass::Call::proxy_extrinsic(), and the caller is a delegate
 		// of the FP(futurepass), we switch the gas payer to the FP
 		if let Some(pallet_futurepass::Call::proxy_extrinsic { futurepass, .. }) =
@@ -64,87 +87,68 @@ where
 				who = futurepass;
 			}
 		}
+		// likewise for batched proxy calls, pallet_futurepass::Call::proxy_extrinsic_batch()
+		if let Some(pallet_futurepass::Call::proxy_extrinsic_batch { futurepass, .. }) =
+			call.is_sub_type()
+		{
+			if <T as pallet_futurepass::Config>::Proxy::exists(futurepass, who, None) {
+				who = futurepass;
+			}
+		}
+		// if the call is a signed pallet_futurepass::Call::proxy_extrinsic_with_signature(), and
+		// the delegate signed for the FP to pay the fee, we switch the gas payer to the FP
+		if let Some(futurepass) = signed_proxy_fee_payer::<T>(call.is_sub_type()) {
+			who = futurepass;
+		}
 
 		// Check whether this call has specified fee preferences
 		if let Some(call_with_fee_preferences { payment_asset, max_payment, call }) =
 			call.is_sub_type()
 		{
-			let mut total_fee: Balance = Balance::from(fee);
-			let native_asset = <T as Config>::FeeAssetId::get();
-
-			let mut add_evm_gas_cost =
-				|gas_limit: &u64,
-				 max_fee_per_gas: &U256,
-				 max_priority_fee_per_gas: &Option<U256>| {
-					if let Some(FeePreferencesData { max_fee_scaled, .. }) =
-						get_fee_preferences_data::<
-							T,
-							<T as Config>::ErcIdConversion,
-							pallet_futurepass::Pallet<T>,
-						>(
-							*gas_limit,
-							<T as Config>::EVMBaseFeeProvider::evm_base_fee_per_gas(),
-							Some(*max_fee_per_gas),
-							*max_priority_fee_per_gas,
-							*payment_asset,
-						)
-						.ok()
-					{
-						total_fee = total_fee.saturating_add(max_fee_scaled);
-					}
-				};
-
 			// if the inner call is pallet_futurepass::Call::proxy_extrinsic(), and the caller is a
 			// delegate of the FP(futurepass), we switch the gas payer to the FP
-			if let Some(pallet_futurepass::Call::proxy_extrinsic { futurepass, call }) =
+			if let Some(futurepass) = signed_proxy_fee_payer::<T>(call.is_sub_type()) {
+				who = futurepass;
+			}
+			if let Some(pallet_futurepass::Call::proxy_extrinsic_batch { futurepass, .. }) =
 				call.is_sub_type()
 			{
 				if <T as pallet_futurepass::Config>::Proxy::exists(futurepass, who, None) {
 					who = futurepass;
 				}
-
-				// if the inner call of the proxy_extrinsic is an evm call, we need to add extra gas
-				// cost for that evm call
-				if let Some(pallet_evm::Call::call {
-					gas_limit,
-					max_fee_per_gas,
-					max_priority_fee_per_gas,
-					..
-				}) = call.is_sub_type()
-				{
-					add_evm_gas_cost(gas_limit, max_fee_per_gas, max_priority_fee_per_gas);
-				}
 			}
-
-			// Check if the inner call of the call_with_fee_preferences is an evm call. This will
-			// increase total gas to swap This is required as the fee value here does not take into
-			// account the max fee from an evm call. For all other extrinsics, the fee parameter
-			// should cover all required fees.
-			if let Some(pallet_evm::Call::call {
-				gas_limit,
-				max_fee_per_gas,
-				max_priority_fee_per_gas,
-				..
-			}) = call.is_sub_type()
+			if let Some(pallet_futurepass::Call::proxy_extrinsic { futurepass, .. }) =
+				call.is_sub_type()
 			{
-				add_evm_gas_cost(gas_limit, max_fee_per_gas, max_priority_fee_per_gas);
+				if <T as pallet_futurepass::Config>::Proxy::exists(futurepass, who, None) {
+					who = futurepass;
+				}
 			}
 
-			let path: &[AssetId] = &[*payment_asset, native_asset];
+			// swap along the cheapest DEX path, which may route through other assets if the payment
+			// asset has no direct pair with the gas token
+			let total_fee = Pallet::<T>::fee_with_evm_gas(*payment_asset, call, Balance::from(fee));
+			let path = Pallet::<T>::fee_swap_path(*payment_asset, total_fee);
 			pallet_dex::Pallet::<T>::do_swap_with_exact_target(
 				who,
 				total_fee,
 				*max_payment,
-				path,
+				&path,
 				who.clone(),
 				None,
 			)
 			.map_err(|_| InvalidTransaction::Payment)?;
-		};
+		} else {
+			// otherwise the fee preference of the payer applies if it lacks the gas token
+			Pallet::<T>::swap_fee_preference(who, Balance::from(fee))
+				.map_err(|_| InvalidTransaction::Payment)?;
+		}
 
-		<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::withdraw_fee(
-			who, call, info, fee, tip,
-		)
+		let already_withdrawn =
+			<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::withdraw_fee(
+				who, call, info, fee, tip,
+			)?;
+		Ok((already_withdrawn, sponsored))
 	}
 
 	/// Hand the fee and the tip over to the `[OnUnbalanced]` implementation.
@@ -164,6 +168,17 @@ where
 		// the call information within this function. What this means, if any extra fee was charged,
 		// that fee wont return to FP but the caller. Ideally we could pass the required info via
 		// pre, But this requires a new signed extension and some research.
+		let (already_withdrawn, sponsored) = already_withdrawn;
+		let caller = who;
+		let mut who = who;
+
+		// any extra fee charged to a sponsor returns to the sponsor's funds
+		let sponsor_account;
+		if let Some((sponsor, _)) = &sponsored {
+			sponsor_account = Pallet::<T>::sponsor_account(sponsor);
+			who = &sponsor_account;
+		}
+
 		<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::correct_and_deposit_fee(
 			who,
 			dispatch_info,
@@ -171,6 +186,268 @@ where
 			corrected_fee,
 			tip,
 			already_withdrawn,
+		)?;
+
+		// and no longer counts towards the sponsor's caps
+		if let Some((sponsor, fee)) = sponsored {
+			Pallet::<T>::release_sponsored_spending(
+				&sponsor,
+				caller,
+				fee.saturating_sub(Balance::from(corrected_fee)),
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Charges the fees of a sponsored EVM call to the sponsor's funds, other EVM fees are charged by
+/// `OC`
+pub struct SponsoredEVMFeeAdapter<OC>(PhantomData<OC>);
+
+impl<OC> SponsoredEVMFeeAdapter<OC> {
+	/// The address paying the EVM fees of `who`
+	fn fee_payer<T>(who: &H160) -> H160
+	where
+		T: Config + pallet_evm::Config<AccountId = AccountId>,
+	{
+		let account = <T as pallet_evm::Config>::AddressMapping::into_account_id(*who);
+		Pallet::<T>::sponsored_evm_fee_payer(&account).map_or(*who, Into::into)
+	}
+}
+
+impl<T, OC> OnChargeEVMTransaction<T> for SponsoredEVMFeeAdapter<OC>
+where
+	T: Config + pallet_evm::Config<AccountId = AccountId>,
+	OC: OnChargeEVMTransaction<T>,
+{
+	type LiquidityInfo = OC::LiquidityInfo;
+
+	fn withdraw_fee(who: &H160, fee: U256) -> Result<Self::LiquidityInfo, pallet_evm::Error<T>> {
+		OC::withdraw_fee(&Self::fee_payer::<T>(who), fee)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &H160,
+		corrected_fee: U256,
+		base_fee: U256,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Self::LiquidityInfo {
+		// any unused fee returns to the sponsor's funds
+		OC::correct_and_deposit_fee(
+			&Self::fee_payer::<T>(who),
+			corrected_fee,
+			base_fee,
+			already_withdrawn,
+		)
+	}
+
+	fn pay_priority_fee(tip: Self::LiquidityInfo) {
+		OC::pay_priority_fee(tip)
+	}
+}
+
+impl<T> Pallet<T>
+where
+	T: Config + frame_system::Config<AccountId = AccountId> + pallet_dex::Config,
+{
+	/// The cheapest DEX path to swap `payment_asset` for `fee` of the gas token, or the direct path
+	/// if there is no route
+	pub fn fee_swap_path(payment_asset: AssetId, fee: Balance) -> Vec<AssetId> {
+		let native_asset = <T as Config>::FeeAssetId::get();
+		pallet_dex::Pallet::<T>::get_best_path_in(fee, payment_asset, native_asset)
+			.map(|(path, _)| path)
+			.unwrap_or_else(|_| vec![payment_asset, native_asset])
+	}
+
+	/// Swap the preferred fee asset of `who` for `fee` of the gas token, if `who` has a fee
+	/// preference and lacks the gas token to pay `fee`
+	pub fn swap_fee_preference(who: &T::AccountId, fee: Balance) -> DispatchResult {
+		if let Some((path, max_payment)) = Self::fee_preference_swap(who, fee)? {
+			pallet_dex::Pallet::<T>::do_swap_with_exact_target(
+				who,
+				fee,
+				max_payment,
+				&path,
+				who.clone(),
+				None,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Check whether the fee preference of `who`, if it applies, covers `fee` of the gas token,
+	/// without swapping
+	pub fn ensure_fee_preference(who: &T::AccountId, fee: Balance) -> DispatchResult {
+		if let Some((path, max_payment)) = Self::fee_preference_swap(who, fee)? {
+			let amount_in = pallet_dex::Pallet::<T>::get_amounts_in(fee, &path)?[0];
+			ensure!(amount_in <= max_payment, Error::<T>::FeePreferenceSlippageExceeded);
+			ensure!(
+				amount_in <= <T as Config>::MultiCurrency::reducible_balance(path[0], who, false),
+				TokenError::NoFunds
+			);
+		}
+		Ok(())
+	}
+
+	/// The path and the max payment of the swap for `fee` of the gas token with the fee preference
+	/// of `who`, or `None` if `who` has no fee preference or enough of the gas token
+	fn fee_preference_swap(
+		who: &T::AccountId,
+		fee: Balance,
+	) -> Result<Option<(Vec<AssetId>, Balance)>, DispatchError> {
+		let FeePreference { asset_id, max_slippage } = match FeePreferences::<T>::get(who) {
+			Some(preference) => preference,
+			None => return Ok(None),
+		};
+		let native_asset = <T as Config>::FeeAssetId::get();
+		if <T as Config>::MultiCurrency::reducible_balance(native_asset, who, false) >= fee {
+			return Ok(None)
+		}
+
+		let path = Self::fee_swap_path(asset_id, fee);
+		let max_payment = Self::max_payment_with_slippage(&path, fee, max_slippage)?;
+		Ok(Some((path, max_payment)))
+	}
+
+	/// The max amount of the first asset in `path` to swap for `amount_out` of the last asset, i.e.
+	/// the amount at the DEX spot price plus `max_slippage`
+	pub fn max_payment_with_slippage(
+		path: &[AssetId],
+		amount_out: Balance,
+		max_slippage: Permill,
+	) -> Result<Balance, DispatchError> {
+		let mut amount = amount_out;
+		for pair in path.windows(2).rev() {
+			let (reserve_in, reserve_out) =
+				pallet_dex::Pallet::<T>::get_liquidity(pair[0], pair[1]);
+			amount = pallet_dex::Pallet::<T>::quote(U256::from(amount), reserve_out, reserve_in)?
+				.saturated_into();
+		}
+		Ok(amount.saturating_add(max_slippage * amount))
+	}
+}
+
+impl<T> Pallet<T>
+where
+	T: Config
+		+ frame_system::Config<AccountId = AccountId>
+		+ pallet_dex::Config
+		+ pallet_evm::Config
+		+ pallet_assets_ext::Config
+		+ pallet_futurepass::Config,
+	<T as Config>::RuntimeCall: IsSubType<pallet_evm::Call<T>>,
+	<T as Config>::RuntimeCall: IsSubType<pallet_futurepass::Call<T>>,
+	<T as pallet_futurepass::Config>::RuntimeCall: IsSubType<pallet_evm::Call<T>>,
+	<T as Config>::ErcIdConversion: ErcIdConversion<AssetId, EvmId = Address>,
+{
+	/// The fee in the gas token of `call_with_fee_preferences` wrapping `call`, given the `fee` of
+	/// the extrinsic
+	///
+	/// `fee` does not account for the max fee of an inner EVM call (directly or via
+	/// `proxy_extrinsic`) so it is added here. For all other calls `fee` covers all required fees.
+	pub fn fee_with_evm_gas(
+		payment_asset: AssetId,
+		call: &<T as Config>::RuntimeCall,
+		fee: Balance,
+	) -> Balance {
+		let evm_call = match call.is_sub_type() {
+			Some(pallet_futurepass::Call::proxy_extrinsic { call, .. }) => call.is_sub_type(),
+			_ => call.is_sub_type(),
+		};
+		match evm_call {
+			Some(pallet_evm::Call::call {
+				gas_limit,
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+				..
+			}) => Self::evm_fee_preferences_data(
+				payment_asset,
+				*gas_limit,
+				*max_fee_per_gas,
+				*max_priority_fee_per_gas,
+			)
+			.map_or(fee, |FeePreferencesData { max_fee_scaled, .. }| {
+				fee.saturating_add(max_fee_scaled)
+			}),
+			_ => fee,
+		}
+	}
+
+	/// The amount of `payment_asset` swapped along the cheapest DEX path for
+	/// `call_with_fee_preferences` wrapping `call`, given the `fee` of the extrinsic
+	pub fn quote_call_fee(
+		payment_asset: AssetId,
+		call: &<T as Config>::RuntimeCall,
+		fee: Balance,
+	) -> Result<Balance, DispatchError> {
+		let total_fee = Self::fee_with_evm_gas(payment_asset, call, fee);
+		pallet_dex::Pallet::<T>::get_best_path_in(
+			total_fee,
+			payment_asset,
+			<T as Config>::FeeAssetId::get(),
+		)
+		.map(|(_, amounts)| amounts[0])
+	}
+
+	/// The max amount of `payment_asset` swapped along the cheapest DEX path for an EVM call via
+	/// the fee proxy precompile
+	pub fn quote_evm_fee(
+		payment_asset: AssetId,
+		gas_limit: u64,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: Option<U256>,
+	) -> Result<Balance, DispatchError> {
+		let FeePreferencesData { max_fee_scaled, .. } = Self::evm_fee_preferences_data(
+			payment_asset,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+		)
+		.map_err(|_| DispatchError::Other("Fee overflow"))?;
+		pallet_dex::Pallet::<T>::get_best_path_in(
+			max_fee_scaled,
+			payment_asset,
+			<T as Config>::FeeAssetId::get(),
+		)
+		.map(|(_, amounts)| amounts[0])
+	}
+
+	fn evm_fee_preferences_data(
+		payment_asset: AssetId,
+		gas_limit: u64,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: Option<U256>,
+	) -> Result<FeePreferencesData, crate::runner::FeePreferencesError> {
+		get_fee_preferences_data::<T, <T as Config>::ErcIdConversion, pallet_futurepass::Pallet<T>>(
+			gas_limit,
+			<T as Config>::EVMBaseFeeProvider::evm_base_fee_per_gas(),
+			Some(max_fee_per_gas),
+			max_priority_fee_per_gas,
+			payment_asset,
+		)
+	}
+}
+
+/// The futurepass paying the fee of `call`, if it is a valid
+/// `pallet_futurepass::Call::proxy_extrinsic_with_signature()` signed for the futurepass to pay
+fn signed_proxy_fee_payer<T>(call: Option<&pallet_futurepass::Call<T>>) -> Option<&T::AccountId>
+where
+	T: pallet_futurepass::Config + frame_system::Config<AccountId = AccountId>,
+{
+	match call {
+		Some(pallet_futurepass::Call::proxy_extrinsic_with_signature {
+			futurepass,
+			delegate,
+			call,
+			nonce,
+			deadline,
+			futurepass_pays_fee: true,
+			signature,
+		}) if pallet_futurepass::Pallet::<T>::validate_signed_proxy_extrinsic(
+			futurepass, delegate, call, *nonce, *deadline, true, signature,
 		)
+		.is_ok() =>
+			Some(futurepass),
+		_ => None,
 	}
 }
@@ -17,6 +17,9 @@
 //!
 //! A utility pallet providing the possibility to call any runtime extrinsic with a specified gas
 //! token and pay for fees in that token.
+//!
+//! Sponsors may also pay the fees of other accounts. A sponsor deposits funds and sets rules
+//! covering the calls, EVM targets and accounts it pays for, with caps per account and period.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -25,21 +28,43 @@ pub use pallet::*;
 use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
 	pallet_prelude::*,
-	traits::IsSubType,
+	traits::{
+		fungibles::{self, Inspect as _, Transfer as _},
+		IsSubType,
+	},
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
+use pallet_futurepass::SpendWindow;
 use seed_pallet_common::{FeeConfig, MaintenanceCheckEVM};
 use seed_primitives::{AssetId, Balance};
+use sp_core::H160;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	Permill,
+};
 use sp_std::prelude::*;
 
+mod benchmarking;
 mod impls;
 #[cfg(test)]
 mod mock;
 mod runner;
 #[cfg(test)]
 mod tests;
+mod types;
+mod weights;
 
+pub use impls::SponsoredEVMFeeAdapter;
 pub use runner::{get_fee_preferences_data, FeePreferencesData, FeePreferencesRunner};
+pub use types::*;
+pub use weights::WeightInfo;
+
+pub type SponsorshipRulesOf<T> = SponsorshipRules<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxSponsorshipItems,
+>;
 
 pub(crate) const LOG_TARGET: &str = "fee-preferences";
 
@@ -82,13 +107,72 @@ pub mod pallet {
 		type EVMBaseFeeProvider: seed_pallet_common::FeeConfig;
 		// Maintenance mode checker
 		type MaintenanceChecker: MaintenanceCheckEVM<Self>;
+		/// Handles a multi-currency fungible asset system, used to hold sponsorship funds
+		type MultiCurrency: fungibles::Transfer<Self::AccountId, AssetId = AssetId, Balance = Balance>
+			+ fungibles::Mutate<Self::AccountId>;
+		/// The pallet id, sponsorship funds are held in sub accounts of it
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The maximum number of call permissions, EVM call permissions and accounts (each) in
+		/// sponsorship rules
+		#[pallet::constant]
+		type MaxSponsorshipItems: Get<u32>;
+		/// Interface to access weight values
+		type WeightInfo: WeightInfo;
 	}
 
+	/// The rules of fee sponsors (sponsor -> rules)
+	#[pallet::storage]
+	pub type Sponsorships<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SponsorshipRulesOf<T>>;
+
+	/// The fees paid by a sponsor in the current period (sponsor -> spending)
+	#[pallet::storage]
+	pub type SponsorSpending<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SpendWindow<T::BlockNumber>, ValueQuery>;
+
+	/// The fees paid by a sponsor for an account in the current period
+	/// (sponsor, account -> spending)
+	#[pallet::storage]
+	pub type SponsoredSpending<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		SpendWindow<T::BlockNumber>,
+		ValueQuery,
+	>;
+
+	/// The sponsor and the caller of the sponsored EVM call being executed, the call's fees are
+	/// paid from the sponsor's funds (sponsor, caller)
+	#[pallet::storage]
+	pub type ActiveEvmSponsorship<T: Config> = StorageValue<_, (T::AccountId, T::AccountId)>;
+
+	/// The fee asset preferences of accounts, applied when an account lacks the fee asset
+	/// (account -> preference)
+	#[pallet::storage]
+	pub type FeePreferences<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, FeePreference>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A call was made with specified payment asset
 		CallWithFeePreferences { who: T::AccountId, payment_asset: AssetId, max_payment: Balance },
+		/// A call was made with fees paid by a sponsor
+		CallWithSponsor { who: T::AccountId, sponsor: T::AccountId },
+		/// The sponsorship rules of a sponsor were set
+		SponsorshipSet { sponsor: T::AccountId, rules: SponsorshipRulesOf<T> },
+		/// The sponsorship rules of a sponsor were removed
+		SponsorshipRemoved { sponsor: T::AccountId },
+		/// A sponsor deposited funds for paying fees
+		SponsorshipFundsDeposited { sponsor: T::AccountId, amount: Balance },
+		/// A sponsor withdrew funds for paying fees
+		SponsorshipFundsWithdrawn { sponsor: T::AccountId, amount: Balance },
+		/// The fee asset preference of an account was set
+		FeePreferenceSet { who: T::AccountId, preference: FeePreference },
+		/// The fee asset preference of an account was removed
+		FeePreferenceRemoved { who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -97,6 +181,20 @@ pub mod pallet {
 		NestedFeePreferenceCall,
 		/// The selected fee token is equal to the native gas token
 		FeeTokenIsGasToken,
+		/// The sponsorship rules have a zero period or a user cap above the period cap
+		InvalidSponsorship,
+		/// The sponsor has no sponsorship rules
+		SponsorshipNotFound,
+		/// The call or the account is not sponsored by the sponsor
+		CallNotSponsored,
+		/// The fee exceeds the sponsor's user cap or period cap
+		SponsorshipCapExceeded,
+		/// The sponsor's funds are insufficient to pay the fee
+		InsufficientSponsorshipFunds,
+		/// The account has no fee asset preference
+		FeePreferenceNotFound,
+		/// The swap for the fee exceeds the max slippage of the fee asset preference
+		FeePreferenceSlippageExceeded,
 	}
 
 	#[pallet::call]
@@ -130,5 +228,263 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Call an internal call with the transaction fees paid by a sponsor
+		/// The sponsor's rules must cover the caller and the call, and the fee is paid from the
+		/// sponsor's deposited funds. This is enforced in OnChargeTransaction::withdraw_fee()
+		/// sponsor: The account paying the fees
+		/// call: The inner call to be performed
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(dispatch_info.weight.saturating_add(T::WeightInfo::call_with_sponsor()), dispatch_info.class)
+		})]
+		pub fn call_with_sponsor(
+			origin: OriginFor<T>,
+			sponsor: T::AccountId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(
+				!matches!(
+					call.is_sub_type(),
+					Some(Call::call_with_fee_preferences { .. }) |
+						Some(Call::call_with_sponsor { .. })
+				),
+				Error::<T>::NestedFeePreferenceCall
+			);
+			let rules = Sponsorships::<T>::get(&sponsor).ok_or(Error::<T>::SponsorshipNotFound)?;
+			ensure!(
+				rules.allows_account(&who) && rules.allows_call(&call.encode()),
+				Error::<T>::CallNotSponsored
+			);
+			let _ = call.dispatch(origin).map_err(|err| err.error)?;
+
+			Self::deposit_event(Event::CallWithSponsor { who, sponsor });
+
+			Ok(())
+		}
+
+		/// Set the sponsorship rules of the caller, replacing any existing rules
+		/// The spending of the current period is kept.
+		/// rules: The calls, EVM calls and accounts sponsored and the caps on the fees paid
+		#[pallet::weight(T::WeightInfo::set_sponsorship())]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			rules: SponsorshipRulesOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				!rules.period.is_zero() && rules.user_cap <= rules.period_cap,
+				Error::<T>::InvalidSponsorship
+			);
+			Sponsorships::<T>::insert(&sponsor, rules.clone());
+			Self::deposit_event(Event::SponsorshipSet { sponsor, rules });
+			Ok(())
+		}
+
+		/// Remove the sponsorship rules of the caller, the deposited funds may still be withdrawn
+		#[pallet::weight(T::WeightInfo::remove_sponsorship())]
+		pub fn remove_sponsorship(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(Sponsorships::<T>::contains_key(&sponsor), Error::<T>::SponsorshipNotFound);
+			Sponsorships::<T>::remove(&sponsor);
+			SponsorSpending::<T>::remove(&sponsor);
+			let _ = SponsoredSpending::<T>::clear_prefix(&sponsor, u32::MAX, None);
+			Self::deposit_event(Event::SponsorshipRemoved { sponsor });
+			Ok(())
+		}
+
+		/// Deposit funds of the fee asset for paying sponsored fees
+		/// amount: The amount of the fee asset to deposit
+		#[pallet::weight(T::WeightInfo::deposit_sponsorship_funds())]
+		pub fn deposit_sponsorship_funds(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			T::MultiCurrency::transfer(
+				T::FeeAssetId::get(),
+				&sponsor,
+				&Self::sponsor_account(&sponsor),
+				amount,
+				false,
+			)?;
+			Self::deposit_event(Event::SponsorshipFundsDeposited { sponsor, amount });
+			Ok(())
+		}
+
+		/// Withdraw funds deposited for paying sponsored fees
+		/// amount: The amount of the fee asset to withdraw
+		#[pallet::weight(T::WeightInfo::withdraw_sponsorship_funds())]
+		pub fn withdraw_sponsorship_funds(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			T::MultiCurrency::transfer(
+				T::FeeAssetId::get(),
+				&Self::sponsor_account(&sponsor),
+				&sponsor,
+				amount,
+				false,
+			)?;
+			Self::deposit_event(Event::SponsorshipFundsWithdrawn { sponsor, amount });
+			Ok(())
+		}
+
+		/// Set the asset the caller pays transaction fees with when it lacks the fee asset, applied
+		/// to extrinsics and EVM transactions without fee preferences
+		/// asset_id: The asset swapped for the fee asset
+		/// max_slippage: The max amount the swap may cost above the DEX spot price, including DEX
+		///               fees
+		#[pallet::weight(T::WeightInfo::set_fee_preference())]
+		pub fn set_fee_preference(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			max_slippage: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(asset_id != T::FeeAssetId::get(), Error::<T>::FeeTokenIsGasToken);
+			let preference = FeePreference { asset_id, max_slippage };
+			FeePreferences::<T>::insert(&who, preference);
+			Self::deposit_event(Event::FeePreferenceSet { who, preference });
+			Ok(())
+		}
+
+		/// Remove the fee asset preference of the caller
+		#[pallet::weight(T::WeightInfo::remove_fee_preference())]
+		pub fn remove_fee_preference(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(FeePreferences::<T>::contains_key(&who), Error::<T>::FeePreferenceNotFound);
+			FeePreferences::<T>::remove(&who);
+			Self::deposit_event(Event::FeePreferenceRemoved { who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding the funds of `sponsor`, sponsored fees are paid from it
+	pub fn sponsor_account(sponsor: &T::AccountId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(sponsor)
+	}
+
+	/// Check whether `sponsor` pays the `fee` of `call` for `who`, without charging the sponsor
+	pub fn ensure_sponsored_call(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		fee: Balance,
+	) -> DispatchResult {
+		Self::sponsored_spending(sponsor, who, fee, |rules| rules.allows_call(&call.encode()))
+			.map(|_| ())
+	}
+
+	/// Check whether `sponsor` pays the `fee` of an EVM call to `target` with `input` for `who`,
+	/// without charging the sponsor
+	pub fn ensure_sponsored_evm_call(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		target: &H160,
+		input: &[u8],
+		fee: Balance,
+	) -> DispatchResult {
+		Self::sponsored_spending(sponsor, who, fee, |rules| rules.allows_evm_call(target, input))
+			.map(|_| ())
+	}
+
+	/// Record the `fee` of `call` paid by `sponsor` for `who`
+	/// Returns the account the fee must be paid from
+	pub fn charge_sponsored_call(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		fee: Balance,
+	) -> Result<T::AccountId, DispatchError> {
+		let (sponsor_spending, sponsored_spending) =
+			Self::sponsored_spending(sponsor, who, fee, |rules| rules.allows_call(&call.encode()))?;
+		SponsorSpending::<T>::insert(sponsor, sponsor_spending);
+		SponsoredSpending::<T>::insert(sponsor, who, sponsored_spending);
+		Ok(Self::sponsor_account(sponsor))
+	}
+
+	/// Record the maximum `fee` of an EVM call to `target` with `input` paid by `sponsor` for
+	/// `who`
+	/// The call's fees are paid from the sponsor's funds until `end_sponsored_evm_call`
+	pub fn charge_sponsored_evm_call(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		target: &H160,
+		input: &[u8],
+		fee: Balance,
+	) -> DispatchResult {
+		let (sponsor_spending, sponsored_spending) =
+			Self::sponsored_spending(sponsor, who, fee, |rules| {
+				rules.allows_evm_call(target, input)
+			})?;
+		SponsorSpending::<T>::insert(sponsor, sponsor_spending);
+		SponsoredSpending::<T>::insert(sponsor, who, sponsored_spending);
+		ActiveEvmSponsorship::<T>::put((sponsor, who));
+		Ok(())
+	}
+
+	/// End the sponsored EVM call of `who`, the `unused_fee` no longer counts towards the caps
+	/// of `sponsor`
+	pub fn end_sponsored_evm_call(sponsor: &T::AccountId, who: &T::AccountId, unused_fee: Balance) {
+		ActiveEvmSponsorship::<T>::kill();
+		Self::release_sponsored_spending(sponsor, who, unused_fee);
+	}
+
+	/// The account paying the EVM fees of `who` if it's executing a sponsored EVM call
+	pub fn sponsored_evm_fee_payer(who: &T::AccountId) -> Option<T::AccountId> {
+		ActiveEvmSponsorship::<T>::get()
+			.filter(|(_, caller)| caller == who)
+			.map(|(sponsor, _)| Self::sponsor_account(&sponsor))
+	}
+
+	/// Deduct the refunded `amount` of a fee sponsored for `who` from the spending of `sponsor`
+	pub(crate) fn release_sponsored_spending(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		amount: Balance,
+	) {
+		SponsorSpending::<T>::mutate(sponsor, |spending| {
+			spending.spent = spending.spent.saturating_sub(amount)
+		});
+		SponsoredSpending::<T>::mutate(sponsor, who, |spending| {
+			spending.spent = spending.spent.saturating_sub(amount)
+		});
+	}
+
+	/// The spending of `sponsor` in total and for `who` after paying `fee`
+	/// Fails if the sponsor's rules don't cover `who` or the call, or the caps are exceeded
+	fn sponsored_spending(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		fee: Balance,
+		is_call_sponsored: impl FnOnce(&SponsorshipRulesOf<T>) -> bool,
+	) -> Result<(SpendWindow<T::BlockNumber>, SpendWindow<T::BlockNumber>), DispatchError> {
+		let rules = Sponsorships::<T>::get(sponsor).ok_or(Error::<T>::SponsorshipNotFound)?;
+		ensure!(
+			rules.allows_account(who) && is_call_sponsored(&rules),
+			Error::<T>::CallNotSponsored
+		);
+		ensure!(
+			T::MultiCurrency::reducible_balance(
+				T::FeeAssetId::get(),
+				&Self::sponsor_account(sponsor),
+				false
+			) >= fee,
+			Error::<T>::InsufficientSponsorshipFunds
+		);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let spend = |mut spending: SpendWindow<T::BlockNumber>, cap: Balance| {
+			if now >= spending.start.saturating_add(rules.period) {
+				spending = SpendWindow { start: now, spent: 0 };
+			}
+			spending.spent = spending.spent.saturating_add(fee);
+			ensure!(spending.spent <= cap, Error::<T>::SponsorshipCapExceeded);
+			Ok::<_, Error<T>>(spending)
+		};
+		Ok((
+			spend(SponsorSpending::<T>::get(sponsor), rules.period_cap)?,
+			spend(SponsoredSpending::<T>::get(sponsor, who), rules.user_cap)?,
+		))
 	}
 }
@@ -85,6 +85,8 @@ where
 
 parameter_types! {
 	pub const XrpAssetId: AssetId = XRP_ASSET_ID;
+	pub const FeeProxyPalletId: PalletId = PalletId(*b"feeproxy");
+	pub const MaxSponsorshipItems: u32 = 4;
 }
 impl Config for Test {
 	type RuntimeCall = RuntimeCall;
@@ -95,6 +97,10 @@ impl Config for Test {
 	type ErcIdConversion = Self;
 	type EVMBaseFeeProvider = ();
 	type MaintenanceChecker = ();
+	type MultiCurrency = AssetsExt;
+	type PalletId = FeeProxyPalletId;
+	type MaxSponsorshipItems = MaxSponsorshipItems;
+	type WeightInfo = ();
 }
 
 /// type alias for runtime configured FeePreferencesRunner
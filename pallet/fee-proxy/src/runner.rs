@@ -24,14 +24,14 @@ use pallet_evm::{
 use precompile_utils::{
 	constants::{
 		ERC20_PRECOMPILE_ADDRESS_PREFIX, FEE_FUNCTION_SELECTOR, FEE_FUNCTION_SELECTOR_DEPRECATED,
-		FEE_PROXY_ADDRESS,
+		FEE_PROXY_ADDRESS, FEE_SPONSOR_FUNCTION_SELECTOR,
 	},
 	Address as EthAddress, ErcIdConversion,
 };
 use seed_pallet_common::{
 	log, utils::scale_wei_to_correct_decimals, AccountProxy, FeeConfig, MaintenanceCheckEVM,
 };
-use seed_primitives::{AccountId, AssetId};
+use seed_primitives::{AccountId, AssetId, Balance};
 use sp_core::{H160, H256, U256};
 use sp_runtime::{
 	traits::Get,
@@ -97,24 +97,21 @@ pub fn get_fee_preferences_data<T, U, P>(
 	payment_asset_id: u32,
 ) -> Result<FeePreferencesData, FeePreferencesError>
 where
-	T: pallet_evm::Config<AccountId = AccountId> + pallet_assets_ext::Config + Config,
+	T: pallet_evm::Config<AccountId = AccountId>
+		+ pallet_assets_ext::Config
+		+ pallet_dex::Config
+		+ Config,
 	U: ErcIdConversion<AssetId, EvmId = EthAddress>,
 	P: AccountProxy<AccountId>,
 {
-	let (total_fee, max_fee) = FeePreferencesRunner::<T, U, P>::calculate_total_gas(
+	let (total_fee_scaled, max_fee_scaled) = FeePreferencesRunner::<T, U, P>::calculate_fee_scaled(
 		gas_limit,
 		base_fee_per_gas,
 		max_fee_per_gas,
 		max_priority_fee_per_gas,
 	)?;
 
-	let gas_token_asset_id = <T as Config>::FeeAssetId::get();
-	let decimals =
-		<pallet_assets_ext::Pallet<T> as InspectMetadata<AccountId>>::decimals(&gas_token_asset_id);
-	let total_fee_scaled = scale_wei_to_correct_decimals(total_fee, decimals);
-	let max_fee_scaled = scale_wei_to_correct_decimals(max_fee, decimals);
-
-	let path = vec![payment_asset_id, gas_token_asset_id];
+	let path = crate::Pallet::<T>::fee_swap_path(payment_asset_id, max_fee_scaled);
 	Ok(FeePreferencesData { total_fee_scaled, max_fee_scaled, path })
 }
 
@@ -178,6 +175,28 @@ where
 		}
 	}
 
+	/// Decodes the input for call_with_sponsor
+	pub fn decode_sponsored_input(
+		input: Vec<u8>,
+	) -> Result<(AccountId, H160, Vec<u8>), FeePreferencesError> {
+		ensure!(input.len() >= 4, FeePreferencesError::InvalidInputArguments);
+		ensure!(
+			input[..4] == FEE_SPONSOR_FUNCTION_SELECTOR,
+			FeePreferencesError::InvalidFunctionSelector
+		);
+
+		let types = [ParamType::Address, ParamType::Address, ParamType::Bytes];
+		let tokens = ethabi::decode(&types, &input[4..])
+			.map_err(|_| FeePreferencesError::FailedToDecodeInput)?;
+		if let [Token::Address(sponsor), Token::Address(new_target), Token::Bytes(new_input)] =
+			tokens.as_slice()
+		{
+			Ok((H160::from(*sponsor).into(), (*new_target).into(), new_input.clone()))
+		} else {
+			Err(FeePreferencesError::InvalidInputArguments)?
+		}
+	}
+
 	// Calculate gas price for transaction to use for exchanging asset into gas-token currency using
 	// eip1559
 	pub fn calculate_total_gas(
@@ -213,6 +232,36 @@ where
 	}
 }
 
+impl<T, U, P> FeePreferencesRunner<T, U, P>
+where
+	T: pallet_evm::Config<AccountId = AccountId> + pallet_assets_ext::Config + Config,
+	U: ErcIdConversion<AssetId, EvmId = EthAddress>,
+	P: AccountProxy<AccountId>,
+{
+	/// Calculate the total and max fee of a transaction in the gas token, scaled to its decimals
+	pub fn calculate_fee_scaled(
+		gas_limit: u64,
+		base_fee_per_gas: U256,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+	) -> Result<(Balance, Balance), FeePreferencesError> {
+		let (total_fee, max_fee) = Self::calculate_total_gas(
+			gas_limit,
+			base_fee_per_gas,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+		)?;
+
+		let decimals = <pallet_assets_ext::Pallet<T> as InspectMetadata<AccountId>>::decimals(
+			&<T as Config>::FeeAssetId::get(),
+		);
+		Ok((
+			scale_wei_to_correct_decimals(total_fee, decimals),
+			scale_wei_to_correct_decimals(max_fee, decimals),
+		))
+	}
+}
+
 impl<T, U, P> RunnerT<T> for FeePreferencesRunner<T, U, P>
 where
 	T: pallet_evm::Config<AccountId = AccountId>
@@ -293,6 +342,97 @@ where
 		// These values may change if we are using the fee_preferences precompile
 		let mut input = input;
 
+		// Check if we are calling with a sponsor
+		if target == H160::from_low_u64_be(FEE_PROXY_ADDRESS) &&
+			input.get(..4) == Some(&FEE_SPONSOR_FUNCTION_SELECTOR[..])
+		{
+			let (_, weight) = T::FeeCalculator::min_gas_price();
+
+			let (sponsor, new_target, new_input) = Self::decode_sponsored_input(input.clone())
+				.map_err(|err| RunnerError { error: err.into(), weight })?;
+
+			let base_fee_per_gas = <T as Config>::EVMBaseFeeProvider::evm_base_fee_per_gas();
+			let max_fee_scaled = Self::calculate_fee_scaled(
+				gas_limit,
+				base_fee_per_gas,
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+			)
+			.map_err(|_| RunnerError { error: Self::Error::FeeOverflow, weight })?
+			.1;
+
+			// the caller's balance isn't checked as the sponsor pays the fees, like the fee proxy
+			// transaction itself
+			if validate {
+				<Runner<T> as RunnerT<T>>::validate(
+					source,
+					Some(target),
+					input,
+					value,
+					gas_limit,
+					max_fee_per_gas,
+					max_priority_fee_per_gas,
+					nonce,
+					access_list.clone(),
+					is_transactional,
+					config,
+				)?;
+			}
+
+			// the fees of the call are paid from the sponsor's funds, up to the maximum fee
+			crate::Pallet::<T>::charge_sponsored_evm_call(
+				&sponsor,
+				&account,
+				&new_target,
+				&new_input,
+				max_fee_scaled,
+			)
+			.map_err(|err| {
+				log!(
+					error,
+					"⛽️ sponsoring fee {:?} units by {:?} failed: {:?}",
+					max_fee_scaled,
+					sponsor,
+					err
+				);
+				RunnerError { error: Self::Error::WithdrawFailed, weight }
+			})?;
+
+			let result = <Runner<T> as RunnerT<T>>::call(
+				source,
+				new_target,
+				new_input,
+				value,
+				gas_limit,
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+				nonce,
+				access_list,
+				is_transactional,
+				false,
+				config,
+			);
+
+			// nothing is charged if the call fails before execution
+			let used_fee_scaled = result.as_ref().map_or(0, |info| {
+				Self::calculate_fee_scaled(
+					U256::from(info.used_gas).low_u64(),
+					base_fee_per_gas,
+					max_fee_per_gas,
+					max_priority_fee_per_gas,
+				)
+				.map(|(total_fee_scaled, _)| total_fee_scaled)
+				.unwrap_or(max_fee_scaled)
+			});
+			crate::Pallet::<T>::end_sponsored_evm_call(
+				&sponsor,
+				&account,
+				max_fee_scaled.saturating_sub(used_fee_scaled),
+			);
+
+			return result
+		}
+
 		// Check if we are calling with fee preferences
 		if target == H160::from_low_u64_be(FEE_PROXY_ADDRESS) {
 			let (_, weight) = T::FeeCalculator::min_gas_price();
@@ -317,7 +457,7 @@ where
 
 			let max_payment_tokens = {
 				let amounts_in =
-					pallet_dex::Pallet::<T>::get_amounts_in(max_fee_scaled, &path) // [token, xrp]
+					pallet_dex::Pallet::<T>::get_amounts_in(max_fee_scaled, &path) // [token, .., xrp]
 						.map_err(|_| RunnerError { error: Self::Error::Undefined, weight })?;
 				amounts_in[0]
 			};
@@ -354,6 +494,29 @@ where
 				);
 				RunnerError { error: Self::Error::WithdrawFailed, weight }
 			})?;
+		} else if crate::FeePreferences::<T>::contains_key(&account) {
+			// otherwise the fee preference of the account applies if it lacks the gas token
+			let (_, weight) = T::FeeCalculator::min_gas_price();
+
+			let max_fee_scaled = Self::calculate_fee_scaled(
+				gas_limit,
+				<T as Config>::EVMBaseFeeProvider::evm_base_fee_per_gas(),
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+			)
+			.map_err(|_| RunnerError { error: Self::Error::FeeOverflow, weight })?
+			.1;
+
+			crate::Pallet::<T>::swap_fee_preference(&account, max_fee_scaled).map_err(|err| {
+				log!(
+					error,
+					"⛽️ swapping preferred fee asset of {:?} for fee {:?} units failed: {:?}",
+					account,
+					max_fee_scaled,
+					err
+				);
+				RunnerError { error: Self::Error::WithdrawFailed, weight }
+			})?;
 		}
 
 		// continue with the call - with fees payable in gas asset currency - via dex swap
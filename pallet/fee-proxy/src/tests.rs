@@ -16,7 +16,7 @@
 
 use super::*;
 use crate::{
-	mock::{FeeProxy, Futurepass, Runner, RuntimeOrigin, System, Test},
+	mock::{AssetsExt, Balances, FeeProxy, Futurepass, Runner, RuntimeOrigin, System, Test},
 	runner::*,
 };
 use ethabi::Token;
@@ -24,6 +24,7 @@ use hex_literal::hex;
 use precompile_utils::{
 	constants::{
 		ERC20_PRECOMPILE_ADDRESS_PREFIX, FEE_FUNCTION_SELECTOR, FEE_FUNCTION_SELECTOR_DEPRECATED,
+		FEE_SPONSOR_FUNCTION_SELECTOR,
 	},
 	ErcIdConversion,
 };
@@ -440,3 +441,561 @@ mod calculate_total_gas {
 		});
 	}
 }
+
+/// Tests for fee sponsorship
+mod sponsorship {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{
+		dispatch::{DispatchInfo, PostDispatchInfo},
+		traits::fungibles::Inspect,
+	};
+	use pallet_evm::OnChargeEVMTransaction;
+	use pallet_futurepass::{CallPermission, EvmCallPermission, SpendWindow};
+	use pallet_transaction_payment::OnChargeTransaction;
+
+	fn remark_call() -> mock::RuntimeCall {
+		mock::RuntimeCall::System(frame_system::Call::remark {
+			remark: b"Mischief Managed".to_vec(),
+		})
+	}
+
+	/// Rules sponsoring remarks and EVM calls to `evm_target` for `accounts`
+	fn sponsorship_rules(
+		accounts: Vec<AccountId>,
+		evm_target: H160,
+		user_cap: Balance,
+		period_cap: Balance,
+	) -> SponsorshipRulesOf<Test> {
+		let encoded = remark_call().encode();
+		SponsorshipRules {
+			calls: BoundedVec::truncate_from(vec![CallPermission {
+				pallet_index: encoded[0],
+				call_index: Some(encoded[1]),
+			}]),
+			evm_calls: BoundedVec::truncate_from(vec![EvmCallPermission {
+				target: evm_target,
+				selector: None,
+			}]),
+			accounts: BoundedVec::truncate_from(accounts),
+			user_cap,
+			period_cap,
+			period: 10,
+		}
+	}
+
+	fn xrp_balance(who: &AccountId) -> Balance {
+		AssetsExt::balance(XRP_ASSET_ID, who)
+	}
+
+	#[test]
+	fn set_sponsorship_works() {
+		TestExt::<Test>::default().build().execute_with(|| {
+			let sponsor: AccountId = create_account(1);
+			let rules = sponsorship_rules(vec![], H160::zero(), 100, 1_000);
+
+			// zero period or user cap above the period cap
+			let mut invalid = rules.clone();
+			invalid.period = 0;
+			assert_noop!(
+				FeeProxy::set_sponsorship(Some(sponsor).into(), invalid),
+				Error::<Test>::InvalidSponsorship
+			);
+			let mut invalid = rules.clone();
+			invalid.user_cap = 1_001;
+			assert_noop!(
+				FeeProxy::set_sponsorship(Some(sponsor).into(), invalid),
+				Error::<Test>::InvalidSponsorship
+			);
+
+			assert_ok!(FeeProxy::set_sponsorship(Some(sponsor).into(), rules.clone()));
+			assert_eq!(Sponsorships::<Test>::get(&sponsor), Some(rules.clone()));
+			System::assert_last_event(Event::SponsorshipSet { sponsor, rules }.into());
+
+			assert_ok!(FeeProxy::remove_sponsorship(Some(sponsor).into()));
+			assert!(!Sponsorships::<Test>::contains_key(&sponsor));
+			System::assert_last_event(Event::SponsorshipRemoved { sponsor }.into());
+			assert_noop!(
+				FeeProxy::remove_sponsorship(Some(sponsor).into()),
+				Error::<Test>::SponsorshipNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn sponsorship_funds_works() {
+		let sponsor: AccountId = create_account(1);
+
+		TestExt::<Test>::default()
+			.with_xrp_balances(&[(sponsor, 1_000)])
+			.build()
+			.execute_with(|| {
+				let sponsor_account = FeeProxy::sponsor_account(&sponsor);
+
+				assert_ok!(FeeProxy::deposit_sponsorship_funds(Some(sponsor).into(), 600));
+				System::assert_last_event(
+					Event::SponsorshipFundsDeposited { sponsor, amount: 600 }.into(),
+				);
+				assert_eq!(xrp_balance(&sponsor), 400);
+				assert_eq!(xrp_balance(&sponsor_account), 600);
+
+				assert_ok!(FeeProxy::withdraw_sponsorship_funds(Some(sponsor).into(), 200));
+				System::assert_last_event(
+					Event::SponsorshipFundsWithdrawn { sponsor, amount: 200 }.into(),
+				);
+				assert_eq!(xrp_balance(&sponsor), 600);
+				assert_eq!(xrp_balance(&sponsor_account), 400);
+
+				// only the sponsor's own funds may be withdrawn
+				assert_noop!(
+					FeeProxy::withdraw_sponsorship_funds(Some(create_account(2)).into(), 200),
+					pallet_assets::Error::<Test>::NoAccount
+				);
+			});
+	}
+
+	#[test]
+	fn call_with_sponsor_works() {
+		TestExt::<Test>::default().build().execute_with(|| {
+			let sponsor: AccountId = create_account(1);
+			let caller: AccountId = create_account(2);
+			let other: AccountId = create_account(3);
+
+			assert_noop!(
+				FeeProxy::call_with_sponsor(Some(caller).into(), sponsor, Box::new(remark_call())),
+				Error::<Test>::SponsorshipNotFound
+			);
+
+			let rules = sponsorship_rules(vec![caller], H160::zero(), 100, 1_000);
+			assert_ok!(FeeProxy::set_sponsorship(Some(sponsor).into(), rules));
+
+			// the account and the call must be sponsored
+			assert_noop!(
+				FeeProxy::call_with_sponsor(Some(other).into(), sponsor, Box::new(remark_call())),
+				Error::<Test>::CallNotSponsored
+			);
+			let call = mock::RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: b"Mischief Managed".to_vec(),
+			});
+			assert_noop!(
+				FeeProxy::call_with_sponsor(Some(caller).into(), sponsor, Box::new(call)),
+				Error::<Test>::CallNotSponsored
+			);
+			let call = mock::RuntimeCall::FeeProxy(crate::Call::call_with_sponsor {
+				sponsor,
+				call: Box::new(remark_call()),
+			});
+			assert_noop!(
+				FeeProxy::call_with_sponsor(Some(caller).into(), sponsor, Box::new(call)),
+				Error::<Test>::NestedFeePreferenceCall
+			);
+
+			assert_ok!(FeeProxy::call_with_sponsor(
+				Some(caller).into(),
+				sponsor,
+				Box::new(remark_call())
+			));
+			System::assert_last_event(Event::CallWithSponsor { who: caller, sponsor }.into());
+		});
+	}
+
+	#[test]
+	fn sponsor_pays_fees() {
+		let sponsor: AccountId = create_account(1);
+		let caller: AccountId = create_account(2);
+		let other: AccountId = create_account(3);
+
+		TestExt::<Test>::default()
+			.with_xrp_balances(&[(sponsor, 1_000)])
+			.build()
+			.execute_with(|| {
+				let sponsor_account = FeeProxy::sponsor_account(&sponsor);
+				assert_ok!(FeeProxy::deposit_sponsorship_funds(Some(sponsor).into(), 500));
+				let rules = sponsorship_rules(vec![caller, other], H160::zero(), 100, 150);
+				assert_ok!(FeeProxy::set_sponsorship(Some(sponsor).into(), rules));
+
+				let call = mock::RuntimeCall::FeeProxy(crate::Call::call_with_sponsor {
+					sponsor,
+					call: Box::new(remark_call()),
+				});
+				let withdraw_fee = |who: &AccountId, fee: Balance| {
+					<FeeProxy as OnChargeTransaction<Test>>::withdraw_fee(
+						who,
+						&call,
+						&DispatchInfo::default(),
+						fee,
+						0,
+					)
+				};
+
+				assert_ok!(withdraw_fee(&caller, 60));
+				assert_eq!(xrp_balance(&sponsor_account), 440);
+				assert_eq!(xrp_balance(&caller), 0);
+				assert_eq!(
+					SponsoredSpending::<Test>::get(&sponsor, &caller),
+					SpendWindow { start: 0, spent: 60 }
+				);
+
+				// the user cap is exceeded
+				assert!(withdraw_fee(&caller, 41).is_err());
+				// the period cap is exceeded
+				assert_ok!(withdraw_fee(&other, 80));
+				assert!(withdraw_fee(&caller, 20).is_err());
+				assert_eq!(
+					SponsorSpending::<Test>::get(&sponsor),
+					SpendWindow { start: 0, spent: 140 }
+				);
+
+				// the caps reset after the period
+				System::set_block_number(11);
+				assert_ok!(withdraw_fee(&caller, 100));
+				assert_eq!(xrp_balance(&sponsor_account), 260);
+				assert_eq!(
+					SponsorSpending::<Test>::get(&sponsor),
+					SpendWindow { start: 11, spent: 100 }
+				);
+			});
+	}
+
+	#[test]
+	fn sponsor_is_refunded_unused_fees() {
+		let sponsor: AccountId = create_account(1);
+		let caller: AccountId = create_account(2);
+
+		TestExt::<Test>::default()
+			.with_xrp_balances(&[(sponsor, 1_000)])
+			.build()
+			.execute_with(|| {
+				let sponsor_account = FeeProxy::sponsor_account(&sponsor);
+				assert_ok!(FeeProxy::deposit_sponsorship_funds(Some(sponsor).into(), 500));
+				let rules = sponsorship_rules(vec![caller], H160::zero(), 100, 150);
+				assert_ok!(FeeProxy::set_sponsorship(Some(sponsor).into(), rules));
+
+				let call = mock::RuntimeCall::FeeProxy(crate::Call::call_with_sponsor {
+					sponsor,
+					call: Box::new(remark_call()),
+				});
+				let already_withdrawn = <FeeProxy as OnChargeTransaction<Test>>::withdraw_fee(
+					&caller,
+					&call,
+					&DispatchInfo::default(),
+					60,
+					0,
+				)
+				.unwrap();
+				assert_eq!(xrp_balance(&sponsor_account), 440);
+
+				// the unused fee returns to the sponsor, not the caller
+				assert_ok!(<FeeProxy as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+					&caller,
+					&DispatchInfo::default(),
+					&PostDispatchInfo::default(),
+					20,
+					0,
+					already_withdrawn,
+				));
+				assert_eq!(xrp_balance(&sponsor_account), 480);
+				assert_eq!(xrp_balance(&caller), 0);
+				assert_eq!(SponsorSpending::<Test>::get(&sponsor).spent, 20);
+				assert_eq!(SponsoredSpending::<Test>::get(&sponsor, &caller).spent, 20);
+			});
+	}
+
+	#[test]
+	fn sponsor_pays_evm_fees() {
+		let sponsor: AccountId = create_account(1);
+		let caller: AccountId = create_account(2);
+		let target = H160::from_low_u64_be(1234);
+		let sponsor_account = FeeProxy::sponsor_account(&sponsor);
+
+		TestExt::<Test>::default()
+			.with_balances(&[(sponsor_account, 500)])
+			.with_xrp_balances(&[(sponsor, 1_000)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(FeeProxy::deposit_sponsorship_funds(Some(sponsor).into(), 500));
+				let rules = sponsorship_rules(vec![caller], target, 100, 1_000);
+				assert_ok!(FeeProxy::set_sponsorship(Some(sponsor).into(), rules));
+
+				assert_noop!(
+					FeeProxy::ensure_sponsored_evm_call(
+						&sponsor,
+						&caller,
+						&H160::from_low_u64_be(4321),
+						&[],
+						50
+					),
+					Error::<Test>::CallNotSponsored
+				);
+				assert_ok!(FeeProxy::ensure_sponsored_evm_call(
+					&sponsor,
+					&caller,
+					&target,
+					&[],
+					50
+				));
+
+				// the max fee counts towards the caps while the call is executed
+				assert_ok!(FeeProxy::charge_sponsored_evm_call(
+					&sponsor,
+					&caller,
+					&target,
+					&[],
+					50
+				));
+				assert_eq!(SponsoredSpending::<Test>::get(&sponsor, &caller).spent, 50);
+				assert_eq!(FeeProxy::sponsored_evm_fee_payer(&caller), Some(sponsor_account));
+				assert_eq!(FeeProxy::sponsored_evm_fee_payer(&sponsor), None);
+
+				// the EVM fees are charged to the sponsor's funds, never to the caller
+				let already_withdrawn = <SponsoredEVMFeeAdapter<()> as OnChargeEVMTransaction<
+					Test,
+				>>::withdraw_fee(&caller.into(), U256::from(50))
+				.unwrap();
+				assert_eq!(Balances::free_balance(&sponsor_account), 450);
+				<SponsoredEVMFeeAdapter<()> as OnChargeEVMTransaction<Test>>::correct_and_deposit_fee(
+					&caller.into(),
+					U256::from(20),
+					U256::zero(),
+					already_withdrawn,
+				);
+				assert_eq!(Balances::free_balance(&sponsor_account), 480);
+				assert_eq!(Balances::free_balance(&caller), 0);
+
+				// the unused fee no longer counts towards the caps
+				FeeProxy::end_sponsored_evm_call(&sponsor, &caller, 30);
+				assert_eq!(SponsoredSpending::<Test>::get(&sponsor, &caller).spent, 20);
+				assert_eq!(SponsorSpending::<Test>::get(&sponsor).spent, 20);
+				assert_eq!(FeeProxy::sponsored_evm_fee_payer(&caller), None);
+				assert!(
+					<SponsoredEVMFeeAdapter<()> as OnChargeEVMTransaction<Test>>::withdraw_fee(
+						&caller.into(),
+						U256::from(50)
+					)
+					.is_err()
+				);
+
+				// the user cap is exceeded
+				assert_noop!(
+					FeeProxy::ensure_sponsored_evm_call(&sponsor, &caller, &target, &[], 81),
+					Error::<Test>::SponsorshipCapExceeded
+				);
+			});
+	}
+
+	#[test]
+	fn decode_sponsored_input_works() {
+		TestExt::<Test>::default().build().execute_with(|| {
+			let sponsor: AccountId = create_account(1);
+			let target = H160::from_low_u64_be(1234);
+			let input = vec![1_u8, 2, 3, 4];
+
+			let mut call_input = FEE_SPONSOR_FUNCTION_SELECTOR.to_vec();
+			call_input.append(&mut ethabi::encode(&[
+				Token::Address(sponsor.into()),
+				Token::Address(target),
+				Token::Bytes(input.clone()),
+			]));
+			assert_eq!(Runner::decode_sponsored_input(call_input), Ok((sponsor, target, input)));
+
+			assert_noop!(
+				Runner::decode_sponsored_input(FEE_FUNCTION_SELECTOR.to_vec()),
+				FeePreferencesError::InvalidFunctionSelector
+			);
+		});
+	}
+}
+
+/// Tests for fees swapped along the cheapest DEX path
+mod fee_swap_path {
+	use super::*;
+	use crate::mock::Dex;
+	use frame_support::{
+		dispatch::DispatchInfo,
+		traits::fungibles::{Inspect, Mutate},
+	};
+	use pallet_transaction_payment::OnChargeTransaction;
+	use seed_pallet_common::CreateExt;
+
+	#[test]
+	fn fee_is_swapped_through_intermediate_asset() {
+		let caller: AccountId = create_account(1);
+
+		TestExt::<Test>::default()
+			.with_xrp_balances(&[(caller, 10_000_000)])
+			.build()
+			.execute_with(|| {
+				let payment_asset = AssetsExt::create(&caller, None).unwrap();
+				let intermediate_asset = AssetsExt::create(&caller, None).unwrap();
+				assert_ok!(AssetsExt::mint_into(payment_asset, &caller, 10_000_000));
+				assert_ok!(AssetsExt::mint_into(intermediate_asset, &caller, 10_000_000));
+
+				// no direct pool between the payment asset and XRP
+				for (token_a, token_b) in
+					[(payment_asset, intermediate_asset), (intermediate_asset, XRP_ASSET_ID)]
+				{
+					assert_ok!(Dex::add_liquidity(
+						RuntimeOrigin::signed(caller),
+						token_a,
+						token_b,
+						1_000_000,
+						1_000_000,
+						1_000_000,
+						1_000_000,
+						None,
+						None,
+					));
+				}
+				let path = vec![payment_asset, intermediate_asset, XRP_ASSET_ID];
+				assert_eq!(FeeProxy::fee_swap_path(payment_asset, 1_000), path);
+
+				let inner_call = mock::RuntimeCall::System(frame_system::Call::remark {
+					remark: b"Mischief Managed".to_vec(),
+				});
+				let quote = FeeProxy::quote_call_fee(payment_asset, &inner_call, 1_000).unwrap();
+				assert_eq!(quote, Dex::get_amounts_in(1_000, &path).unwrap()[0]);
+
+				let payment_balance = AssetsExt::balance(payment_asset, &caller);
+				let xrp_balance = AssetsExt::balance(XRP_ASSET_ID, &caller);
+				let call = mock::RuntimeCall::FeeProxy(crate::Call::call_with_fee_preferences {
+					payment_asset,
+					max_payment: quote,
+					call: Box::new(inner_call),
+				});
+				assert_ok!(<FeeProxy as OnChargeTransaction<Test>>::withdraw_fee(
+					&caller,
+					&call,
+					&DispatchInfo::default(),
+					1_000,
+					0,
+				));
+
+				// the quoted amount is swapped for XRP which pays the fee
+				assert_eq!(AssetsExt::balance(payment_asset, &caller), payment_balance - quote);
+				assert_eq!(AssetsExt::balance(XRP_ASSET_ID, &caller), xrp_balance);
+			});
+	}
+}
+
+/// Tests for account fee asset preferences
+mod fee_preference {
+	use super::*;
+	use crate::mock::Dex;
+	use frame_support::{
+		dispatch::DispatchInfo,
+		traits::fungibles::{Inspect, Mutate},
+	};
+	use pallet_transaction_payment::OnChargeTransaction;
+	use seed_pallet_common::CreateExt;
+	use sp_runtime::Permill;
+
+	fn remark_call() -> mock::RuntimeCall {
+		mock::RuntimeCall::System(frame_system::Call::remark {
+			remark: b"Mischief Managed".to_vec(),
+		})
+	}
+
+	#[test]
+	fn set_fee_preference_works() {
+		TestExt::<Test>::default().build().execute_with(|| {
+			let who: AccountId = create_account(1);
+			let preference = FeePreference { asset_id: 5, max_slippage: Permill::from_percent(1) };
+
+			assert_noop!(
+				FeeProxy::set_fee_preference(
+					Some(who).into(),
+					XRP_ASSET_ID,
+					preference.max_slippage
+				),
+				Error::<Test>::FeeTokenIsGasToken
+			);
+			assert_noop!(
+				FeeProxy::remove_fee_preference(Some(who).into()),
+				Error::<Test>::FeePreferenceNotFound
+			);
+
+			assert_ok!(FeeProxy::set_fee_preference(
+				Some(who).into(),
+				preference.asset_id,
+				preference.max_slippage
+			));
+			assert_eq!(FeePreferences::<Test>::get(&who), Some(preference));
+			System::assert_last_event(Event::FeePreferenceSet { who, preference }.into());
+
+			assert_ok!(FeeProxy::remove_fee_preference(Some(who).into()));
+			assert_eq!(FeePreferences::<Test>::get(&who), None);
+			System::assert_last_event(Event::FeePreferenceRemoved { who }.into());
+		});
+	}
+
+	#[test]
+	fn fee_preference_pays_fees() {
+		let funder: AccountId = create_account(1);
+		let caller: AccountId = create_account(2);
+
+		TestExt::<Test>::default()
+			.with_xrp_balances(&[(funder, 10_000_000)])
+			.build()
+			.execute_with(|| {
+				let payment_asset = AssetsExt::create(&funder, None).unwrap();
+				assert_ok!(AssetsExt::mint_into(payment_asset, &funder, 1_000_000));
+				assert_ok!(AssetsExt::mint_into(payment_asset, &caller, 100_000));
+				assert_ok!(Dex::add_liquidity(
+					RuntimeOrigin::signed(funder),
+					payment_asset,
+					XRP_ASSET_ID,
+					1_000_000,
+					1_000_000,
+					1_000_000,
+					1_000_000,
+					None,
+					None,
+				));
+
+				let call = remark_call();
+				let withdraw_fee = |fee: Balance| {
+					<FeeProxy as OnChargeTransaction<Test>>::withdraw_fee(
+						&caller,
+						&call,
+						&DispatchInfo::default(),
+						fee,
+						0,
+					)
+				};
+
+				// the caller lacks XRP and has no fee preference
+				assert!(withdraw_fee(1_000).is_err());
+
+				// the max slippage is below the DEX fee
+				assert_ok!(FeeProxy::set_fee_preference(
+					Some(caller).into(),
+					payment_asset,
+					Permill::from_parts(1_000)
+				));
+				assert_noop!(
+					FeeProxy::ensure_fee_preference(&caller, 1_000),
+					Error::<Test>::FeePreferenceSlippageExceeded
+				);
+				assert!(withdraw_fee(1_000).is_err());
+
+				// the payment asset is swapped for the fee
+				assert_ok!(FeeProxy::set_fee_preference(
+					Some(caller).into(),
+					payment_asset,
+					Permill::from_percent(1)
+				));
+				assert_ok!(FeeProxy::ensure_fee_preference(&caller, 1_000));
+				let payment =
+					Dex::get_amounts_in(1_000, &[payment_asset, XRP_ASSET_ID]).unwrap()[0];
+				assert_ok!(withdraw_fee(1_000));
+				assert_eq!(AssetsExt::balance(payment_asset, &caller), 100_000 - payment);
+				assert_eq!(AssetsExt::balance(XRP_ASSET_ID, &caller), 0);
+
+				// the preference does not apply while the caller has enough XRP
+				assert_ok!(AssetsExt::mint_into(XRP_ASSET_ID, &caller, 1_000));
+				assert_ok!(withdraw_fee(1_000));
+				assert_eq!(AssetsExt::balance(payment_asset, &caller), 100_000 - payment);
+				assert_eq!(AssetsExt::balance(XRP_ASSET_ID, &caller), 0);
+			});
+	}
+}
@@ -0,0 +1,84 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Fee proxy module types
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::Get, CloneNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound,
+};
+use pallet_futurepass::{CallPermission, EvmCallPermission};
+use scale_info::TypeInfo;
+use seed_primitives::{AssetId, Balance};
+use sp_core::H160;
+use sp_runtime::{BoundedVec, Permill};
+use sp_std::fmt::Debug;
+
+/// The calls and accounts a sponsor pays transaction fees for
+#[derive(
+	PartialEqNoBound, RuntimeDebugNoBound, Decode, Encode, CloneNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxItems))]
+pub struct SponsorshipRules<AccountId, BlockNumber, MaxItems>
+where
+	AccountId: Debug + PartialEq + Clone,
+	BlockNumber: Debug + PartialEq + Clone,
+	MaxItems: Get<u32>,
+{
+	/// The runtime calls sponsored via `call_with_sponsor`, matched on the outer call only
+	pub calls: BoundedVec<CallPermission, MaxItems>,
+	/// The EVM calls sponsored via `callWithSponsor` of the fee proxy address
+	pub evm_calls: BoundedVec<EvmCallPermission, MaxItems>,
+	/// The accounts sponsored, all accounts are sponsored if empty
+	pub accounts: BoundedVec<AccountId, MaxItems>,
+	/// The maximum fees paid for a single account per period
+	pub user_cap: Balance,
+	/// The maximum fees paid for all accounts per period
+	pub period_cap: Balance,
+	/// The length of a period in blocks
+	pub period: BlockNumber,
+}
+
+impl<AccountId, BlockNumber, MaxItems> SponsorshipRules<AccountId, BlockNumber, MaxItems>
+where
+	AccountId: Debug + PartialEq + Clone,
+	BlockNumber: Debug + PartialEq + Clone,
+	MaxItems: Get<u32>,
+{
+	/// Whether the fees of `who` are sponsored
+	pub fn allows_account(&self, who: &AccountId) -> bool {
+		self.accounts.is_empty() || self.accounts.contains(who)
+	}
+
+	/// Whether the SCALE encoded `call` is sponsored
+	pub fn allows_call(&self, call: &[u8]) -> bool {
+		self.calls.iter().any(|permission| permission.matches(call))
+	}
+
+	/// Whether an EVM call to `target` with `call_data` is sponsored
+	pub fn allows_evm_call(&self, target: &H160, call_data: &[u8]) -> bool {
+		self.evm_calls.iter().any(|permission| permission.matches(target, call_data))
+	}
+}
+
+/// The asset an account pays transaction fees with when it lacks the fee asset
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeePreference {
+	/// The asset swapped for the fee asset
+	pub asset_id: AssetId,
+	/// The max amount the swap may cost above the DEX spot price, including DEX fees
+	pub max_slippage: Permill,
+}
@@ -0,0 +1,152 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for `pallet_fee_proxy`
+//!
+//! THESE WEIGHTS WERE NOT PRODUCED BY A BENCHMARK RUN. The storage accesses follow the
+//! benchmarked code paths but the ref times are estimates, they must be replaced by
+//! regenerating this file before release.
+
+// Regenerate with:
+// ./target/release/seed
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_fee_proxy
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output
+// ./pallet/fee-proxy/src/weights.rs
+// --template
+// ./scripts/pallet_template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_fee_proxy.
+pub trait WeightInfo {
+	fn call_with_sponsor() -> Weight;
+	fn set_sponsorship() -> Weight;
+	fn remove_sponsorship() -> Weight;
+	fn deposit_sponsorship_funds() -> Weight;
+	fn withdraw_sponsorship_funds() -> Weight;
+	fn set_fee_preference() -> Weight;
+	fn remove_fee_preference() -> Weight;
+}
+
+/// Weights for pallet_fee_proxy using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: FeeProxy Sponsorships (r:1 w:0)
+	fn call_with_sponsor() -> Weight {
+		Weight::from_ref_time(10_283_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: FeeProxy Sponsorships (r:0 w:1)
+	fn set_sponsorship() -> Weight {
+		Weight::from_ref_time(21_904_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeProxy Sponsorships (r:1 w:1)
+	// Storage: FeeProxy SponsorSpending (r:0 w:1)
+	// Storage: FeeProxy SponsoredSpending (r:0 w:1)
+	fn remove_sponsorship() -> Weight {
+		Weight::from_ref_time(28_612_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_sponsorship_funds() -> Weight {
+		Weight::from_ref_time(52_430_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_sponsorship_funds() -> Weight {
+		Weight::from_ref_time(51_876_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: FeeProxy FeePreferences (r:0 w:1)
+	fn set_fee_preference() -> Weight {
+		Weight::from_ref_time(14_512_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeProxy FeePreferences (r:1 w:1)
+	fn remove_fee_preference() -> Weight {
+		Weight::from_ref_time(17_338_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: FeeProxy Sponsorships (r:1 w:0)
+	fn call_with_sponsor() -> Weight {
+		Weight::from_ref_time(10_283_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: FeeProxy Sponsorships (r:0 w:1)
+	fn set_sponsorship() -> Weight {
+		Weight::from_ref_time(21_904_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeProxy Sponsorships (r:1 w:1)
+	// Storage: FeeProxy SponsorSpending (r:0 w:1)
+	// Storage: FeeProxy SponsoredSpending (r:0 w:1)
+	fn remove_sponsorship() -> Weight {
+		Weight::from_ref_time(28_612_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_sponsorship_funds() -> Weight {
+		Weight::from_ref_time(52_430_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_sponsorship_funds() -> Weight {
+		Weight::from_ref_time(51_876_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: FeeProxy FeePreferences (r:0 w:1)
+	fn set_fee_preference() -> Weight {
+		Weight::from_ref_time(14_512_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeProxy FeePreferences (r:1 w:1)
+	fn remove_fee_preference() -> Weight {
+		Weight::from_ref_time(17_338_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+}
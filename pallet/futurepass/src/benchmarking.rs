@@ -61,6 +61,27 @@ where
 	Ok(())
 }
 
+// create a futurepass for `owner` with the max number of guardians
+pub fn setup_recovery<T: Config>(
+	owner: &T::AccountId,
+) -> Result<(T::AccountId, Vec<T::AccountId>), &'static str>
+where
+	<T as frame_system::Config>::AccountId: From<sp_core::H160>,
+{
+	fund::<T>(owner);
+	Futurepass::<T>::create(RawOrigin::Signed(owner.clone()).into(), owner.clone())?;
+	let futurepass: T::AccountId = Holders::<T>::get(owner).ok_or("futurepass not created")?;
+	let guardians: Vec<T::AccountId> =
+		(0..T::MaxGuardians::get()).map(|i| account("guardian", i, 0)).collect();
+	Futurepass::<T>::set_recovery_config(
+		RawOrigin::Signed(owner.clone()).into(),
+		futurepass.clone(),
+		BoundedVec::truncate_from(guardians.clone()),
+		guardians.len() as u32,
+	)?;
+	Ok((futurepass, guardians))
+}
+
 benchmarks! {
 	where_clause { where <T as frame_system::Config>::AccountId: From<sp_core::H160> }
 	create {
@@ -146,6 +167,155 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::ProxyExecuted {  delegate: owner, result: Ok(()) }.into())
 	}
+
+	proxy_extrinsic_with_signature {
+		let p in 1 .. (32 - 1);
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		assert_ok!(Futurepass::<T>::create(RawOrigin::Signed(owner.clone()).into(), owner.clone()));
+		let futurepass: T::AccountId = Holders::<T>::get(&owner).unwrap();
+		add_delegates::<T>(p-1, futurepass.clone(), Some(owner.clone()))?;
+		// the delegate of private key 0x7e9c7ad85df5cdc88659f53e06fb2eb9bab3ebc59083a3190eaf2c730332529c
+		let secret_key = libsecp256k1::SecretKey::parse(&hex!("7e9c7ad85df5cdc88659f53e06fb2eb9bab3ebc59083a3190eaf2c730332529c")).unwrap();
+		let delegate: T::AccountId = H160::from_slice(&hex!("420aC537F1a4f78d4Dfb3A71e902be0E3d480AFB")).into();
+		T::Proxy::add_delegate(&owner, &futurepass, &delegate, &T::ProxyType::default().into())?;
+
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let deadline: u32 = 200;
+		let struct_hash = Futurepass::<T>::proxy_call_struct_hash(&delegate, &call, 0, deadline, false);
+		let digest = Futurepass::<T>::signed_proxy_call_digest(&futurepass, struct_hash);
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), &secret_key);
+		let mut signature = [0_u8; 65];
+		signature[..64].copy_from_slice(&sig.serialize());
+		signature[64] = recovery_id.serialize();
+	}: _(RawOrigin::Signed(owner.clone()), futurepass.clone(), delegate.clone(), Box::new(call), 0, deadline, false, signature)
+	verify {
+		assert_last_event::<T>(Event::SignedProxyExecuted { futurepass, delegate, nonce: 0, result: Ok(()) }.into())
+	}
+
+	set_delegate_policy {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		assert_ok!(Futurepass::<T>::create(RawOrigin::Signed(owner.clone()).into(), owner.clone()));
+		let futurepass: T::AccountId = Holders::<T>::get(&owner).unwrap();
+		let delegate: T::AccountId = account("target", 0, 0);
+		add_delegates::<T>(1, futurepass.clone(), Some(owner.clone()))?;
+
+		let max_items = T::MaxDelegatePolicyItems::get();
+		let policy = DelegatePolicyOf::<T> {
+			calls: BoundedVec::truncate_from(vec![CallPermission { pallet_index: 0, call_index: None }; max_items as usize]),
+			evm_calls: BoundedVec::truncate_from(vec![EvmCallPermission { target: H160::zero(), selector: None }; max_items as usize]),
+			spend_limits: BoundedVec::truncate_from(
+				(0..max_items).map(|asset_id| SpendLimit { asset_id, amount: 1_000, window: 100_u32.into() }).collect(),
+			),
+		};
+		// replaces an existing policy
+		assert_ok!(Futurepass::<T>::set_delegate_policy(RawOrigin::Signed(owner.clone()).into(), futurepass.clone(), delegate.clone(), Some(policy.clone())));
+	}: _(RawOrigin::Signed(owner.clone()), futurepass.clone(), delegate.clone(), Some(policy))
+	verify {
+		assert!(DelegatePolicies::<T>::contains_key(&futurepass, &delegate));
+	}
+
+	set_delegate_expiry {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		assert_ok!(Futurepass::<T>::create(RawOrigin::Signed(owner.clone()).into(), owner.clone()));
+		let futurepass: T::AccountId = Holders::<T>::get(&owner).unwrap();
+		let delegate: T::AccountId = account("target", 0, 0);
+		add_delegates::<T>(1, futurepass.clone(), Some(owner.clone()))?;
+		// replaces an existing expiry
+		assert_ok!(Futurepass::<T>::set_delegate_expiry(RawOrigin::Signed(owner.clone()).into(), futurepass.clone(), delegate.clone(), Some(100_u32.into())));
+	}: _(RawOrigin::Signed(owner.clone()), futurepass.clone(), delegate.clone(), Some(200_u32.into()))
+	verify {
+		assert_eq!(DelegateExpiry::<T>::get(&futurepass, &delegate), Some(200_u32.into()));
+	}
+
+	remove_expired_delegate {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		assert_ok!(Futurepass::<T>::create(RawOrigin::Signed(owner.clone()).into(), owner.clone()));
+		let futurepass: T::AccountId = Holders::<T>::get(&owner).unwrap();
+		let delegate: T::AccountId = account("target", 0, 0);
+		add_delegates::<T>(1, futurepass.clone(), Some(owner.clone()))?;
+		let expiry: T::BlockNumber = 100_u32.into();
+		assert_ok!(Futurepass::<T>::set_delegate_expiry(RawOrigin::Signed(owner.clone()).into(), futurepass.clone(), delegate.clone(), Some(expiry)));
+		NextExpiryCheckBlock::<T>::put(expiry);
+	}: {
+		Futurepass::<T>::remove_expired_delegates(expiry, Weight::from_ref_time(u64::MAX));
+	}
+	verify {
+		assert!(!T::Proxy::exists(&futurepass, &delegate, None));
+	}
+
+	set_recovery_config {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (futurepass, guardians) = setup_recovery::<T>(&owner)?;
+		// cancels a recovery in progress
+		assert_ok!(Futurepass::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].clone()).into(), futurepass.clone(), account("new-owner", 0, 0)));
+	}: _(RawOrigin::Signed(owner.clone()), futurepass.clone(), BoundedVec::truncate_from(guardians), 1)
+	verify {
+		assert!(!ActiveRecoveries::<T>::contains_key(&futurepass));
+		assert_eq!(RecoveryConfigs::<T>::get(&futurepass).map(|config| config.threshold), Some(1));
+	}
+
+	remove_recovery_config {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (futurepass, guardians) = setup_recovery::<T>(&owner)?;
+		assert_ok!(Futurepass::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].clone()).into(), futurepass.clone(), account("new-owner", 0, 0)));
+	}: _(RawOrigin::Signed(owner.clone()), futurepass.clone())
+	verify {
+		assert!(!RecoveryConfigs::<T>::contains_key(&futurepass));
+		assert!(!ActiveRecoveries::<T>::contains_key(&futurepass));
+	}
+
+	initiate_recovery {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let new_owner: T::AccountId = account("new-owner", 0, 0);
+		let (futurepass, guardians) = setup_recovery::<T>(&owner)?;
+	}: _(RawOrigin::Signed(guardians[0].clone()), futurepass.clone(), new_owner.clone())
+	verify {
+		assert_eq!(ActiveRecoveries::<T>::get(&futurepass).map(|recovery| recovery.new_owner), Some(new_owner));
+	}
+
+	approve_recovery {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (futurepass, guardians) = setup_recovery::<T>(&owner)?;
+		assert_ok!(Futurepass::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].clone()).into(), futurepass.clone(), account("new-owner", 0, 0)));
+		let guardian = guardians.last().unwrap().clone();
+	}: _(RawOrigin::Signed(guardian.clone()), futurepass.clone())
+	verify {
+		assert!(ActiveRecoveries::<T>::get(&futurepass).unwrap().approvals.contains(&guardian));
+	}
+
+	cancel_recovery {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (futurepass, guardians) = setup_recovery::<T>(&owner)?;
+		assert_ok!(Futurepass::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].clone()).into(), futurepass.clone(), account("new-owner", 0, 0)));
+	}: _(RawOrigin::Signed(owner.clone()), futurepass.clone())
+	verify {
+		assert!(!ActiveRecoveries::<T>::contains_key(&futurepass));
+	}
+
+	claim_recovery {
+		let p in 1 .. (32 - 1);
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		let new_owner: T::AccountId = account("new-owner", 0, 0);
+		let (futurepass, guardians) = setup_recovery::<T>(&owner)?;
+		add_delegates::<T>(p-1, futurepass.clone(), Some(owner.clone()))?;
+		assert_ok!(Futurepass::<T>::initiate_recovery(RawOrigin::Signed(guardians[0].clone()).into(), futurepass.clone(), new_owner.clone()));
+		for guardian in guardians.iter().skip(1) {
+			assert_ok!(Futurepass::<T>::approve_recovery(RawOrigin::Signed(guardian.clone()).into(), futurepass.clone()));
+		}
+		fund::<T>(&new_owner);
+		frame_system::Pallet::<T>::set_block_number(T::RecoveryDelay::get() + 1_u32.into());
+	}: _(RawOrigin::Signed(new_owner.clone()), futurepass.clone(), true)
+	verify {
+		assert_eq!(Holders::<T>::get(&new_owner), Some(futurepass.clone()));
+		assert!(!Holders::<T>::contains_key(&owner));
+		assert_eq!(T::Proxy::delegates(&futurepass).len(), 1);
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -24,6 +24,7 @@ mod benchmarking;
 mod mock;
 #[cfg(test)]
 mod tests;
+mod types;
 mod weights;
 
 use alloc::boxed::Box;
@@ -31,22 +32,38 @@ use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult, *},
-	traits::{InstanceFilter, IsSubType, IsType},
+	traits::{
+		fungibles::{self, Inspect as _},
+		InstanceFilter, IsSubType, IsType,
+	},
 	transactional,
 	weights::constants::RocksDbWeight,
 };
 use frame_system::pallet_prelude::*;
 use precompile_utils::constants::FUTUREPASS_PRECOMPILE_ADDRESS_PREFIX;
-use sp_core::H160;
+use seed_primitives::{AssetId, Balance};
+use sp_core::{H160, H256, U256};
 use sp_io::hashing::keccak_256;
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::traits::{Dispatchable, One, Saturating, Zero};
 use sp_std::{convert::TryInto, vec::Vec};
+pub use types::*;
 pub use weights::WeightInfo;
 
 /// The logging target for this pallet
-#[allow(dead_code)]
 pub(crate) const LOG_TARGET: &str = "futurepass";
 
+pub type DelegatePolicyOf<T> =
+	DelegatePolicy<<T as frame_system::Config>::BlockNumber, <T as Config>::MaxDelegatePolicyItems>;
+
+pub type RecoveryConfigOf<T> =
+	RecoveryConfig<<T as frame_system::Config>::AccountId, <T as Config>::MaxGuardians>;
+
+pub type ActiveRecoveryOf<T> = ActiveRecovery<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxGuardians,
+>;
+
 pub trait ProxyProvider<T: Config>
 where
 	<T as frame_system::Config>::AccountId: From<H160>,
@@ -77,6 +94,18 @@ where
 	) -> DispatchResult;
 }
 
+/// Provides the calls dispatched by a call which wraps other calls e.g. a batch
+pub trait NestedCalls<Call> {
+	/// The calls dispatched by `call`, empty if it's not a wrapping call
+	fn nested_calls(call: &Call) -> Vec<&Call>;
+}
+
+impl<Call> NestedCalls<Call> for () {
+	fn nested_calls(_call: &Call) -> Vec<&Call> {
+		Vec::new()
+	}
+}
+
 pub trait FuturepassMigrator<T: frame_system::Config>
 where
 	<T as frame_system::Config>::AccountId: From<H160>,
@@ -144,12 +173,39 @@ pub mod pallet {
 		/// EVM Futurepass assets migration provider
 		type FuturepassMigrator: FuturepassMigrator<Self>;
 
-		#[cfg(feature = "runtime-benchmarks")]
-		/// Handles a multi-currency fungible asset system for benchmarking.
-		type MultiCurrency: frame_support::traits::fungibles::Inspect<
-				Self::AccountId,
-				AssetId = seed_primitives::AssetId,
-			> + frame_support::traits::fungibles::Mutate<Self::AccountId>;
+		/// Handles a multi-currency fungible asset system, used to enforce delegate spend limits
+		type MultiCurrency: fungibles::Inspect<Self::AccountId, AssetId = AssetId, Balance = Balance>
+			+ fungibles::Mutate<Self::AccountId>;
+
+		/// Provides the calls wrapped by a call, so delegate policies also apply to them
+		type NestedCalls: NestedCalls<<Self as Config>::RuntimeCall>;
+
+		/// The maximum number of call permissions, EVM call permissions and spend limits (each) in
+		/// a delegate policy
+		#[pallet::constant]
+		type MaxDelegatePolicyItems: Get<u32>;
+
+		/// The maximum number of guardians who may recover a futurepass
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+
+		/// The number of blocks between initiating and claiming a recovery, during which the owner
+		/// may cancel it
+		#[pallet::constant]
+		type RecoveryDelay: Get<Self::BlockNumber>;
+
+		/// The number of blocks after which a recovery without the threshold of guardian approvals
+		/// may be replaced by another guardian
+		#[pallet::constant]
+		type RecoveryExpiry: Get<Self::BlockNumber>;
+
+		/// The EVM chain id, signed proxy calls are bound to it by their EIP-712 domain
+		#[pallet::constant]
+		type ChainId: Get<u64>;
+
+		/// The maximum number of calls in a batched proxy call
+		#[pallet::constant]
+		type MaxProxyBatchCalls: Get<u32>;
 	}
 
 	#[pallet::type_value]
@@ -174,6 +230,73 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MigrationAdmin<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// Policies restricting the calls of futurepass delegates (futurepass, delegate -> policy)
+	#[pallet::storage]
+	pub type DelegatePolicies<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		DelegatePolicyOf<T>,
+	>;
+
+	/// The block at which delegates expire (futurepass, delegate -> block)
+	#[pallet::storage]
+	pub type DelegateExpiry<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, T::BlockNumber>;
+
+	/// Delegates to remove once expired (block, (futurepass, delegate) -> true)
+	/// Entries are stale if the delegate's expiry was changed or the delegate was removed
+	#[pallet::storage]
+	pub type DelegateExpirySchedule<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Twox64Concat,
+		(T::AccountId, T::AccountId),
+		bool,
+	>;
+
+	/// The next block of `DelegateExpirySchedule` to remove expired delegates from
+	#[pallet::storage]
+	pub type NextExpiryCheckBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The guardians of futurepasses (futurepass -> recovery config)
+	#[pallet::storage]
+	pub type RecoveryConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RecoveryConfigOf<T>>;
+
+	/// Recoveries in progress (futurepass -> recovery)
+	#[pallet::storage]
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, ActiveRecoveryOf<T>>;
+
+	/// The next nonce of the signed proxy calls of a delegate (futurepass, delegate -> nonce)
+	#[pallet::storage]
+	pub type SignedProxyNonces<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The amounts spent by delegates with spend limits in the current window
+	/// (futurepass, (delegate, asset) -> spending)
+	#[pallet::storage]
+	pub type DelegateSpending<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		(T::AccountId, AssetId),
+		SpendWindow<T::BlockNumber>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config>
@@ -189,7 +312,11 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 		},
 		/// Delegate unregistration from Futurepass account
-		DelegateUnregistered { futurepass: T::AccountId, delegate: T::AccountId },
+		DelegateUnregistered {
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			reason: UnregisterReason,
+		},
 		/// Futurepass transfer
 		FuturepassTransferred {
 			old_owner: T::AccountId,
@@ -200,6 +327,20 @@ pub mod pallet {
 		DefaultFuturepassSet { delegate: T::AccountId, futurepass: Option<T::AccountId> },
 		/// A proxy call was executed with the given call
 		ProxyExecuted { delegate: T::AccountId, result: DispatchResult },
+		/// A call of a batched proxy call was executed
+		ProxyBatchCallExecuted {
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			index: u32,
+			result: DispatchResult,
+		},
+		/// A proxy call signed by a delegate was executed by a relayer
+		SignedProxyExecuted {
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			nonce: u32,
+			result: DispatchResult,
+		},
 		/// Migration of Futurepass assets
 		FuturepassAssetsMigrated {
 			evm_futurepass: T::AccountId,
@@ -209,6 +350,42 @@ pub mod pallet {
 		},
 		/// Updating Futurepass migrator account
 		FuturepassMigratorSet { migrator: T::AccountId },
+		/// A delegate's expiry was set or removed
+		DelegateExpirySet {
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			expiry: Option<T::BlockNumber>,
+		},
+		/// The guardians of a futurepass were set
+		RecoveryConfigSet {
+			futurepass: T::AccountId,
+			guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+			threshold: u32,
+		},
+		/// The guardians of a futurepass were removed
+		RecoveryConfigRemoved { futurepass: T::AccountId },
+		/// A guardian initiated the recovery of a futurepass
+		RecoveryInitiated {
+			futurepass: T::AccountId,
+			guardian: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// A guardian approved the recovery of a futurepass
+		RecoveryApproved { futurepass: T::AccountId, guardian: T::AccountId },
+		/// The recovery of a futurepass was cancelled
+		RecoveryCancelled { futurepass: T::AccountId },
+		/// The ownership of a futurepass was recovered
+		FuturepassRecovered {
+			futurepass: T::AccountId,
+			old_owner: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// A delegate's policy was set or removed
+		DelegatePolicySet {
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			policy: Option<DelegatePolicyOf<T>>,
+		},
 	}
 
 	#[pallet::error]
@@ -239,12 +416,49 @@ pub mod pallet {
 		AccountParsingFailure,
 		/// RegisterDelegateSignerMismatch
 		RegisterDelegateSignerMismatch,
+		/// The signed proxy call nonce is not the delegate's next nonce
+		InvalidNonce,
+		/// The signed proxy call was not signed by the delegate
+		ProxyCallSignerMismatch,
+		/// The call is not permitted by the delegate's policy
+		CallNotPermitted,
+		/// The batch has more calls than the maximum allowed
+		TooManyCalls,
+		/// The call spends more than the delegate's spend limit
+		SpendLimitExceeded,
+		/// A spend limit has a zero window or the asset has more than one limit
+		InvalidSpendLimit,
+		/// The delegate has expired
+		DelegateExpired,
+		/// The expiry block must be in the future
+		InvalidExpiry,
+		/// The threshold must be between 1 and the number of guardians, guardians must be unique
+		/// and not the futurepass or its owner
+		InvalidRecoveryConfig,
+		/// The futurepass has no guardians
+		RecoveryNotConfigured,
+		/// Account is not a guardian of the futurepass
+		NotGuardian,
+		/// A recovery of the futurepass is in progress
+		RecoveryAlreadyActive,
+		/// No recovery of the futurepass is in progress
+		NoActiveRecovery,
+		/// The guardian already approved the recovery
+		RecoveryAlreadyApproved,
+		/// The recovery does not have enough guardian approvals
+		InsufficientApprovals,
+		/// The recovery delay has not passed
+		RecoveryDelayNotPassed,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> where
-		<T as frame_system::Config>::AccountId: From<H160>
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<H160>,
 	{
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::remove_expired_delegates(now, remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -317,9 +531,10 @@ pub mod pallet {
 				is_futurepass || T::Proxy::exists(&futurepass, &caller, None),
 				Error::<T>::DelegateNotRegistered
 			);
-			// for V1, only T::ProxyType::default() is allowed.
-			// TODO - update the restriction in V2 as required.
-			ensure!(proxy_type == T::ProxyType::default(), Error::<T>::PermissionDenied);
+			// delegates may be given any scoped permission, but not ownership (owner is maxu8) or
+			// no permission (0)
+			let proxy_type_id: u8 = proxy_type.clone().into();
+			ensure!(proxy_type_id != 0 && proxy_type_id != u8::MAX, Error::<T>::PermissionDenied);
 			// delegate should not be an existing proxy of any T::ProxyType
 			// This is required here coz pallet_proxy's duplicate check is only for the specific
 			// proxy_type
@@ -340,16 +555,7 @@ pub mod pallet {
 				&proxy_type,
 				&deadline,
 			)?;
-			let delegate_signer: T::AccountId =
-				match sp_io::crypto::secp256k1_ecdsa_recover(&signature, &eth_signed_msg) {
-					Ok(pubkey_bytes) => H160(
-						keccak_256(&pubkey_bytes)[12..]
-							.try_into()
-							.map_err(|_| Error::<T>::AccountParsingFailure)?,
-					)
-					.into(),
-					Err(_err) => Err(Error::<T>::InvalidSignature)?,
-				};
+			let delegate_signer = Self::recover_signer(&signature, &eth_signed_msg)?;
 			ensure!(delegate_signer == delegate, Error::<T>::RegisterDelegateSignerMismatch);
 
 			T::Proxy::add_delegate(&caller, &futurepass, &delegate, &proxy_type.clone().into())?;
@@ -409,8 +615,14 @@ pub mod pallet {
 
 			// Remove the delegate from the futurepass
 			T::Proxy::remove_delegate(&caller, &futurepass, &delegate)?;
+			Self::remove_delegate_policy(&futurepass, &delegate);
+			Self::remove_delegate_expiry(&futurepass, &delegate);
 
-			Self::deposit_event(Event::<T>::DelegateUnregistered { futurepass, delegate });
+			Self::deposit_event(Event::<T>::DelegateUnregistered {
+				futurepass,
+				delegate,
+				reason: UnregisterReason::Unregistered,
+			});
 			Ok(())
 		}
 
@@ -452,6 +664,9 @@ pub mod pallet {
 				// if current owner is not the caller; then the caller must be futurepass itself
 				ensure!(futurepass == caller.clone(), Error::<T>::NotFuturepassOwner);
 			}
+			// the previous owner's guardians must not be able to recover the futurepass
+			ActiveRecoveries::<T>::remove(&futurepass);
+			RecoveryConfigs::<T>::remove(&futurepass);
 
 			if let Some(ref new_owner) = new_owner {
 				// Ensure that the new owner does not already own a futurepass
@@ -470,12 +685,18 @@ pub mod pallet {
 						T::Proxy::remove_delegate(&caller, &futurepass, &delegate.0)?;
 					}
 				}
+				let _ = DelegatePolicies::<T>::clear_prefix(&futurepass, u32::MAX, None);
+				let _ = DelegateSpending::<T>::clear_prefix(&futurepass, u32::MAX, None);
+				let _ = DelegateExpiry::<T>::clear_prefix(&futurepass, u32::MAX, None);
 
 				// Set the new owner as the owner of the futurepass
 				Holders::<T>::insert(new_owner, futurepass.clone());
 			} else {
 				// remove the account - which should remove all delegates
 				T::Proxy::remove_account(&caller, &futurepass)?;
+				let _ = DelegatePolicies::<T>::clear_prefix(&futurepass, u32::MAX, None);
+				let _ = DelegateSpending::<T>::clear_prefix(&futurepass, u32::MAX, None);
+				let _ = DelegateExpiry::<T>::clear_prefix(&futurepass, u32::MAX, None);
 			}
 
 			Self::deposit_event(Event::<T>::FuturepassTransferred {
@@ -488,6 +709,8 @@ pub mod pallet {
 
 		/// Dispatch the given call through Futurepass account. Transaction fees will be paid by the
 		/// Futurepass. The dispatch origin for this call must be _Signed_
+		/// If the caller has a delegate policy, the call (and any calls it wraps) must be permitted
+		/// by the policy and the call must not exceed the policy's spend limits.
 		///
 		/// Parameters:
 		/// - `futurepass`: The Futurepass account though which the call is dispatched
@@ -499,37 +722,24 @@ pub mod pallet {
 		#[pallet::weight({
 			let di = call.get_dispatch_info();
 			let delegate_count = T::Proxy::delegates(&futurepass).len() as u32;
+			let spend_limits = T::MaxDelegatePolicyItems::get() as u64;
 			(T::WeightInfo::proxy_extrinsic(delegate_count)
 				.saturating_add(di.weight)
 				 // AccountData for inner call origin accountdata.
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// delegate expiry, policy, spend limit balances before and after the call and spending
+				.saturating_add(T::DbWeight::get().reads_writes(2 + 3 * spend_limits, spend_limits)),
 			di.class)
 		})]
+		#[transactional]
 		pub fn proxy_extrinsic(
 			origin: OriginFor<T>,
 			futurepass: T::AccountId,
 			call: Box<<T as Config>::RuntimeCall>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			// restrict delegate access to whitelist
-			match call.is_sub_type() {
-				Some(Call::register_delegate_with_signature { .. }) |
-				Some(Call::unregister_delegate { .. }) |
-				Some(Call::transfer_futurepass { .. }) => {
-					ensure!(
-						Holders::<T>::get(&who.clone()) == Some(futurepass.clone()),
-						Error::<T>::NotFuturepassOwner
-					);
-				},
-				_ => {},
-			}
-
-			let result = T::Proxy::proxy_call(origin, futurepass, *call);
-			Self::deposit_event(Event::ProxyExecuted {
-				delegate: who,
-				result: result.map(|_| ()).map_err(|e| e),
-			});
+			let who = ensure_signed(origin)?;
+			let result = Self::do_proxy_extrinsic(who.clone(), futurepass, *call);
+			Self::deposit_event(Event::ProxyExecuted { delegate: who, result });
 			result
 		}
 
@@ -604,6 +814,494 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set or remove the policy of a futurepass delegate.
+		/// A policy restricts the delegate to the runtime and EVM calls it permits and caps the
+		/// assets the delegate's calls may spend from the futurepass per window of blocks, on top
+		/// of the delegate's proxy type. Setting a policy resets the delegate's spending.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the futurepass owner or
+		/// the futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account of the delegate.
+		/// - `delegate`: The delegate to restrict, the owner can't be restricted.
+		/// - `policy`: The policy of the delegate, `None` removes the delegate's policy.
+		#[pallet::weight(T::WeightInfo::set_delegate_policy())]
+		pub fn set_delegate_policy(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			policy: Option<DelegatePolicyOf<T>>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				caller == futurepass || Holders::<T>::get(&caller) == Some(futurepass.clone()),
+				Error::<T>::NotFuturepassOwner
+			);
+			ensure!(
+				Holders::<T>::get(&delegate) != Some(futurepass.clone()),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(
+				T::Proxy::exists(&futurepass, &delegate, None),
+				Error::<T>::DelegateNotRegistered
+			);
+
+			if let Some(ref policy) = policy {
+				for (i, limit) in policy.spend_limits.iter().enumerate() {
+					ensure!(
+						!limit.window.is_zero() &&
+							!policy.spend_limits[..i]
+								.iter()
+								.any(|other| other.asset_id == limit.asset_id),
+						Error::<T>::InvalidSpendLimit
+					);
+				}
+			}
+
+			Self::remove_delegate_policy(&futurepass, &delegate);
+			if let Some(ref policy) = policy {
+				DelegatePolicies::<T>::insert(&futurepass, &delegate, policy);
+			}
+
+			Self::deposit_event(Event::DelegatePolicySet { futurepass, delegate, policy });
+			Ok(())
+		}
+
+		/// Set or remove the expiry of a futurepass delegate.
+		/// From the expiry block the delegate can't make calls through the futurepass, expired
+		/// delegates are removed when blocks have spare weight.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the futurepass owner or
+		/// the futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account of the delegate.
+		/// - `delegate`: The delegate to expire, the owner can't expire.
+		/// - `expiry`: The block at which the delegate expires, `None` removes the expiry.
+		#[pallet::weight(T::WeightInfo::set_delegate_expiry())]
+		pub fn set_delegate_expiry(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				caller == futurepass || Holders::<T>::get(&caller) == Some(futurepass.clone()),
+				Error::<T>::NotFuturepassOwner
+			);
+			ensure!(
+				Holders::<T>::get(&delegate) != Some(futurepass.clone()),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(
+				T::Proxy::exists(&futurepass, &delegate, None),
+				Error::<T>::DelegateNotRegistered
+			);
+			ensure!(
+				!Self::is_delegate_expired(&futurepass, &delegate),
+				Error::<T>::DelegateExpired
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(expiry) = expiry {
+				ensure!(expiry > now, Error::<T>::InvalidExpiry);
+			}
+
+			Self::remove_delegate_expiry(&futurepass, &delegate);
+			if let Some(expiry) = expiry {
+				DelegateExpiry::<T>::insert(&futurepass, &delegate, expiry);
+				DelegateExpirySchedule::<T>::insert(
+					expiry,
+					(futurepass.clone(), delegate.clone()),
+					true,
+				);
+				// no delegates have expired before now
+				if NextExpiryCheckBlock::<T>::get().is_zero() {
+					NextExpiryCheckBlock::<T>::put(now);
+				}
+			}
+
+			Self::deposit_event(Event::DelegateExpirySet { futurepass, delegate, expiry });
+			Ok(())
+		}
+
+		/// Set the guardians who may recover a futurepass for a new owner, if the owner's key is
+		/// lost.
+		/// Cancels any recovery in progress.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the futurepass owner or
+		/// the futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account to set the guardians of.
+		/// - `guardians`: The accounts which may initiate and approve a recovery.
+		/// - `threshold`: The number of guardian approvals required to complete a recovery.
+		#[pallet::weight(T::WeightInfo::set_recovery_config())]
+		pub fn set_recovery_config(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+			threshold: u32,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				caller == futurepass || Holders::<T>::get(&caller) == Some(futurepass.clone()),
+				Error::<T>::NotFuturepassOwner
+			);
+			ensure!(
+				threshold > 0 && threshold as usize <= guardians.len(),
+				Error::<T>::InvalidRecoveryConfig
+			);
+			for (i, guardian) in guardians.iter().enumerate() {
+				ensure!(
+					*guardian != futurepass &&
+						Holders::<T>::get(guardian) != Some(futurepass.clone()) &&
+						!guardians[..i].contains(guardian),
+					Error::<T>::InvalidRecoveryConfig
+				);
+			}
+
+			ActiveRecoveries::<T>::remove(&futurepass);
+			RecoveryConfigs::<T>::insert(
+				&futurepass,
+				RecoveryConfig { guardians: guardians.clone(), threshold },
+			);
+
+			Self::deposit_event(Event::RecoveryConfigSet { futurepass, guardians, threshold });
+			Ok(())
+		}
+
+		/// Remove the guardians of a futurepass.
+		/// Cancels any recovery in progress.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the futurepass owner or
+		/// the futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account to remove the guardians of.
+		#[pallet::weight(T::WeightInfo::remove_recovery_config())]
+		pub fn remove_recovery_config(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				caller == futurepass || Holders::<T>::get(&caller) == Some(futurepass.clone()),
+				Error::<T>::NotFuturepassOwner
+			);
+			ensure!(
+				RecoveryConfigs::<T>::take(&futurepass).is_some(),
+				Error::<T>::RecoveryNotConfigured
+			);
+			ActiveRecoveries::<T>::remove(&futurepass);
+
+			Self::deposit_event(Event::RecoveryConfigRemoved { futurepass });
+			Ok(())
+		}
+
+		/// Initiate the recovery of a futurepass for a new owner, approved by the caller.
+		/// The recovery may be claimed after `RecoveryDelay` blocks, once it has the threshold of
+		/// guardian approvals.
+		/// Replaces a recovery in progress that did not reach the threshold of guardian approvals
+		/// within `RecoveryExpiry` blocks.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a guardian of the
+		/// futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account to recover.
+		/// - `new_owner`: The account which will become the futurepass owner, it must not own a
+		///   futurepass.
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let guardian = ensure_signed(origin)?;
+			Self::ensure_guardian(&guardian, &futurepass)?;
+			if let Some(recovery) = ActiveRecoveries::<T>::get(&futurepass) {
+				ensure!(
+					Self::is_recovery_expired(&futurepass, &recovery),
+					Error::<T>::RecoveryAlreadyActive
+				);
+			}
+			ensure!(!Holders::<T>::contains_key(&new_owner), Error::<T>::AccountAlreadyRegistered);
+
+			let approvals = BoundedVec::truncate_from(sp_std::vec![guardian.clone()]);
+			ActiveRecoveries::<T>::insert(
+				&futurepass,
+				ActiveRecovery {
+					new_owner: new_owner.clone(),
+					approvals,
+					initiated: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::RecoveryInitiated { futurepass, guardian, new_owner });
+			Ok(())
+		}
+
+		/// Approve the recovery of a futurepass in progress.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a guardian of the
+		/// futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account being recovered.
+		#[pallet::weight(T::WeightInfo::approve_recovery())]
+		pub fn approve_recovery(origin: OriginFor<T>, futurepass: T::AccountId) -> DispatchResult {
+			let guardian = ensure_signed(origin)?;
+			Self::ensure_guardian(&guardian, &futurepass)?;
+			ActiveRecoveries::<T>::try_mutate(&futurepass, |recovery| -> DispatchResult {
+				let recovery = recovery.as_mut().ok_or(Error::<T>::NoActiveRecovery)?;
+				ensure!(
+					!recovery.approvals.contains(&guardian),
+					Error::<T>::RecoveryAlreadyApproved
+				);
+				// guardians are unique so approvals are bounded by the guardians
+				recovery
+					.approvals
+					.try_push(guardian.clone())
+					.map_err(|_| Error::<T>::RecoveryAlreadyApproved)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RecoveryApproved { futurepass, guardian });
+			Ok(())
+		}
+
+		/// Cancel the recovery of a futurepass in progress.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the futurepass owner or
+		/// the futurepass.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account being recovered.
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(origin: OriginFor<T>, futurepass: T::AccountId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				caller == futurepass || Holders::<T>::get(&caller) == Some(futurepass.clone()),
+				Error::<T>::NotFuturepassOwner
+			);
+			ensure!(
+				ActiveRecoveries::<T>::take(&futurepass).is_some(),
+				Error::<T>::NoActiveRecovery
+			);
+
+			Self::deposit_event(Event::RecoveryCancelled { futurepass });
+			Ok(())
+		}
+
+		/// Complete the recovery of a futurepass, the new owner replaces the owner as the
+		/// futurepass holder and `Owner` delegate. Other delegates are kept unless
+		/// `clear_delegates` is set.
+		/// The caller funds the new owner's delegate deposit and is refunded the old owner's and
+		/// those of any removed delegates.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a guardian of the
+		/// futurepass or the new owner.
+		///
+		/// Parameters:
+		/// - `futurepass`: The futurepass account being recovered.
+		/// - `clear_delegates`: Whether to remove all other delegates of the futurepass, which may
+		///   have been added by whoever compromised the owner.
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the futurepass has.
+		/// # </weight>
+		#[pallet::weight({
+			let delegate_count = T::Proxy::delegates(&futurepass).len() as u32;
+			T::WeightInfo::claim_recovery(delegate_count)
+		})]
+		#[transactional]
+		pub fn claim_recovery(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			clear_delegates: bool,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let recovery =
+				ActiveRecoveries::<T>::get(&futurepass).ok_or(Error::<T>::NoActiveRecovery)?;
+			let config =
+				RecoveryConfigs::<T>::get(&futurepass).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(
+				caller == recovery.new_owner || config.guardians.contains(&caller),
+				Error::<T>::NotGuardian
+			);
+			ensure!(
+				recovery.approvals.len() as u32 >= config.threshold,
+				Error::<T>::InsufficientApprovals
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					recovery.initiated.saturating_add(T::RecoveryDelay::get()),
+				Error::<T>::RecoveryDelayNotPassed
+			);
+			let new_owner = recovery.new_owner;
+			ensure!(!Holders::<T>::contains_key(&new_owner), Error::<T>::AccountAlreadyRegistered);
+			let old_owner = T::Proxy::owner(&futurepass).ok_or(Error::<T>::NotFuturepassOwner)?;
+			ensure!(
+				Holders::<T>::take(&old_owner) == Some(futurepass.clone()),
+				Error::<T>::NotFuturepassOwner
+			);
+			ActiveRecoveries::<T>::remove(&futurepass);
+
+			// the new owner replaces any existing delegation
+			if T::Proxy::exists(&futurepass, &new_owner, None) {
+				T::Proxy::remove_delegate(&caller, &futurepass, &new_owner)?;
+				Self::remove_delegate_policy(&futurepass, &new_owner);
+				Self::remove_delegate_expiry(&futurepass, &new_owner);
+			}
+			T::Proxy::add_delegate(&caller, &futurepass, &new_owner, &255)?; // owner is maxu8
+			T::Proxy::remove_delegate(&caller, &futurepass, &old_owner)?;
+			if clear_delegates {
+				for (delegate, _) in T::Proxy::delegates(&futurepass) {
+					if delegate != new_owner {
+						T::Proxy::remove_delegate(&caller, &futurepass, &delegate)?;
+					}
+				}
+				let _ = DelegatePolicies::<T>::clear_prefix(&futurepass, u32::MAX, None);
+				let _ = DelegateSpending::<T>::clear_prefix(&futurepass, u32::MAX, None);
+				let _ = DelegateExpiry::<T>::clear_prefix(&futurepass, u32::MAX, None);
+			}
+			Holders::<T>::insert(&new_owner, futurepass.clone());
+
+			Self::deposit_event(Event::FuturepassRecovered { futurepass, old_owner, new_owner });
+			Ok(())
+		}
+
+		/// Dispatch a call through a Futurepass account on behalf of a delegate, who signed the
+		/// call off-chain, so the delegate needs no funds for fees. The call is executed as
+		/// `proxy_extrinsic` from the delegate. The signature is over EIP-712 typed data:
+		/// ---
+		/// ```solidity
+		/// EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)
+		/// ProxyCall(address delegate,bytes call,uint32 nonce,uint32 deadline,bool futurepassPaysFee)
+		/// ```
+		/// ---
+		/// where the domain name is "Futurepass", the version is "1", the verifying contract is
+		/// the futurepass and `call` is the SCALE encoded call.
+		/// The nonce is used even if the call fails.
+		///
+		/// The dispatch origin for this call must be _Signed_; the relayer. Transaction fees are
+		/// paid by the relayer, or by the futurepass if the delegate signed `futurepass_pays_fee`.
+		/// Either may pay in another asset with `pallet_fee_proxy`.
+		///
+		/// Parameters:
+		/// - `futurepass`: The Futurepass account though which the call is dispatched
+		/// - `delegate`: The delegate who signed the call
+		/// - `call`: The Call that needs to be dispatched through the Futurepass account
+		/// - `nonce`: The delegate's next signed proxy call nonce
+		/// - `deadline`: The last block the signature is valid
+		/// - `futurepass_pays_fee`: Whether the transaction fees are paid by the futurepass
+		/// - `signature`: The delegate's signature of the typed data
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the user has.
+		/// # </weight>
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			let delegate_count = T::Proxy::delegates(&futurepass).len() as u32;
+			let spend_limits = T::MaxDelegatePolicyItems::get() as u64;
+			(T::WeightInfo::proxy_extrinsic_with_signature(delegate_count)
+				.saturating_add(di.weight)
+				 // AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// delegate expiry, policy, spend limit balances before and after the call and spending
+				.saturating_add(T::DbWeight::get().reads_writes(2 + 3 * spend_limits, spend_limits)),
+			di.class)
+		})]
+		pub fn proxy_extrinsic_with_signature(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			delegate: T::AccountId,
+			call: Box<<T as Config>::RuntimeCall>,
+			nonce: u32,
+			deadline: u32,
+			futurepass_pays_fee: bool,
+			signature: [u8; 65],
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::validate_signed_proxy_extrinsic(
+				&futurepass,
+				&delegate,
+				&call,
+				nonce,
+				deadline,
+				futurepass_pays_fee,
+				&signature,
+			)?;
+			SignedProxyNonces::<T>::insert(&futurepass, &delegate, nonce.saturating_add(1));
+
+			// the nonce is used regardless of the result, the relayer has paid for the call
+			let result = frame_support::storage::with_storage_layer(|| {
+				Self::do_proxy_extrinsic(delegate.clone(), futurepass.clone(), *call)
+			});
+			Self::deposit_event(Event::SignedProxyExecuted { futurepass, delegate, nonce, result });
+			Ok(())
+		}
+
+		/// Dispatch a batch of calls through a Futurepass account. Each call is checked and
+		/// executed as if it was dispatched with `proxy_extrinsic`, and its result is reported with
+		/// a `ProxyBatchCallExecuted` event.
+		/// If `atomic` is set, the batch is reverted and fails if any call fails. Otherwise the
+		/// calls which fail are reverted and the remaining calls are executed.
+		/// The dispatch origin for this call must be _Signed_
+		///
+		/// Parameters:
+		/// - `futurepass`: The Futurepass account though which the calls are dispatched
+		/// - `calls`: The Calls that need to be dispatched through the Futurepass account
+		/// - `atomic`: Whether all calls must succeed
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the user has and the number of calls.
+		/// # </weight>
+		#[pallet::weight({
+			let delegate_count = T::Proxy::delegates(&futurepass).len() as u32;
+			let spend_limits = T::MaxDelegatePolicyItems::get() as u64;
+			let per_call = T::WeightInfo::proxy_extrinsic(delegate_count)
+				 // AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// delegate expiry, policy, spend limit balances before and after the call and spending
+				.saturating_add(T::DbWeight::get().reads_writes(2 + 3 * spend_limits, spend_limits));
+			calls.iter().fold(Weight::zero(), |total, call| {
+				total.saturating_add(per_call).saturating_add(call.get_dispatch_info().weight)
+			})
+		})]
+		#[transactional]
+		pub fn proxy_extrinsic_batch(
+			origin: OriginFor<T>,
+			futurepass: T::AccountId,
+			calls: Vec<<T as Config>::RuntimeCall>,
+			atomic: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(calls.len() <= T::MaxProxyBatchCalls::get() as usize, Error::<T>::TooManyCalls);
+
+			for (index, call) in calls.into_iter().enumerate() {
+				let result = if atomic {
+					Self::do_proxy_extrinsic(who.clone(), futurepass.clone(), call)
+				} else {
+					frame_support::storage::with_storage_layer(|| {
+						Self::do_proxy_extrinsic(who.clone(), futurepass.clone(), call)
+					})
+				};
+				Self::deposit_event(Event::ProxyBatchCallExecuted {
+					futurepass: futurepass.clone(),
+					delegate: who.clone(),
+					index: index as u32,
+					result,
+				});
+				if atomic {
+					result?;
+				}
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -645,6 +1343,351 @@ where
 		T::AccountId::from(address)
 	}
 
+	/// Dispatch `call` through `futurepass` on behalf of the delegate `who`, applying the
+	/// whitelist and the delegate's expiry and policy
+	fn do_proxy_extrinsic(
+		who: T::AccountId,
+		futurepass: T::AccountId,
+		call: <T as Config>::RuntimeCall,
+	) -> DispatchResult {
+		// restrict delegate access to whitelist
+		match call.is_sub_type() {
+			Some(Call::register_delegate_with_signature { .. }) |
+			Some(Call::unregister_delegate { .. }) |
+			Some(Call::transfer_futurepass { .. }) |
+			Some(Call::set_delegate_policy { .. }) |
+			Some(Call::set_delegate_expiry { .. }) |
+			Some(Call::set_recovery_config { .. }) |
+			Some(Call::remove_recovery_config { .. }) |
+			Some(Call::initiate_recovery { .. }) |
+			Some(Call::approve_recovery { .. }) |
+			Some(Call::cancel_recovery { .. }) |
+			Some(Call::claim_recovery { .. }) => {
+				ensure!(
+					Holders::<T>::get(&who.clone()) == Some(futurepass.clone()),
+					Error::<T>::NotFuturepassOwner
+				);
+			},
+			_ => {},
+		}
+
+		ensure!(!Self::is_delegate_expired(&futurepass, &who), Error::<T>::DelegateExpired);
+		let policy = DelegatePolicies::<T>::get(&futurepass, &who);
+		if let Some(ref policy) = policy {
+			ensure!(Self::is_call_permitted(policy, &call), Error::<T>::CallNotPermitted);
+		}
+		let balances = policy
+			.as_ref()
+			.map(|policy| Self::spend_limit_balances(&futurepass, policy))
+			.unwrap_or_default();
+
+		let result = T::Proxy::proxy_call(
+			frame_system::RawOrigin::Signed(who.clone()).into(),
+			futurepass.clone(),
+			call,
+		);
+		if let (Some(policy), Ok(_)) = (policy, result) {
+			Self::record_spending(&futurepass, &who, &policy, balances)?;
+		}
+		result
+	}
+
+	/// Validate a signed proxy call for `proxy_extrinsic_with_signature` without executing it
+	pub fn validate_signed_proxy_extrinsic(
+		futurepass: &T::AccountId,
+		delegate: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		nonce: u32,
+		deadline: u32,
+		futurepass_pays_fee: bool,
+		signature: &[u8; 65],
+	) -> DispatchResult {
+		ensure!(T::Proxy::exists(futurepass, delegate, None), Error::<T>::DelegateNotRegistered);
+		let struct_hash =
+			Self::proxy_call_struct_hash(delegate, call, nonce, deadline, futurepass_pays_fee);
+		Self::verify_signed_proxy_call(
+			futurepass,
+			delegate,
+			nonce,
+			deadline,
+			struct_hash,
+			signature,
+		)
+	}
+
+	/// Verify the deadline, nonce and signature of a signed proxy call with the EIP-712
+	/// `struct_hash`
+	pub fn verify_signed_proxy_call(
+		futurepass: &T::AccountId,
+		delegate: &T::AccountId,
+		nonce: u32,
+		deadline: u32,
+		struct_hash: [u8; 32],
+		signature: &[u8; 65],
+	) -> DispatchResult {
+		let deadline_block_number: T::BlockNumber = deadline.into();
+		ensure!(
+			deadline_block_number >= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::ExpiredDeadline
+		);
+		ensure!(
+			nonce == SignedProxyNonces::<T>::get(futurepass, delegate),
+			Error::<T>::InvalidNonce
+		);
+		let digest = Self::signed_proxy_call_digest(futurepass, struct_hash);
+		let signer = Self::recover_signer(signature, &digest)?;
+		ensure!(signer == *delegate, Error::<T>::ProxyCallSignerMismatch);
+		Ok(())
+	}
+
+	/// The EIP-712 digest of a signed proxy call with `struct_hash`, the domain's verifying
+	/// contract is `futurepass`
+	pub fn signed_proxy_call_digest(futurepass: &T::AccountId, struct_hash: [u8; 32]) -> [u8; 32] {
+		let mut domain = Vec::with_capacity(5 * 32);
+		domain.extend(keccak_256(
+			b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+		));
+		domain.extend(keccak_256(b"Futurepass"));
+		domain.extend(keccak_256(b"1"));
+		domain.extend(abi_encode_u256(T::ChainId::get().into()));
+		domain.extend(abi_encode_account(futurepass));
+		let domain_separator = keccak_256(&domain);
+
+		let mut msg = Vec::with_capacity(2 + 2 * 32);
+		msg.extend(b"\x19\x01");
+		msg.extend(domain_separator);
+		msg.extend(struct_hash);
+		keccak_256(&msg)
+	}
+
+	/// The EIP-712 struct hash of a signed runtime call
+	/// `ProxyCall(address delegate,bytes call,uint32 nonce,uint32 deadline,bool futurepassPaysFee)`
+	pub fn proxy_call_struct_hash(
+		delegate: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		nonce: u32,
+		deadline: u32,
+		futurepass_pays_fee: bool,
+	) -> [u8; 32] {
+		let mut msg = Vec::with_capacity(6 * 32);
+		msg.extend(keccak_256(
+			b"ProxyCall(address delegate,bytes call,uint32 nonce,uint32 deadline,bool futurepassPaysFee)",
+		));
+		msg.extend(abi_encode_account(delegate));
+		msg.extend(keccak_256(&call.encode()));
+		msg.extend(abi_encode_u256(nonce.into()));
+		msg.extend(abi_encode_u256(deadline.into()));
+		msg.extend(abi_encode_u256(u8::from(futurepass_pays_fee).into()));
+		keccak_256(&msg)
+	}
+
+	/// The EIP-712 struct hash of a signed EVM call
+	/// `ProxyEvmCall(address delegate,uint8 callType,address to,uint256 value,bytes data,uint32
+	/// nonce,uint32 deadline)`
+	pub fn proxy_evm_call_struct_hash(
+		delegate: H160,
+		call_type: u8,
+		to: H160,
+		value: U256,
+		data: &[u8],
+		nonce: u32,
+		deadline: u32,
+	) -> [u8; 32] {
+		let mut msg = Vec::with_capacity(8 * 32);
+		msg.extend(keccak_256(
+			b"ProxyEvmCall(address delegate,uint8 callType,address to,uint256 value,bytes data,uint32 nonce,uint32 deadline)",
+		));
+		msg.extend(H256::from(delegate).as_bytes());
+		msg.extend(abi_encode_u256(call_type.into()));
+		msg.extend(H256::from(to).as_bytes());
+		msg.extend(abi_encode_u256(value));
+		msg.extend(keccak_256(data));
+		msg.extend(abi_encode_u256(nonce.into()));
+		msg.extend(abi_encode_u256(deadline.into()));
+		keccak_256(&msg)
+	}
+
+	/// Recover the Ethereum account which signed the 32 byte `msg`
+	fn recover_signer(signature: &[u8; 65], msg: &[u8; 32]) -> Result<T::AccountId, DispatchError> {
+		match sp_io::crypto::secp256k1_ecdsa_recover(signature, msg) {
+			Ok(pubkey_bytes) => Ok(H160(
+				keccak_256(&pubkey_bytes)[12..]
+					.try_into()
+					.map_err(|_| Error::<T>::AccountParsingFailure)?,
+			)
+			.into()),
+			Err(_err) => Err(Error::<T>::InvalidSignature)?,
+		}
+	}
+
+	/// Whether `call` and any calls it wraps are permitted by `policy`
+	fn is_call_permitted(policy: &DelegatePolicyOf<T>, call: &<T as Config>::RuntimeCall) -> bool {
+		call.using_encoded(|call| policy.allows_call(call)) &&
+			T::NestedCalls::nested_calls(call)
+				.into_iter()
+				.all(|call| Self::is_call_permitted(policy, call))
+	}
+
+	/// Ensure `guardian` is a guardian of `futurepass`
+	fn ensure_guardian(guardian: &T::AccountId, futurepass: &T::AccountId) -> DispatchResult {
+		let config =
+			RecoveryConfigs::<T>::get(futurepass).ok_or(Error::<T>::RecoveryNotConfigured)?;
+		ensure!(config.guardians.contains(guardian), Error::<T>::NotGuardian);
+		Ok(())
+	}
+
+	/// Whether `recovery` of `futurepass` failed to reach the threshold of guardian approvals
+	/// within `RecoveryExpiry` blocks
+	pub fn is_recovery_expired(futurepass: &T::AccountId, recovery: &ActiveRecoveryOf<T>) -> bool {
+		let threshold = RecoveryConfigs::<T>::get(futurepass).map_or(0, |config| config.threshold);
+		(recovery.approvals.len() as u32) < threshold &&
+			frame_system::Pallet::<T>::block_number() >=
+				recovery.initiated.saturating_add(T::RecoveryExpiry::get())
+	}
+
+	/// Whether `delegate` has reached its expiry block
+	pub fn is_delegate_expired(futurepass: &T::AccountId, delegate: &T::AccountId) -> bool {
+		DelegateExpiry::<T>::get(futurepass, delegate)
+			.map_or(false, |expiry| frame_system::Pallet::<T>::block_number() >= expiry)
+	}
+
+	/// Remove the expiry of `delegate`
+	fn remove_delegate_expiry(futurepass: &T::AccountId, delegate: &T::AccountId) {
+		if let Some(expiry) = DelegateExpiry::<T>::take(futurepass, delegate) {
+			DelegateExpirySchedule::<T>::remove(expiry, (futurepass.clone(), delegate.clone()));
+		}
+	}
+
+	/// Remove the delegates which expired up to block `now`, within `remaining_weight`
+	pub(crate) fn remove_expired_delegates(
+		now: T::BlockNumber,
+		remaining_weight: Weight,
+	) -> Weight {
+		// Read: NextExpiryCheckBlock
+		// Write: NextExpiryCheckBlock
+		let base_weight = T::DbWeight::get().reads_writes(1, 1);
+		// Read: DelegateExpirySchedule for a block
+		let weight_per_block = T::DbWeight::get().reads(1);
+		let weight_per_delegate = T::WeightInfo::remove_expired_delegate();
+
+		if remaining_weight.all_lte(base_weight + weight_per_block + weight_per_delegate) {
+			return Weight::zero()
+		}
+
+		let mut block = NextExpiryCheckBlock::<T>::get();
+		// no delegate expiry has been set
+		if block.is_zero() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut used_weight = base_weight;
+		'blocks: while block <= now {
+			used_weight = used_weight.saturating_add(weight_per_block);
+			let mut expired = DelegateExpirySchedule::<T>::drain_prefix(block);
+			loop {
+				if remaining_weight.all_lte(used_weight.saturating_add(weight_per_delegate)) {
+					// continue from this block when there is weight
+					break 'blocks
+				}
+				let Some(((futurepass, delegate), _)) = expired.next() else { break };
+				used_weight = used_weight.saturating_add(weight_per_delegate);
+				Self::remove_expired_delegate(futurepass, delegate, block);
+			}
+			block = block.saturating_add(One::one());
+		}
+		NextExpiryCheckBlock::<T>::put(block);
+
+		used_weight
+	}
+
+	/// Remove `delegate` from `futurepass` if it expired at block `expiry`
+	fn remove_expired_delegate(
+		futurepass: T::AccountId,
+		delegate: T::AccountId,
+		expiry: T::BlockNumber,
+	) {
+		// the schedule is stale if the expiry was changed or the delegate removed
+		if DelegateExpiry::<T>::get(&futurepass, &delegate) != Some(expiry) {
+			return
+		}
+		// the deposit is refunded to the owner
+		let receiver = T::Proxy::owner(&futurepass).unwrap_or_else(|| futurepass.clone());
+		let result = frame_support::storage::with_storage_layer(|| {
+			T::Proxy::remove_delegate(&receiver, &futurepass, &delegate)
+		});
+		// the delegate remains expired if it can't be removed
+		if let Err(err) = result {
+			log::error!(
+				target: LOG_TARGET,
+				"💳 failed to remove expired delegate {:?} of futurepass {:?}: {:?}",
+				delegate,
+				futurepass,
+				err
+			);
+			return
+		}
+		DelegateExpiry::<T>::remove(&futurepass, &delegate);
+		Self::remove_delegate_policy(&futurepass, &delegate);
+		Self::deposit_event(Event::<T>::DelegateUnregistered {
+			futurepass,
+			delegate,
+			reason: UnregisterReason::Expired,
+		});
+	}
+
+	/// Remove the policy and spending of `delegate`
+	fn remove_delegate_policy(futurepass: &T::AccountId, delegate: &T::AccountId) {
+		if let Some(policy) = DelegatePolicies::<T>::take(futurepass, delegate) {
+			for limit in policy.spend_limits {
+				DelegateSpending::<T>::remove(futurepass, (delegate, limit.asset_id));
+			}
+		}
+	}
+
+	/// The futurepass balances of the assets capped by the spend limits of `policy`
+	pub fn spend_limit_balances(
+		futurepass: &T::AccountId,
+		policy: &DelegatePolicyOf<T>,
+	) -> Vec<Balance> {
+		policy
+			.spend_limits
+			.iter()
+			.map(|limit| T::MultiCurrency::balance(limit.asset_id, futurepass))
+			.collect()
+	}
+
+	/// Record the assets spent from the futurepass by `delegate` since the spend limit `balances`
+	/// were taken
+	/// Fails if the spending of the current window exceeds a spend limit of `policy`
+	pub fn record_spending(
+		futurepass: &T::AccountId,
+		delegate: &T::AccountId,
+		policy: &DelegatePolicyOf<T>,
+		balances: Vec<Balance>,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		for (limit, before) in policy.spend_limits.iter().zip(balances) {
+			let spent =
+				before.saturating_sub(T::MultiCurrency::balance(limit.asset_id, futurepass));
+			if spent.is_zero() {
+				continue
+			}
+			DelegateSpending::<T>::try_mutate(
+				futurepass,
+				(delegate, limit.asset_id),
+				|spending| -> DispatchResult {
+					if now >= spending.start.saturating_add(limit.window) {
+						*spending = SpendWindow { start: now, spent: 0 };
+					}
+					spending.spent = spending.spent.saturating_add(spent);
+					ensure!(spending.spent <= limit.amount, Error::<T>::SpendLimitExceeded);
+					Ok(())
+				},
+			)?;
+		}
+		Ok(())
+	}
+
 	pub fn do_create_futurepass(
 		funder: T::AccountId,
 		account: T::AccountId,
@@ -717,3 +1760,20 @@ where
 		<DefaultProxy<T>>::get(who)
 	}
 }
+
+/// ABI encode a `uint256`, as Solidity's `abi.encode`
+fn abi_encode_u256(value: U256) -> [u8; 32] {
+	let mut word = [0_u8; 32];
+	value.to_big_endian(&mut word);
+	word
+}
+
+/// ABI encode an `address`, as Solidity's `abi.encode`
+fn abi_encode_account<AccountId: Encode>(account: &AccountId) -> [u8; 32] {
+	let mut word = [0_u8; 32];
+	let encoded = account.encode();
+	// an account is encoded as its 20 byte address
+	let len = encoded.len().min(32);
+	word[32 - len..].copy_from_slice(&encoded[..len]);
+	word
+}
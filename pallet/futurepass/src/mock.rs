@@ -216,6 +216,12 @@ impl ProxyProvider<Test> for ProxyPalletProvider {
 parameter_types! {
 	/// 4 byte futurepass account prefix
 	pub const FuturepassPrefix: [u8; 4] = [0xFF; 4];
+	pub const MaxDelegatePolicyItems: u32 = 4;
+	pub const MaxGuardians: u32 = 3;
+	pub const RecoveryDelay: u64 = 10;
+	pub const RecoveryExpiry: u64 = 20;
+	pub const ChainId: u64 = 7672;
+	pub const MaxProxyBatchCalls: u32 = 4;
 }
 
 impl Config for Test {
@@ -227,8 +233,14 @@ impl Config for Test {
 	type WeightInfo = ();
 
 	type FuturepassMigrator = MockMigrationProvider;
-	#[cfg(feature = "runtime-benchmarks")]
 	type MultiCurrency = pallet_assets_ext::Pallet<Test>;
+	type NestedCalls = ();
+	type MaxDelegatePolicyItems = MaxDelegatePolicyItems;
+	type MaxGuardians = MaxGuardians;
+	type RecoveryDelay = RecoveryDelay;
+	type RecoveryExpiry = RecoveryExpiry;
+	type ChainId = ChainId;
+	type MaxProxyBatchCalls = MaxProxyBatchCalls;
 }
 
 pub struct MockMigrationProvider;
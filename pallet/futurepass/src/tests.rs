@@ -264,18 +264,76 @@ fn register_delegate_with_not_allowed_proxy_type_fails() {
 			let futurepass = Holders::<Test>::get(&owner).unwrap();
 			// fund the owner
 			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &owner, FP_DELEGATE_RESERVE);
-			// register_delegate with proxy_type != ProxyType::Any
-			assert_noop!(
-				Futurepass::register_delegate_with_signature(
+			// delegates can't be given ownership or no permission
+			for proxy_type in [ProxyType::Owner, ProxyType::NoPermission] {
+				assert_noop!(
+					Futurepass::register_delegate_with_signature(
+						RuntimeOrigin::signed(owner),
+						futurepass,
+						delegate1,
+						proxy_type,
+						deadline,
+						[0u8; 65],
+					),
+					Error::<Test>::PermissionDenied
+				);
+			}
+		});
+}
+
+#[test]
+fn register_delegate_with_scoped_proxy_type_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let deadline = 200;
+
+			// fund owner
+			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &owner, FP_CREATION_RESERVE);
+			// create FP
+			assert_ok!(Futurepass::create(RuntimeOrigin::signed(owner), owner));
+			let futurepass = Holders::<Test>::get(&owner).unwrap();
+
+			for proxy_type in [ProxyType::NonTransfer, ProxyType::Governance, ProxyType::Staking] {
+				let (signer, delegate) = create_random_pair();
+				transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &owner, FP_DELEGATE_RESERVE);
+				let signature = signer
+					.sign_prehashed(
+						&Futurepass::generate_add_delegate_eth_signed_message(
+							&futurepass,
+							&delegate,
+							&proxy_type,
+							&deadline,
+						)
+						.unwrap()
+						.1,
+					)
+					.0;
+				assert_ok!(Futurepass::register_delegate_with_signature(
 					RuntimeOrigin::signed(owner),
 					futurepass,
-					delegate1,
-					ProxyType::NonTransfer,
+					delegate,
+					proxy_type,
 					deadline,
-					[0u8; 65],
-				),
-				Error::<Test>::PermissionDenied
-			);
+					signature,
+				));
+				System::assert_has_event(
+					Event::<Test>::DelegateRegistered { futurepass, delegate, proxy_type }.into(),
+				);
+
+				assert!(<Test as Config>::Proxy::exists(&futurepass, &delegate, Some(proxy_type)));
+				assert!(!<Test as Config>::Proxy::exists(
+					&futurepass,
+					&delegate,
+					Some(ProxyType::Any)
+				));
+			}
 		});
 }
 
@@ -360,7 +418,7 @@ fn register_delegate_fails_on_signature_mismatch() {
 					deadline,
 					signature,
 				),
-				Error::<Test>::PermissionDenied
+				Error::<Test>::RegisterDelegateSignerMismatch
 			);
 			assert_noop!(
 				Futurepass::register_delegate_with_signature(
@@ -550,7 +608,12 @@ fn unregister_delegate_by_owner_works() {
 			));
 			// assert event
 			System::assert_has_event(
-				Event::<Test>::DelegateUnregistered { futurepass, delegate }.into(),
+				Event::<Test>::DelegateUnregistered {
+					futurepass,
+					delegate,
+					reason: UnregisterReason::Unregistered,
+				}
+				.into(),
 			);
 
 			// check the reserved amount has been received by the caller. i.e the owner
@@ -623,7 +686,12 @@ fn unregister_delegate_by_the_delegate_works() {
 			));
 			// assert event
 			System::assert_has_event(
-				Event::<Test>::DelegateUnregistered { futurepass, delegate }.into(),
+				Event::<Test>::DelegateUnregistered {
+					futurepass,
+					delegate,
+					reason: UnregisterReason::Unregistered,
+				}
+				.into(),
 			);
 			// check the reserved amount has been received by the caller. i.e the delegate
 			assert_eq!(AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &delegate), FP_DELEGATE_RESERVE);
@@ -1700,7 +1768,12 @@ fn whitelist_works() {
 				Event::<Test>::ProxyExecuted { delegate: owner, result: Ok(()) }.into(),
 			);
 			System::assert_has_event(
-				Event::<Test>::DelegateUnregistered { futurepass, delegate }.into(),
+				Event::<Test>::DelegateUnregistered {
+					futurepass,
+					delegate,
+					reason: UnregisterReason::Unregistered,
+				}
+				.into(),
 			);
 			// check delegate is not a delegate
 			assert_eq!(
@@ -1880,6 +1953,1077 @@ fn delegate_can_not_call_whitelist_via_proxy_extrinsic() {
 		});
 }
 
+/// Create a futurepass for `owner` with a registered `ProxyType::Any` delegate
+fn setup_futurepass_with_delegate(funder: &AccountId, owner: &AccountId) -> (AccountId, AccountId) {
+	let (signer, delegate) = create_random_pair();
+	let proxy_type = ProxyType::Any;
+	let deadline = 200;
+
+	transfer_funds(MOCK_NATIVE_ASSET_ID, funder, owner, FP_CREATION_RESERVE + FP_DELEGATE_RESERVE);
+	assert_ok!(Futurepass::create(RuntimeOrigin::signed(*owner), *owner));
+	let futurepass = Holders::<Test>::get(owner).unwrap();
+
+	let signature = signer
+		.sign_prehashed(
+			&Futurepass::generate_add_delegate_eth_signed_message(
+				&futurepass,
+				&delegate,
+				&proxy_type,
+				&deadline,
+			)
+			.unwrap()
+			.1,
+		)
+		.0;
+	assert_ok!(Futurepass::register_delegate_with_signature(
+		RuntimeOrigin::signed(*owner),
+		futurepass,
+		delegate,
+		proxy_type,
+		deadline,
+		signature,
+	));
+	(futurepass, delegate)
+}
+
+/// A policy permitting `calls` with a spend limit of the native asset
+fn delegate_policy(
+	calls: Vec<CallPermission>,
+	amount: Balance,
+	window: u64,
+) -> DelegatePolicyOf<Test> {
+	DelegatePolicy {
+		calls: BoundedVec::truncate_from(calls),
+		evm_calls: Default::default(),
+		spend_limits: BoundedVec::truncate_from(vec![SpendLimit {
+			asset_id: MOCK_NATIVE_ASSET_ID,
+			amount,
+			window,
+		}]),
+	}
+}
+
+fn sign_proxy_call(
+	signer: &sp_core::ecdsa::Pair,
+	futurepass: &AccountId,
+	delegate: &AccountId,
+	call: &MockCall,
+	nonce: u32,
+	deadline: u32,
+	futurepass_pays_fee: bool,
+) -> [u8; 65] {
+	let struct_hash =
+		Futurepass::proxy_call_struct_hash(delegate, call, nonce, deadline, futurepass_pays_fee);
+	signer
+		.sign_prehashed(&Futurepass::signed_proxy_call_digest(futurepass, struct_hash))
+		.0
+}
+
+#[test]
+fn proxy_extrinsic_with_signature_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let relayer = create_account(3);
+			let (signer, delegate) = create_random_pair();
+			let (other_signer, _) = create_random_pair();
+			transfer_funds(
+				MOCK_NATIVE_ASSET_ID,
+				&funder,
+				&owner,
+				FP_CREATION_RESERVE + FP_DELEGATE_RESERVE,
+			);
+			assert_ok!(Futurepass::create(RuntimeOrigin::signed(owner), owner));
+			let futurepass = Holders::<Test>::get(&owner).unwrap();
+			assert_ok!(<Test as Config>::Proxy::add_delegate(
+				&owner,
+				&futurepass,
+				&delegate,
+				&ProxyType::Any.into()
+			));
+			System::set_block_number(5);
+
+			let call =
+				MockCall::System(frame_system::Call::remark_with_event { remark: b"hi".to_vec() });
+			let proxy_call = |call: &MockCall, nonce, deadline, signature| {
+				Futurepass::proxy_extrinsic_with_signature(
+					RuntimeOrigin::signed(relayer),
+					futurepass,
+					delegate,
+					Box::new(call.clone()),
+					nonce,
+					deadline,
+					false,
+					signature,
+				)
+			};
+
+			// invalid nonce, deadline or signer
+			let signature = sign_proxy_call(&signer, &futurepass, &delegate, &call, 1, 10, false);
+			assert_noop!(proxy_call(&call, 1, 10, signature), Error::<Test>::InvalidNonce);
+			let signature = sign_proxy_call(&signer, &futurepass, &delegate, &call, 0, 4, false);
+			assert_noop!(proxy_call(&call, 0, 4, signature), Error::<Test>::ExpiredDeadline);
+			let signature =
+				sign_proxy_call(&other_signer, &futurepass, &delegate, &call, 0, 10, false);
+			assert_noop!(
+				proxy_call(&call, 0, 10, signature),
+				Error::<Test>::ProxyCallSignerMismatch
+			);
+			// the relayer can't change the signed parameters
+			let signature = sign_proxy_call(&signer, &futurepass, &delegate, &call, 0, 10, true);
+			assert_noop!(
+				proxy_call(&call, 0, 10, signature),
+				Error::<Test>::ProxyCallSignerMismatch
+			);
+
+			let signature = sign_proxy_call(&signer, &futurepass, &delegate, &call, 0, 10, false);
+			assert_ok!(proxy_call(&call, 0, 10, signature));
+			System::assert_has_event(
+				frame_system::Event::<Test>::Remarked {
+					sender: futurepass,
+					hash: H256::from(sp_io::hashing::blake2_256(b"hi")),
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::<Test>::SignedProxyExecuted {
+					futurepass,
+					delegate,
+					nonce: 0,
+					result: Ok(()),
+				}
+				.into(),
+			);
+			assert_eq!(SignedProxyNonces::<Test>::get(&futurepass, &delegate), 1);
+
+			// the signature can't be replayed
+			assert_noop!(proxy_call(&call, 0, 10, signature), Error::<Test>::InvalidNonce);
+
+			// the nonce is used when the call fails
+			let owner_call = MockCall::Futurepass(Call::transfer_futurepass {
+				current_owner: owner,
+				new_owner: Some(delegate),
+			});
+			let signature =
+				sign_proxy_call(&signer, &futurepass, &delegate, &owner_call, 1, 10, false);
+			assert_ok!(proxy_call(&owner_call, 1, 10, signature));
+			System::assert_last_event(
+				Event::<Test>::SignedProxyExecuted {
+					futurepass,
+					delegate,
+					nonce: 1,
+					result: Err(Error::<Test>::NotFuturepassOwner.into()),
+				}
+				.into(),
+			);
+			assert_eq!(SignedProxyNonces::<Test>::get(&futurepass, &delegate), 2);
+			assert_eq!(Holders::<Test>::get(&owner), Some(futurepass));
+		});
+}
+
+#[test]
+fn proxy_extrinsic_batch_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let other = create_account(4);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &futurepass, 1000);
+
+			let transfer_amount: Balance = 100;
+			let transfer_call = MockCall::Balances(pallet_balances::Call::transfer {
+				dest: other,
+				value: transfer_amount,
+			});
+			let remark_call =
+				MockCall::System(frame_system::Call::remark_with_event { remark: b"hi".to_vec() });
+			// the delegate may not transfer the futurepass
+			let owner_call = MockCall::Futurepass(Call::transfer_futurepass {
+				current_owner: owner,
+				new_owner: Some(delegate),
+			});
+
+			// too many calls
+			assert_noop!(
+				Futurepass::proxy_extrinsic_batch(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					vec![remark_call.clone(); 5],
+					true,
+				),
+				Error::<Test>::TooManyCalls
+			);
+
+			// atomic batch is reverted if a call fails
+			assert_noop!(
+				Futurepass::proxy_extrinsic_batch(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					vec![transfer_call.clone(), owner_call.clone()],
+					true,
+				),
+				Error::<Test>::NotFuturepassOwner
+			);
+
+			assert_ok!(Futurepass::proxy_extrinsic_batch(
+				RuntimeOrigin::signed(delegate),
+				futurepass,
+				vec![transfer_call.clone(), remark_call.clone()],
+				true,
+			));
+			assert_eq!(AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &other), transfer_amount);
+			for index in 0..2 {
+				System::assert_has_event(
+					Event::<Test>::ProxyBatchCallExecuted {
+						futurepass,
+						delegate,
+						index,
+						result: Ok(()),
+					}
+					.into(),
+				);
+			}
+
+			// best-effort batch skips the failed calls
+			assert_ok!(Futurepass::proxy_extrinsic_batch(
+				RuntimeOrigin::signed(delegate),
+				futurepass,
+				vec![transfer_call, owner_call, remark_call],
+				false,
+			));
+			assert_eq!(AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &other), 2 * transfer_amount);
+			assert_eq!(Holders::<Test>::get(&owner), Some(futurepass));
+			System::assert_has_event(
+				Event::<Test>::ProxyBatchCallExecuted {
+					futurepass,
+					delegate,
+					index: 1,
+					result: Err(Error::<Test>::NotFuturepassOwner.into()),
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::<Test>::ProxyBatchCallExecuted {
+					futurepass,
+					delegate,
+					index: 2,
+					result: Ok(()),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn set_delegate_policy_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let other = create_account(3);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			let policy = delegate_policy(vec![], 100, 10);
+
+			// only the owner or futurepass can set a policy
+			assert_noop!(
+				Futurepass::set_delegate_policy(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					delegate,
+					Some(policy.clone()),
+				),
+				Error::<Test>::NotFuturepassOwner
+			);
+			// the owner can't be restricted
+			assert_noop!(
+				Futurepass::set_delegate_policy(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					owner,
+					Some(policy.clone()),
+				),
+				Error::<Test>::PermissionDenied
+			);
+			assert_noop!(
+				Futurepass::set_delegate_policy(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					other,
+					Some(policy.clone()),
+				),
+				Error::<Test>::DelegateNotRegistered
+			);
+			// spend limits must have a window and be unique per asset
+			assert_noop!(
+				Futurepass::set_delegate_policy(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					delegate,
+					Some(delegate_policy(vec![], 100, 0)),
+				),
+				Error::<Test>::InvalidSpendLimit
+			);
+			let mut duplicate_limits = policy.clone();
+			assert_ok!(duplicate_limits.spend_limits.try_push(policy.spend_limits[0]));
+			assert_noop!(
+				Futurepass::set_delegate_policy(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					delegate,
+					Some(duplicate_limits),
+				),
+				Error::<Test>::InvalidSpendLimit
+			);
+
+			assert_ok!(Futurepass::set_delegate_policy(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(policy.clone()),
+			));
+			System::assert_last_event(
+				Event::<Test>::DelegatePolicySet {
+					futurepass,
+					delegate,
+					policy: Some(policy.clone()),
+				}
+				.into(),
+			);
+			assert_eq!(DelegatePolicies::<Test>::get(&futurepass, &delegate), Some(policy.clone()));
+
+			// removed
+			assert_ok!(Futurepass::set_delegate_policy(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				None,
+			));
+			System::assert_last_event(
+				Event::<Test>::DelegatePolicySet { futurepass, delegate, policy: None }.into(),
+			);
+			assert!(!DelegatePolicies::<Test>::contains_key(&futurepass, &delegate));
+
+			// removed with the delegate
+			assert_ok!(Futurepass::set_delegate_policy(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(policy),
+			));
+			DelegateSpending::<Test>::insert(
+				&futurepass,
+				(delegate, MOCK_NATIVE_ASSET_ID),
+				SpendWindow { start: 1, spent: 50 },
+			);
+			assert_ok!(Futurepass::unregister_delegate(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+			));
+			assert!(!DelegatePolicies::<Test>::contains_key(&futurepass, &delegate));
+			assert!(!DelegateSpending::<Test>::contains_key(
+				&futurepass,
+				(delegate, MOCK_NATIVE_ASSET_ID)
+			));
+		});
+}
+
+#[test]
+fn proxy_extrinsic_with_delegate_policy() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let other = create_account(3);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &futurepass, 1_000);
+
+			let transfer_call = Box::new(MockCall::Balances(pallet_balances::Call::transfer {
+				dest: other,
+				value: 100,
+			}));
+			let remark_call =
+				Box::new(MockCall::System(frame_system::Call::remark { remark: b"hi".to_vec() }));
+			let encoded_transfer = transfer_call.encode();
+			let transfer_permission = CallPermission {
+				pallet_index: encoded_transfer[0],
+				call_index: Some(encoded_transfer[1]),
+			};
+
+			// the delegate may transfer up to 150 per 10 blocks
+			assert_ok!(Futurepass::set_delegate_policy(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(delegate_policy(vec![transfer_permission], 150, 10)),
+			));
+			System::set_block_number(1);
+
+			assert_noop!(
+				Futurepass::proxy_extrinsic(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					remark_call.clone(),
+				),
+				Error::<Test>::CallNotPermitted
+			);
+			assert_ok!(Futurepass::proxy_extrinsic(
+				RuntimeOrigin::signed(delegate),
+				futurepass,
+				transfer_call.clone(),
+			));
+			assert_eq!(
+				DelegateSpending::<Test>::get(&futurepass, (delegate, MOCK_NATIVE_ASSET_ID)),
+				SpendWindow { start: 1, spent: 100 }
+			);
+			assert_noop!(
+				Futurepass::proxy_extrinsic(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					transfer_call.clone(),
+				),
+				Error::<Test>::SpendLimitExceeded
+			);
+			assert_eq!(AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &other), 100);
+
+			// the owner is not restricted
+			assert_ok!(Futurepass::proxy_extrinsic(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				remark_call,
+			));
+
+			// the spending is reset in the next window
+			System::set_block_number(11);
+			assert_ok!(Futurepass::proxy_extrinsic(
+				RuntimeOrigin::signed(delegate),
+				futurepass,
+				transfer_call,
+			));
+			assert_eq!(
+				DelegateSpending::<Test>::get(&futurepass, (delegate, MOCK_NATIVE_ASSET_ID)),
+				SpendWindow { start: 11, spent: 100 }
+			);
+			assert_eq!(AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &other), 200);
+		});
+}
+
+#[test]
+fn set_delegate_expiry_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let other = create_account(3);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			System::set_block_number(5);
+
+			// only the owner or futurepass can set an expiry
+			assert_noop!(
+				Futurepass::set_delegate_expiry(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					delegate,
+					Some(10),
+				),
+				Error::<Test>::NotFuturepassOwner
+			);
+			// the owner can't expire
+			assert_noop!(
+				Futurepass::set_delegate_expiry(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					owner,
+					Some(10),
+				),
+				Error::<Test>::PermissionDenied
+			);
+			assert_noop!(
+				Futurepass::set_delegate_expiry(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					other,
+					Some(10),
+				),
+				Error::<Test>::DelegateNotRegistered
+			);
+			assert_noop!(
+				Futurepass::set_delegate_expiry(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					delegate,
+					Some(5),
+				),
+				Error::<Test>::InvalidExpiry
+			);
+
+			assert_ok!(Futurepass::set_delegate_expiry(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(10),
+			));
+			System::assert_last_event(
+				Event::<Test>::DelegateExpirySet { futurepass, delegate, expiry: Some(10) }.into(),
+			);
+			assert_eq!(DelegateExpiry::<Test>::get(&futurepass, &delegate), Some(10));
+			assert!(DelegateExpirySchedule::<Test>::contains_key(10, (futurepass, delegate)));
+			assert_eq!(NextExpiryCheckBlock::<Test>::get(), 5);
+
+			// changing the expiry reschedules the removal
+			assert_ok!(Futurepass::set_delegate_expiry(
+				RuntimeOrigin::signed(futurepass),
+				futurepass,
+				delegate,
+				Some(20),
+			));
+			assert!(!DelegateExpirySchedule::<Test>::contains_key(10, (futurepass, delegate)));
+			assert!(DelegateExpirySchedule::<Test>::contains_key(20, (futurepass, delegate)));
+
+			// removed
+			assert_ok!(Futurepass::set_delegate_expiry(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				None,
+			));
+			System::assert_last_event(
+				Event::<Test>::DelegateExpirySet { futurepass, delegate, expiry: None }.into(),
+			);
+			assert!(!DelegateExpiry::<Test>::contains_key(&futurepass, &delegate));
+			assert!(!DelegateExpirySchedule::<Test>::contains_key(20, (futurepass, delegate)));
+
+			// removed with the delegate
+			assert_ok!(Futurepass::set_delegate_expiry(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(10),
+			));
+			assert_ok!(Futurepass::unregister_delegate(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+			));
+			assert!(!DelegateExpiry::<Test>::contains_key(&futurepass, &delegate));
+			assert!(!DelegateExpirySchedule::<Test>::contains_key(10, (futurepass, delegate)));
+		});
+}
+
+#[test]
+fn expired_delegate_is_removed() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			let remark_call =
+				Box::new(MockCall::System(frame_system::Call::remark { remark: b"hi".to_vec() }));
+			System::set_block_number(5);
+
+			assert_ok!(Futurepass::set_delegate_expiry(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(10),
+			));
+			assert_ok!(Futurepass::proxy_extrinsic(
+				RuntimeOrigin::signed(delegate),
+				futurepass,
+				remark_call.clone(),
+			));
+
+			// the delegate can't make calls from the expiry block
+			System::set_block_number(10);
+			assert_noop!(
+				Futurepass::proxy_extrinsic(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					remark_call.clone(),
+				),
+				Error::<Test>::DelegateExpired
+			);
+			assert_noop!(
+				Futurepass::set_delegate_expiry(
+					RuntimeOrigin::signed(owner),
+					futurepass,
+					delegate,
+					Some(20),
+				),
+				Error::<Test>::DelegateExpired
+			);
+
+			// no weight to remove the delegate
+			assert_eq!(Futurepass::on_idle(10, Weight::zero()), Weight::zero());
+			assert!(<Test as Config>::Proxy::exists(&futurepass, &delegate, None));
+
+			let owner_balance = AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &owner);
+			Futurepass::on_idle(10, Weight::from_ref_time(u64::MAX));
+			assert!(!<Test as Config>::Proxy::exists(&futurepass, &delegate, None));
+			assert!(!DelegateExpiry::<Test>::contains_key(&futurepass, &delegate));
+			assert!(!DelegateExpirySchedule::<Test>::contains_key(10, (futurepass, delegate)));
+			assert_eq!(NextExpiryCheckBlock::<Test>::get(), 11);
+			System::assert_last_event(
+				Event::<Test>::DelegateUnregistered {
+					futurepass,
+					delegate,
+					reason: UnregisterReason::Expired,
+				}
+				.into(),
+			);
+			// the delegate deposit is refunded to the owner
+			assert_eq!(
+				AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &owner),
+				owner_balance + FP_DELEGATE_RESERVE
+			);
+			// the owner is still a delegate
+			assert_ok!(Futurepass::proxy_extrinsic(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				remark_call,
+			));
+		});
+}
+
+#[test]
+fn set_recovery_config_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			let guardians: Vec<AccountId> = (10..13).map(create_account).collect();
+			let bounded = |accounts: Vec<AccountId>| BoundedVec::truncate_from(accounts);
+
+			// only the owner or futurepass can set guardians
+			assert_noop!(
+				Futurepass::set_recovery_config(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					bounded(guardians.clone()),
+					2,
+				),
+				Error::<Test>::NotFuturepassOwner
+			);
+			for (invalid_guardians, threshold) in [
+				(guardians.clone(), 0),
+				(guardians.clone(), 4),
+				(vec![guardians[0], guardians[0]], 1),
+				(vec![guardians[0], owner], 1),
+				(vec![guardians[0], futurepass], 1),
+			] {
+				assert_noop!(
+					Futurepass::set_recovery_config(
+						RuntimeOrigin::signed(owner),
+						futurepass,
+						bounded(invalid_guardians),
+						threshold,
+					),
+					Error::<Test>::InvalidRecoveryConfig
+				);
+			}
+
+			assert_ok!(Futurepass::set_recovery_config(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				bounded(guardians.clone()),
+				2,
+			));
+			System::assert_last_event(
+				Event::<Test>::RecoveryConfigSet {
+					futurepass,
+					guardians: bounded(guardians.clone()),
+					threshold: 2,
+				}
+				.into(),
+			);
+			assert_eq!(
+				RecoveryConfigs::<Test>::get(&futurepass),
+				Some(RecoveryConfig { guardians: bounded(guardians.clone()), threshold: 2 })
+			);
+
+			// changing the guardians cancels a recovery in progress
+			assert_ok!(Futurepass::initiate_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				create_account(20),
+			));
+			assert_ok!(Futurepass::set_recovery_config(
+				RuntimeOrigin::signed(futurepass),
+				futurepass,
+				bounded(vec![guardians[1]]),
+				1,
+			));
+			assert!(!ActiveRecoveries::<Test>::contains_key(&futurepass));
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[0]),
+					futurepass,
+					create_account(20),
+				),
+				Error::<Test>::NotGuardian
+			);
+
+			assert_ok!(Futurepass::remove_recovery_config(
+				RuntimeOrigin::signed(owner),
+				futurepass
+			));
+			System::assert_last_event(Event::<Test>::RecoveryConfigRemoved { futurepass }.into());
+			assert!(!RecoveryConfigs::<Test>::contains_key(&futurepass));
+			assert_noop!(
+				Futurepass::remove_recovery_config(RuntimeOrigin::signed(owner), futurepass),
+				Error::<Test>::RecoveryNotConfigured
+			);
+		});
+}
+
+#[test]
+fn futurepass_recovery_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let new_owner = create_account(3);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			let guardians: Vec<AccountId> = (10..13).map(create_account).collect();
+			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &guardians[0], FP_DELEGATE_RESERVE);
+			System::set_block_number(5);
+
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[0]),
+					futurepass,
+					new_owner,
+				),
+				Error::<Test>::RecoveryNotConfigured
+			);
+			assert_ok!(Futurepass::set_recovery_config(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				BoundedVec::truncate_from(guardians.clone()),
+				2,
+			));
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(delegate),
+					futurepass,
+					new_owner
+				),
+				Error::<Test>::NotGuardian
+			);
+			// the new owner can't hold a futurepass
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[0]),
+					futurepass,
+					owner
+				),
+				Error::<Test>::AccountAlreadyRegistered
+			);
+
+			// the owner can cancel a recovery
+			assert_ok!(Futurepass::initiate_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				new_owner,
+			));
+			assert_noop!(
+				Futurepass::cancel_recovery(RuntimeOrigin::signed(guardians[0]), futurepass),
+				Error::<Test>::NotFuturepassOwner
+			);
+			assert_ok!(Futurepass::cancel_recovery(RuntimeOrigin::signed(owner), futurepass));
+			System::assert_last_event(Event::<Test>::RecoveryCancelled { futurepass }.into());
+			assert_noop!(
+				Futurepass::approve_recovery(RuntimeOrigin::signed(guardians[1]), futurepass),
+				Error::<Test>::NoActiveRecovery
+			);
+
+			assert_ok!(Futurepass::initiate_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				new_owner,
+			));
+			System::assert_last_event(
+				Event::<Test>::RecoveryInitiated { futurepass, guardian: guardians[0], new_owner }
+					.into(),
+			);
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[1]),
+					futurepass,
+					new_owner,
+				),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			assert_noop!(
+				Futurepass::approve_recovery(RuntimeOrigin::signed(guardians[0]), futurepass),
+				Error::<Test>::RecoveryAlreadyApproved
+			);
+
+			// the recovery needs the threshold of approvals and the delay to pass
+			System::set_block_number(5 + RecoveryDelay::get());
+			assert_noop!(
+				Futurepass::claim_recovery(RuntimeOrigin::signed(guardians[0]), futurepass, false),
+				Error::<Test>::InsufficientApprovals
+			);
+			System::set_block_number(5);
+			assert_ok!(Futurepass::approve_recovery(
+				RuntimeOrigin::signed(guardians[1]),
+				futurepass
+			));
+			System::assert_last_event(
+				Event::<Test>::RecoveryApproved { futurepass, guardian: guardians[1] }.into(),
+			);
+			assert_noop!(
+				Futurepass::claim_recovery(RuntimeOrigin::signed(guardians[0]), futurepass, false),
+				Error::<Test>::RecoveryDelayNotPassed
+			);
+			System::set_block_number(5 + RecoveryDelay::get());
+			assert_noop!(
+				Futurepass::claim_recovery(RuntimeOrigin::signed(delegate), futurepass, false),
+				Error::<Test>::NotGuardian
+			);
+
+			assert_ok!(Futurepass::claim_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				false
+			));
+			System::assert_last_event(
+				Event::<Test>::FuturepassRecovered { futurepass, old_owner: owner, new_owner }
+					.into(),
+			);
+			assert_eq!(Holders::<Test>::get(&new_owner), Some(futurepass));
+			assert!(!Holders::<Test>::contains_key(&owner));
+			assert!(!ActiveRecoveries::<Test>::contains_key(&futurepass));
+			assert_eq!(<Test as Config>::Proxy::owner(&futurepass), Some(new_owner));
+			assert!(!<Test as Config>::Proxy::exists(&futurepass, &owner, None));
+			// other delegates are kept
+			assert!(<Test as Config>::Proxy::exists(&futurepass, &delegate, None));
+			// the guardian's deposit for the new owner is refunded from the old owner's
+			assert_eq!(
+				AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &guardians[0]),
+				FP_DELEGATE_RESERVE
+			);
+
+			// the old owner has lost control of the futurepass
+			assert_noop!(
+				Futurepass::cancel_recovery(RuntimeOrigin::signed(owner), futurepass),
+				Error::<Test>::NotFuturepassOwner
+			);
+			assert_ok!(Futurepass::proxy_extrinsic(
+				RuntimeOrigin::signed(new_owner),
+				futurepass,
+				Box::new(MockCall::System(frame_system::Call::remark { remark: b"hi".to_vec() })),
+			));
+		});
+}
+
+#[test]
+fn claim_recovery_clear_delegates_works() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let new_owner = create_account(3);
+			let (futurepass, delegate) = setup_futurepass_with_delegate(&funder, &owner);
+			let guardians: Vec<AccountId> = (10..13).map(create_account).collect();
+			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &guardians[0], FP_DELEGATE_RESERVE);
+			System::set_block_number(5);
+
+			assert_ok!(Futurepass::set_delegate_expiry(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				delegate,
+				Some(100),
+			));
+			assert_ok!(Futurepass::set_recovery_config(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				BoundedVec::truncate_from(guardians.clone()),
+				1,
+			));
+			assert_ok!(Futurepass::initiate_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				new_owner,
+			));
+			System::set_block_number(5 + RecoveryDelay::get());
+
+			assert_ok!(Futurepass::claim_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				true
+			));
+			assert_eq!(Holders::<Test>::get(&new_owner), Some(futurepass));
+			assert_eq!(<Test as Config>::Proxy::owner(&futurepass), Some(new_owner));
+			// other delegates are removed
+			assert!(!<Test as Config>::Proxy::exists(&futurepass, &delegate, None));
+			assert_eq!(<Test as Config>::Proxy::delegates(&futurepass).len(), 1);
+			assert!(!DelegateExpiry::<Test>::contains_key(&futurepass, &delegate));
+			// the guardian is refunded the removed delegate's deposit
+			assert_eq!(
+				AssetsExt::balance(MOCK_NATIVE_ASSET_ID, &guardians[0]),
+				2 * FP_DELEGATE_RESERVE
+			);
+		});
+}
+
+#[test]
+fn unapproved_recovery_expires() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let new_owner = create_account(3);
+			let rogue_owner = create_account(4);
+			let (futurepass, _) = setup_futurepass_with_delegate(&funder, &owner);
+			let guardians: Vec<AccountId> = (10..13).map(create_account).collect();
+			System::set_block_number(5);
+
+			assert_ok!(Futurepass::set_recovery_config(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				BoundedVec::truncate_from(guardians.clone()),
+				2,
+			));
+			// a rogue guardian initiates a recovery no other guardian approves
+			assert_ok!(Futurepass::initiate_recovery(
+				RuntimeOrigin::signed(guardians[2]),
+				futurepass,
+				rogue_owner,
+			));
+			System::set_block_number(5 + RecoveryExpiry::get() - 1);
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[0]),
+					futurepass,
+					new_owner,
+				),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+
+			// once expired it is replaced
+			System::set_block_number(5 + RecoveryExpiry::get());
+			assert_ok!(Futurepass::initiate_recovery(
+				RuntimeOrigin::signed(guardians[0]),
+				futurepass,
+				new_owner,
+			));
+			assert_eq!(ActiveRecoveries::<Test>::get(&futurepass).unwrap().new_owner, new_owner);
+
+			// a recovery with the threshold of approvals does not expire
+			assert_ok!(Futurepass::approve_recovery(
+				RuntimeOrigin::signed(guardians[1]),
+				futurepass
+			));
+			System::set_block_number(5 + 2 * RecoveryExpiry::get());
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[2]),
+					futurepass,
+					rogue_owner,
+				),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+		});
+}
+
+#[test]
+fn transfer_futurepass_removes_recovery_config() {
+	let funder = create_account(1);
+	let endowed = [(funder, 1_000_000)];
+
+	TestExt::<Test>::default()
+		.with_balances(&endowed)
+		.with_xrp_balances(&endowed)
+		.build()
+		.execute_with(|| {
+			let owner = create_account(2);
+			let buyer = create_account(3);
+			let rogue_owner = create_account(4);
+			let (futurepass, _) = setup_futurepass_with_delegate(&funder, &owner);
+			let guardians: Vec<AccountId> = (10..13).map(create_account).collect();
+
+			assert_ok!(Futurepass::set_recovery_config(
+				RuntimeOrigin::signed(owner),
+				futurepass,
+				BoundedVec::truncate_from(guardians.clone()),
+				1,
+			));
+			transfer_funds(MOCK_NATIVE_ASSET_ID, &funder, &owner, FP_DELEGATE_RESERVE);
+			assert_ok!(Futurepass::transfer_futurepass(
+				RuntimeOrigin::signed(owner),
+				owner,
+				Some(buyer)
+			));
+			assert!(!RecoveryConfigs::<Test>::contains_key(&futurepass));
+
+			// the previous owner's guardians can't take the futurepass from the new owner
+			assert_noop!(
+				Futurepass::initiate_recovery(
+					RuntimeOrigin::signed(guardians[0]),
+					futurepass,
+					rogue_owner,
+				),
+				Error::<Test>::RecoveryNotConfigured
+			);
+			assert_eq!(Holders::<Test>::get(&buyer), Some(futurepass));
+		});
+}
+
 #[test]
 fn futurepass_admin_migrator_set_by_sudo() {
 	let futurepass_admin_migrator = create_account(1337);
@@ -0,0 +1,169 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Futurepass module types
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::Get, CloneNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use seed_primitives::{AssetId, Balance};
+use sp_core::H160;
+use sp_runtime::BoundedVec;
+use sp_std::fmt::Debug;
+
+/// A runtime call a delegate may make, matched on the call's pallet and call index i.e. the first
+/// two bytes of the SCALE encoded call
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallPermission {
+	/// Index of the pallet in the runtime
+	pub pallet_index: u8,
+	/// Index of the call in the pallet, `None` allows all calls of the pallet
+	pub call_index: Option<u8>,
+}
+
+impl CallPermission {
+	/// Whether the SCALE encoded `call` is permitted
+	pub fn matches(&self, call: &[u8]) -> bool {
+		match call {
+			[pallet_index, call_index, ..] =>
+				*pallet_index == self.pallet_index &&
+					self.call_index.map_or(true, |index| index == *call_index),
+			_ => false,
+		}
+	}
+}
+
+/// An EVM call a delegate may make via the futurepass precompile
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EvmCallPermission {
+	/// The contract or precompile address
+	pub target: H160,
+	/// The 4 byte function selector, `None` allows all calls to `target`
+	pub selector: Option<[u8; 4]>,
+}
+
+impl EvmCallPermission {
+	/// Whether a call to `target` with `call_data` is permitted
+	pub fn matches(&self, target: &H160, call_data: &[u8]) -> bool {
+		*target == self.target &&
+			self.selector.map_or(true, |selector| call_data.get(..4) == Some(&selector[..]))
+	}
+}
+
+/// A cap on the amount of an asset a delegate may spend from the futurepass per window of blocks
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SpendLimit<BlockNumber> {
+	/// The asset which is capped
+	pub asset_id: AssetId,
+	/// The maximum amount which may be spent within a window
+	pub amount: Balance,
+	/// The length of a window in blocks
+	pub window: BlockNumber,
+}
+
+/// The amount of an asset spent by a delegate in the current window
+#[derive(
+	Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct SpendWindow<BlockNumber> {
+	/// The first block of the window
+	pub start: BlockNumber,
+	/// The amount spent since `start`
+	pub spent: Balance,
+}
+
+/// Restricts the calls a delegate may make through a futurepass, on top of the delegate's
+/// `ProxyType`
+#[derive(
+	PartialEqNoBound, RuntimeDebugNoBound, Decode, Encode, CloneNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxItems))]
+pub struct DelegatePolicy<BlockNumber, MaxItems>
+where
+	BlockNumber: Debug + PartialEq + Clone,
+	MaxItems: Get<u32>,
+{
+	/// The runtime calls the delegate may make via `proxy_extrinsic`
+	pub calls: BoundedVec<CallPermission, MaxItems>,
+	/// The EVM calls the delegate may make via the futurepass precompile
+	pub evm_calls: BoundedVec<EvmCallPermission, MaxItems>,
+	/// Caps on the assets the delegate's calls may spend from the futurepass
+	pub spend_limits: BoundedVec<SpendLimit<BlockNumber>, MaxItems>,
+}
+
+impl<BlockNumber, MaxItems> DelegatePolicy<BlockNumber, MaxItems>
+where
+	BlockNumber: Debug + PartialEq + Clone,
+	MaxItems: Get<u32>,
+{
+	/// Whether the SCALE encoded `call` is permitted
+	pub fn allows_call(&self, call: &[u8]) -> bool {
+		self.calls.iter().any(|permission| permission.matches(call))
+	}
+
+	/// Whether an EVM call to `target` with `call_data` is permitted
+	pub fn allows_evm_call(&self, target: &H160, call_data: &[u8]) -> bool {
+		self.evm_calls.iter().any(|permission| permission.matches(target, call_data))
+	}
+}
+
+/// Why a delegate was removed from a futurepass
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum UnregisterReason {
+	/// Unregistered by the owner, the futurepass or the delegate
+	Unregistered,
+	/// The delegate's expiry block was reached
+	Expired,
+}
+
+/// The guardians who may recover a futurepass for a new owner
+#[derive(
+	PartialEqNoBound, RuntimeDebugNoBound, Decode, Encode, CloneNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxGuardians))]
+pub struct RecoveryConfig<AccountId, MaxGuardians>
+where
+	AccountId: Debug + PartialEq + Clone,
+	MaxGuardians: Get<u32>,
+{
+	/// The accounts which may initiate and approve a recovery
+	pub guardians: BoundedVec<AccountId, MaxGuardians>,
+	/// The number of guardian approvals required to complete a recovery
+	pub threshold: u32,
+}
+
+/// A recovery of a futurepass in progress
+#[derive(
+	PartialEqNoBound, RuntimeDebugNoBound, Decode, Encode, CloneNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxGuardians))]
+pub struct ActiveRecovery<AccountId, BlockNumber, MaxGuardians>
+where
+	AccountId: Debug + PartialEq + Clone,
+	BlockNumber: Debug + PartialEq + Clone,
+	MaxGuardians: Get<u32>,
+{
+	/// The account which will become the futurepass owner
+	pub new_owner: AccountId,
+	/// The guardians which approved the recovery
+	pub approvals: BoundedVec<AccountId, MaxGuardians>,
+	/// The block the recovery was initiated
+	pub initiated: BlockNumber,
+}
@@ -16,6 +16,7 @@
 use crate as pallet_nft_peg;
 use frame_support::pallet_prelude::*;
 use seed_pallet_common::test_prelude::*;
+use seed_primitives::ethy::EthyChainId;
 
 construct_runtime!(
 	pub enum Test where
@@ -76,10 +77,16 @@ pub struct MockEthereumEventRouter;
 
 impl EthereumEventRouter for MockEthereumEventRouter {
 	/// Route an event to a handler at `destination`
+	/// - `chain_id` the EVM chain the event was observed on
 	/// - `source` the sender address on Ethereum
 	/// - `destination` the intended handler (pseudo) address
 	/// - `data` the Ethereum ABI encoded event data
-	fn route(source: &H160, destination: &H160, data: &[u8]) -> EventRouterResult {
+	fn route(
+		_chain_id: EthyChainId,
+		source: &H160,
+		destination: &H160,
+		data: &[u8],
+	) -> EventRouterResult {
 		// Route event to specific subscriber pallet
 		if destination == &<pallet_nft_peg::Pallet<Test> as EthereumEventSubscriber>::address() {
 			<pallet_nft_peg::Pallet<Test> as EthereumEventSubscriber>::process_event(source, data)
@@ -528,6 +528,17 @@ impl<T: Config> NFTExt for Pallet<T> {
 		Self::mint(RawOrigin::Signed(origin).into(), collection_id, quantity, token_owner)
 	}
 
+	fn do_mint_bridged(
+		owner: &Self::AccountId,
+		collection_id: CollectionUuid,
+		serial_numbers: Vec<SerialNumber>,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::NoCollectionFound);
+		Self::mint_bridged_token(owner, collection_id, serial_numbers)
+			.map(|_| ())
+			.map_err(|(_, e)| e)
+	}
+
 	fn do_transfer(
 		origin: Self::AccountId,
 		collection_id: CollectionUuid,
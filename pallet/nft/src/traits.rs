@@ -35,6 +35,14 @@ pub trait NFTExt {
 		token_owner: Option<Self::AccountId>,
 	) -> DispatchResult;
 
+	/// Mint the given `serial_numbers` in `collection_id` to `owner`, used for tokens bridged in
+	/// from another chain
+	fn do_mint_bridged(
+		owner: &Self::AccountId,
+		collection_id: CollectionUuid,
+		serial_numbers: Vec<SerialNumber>,
+	) -> DispatchResult;
+
 	fn do_transfer(
 		origin: Self::AccountId,
 		collection_id: CollectionUuid,
@@ -25,13 +25,18 @@ use frame_support::{
 	ensure,
 	pallet_prelude::*,
 	traits::{fungibles::Transfer, Get},
-	transactional,
+	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
 use pallet_nft::traits::NFTExt;
-use seed_pallet_common::Xls20MintRequest;
-use seed_primitives::{AssetId, Balance, CollectionUuid, MetadataScheme, SerialNumber, TokenCount};
-use sp_runtime::{traits::Zero, DispatchResult, SaturatedConversion};
+use seed_pallet_common::{Xls20Ext, Xls20MintRequest};
+use seed_primitives::{
+	AssetId, Balance, CollectionUuid, MetadataScheme, SerialNumber, TokenCount, TokenId,
+};
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	DispatchResult, SaturatedConversion,
+};
 use sp_std::prelude::*;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -47,9 +52,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-/// TokenId type for XLS-20 Token Ids
-/// See: https://github.com/XRPLF/XRPL-Standards/discussions/46
-pub type Xls20TokenId = [u8; 64];
+pub use seed_primitives::xrpl::Xls20TokenId;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -57,7 +60,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
@@ -78,6 +81,9 @@ pub mod pallet {
 		type NFTExt: NFTExt<AccountId = Self::AccountId>;
 		/// AssetId used to pay Xls20 Mint Fees
 		type Xls20PaymentAsset: Get<AssetId>;
+		/// This pallet's Id, used for deriving an account to hold tokens bridged to XRPL
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
 	}
 
 	/// The permissioned relayer
@@ -93,6 +99,10 @@ pub mod pallet {
 	pub type Xls20TokenMap<T> =
 		StorageDoubleMap<_, Twox64Concat, CollectionUuid, Twox64Concat, SerialNumber, Xls20TokenId>;
 
+	/// Maps from XLS-20 TokenId to TRN native token_id
+	#[pallet::storage]
+	pub type Xls20TokenIdMap<T> = StorageMap<_, Blake2_128Concat, Xls20TokenId, TokenId>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -131,6 +141,10 @@ pub mod pallet {
 		NoToken,
 		/// No the owner of the collection
 		NotCollectionOwner,
+		/// There is no XLS-20 mapping for this token
+		NoMappingExists,
+		/// The token is not locked for bridging to XRPL
+		TokenNotLocked,
 	}
 
 	#[pallet::call]
@@ -239,6 +253,7 @@ pub mod pallet {
 				);
 				// Insert mapping into storage
 				Xls20TokenMap::<T>::insert(collection_id, serial_number, xls20_token_id);
+				Xls20TokenIdMap::<T>::insert(xls20_token_id, (collection_id, *serial_number));
 			}
 
 			Self::deposit_event(Event::<T>::Xls20MappingSet {
@@ -251,6 +266,11 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The account holding tokens that have been bridged to XRPL
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
 	/// Pay additional fee to cover relayer costs for minting XLS-20 tokens
 	pub fn pay_xls20_fee(who: &T::AccountId, token_count: TokenCount) -> DispatchResult {
 		let xls20_mint_fee = Xls20MintFee::<T>::get();
@@ -316,3 +336,65 @@ impl<T: Config> Xls20MintRequest for Pallet<T> {
 		Ok(())
 	}
 }
+
+impl<T: Config> Xls20Ext for Pallet<T> {
+	type AccountId = T::AccountId;
+
+	fn lock_xls20_token(
+		who: &Self::AccountId,
+		token_id: TokenId,
+	) -> Result<Xls20TokenId, DispatchError> {
+		let (collection_id, serial_number) = token_id;
+		let xls20_token_id = Xls20TokenMap::<T>::get(collection_id, serial_number)
+			.ok_or(Error::<T>::NoMappingExists)?;
+		// The token is held by the pallet while it lives on XRPL
+		T::NFTExt::do_transfer(
+			who.clone(),
+			collection_id,
+			vec![serial_number],
+			Self::account_id(),
+		)?;
+		Ok(xls20_token_id)
+	}
+
+	fn unlock_xls20_token(
+		who: &Self::AccountId,
+		xls20_token_id: &Xls20TokenId,
+	) -> Result<TokenId, DispatchError> {
+		let token_id =
+			Xls20TokenIdMap::<T>::get(xls20_token_id).ok_or(Error::<T>::NoMappingExists)?;
+		match T::NFTExt::get_token_owner(&token_id) {
+			// The token was bridged out from Root and is held by the pallet
+			Some(owner) if owner == Self::account_id() => T::NFTExt::do_transfer(
+				Self::account_id(),
+				token_id.0,
+				vec![token_id.1],
+				who.clone(),
+			)?,
+			// The token has never existed on Root, mint it for the depositor
+			None => T::NFTExt::do_mint_bridged(who, token_id.0, vec![token_id.1])?,
+			Some(_) => return Err(Error::<T>::TokenNotLocked.into()),
+		}
+		Ok(token_id)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_benchmark_token(owner: &Self::AccountId) -> TokenId {
+		let metadata_scheme = MetadataScheme::try_from(b"https://google.com/".as_slice()).unwrap();
+		let collection_id = T::NFTExt::do_create_collection(
+			owner.clone(),
+			BoundedVec::truncate_from(b"Xls20 Collection".to_vec()),
+			1,
+			None,
+			None,
+			metadata_scheme,
+			None,
+			seed_primitives::nft::OriginChain::Root,
+		)
+		.unwrap();
+		let xls20_token_id: Xls20TokenId = [b'1'; 64];
+		Xls20TokenMap::<T>::insert(collection_id, 0, xls20_token_id);
+		Xls20TokenIdMap::<T>::insert(xls20_token_id, (collection_id, 0));
+		(collection_id, 0)
+	}
+}
@@ -77,6 +77,7 @@ impl pallet_nft::Config for Test {
 
 parameter_types! {
 	pub const MaxTokensPerXls20Mint: u32 = 1000;
+	pub const Xls20PalletId: PalletId = PalletId(*b"xls20brg");
 }
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -85,4 +86,5 @@ impl crate::Config for Test {
 	type WeightInfo = ();
 	type NFTExt = Nft;
 	type Xls20PaymentAsset = Xls20PaymentAsset;
+	type PalletId = Xls20PalletId;
 }
@@ -465,6 +465,10 @@ fn fulfill_xls20_mint_works() {
 				Xls20TokenMap::<Test>::get(collection_id, serial_number),
 				Some(*xls20_token_id)
 			);
+			assert_eq!(
+				Xls20TokenIdMap::<Test>::get(xls20_token_id),
+				Some((collection_id, *serial_number))
+			);
 		}
 
 		// Check event is thrown with new mappings
@@ -756,3 +760,104 @@ fn enable_xls20_compatibility_non_zero_issuance_fails() {
 		);
 	});
 }
+
+#[test]
+fn lock_and_unlock_xls20_token_works() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let collection_owner = create_account(10);
+		let relayer = create_account(11);
+		let recipient = create_account(12);
+		let collection_id = setup_xls20_collection(collection_owner, true);
+		let token_mappings = setup_token_mappings(vec![(
+			0,
+			"000b013a95f14b0e44f78a264e41713c64b5f89242540ee2bc8b858e00000d66",
+		)]);
+		let xls20_token_id = token_mappings[0].1;
+		let token_id = (collection_id, 0);
+
+		assert_ok!(Xls20::set_relayer(RawOrigin::Root.into(), relayer));
+		assert_ok!(Nft::mint(Some(collection_owner).into(), collection_id, 1, None));
+		assert_ok!(Xls20::fulfill_xls20_mint(
+			RawOrigin::Signed(relayer).into(),
+			collection_id,
+			token_mappings
+		));
+
+		// Only the token owner can lock the token
+		assert_noop!(
+			<Xls20 as Xls20Ext>::lock_xls20_token(&recipient, token_id),
+			pallet_nft::Error::<Test>::NotTokenOwner
+		);
+		// The token can't be unlocked until it has been locked
+		assert_noop!(
+			<Xls20 as Xls20Ext>::unlock_xls20_token(&recipient, &xls20_token_id),
+			Error::<Test>::TokenNotLocked
+		);
+
+		assert_eq!(
+			<Xls20 as Xls20Ext>::lock_xls20_token(&collection_owner, token_id),
+			Ok(xls20_token_id)
+		);
+		assert_eq!(Nft::get_token_owner(&token_id), Some(Xls20::account_id()));
+
+		assert_eq!(
+			<Xls20 as Xls20Ext>::unlock_xls20_token(&recipient, &xls20_token_id),
+			Ok(token_id)
+		);
+		assert_eq!(Nft::get_token_owner(&token_id), Some(recipient));
+	});
+}
+
+#[test]
+fn unlock_xls20_token_mints_token_not_on_root() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let collection_owner = create_account(10);
+		let recipient = create_account(12);
+		let collection_id = setup_xls20_collection(collection_owner, true);
+		let xls20_token_id = string_to_xls20_token(
+			"000b013a95f14b0e44f78a264e41713c64b5f89242540ee2bc8b858e00000d66",
+		);
+		let token_id = (collection_id, 0);
+
+		// The token is mapped but has never been minted on Root
+		Xls20TokenMap::<Test>::insert(collection_id, 0, xls20_token_id);
+		Xls20TokenIdMap::<Test>::insert(xls20_token_id, token_id);
+		assert_eq!(Nft::get_token_owner(&token_id), None);
+
+		assert_eq!(
+			<Xls20 as Xls20Ext>::unlock_xls20_token(&recipient, &xls20_token_id),
+			Ok(token_id)
+		);
+		assert_eq!(Nft::get_token_owner(&token_id), Some(recipient));
+		assert_eq!(CollectionInfo::<Test>::get(collection_id).unwrap().collection_issuance, 1);
+
+		// The minted token is now owned by the recipient and can't be unlocked again
+		assert_noop!(
+			<Xls20 as Xls20Ext>::unlock_xls20_token(&recipient, &xls20_token_id),
+			Error::<Test>::TokenNotLocked
+		);
+	});
+}
+
+#[test]
+fn lock_and_unlock_xls20_token_no_mapping_fails() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let collection_owner = create_account(10);
+		let collection_id = setup_xls20_collection(collection_owner, true);
+		assert_ok!(Nft::mint(Some(collection_owner).into(), collection_id, 1, None));
+
+		assert_noop!(
+			<Xls20 as Xls20Ext>::lock_xls20_token(&collection_owner, (collection_id, 0)),
+			Error::<Test>::NoMappingExists
+		);
+		assert_noop!(
+			<Xls20 as Xls20Ext>::unlock_xls20_token(
+				&collection_owner,
+				&string_to_xls20_token(
+					"000b013a95f14b0e44f78a264e41713c64b5f89242540ee2bc8b858e00000d66"
+				)
+			),
+			Error::<Test>::NoMappingExists
+		);
+	});
+}
@@ -0,0 +1,64 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Node-specific RPC methods for interaction with XRPL bridge module.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{Error as RpcError, RpcResult},
+	proc_macros::rpc,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_xrpl_bridge_rpc_runtime_api::{
+	self as runtime_api, XrplBridgeApi as XrplBridgeRuntimeApi,
+};
+
+/// XRPL bridge RPC methods.
+#[rpc(client, server, namespace = "xrplBridge")]
+pub trait XrplBridgeApi {
+	#[method(name = "doorTxFee")]
+	fn door_tx_fee(&self) -> RpcResult<u64>;
+}
+
+/// An implementation of XRPL bridge specific RPC methods.
+pub struct XrplBridge<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> XrplBridge<C, Block> {
+	/// Create new `XrplBridge` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		XrplBridge { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> XrplBridgeApiServer for XrplBridge<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: XrplBridgeRuntimeApi<Block>,
+{
+	fn door_tx_fee(&self) -> RpcResult<u64> {
+		let api = self.client.runtime_api();
+		let best = self.client.info().best_hash;
+		let at = BlockId::hash(best);
+		api.door_tx_fee(&at).map_err(|e| RpcError::to_call_error(e))
+	}
+}
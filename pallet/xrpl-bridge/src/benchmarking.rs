@@ -34,7 +34,7 @@ benchmarks! {
 		let relayer = account::<T>("Relayer");
 		let ledger_index = 0;
 		let transaction_hash: XrplTxHash = [0u8; 64].into();
-		let transaction = XrplTxData::Xls20;
+		let transaction = XrplTxData::Xls20 { token_id: [b'a'; 64], address: sp_core::H160::default() };
 		let timestamp = 100;
 
 		assert_ok!(XrplBridge::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone()));
@@ -112,6 +112,48 @@ benchmarks! {
 		assert_eq!(is_relayer, None);
 	}
 
+	set_relayer_quorum {
+		let quorum = 3;
+	}: _(RawOrigin::Root, quorum)
+	verify {
+		assert_eq!(RelayerQuorum::<T>::get(), quorum);
+	}
+
+	set_relayer_bond {
+		let amount: Balance = 100;
+	}: _(RawOrigin::Root, amount)
+	verify {
+		assert_eq!(RelayerBond::<T>::get(), amount);
+	}
+
+	deposit_relayer_bond {
+		let relayer = account::<T>("Relayer");
+		let amount: Balance = 100;
+		assert_ok!(XrplBridge::<T>::set_relayer_bond(RawOrigin::Root.into(), amount));
+		assert_ok!(T::MultiCurrency::mint_into(T::NativeAssetId::get(), &relayer, amount * 10));
+
+	}: _(origin::<T>(&relayer))
+	verify {
+		assert_eq!(RelayerPaidBond::<T>::get(&relayer), amount);
+	}
+
+	withdraw_relayer_bond {
+		let relayer = account::<T>("Relayer");
+		let amount: Balance = 100;
+		assert_ok!(XrplBridge::<T>::set_relayer_bond(RawOrigin::Root.into(), amount));
+		assert_ok!(T::MultiCurrency::mint_into(T::NativeAssetId::get(), &relayer, amount * 10));
+		assert_ok!(XrplBridge::<T>::deposit_relayer_bond(origin::<T>(&relayer).into()));
+		assert_ok!(XrplBridge::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone()));
+		assert_ok!(XrplBridge::<T>::remove_relayer(RawOrigin::Root.into(), relayer.clone()));
+		let unbonding_at =
+			frame_system::Pallet::<T>::block_number() + T::RelayerUnbondingPeriod::get();
+		frame_system::Pallet::<T>::set_block_number(unbonding_at);
+
+	}: _(origin::<T>(&relayer))
+	verify {
+		assert_eq!(RelayerPaidBond::<T>::get(&relayer), 0);
+	}
+
 	set_door_tx_fee {
 		let tx_fee = 100;
 		// Sanity check
@@ -122,6 +164,33 @@ benchmarks! {
 		assert_eq!(DoorTxFee::<T>::get(), tx_fee);
 	}
 
+	submit_fee_observation {
+		let relayer = account::<T>("Relayer");
+		assert_ok!(XrplBridge::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone()));
+		assert_ok!(XrplBridge::<T>::set_door_tx_fee_bounds(RawOrigin::Root.into(), 1, 1_000_000));
+		let base_fee = 10;
+		let load_factor = 512;
+
+	}: _(origin::<T>(&relayer), base_fee, load_factor)
+	verify {
+		let observation = XrplFeeObservation { base_fee, load_factor };
+		let observed_at = frame_system::Pallet::<T>::block_number();
+		assert_eq!(DoorTxFeeObservations::<T>::get(&relayer), Some((observation, observed_at)));
+	}
+
+	set_door_tx_fee_bounds {
+		let relayer = account::<T>("Relayer");
+		assert_ok!(XrplBridge::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone()));
+		assert_ok!(XrplBridge::<T>::submit_fee_observation(origin::<T>(&relayer).into(), 10, 256));
+		let min_fee = 100;
+		let max_fee = 1_000;
+
+	}: _(RawOrigin::Root, min_fee, max_fee)
+	verify {
+		assert_eq!(DoorTxFeeBounds::<T>::get(), Some((min_fee, max_fee)));
+		assert!(DoorTxFee::<T>::get() >= min_fee && DoorTxFee::<T>::get() <= max_fee);
+	}
+
 	set_door_address {
 		let door_address: XrplAccountId = [1u8; 20].into();
 		// Sanity check
@@ -171,7 +240,7 @@ benchmarks! {
 			let ledger_index = j;
 			let transaction_hash: XrplTxHash = [j as u8; 64].into();
 			let timestamp = 100;
-			let transaction = XrpTransaction { transaction_hash, transaction: XrplTxData::Xls20, timestamp } ;
+			let transaction = XrpTransaction { transaction_hash, transaction: XrplTxData::default(), timestamp } ;
 			settled_data.push((transaction_hash, ledger_index, transaction, alice.clone()));
 		}
 
@@ -193,6 +262,57 @@ benchmarks! {
 		}
 	}
 
+	withdraw_asset {
+		let alice = account::<T>("Alice");
+		let amount: Balance = 100u32.into();
+		let destination: XrplAccountId = [0u8; 20].into();
+		let door_address: XrplAccountId  = [1u8; 20].into();
+		let xrpl_currency = XrplCurrency { issuer: [2u8; 20].into(), currency_code: [3u8; 20] };
+		let xrp_asset_id = T::XrpAssetId::get();
+		let asset_id = T::MultiCurrency::create(&alice, None).unwrap();
+
+		assert_ok!(XrplBridge::<T>::set_door_address(RawOrigin::Root.into(), door_address));
+		assert_ok!(XrplBridge::<T>::set_xrpl_asset_map(RawOrigin::Root.into(), asset_id, Some(xrpl_currency)));
+		assert_ok!(T::MultiCurrency::mint_into(xrp_asset_id, &alice, 1000000000));
+		assert_ok!(T::MultiCurrency::mint_into(asset_id, &alice, amount));
+		assert_ok!(XrplBridge::<T>::add_relayer(RawOrigin::Root.into(), alice.clone()));
+		assert_ok!(XrplBridge::<T>::set_ticket_sequence_next_allocation(origin::<T>(&alice).into(), 1, 1));
+
+	}: _(origin::<T>(&alice), asset_id, amount, destination, None)
+	verify {
+		assert_eq!(T::MultiCurrency::balance(asset_id, &alice), 0);
+	}
+
+	withdraw_xls20 {
+		let alice = account::<T>("Alice");
+		let destination: XrplAccountId = [0u8; 20].into();
+		let door_address: XrplAccountId  = [1u8; 20].into();
+		let xrp_asset_id = T::XrpAssetId::get();
+		let token_id = T::Xls20Ext::create_benchmark_token(&alice);
+
+		assert_ok!(XrplBridge::<T>::set_door_tx_fee(RawOrigin::Root.into(), 100));
+		assert_ok!(XrplBridge::<T>::set_door_address(RawOrigin::Root.into(), door_address));
+		assert_ok!(T::MultiCurrency::mint_into(xrp_asset_id, &alice, 1000000000));
+		assert_ok!(XrplBridge::<T>::add_relayer(RawOrigin::Root.into(), alice.clone()));
+		assert_ok!(XrplBridge::<T>::set_ticket_sequence_next_allocation(origin::<T>(&alice).into(), 1, 1));
+
+	}: _(origin::<T>(&alice), token_id, destination)
+	verify {
+		assert_eq!(T::MultiCurrency::balance(xrp_asset_id, &alice), 1000000000 - 100);
+	}
+
+	set_xrpl_asset_map {
+		let asset_id: AssetId = 100;
+		let xrpl_currency = XrplCurrency { issuer: [1u8; 20].into(), currency_code: [2u8; 20] };
+		// Sanity check
+		assert!(AssetIdToXrplCurrency::<T>::get(asset_id).is_none());
+
+	}: _(RawOrigin::Root, asset_id, Some(xrpl_currency))
+	verify {
+		assert_eq!(AssetIdToXrplCurrency::<T>::get(asset_id), Some(xrpl_currency));
+		assert_eq!(XrplCurrencyToAssetId::<T>::get(xrpl_currency), Some(asset_id));
+	}
+
 	prune_settled_ledger_index {
 		// The amount of transactions stored under the ledger index
 		let i in 0..10;
@@ -222,6 +342,14 @@ benchmarks! {
 			assert!(ProcessXRPTransactionDetails::<T>::get(tx_hash).is_none());
 		}
 	}
+
+	set_asset_payment_delay {
+		let asset_id: AssetId = 3;
+		let payment_delay: (u128, T::BlockNumber) = (100, T::BlockNumber::from(1000u32));
+	}: _(RawOrigin::Root, asset_id, Some(payment_delay))
+	verify {
+		assert_eq!(AssetPaymentDelay::<T>::get(asset_id), Some(payment_delay));
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -16,39 +16,47 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use crate::types::{
-	DelayedPaymentId, DelayedWithdrawal, XrpTransaction, XrpWithdrawTransaction,
+	xls20_token_id_to_nftoken_id, DelayedAssetWithdrawal, DelayedPaymentId, DelayedWithdrawal,
+	XrpTransaction, XrpWithdrawTransaction, XrplCurrency, XrplFeeObservation, XrplIssuedValue,
 	XrplTicketSequenceParams, XrplTxData,
 };
 use frame_support::{
 	fail,
 	pallet_prelude::*,
 	traits::{
-		fungibles::{Inspect, Mutate, Transfer},
+		fungibles::{Inspect, InspectMetadata, Mutate, Transfer},
 		UnixTime,
 	},
 	transactional,
 	weights::constants::RocksDbWeight as DbWeight,
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
-use seed_pallet_common::{CreateExt, EthyToXrplBridgeAdapter, XrplBridgeToEthyAdapter};
+use seed_pallet_common::{
+	CreateExt, EthyToXrplBridgeAdapter, Hold, Xls20Ext, XrplBridgeToEthyAdapter,
+};
 use seed_primitives::{
 	ethy::{crypto::AuthorityId, EventProofId},
-	xrpl::{LedgerIndex, XrplAccountId, XrplTxHash, XrplTxTicketSequence},
-	AssetId, Balance, Timestamp,
+	xrpl::{LedgerIndex, Xls20TokenId, XrplAccountId, XrplTxHash, XrplTxTicketSequence},
+	AssetId, Balance, Timestamp, TokenId,
 };
 use sp_runtime::{
-	traits::{One, Zero},
+	traits::{Hash, One, Zero},
 	ArithmeticError, Percent, SaturatedConversion, Saturating,
 };
 use sp_std::{prelude::*, vec};
 use xrpl_codec::{
 	traits::BinarySerialize,
-	transaction::{Payment, PaymentWithDestinationTag, SignerListSet},
+	transaction::{
+		NFTokenCreateOffer, Payment, PaymentAltCurrency, PaymentAltCurrencyWithDestinationTag,
+		PaymentWithDestinationTag, SignerListSet, TicketCreate,
+	},
+	types::IssuedAmount,
 };
 
 pub use pallet::*;
 
-mod types;
+pub mod types;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -69,7 +77,7 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use super::*;
 
-	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -80,7 +88,12 @@ pub mod pallet {
 		type MultiCurrency: CreateExt<AccountId = Self::AccountId>
 			+ Transfer<Self::AccountId, Balance = Balance>
 			+ Inspect<Self::AccountId, AssetId = AssetId>
-			+ Mutate<Self::AccountId>;
+			+ InspectMetadata<Self::AccountId>
+			+ Mutate<Self::AccountId>
+			+ Hold<AccountId = Self::AccountId>;
+
+		/// Locks and unlocks XLS-20 tokens bridged to and from XRPL
+		type Xls20Ext: Xls20Ext<AccountId = Self::AccountId>;
 
 		/// Allowed origins to add/remove the relayers
 		type ApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -92,6 +105,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type XrpAssetId: Get<AssetId>;
 
+		/// The native token asset Id, relayer bonds are paid in this asset
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// This pallet's Id, used for holding relayer bonds
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		/// Challenge Period to wait for a challenge before processing the transaction
 		#[pallet::constant]
 		type ChallengePeriod: Get<u32>;
@@ -108,6 +129,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type DelayedPaymentBlockLimit: Get<Self::BlockNumber>;
 
+		/// Number of blocks a removed relayer must wait before withdrawing its bond, the bond can
+		/// still be slashed during this period
+		#[pallet::constant]
+		type RelayerUnbondingPeriod: Get<Self::BlockNumber>;
+
+		/// Number of blocks a relayer fee observation is used for before it expires
+		#[pallet::constant]
+		type FeeObservationExpiry: Get<Self::BlockNumber>;
+
 		/// Unix time
 		type UnixTime: UnixTime;
 
@@ -160,6 +190,28 @@ pub mod pallet {
 		OutSideSubmissionWindow,
 		/// Too Many transactions per ledger
 		TooManyTransactionsPerLedger,
+		/// The asset is not mapped to an XRPL issued currency
+		AssetNotSupported,
+		/// The XRPL issued currency is already mapped to another asset
+		XrplCurrencyAlreadyMapped,
+		/// The XLS-20 token id is not a valid hex encoded NFTokenID
+		InvalidXls20TokenId,
+		/// The observed base fee and load factor must be non-zero
+		InvalidFeeObservation,
+		/// The minimum door tx fee must be non-zero and <= the maximum
+		InvalidDoorTxFeeBounds,
+		/// The relayer has already attested to this transaction
+		RelayerAlreadyAttested,
+		/// The relayer quorum must be non-zero
+		InvalidRelayerQuorum,
+		/// The relayer hasn't paid the relayer bond so can't be added as a relayer
+		NoBondPaid,
+		/// The account already has a bonded amount or no bond is required
+		CantBondRelayer,
+		/// The account is an active relayer or has no bond to withdraw
+		CantUnbondRelayer,
+		/// The relayer was removed recently and its bond is still unbonding
+		RelayerBondUnbonding,
 	}
 
 	#[pallet::event]
@@ -211,6 +263,110 @@ pub mod pallet {
 			total_cleared: u32,
 		},
 		TicketSequenceThresholdReached(u32),
+		/// An XRPL issued currency was mapped to an asset
+		XrplAssetMapSet {
+			asset_id: AssetId,
+			xrpl_currency: XrplCurrency,
+		},
+		/// The XRPL issued currency mapping for an asset was removed
+		XrplAssetMapRemoved {
+			asset_id: AssetId,
+			xrpl_currency: XrplCurrency,
+		},
+		/// Request to withdraw some amount of an issued currency to XRPL
+		AssetWithdrawRequest {
+			proof_id: u64,
+			sender: T::AccountId,
+			asset_id: AssetId,
+			amount: Balance,
+			destination: XrplAccountId,
+		},
+		/// The door tx fee was updated from relayer fee observations
+		DoorTxFeeUpdated {
+			fee: u64,
+		},
+		/// The bounds of the door tx fee were set
+		DoorTxFeeBoundsSet {
+			min_fee: u64,
+			max_fee: u64,
+		},
+		/// A relayer attested to the details of an XRPL transaction
+		TransactionAttested {
+			ledger_index: LedgerIndex,
+			transaction_hash: XrplTxHash,
+			relayer: T::AccountId,
+		},
+		/// A relayer submitted details conflicting with another submission, the transaction is
+		/// challenged until the relayer quorum agrees on its details
+		ConflictingTransaction {
+			ledger_index: LedgerIndex,
+			transaction_hash: XrplTxHash,
+			relayer: T::AccountId,
+		},
+		/// A relayer attested to details rejected by the relayer quorum and was slashed
+		RelayerSlashed {
+			relayer: T::AccountId,
+			amount: Balance,
+		},
+		/// A relayer attested to details rejected by the relayer quorum but could not be slashed
+		RelayerSlashFailed {
+			relayer: T::AccountId,
+			error: DispatchError,
+		},
+		/// The number of relayer attestations required to accept a transaction was set
+		RelayerQuorumSet {
+			quorum: u32,
+		},
+		/// The bond required to be added as a relayer was set
+		RelayerBondSet {
+			amount: Balance,
+		},
+		/// An account has deposited a relayer bond
+		RelayerBondDeposit {
+			relayer: T::AccountId,
+			amount: Balance,
+		},
+		/// An account has withdrawn a relayer bond
+		RelayerBondWithdraw {
+			relayer: T::AccountId,
+			amount: Balance,
+		},
+		/// The payment delay for an issued currency was set
+		AssetPaymentDelaySet {
+			asset_id: AssetId,
+			payment_threshold: Balance,
+			delay: T::BlockNumber,
+		},
+		/// The payment delay for an issued currency was removed
+		AssetPaymentDelayRemoved {
+			asset_id: AssetId,
+		},
+		/// An issued currency withdrawal was delayed as it was above the asset's payment threshold
+		AssetWithdrawDelayed {
+			sender: T::AccountId,
+			asset_id: AssetId,
+			amount: Balance,
+			destination: XrplAccountId,
+			delayed_payment_id: DelayedPaymentId,
+		},
+		/// Request to withdraw an XLS-20 token to XRPL
+		Xls20WithdrawRequest {
+			proof_id: u64,
+			sender: T::AccountId,
+			token_id: TokenId,
+			xls20_token_id: Xls20TokenId,
+			destination: XrplAccountId,
+		},
+		/// Request to allocate a new ticket sequence range for the door account
+		TicketCreateRequest {
+			proof_id: u64,
+			ticket_sequence: u32,
+			ticket_count: u32,
+		},
+		/// Requesting a new ticket sequence range failed, it is retried on the next door tx
+		TicketCreateRequestFailed {
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::hooks]
@@ -296,6 +452,17 @@ pub mod pallet {
 	pub type DelayedPayments<T: Config> =
 		StorageMap<_, Identity, DelayedPaymentId, DelayedWithdrawal<T::AccountId>>;
 
+	#[pallet::storage]
+	/// Payment delay for any issued currency withdraw over the specified Balance threshold
+	/// The threshold is in the smallest unit of the asset
+	pub type AssetPaymentDelay<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, (Balance, T::BlockNumber)>;
+
+	#[pallet::storage]
+	/// Map from DelayedPaymentId to (sender, issued currency WithdrawTx)
+	pub type DelayedAssetPayments<T: Config> =
+		StorageMap<_, Identity, DelayedPaymentId, DelayedAssetWithdrawal<T::AccountId>>;
+
 	#[pallet::storage]
 	/// Map from block number to DelayedPatmentIds scheduled for that block
 	pub type DelayedPaymentSchedule<T: Config> = StorageMap<
@@ -320,6 +487,49 @@ pub mod pallet {
 	pub type ChallengeXRPTransactionList<T: Config> =
 		StorageMap<_, Identity, XrplTxHash, T::AccountId>;
 
+	#[pallet::type_value]
+	pub fn DefaultRelayerQuorum() -> u32 {
+		1_u32
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_quorum)]
+	/// The number of relayers that must submit identical details before a transaction is accepted
+	pub type RelayerQuorum<T: Config> = StorageValue<_, u32, ValueQuery, DefaultRelayerQuorum>;
+
+	#[pallet::storage]
+	/// Relayer attestations to transactions awaiting quorum, mapped to the hash of the attested
+	/// details
+	pub type XrplTxAttestations<T: Config> =
+		StorageDoubleMap<_, Identity, XrplTxHash, Twox64Concat, T::AccountId, T::Hash>;
+
+	#[pallet::storage]
+	/// The distinct details submitted for transactions awaiting quorum and their attestation count
+	pub type PendingXrplTxDetails<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		XrplTxHash,
+		Identity,
+		T::Hash,
+		(LedgerIndex, XrpTransaction, u32),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_bond)]
+	/// The bond required for an account to be added as a relayer
+	pub type RelayerBond<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_paid_bond)]
+	/// Maps from relayer account to their paid bond amount
+	pub type RelayerPaidBond<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Maps from removed relayer account to the block its bond can be withdrawn from
+	pub type RelayerUnbondingAt<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber>;
+
 	#[pallet::type_value]
 	pub fn DefaultDoorTicketSequence() -> u32 {
 		0_u32
@@ -352,6 +562,11 @@ pub mod pallet {
 	pub type TicketSequenceThresholdReachedEmitted<T: Config> =
 		StorageValue<_, bool, ValueQuery, DefaultTicketSequenceThresholdReachedEmitted>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn door_ticket_refill_requested)]
+	/// Keeps track whether a TicketCreate tx was requested for the current ticket allocation
+	pub type DoorTicketRefillRequested<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	/// Default door tx fee 1 XRP
 	#[pallet::type_value]
 	pub fn DefaultDoorTxFee() -> u64 {
@@ -363,11 +578,31 @@ pub mod pallet {
 	/// The flat fee for XRPL door txs
 	pub type DoorTxFee<T: Config> = StorageValue<_, u64, ValueQuery, DefaultDoorTxFee>;
 
+	#[pallet::storage]
+	/// The latest XRPL fee level observed by each relayer and the block it was submitted at
+	pub type DoorTxFeeObservations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (XrplFeeObservation, T::BlockNumber)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn door_tx_fee_bounds)]
+	/// The (min, max) bounds in drops the observed door tx fee is clamped to
+	/// Fee observations are ignored until the bounds are set
+	pub type DoorTxFeeBounds<T: Config> = StorageValue<_, (u64, u64)>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn door_address)]
 	/// The door address on XRPL
 	pub type DoorAddress<T: Config> = StorageValue<_, XrplAccountId>;
 
+	#[pallet::storage]
+	/// Map from an XRPL issued currency to the asset it is bridged as
+	pub type XrplCurrencyToAssetId<T: Config> =
+		StorageMap<_, Blake2_128Concat, XrplCurrency, AssetId>;
+
+	#[pallet::storage]
+	/// Map from a bridged asset to its XRPL issued currency
+	pub type AssetIdToXrplCurrency<T: Config> = StorageMap<_, Twox64Concat, AssetId, XrplCurrency>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub xrp_relayers: Vec<T::AccountId>,
@@ -481,6 +716,10 @@ pub mod pallet {
 		#[transactional]
 		pub fn add_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
 			T::ApproveOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::relayer_paid_bond(&relayer) >= Self::relayer_bond(),
+				Error::<T>::NoBondPaid
+			);
 			Self::initialize_relayer(&vec![relayer.clone()]);
 			Self::deposit_event(Event::<T>::RelayerAdded(relayer));
 			Ok(())
@@ -492,7 +731,7 @@ pub mod pallet {
 		pub fn remove_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
 			T::ApproveOrigin::ensure_origin(origin)?;
 			if <Relayer<T>>::contains_key(relayer.clone()) {
-				<Relayer<T>>::remove(relayer.clone());
+				Self::do_remove_relayer(&relayer);
 				Self::deposit_event(Event::<T>::RelayerRemoved(relayer));
 				Ok(())
 			} else {
@@ -540,24 +779,7 @@ pub mod pallet {
 			let active_relayer = <Relayer<T>>::get(&relayer).unwrap_or(false);
 			ensure!(active_relayer, Error::<T>::NotPermitted);
 
-			let current_ticket_sequence = Self::door_ticket_sequence();
-			let current_params = Self::door_ticket_sequence_params();
-
-			if start_ticket_sequence < current_ticket_sequence ||
-				start_ticket_sequence < current_params.start_sequence ||
-				ticket_bucket_size == 0
-			{
-				fail!(Error::<T>::NextTicketSequenceParamsInvalid);
-			}
-			DoorTicketSequenceParamsNext::<T>::put(XrplTicketSequenceParams {
-				start_sequence: start_ticket_sequence,
-				bucket_size: ticket_bucket_size,
-			});
-			Self::deposit_event(Event::<T>::DoorNextTicketSequenceParamSet {
-				ticket_sequence_start_next: start_ticket_sequence,
-				ticket_bucket_size_next: ticket_bucket_size,
-			});
-			Ok(())
+			Self::do_set_ticket_sequence_next_allocation(start_ticket_sequence, ticket_bucket_size)
 		}
 
 		/// Set the door account current ticket sequence params for current allocation - force set
@@ -585,6 +807,7 @@ pub mod pallet {
 				bucket_size: ticket_bucket_size,
 			});
 			TicketSequenceThresholdReachedEmitted::<T>::kill();
+			DoorTicketRefillRequested::<T>::kill();
 			Self::deposit_event(Event::<T>::DoorTicketSequenceParamSet {
 				ticket_sequence,
 				ticket_sequence_start: start_ticket_sequence,
@@ -656,6 +879,209 @@ pub mod pallet {
 			Self::deposit_event(Event::LedgerIndexManualPrune { ledger_index, total_cleared });
 			Ok(())
 		}
+
+		/// Withdraw an XRPL issued currency (IOU) bridged as `asset_id`
+		/// The XRPL tx fee is paid in XRP by the caller
+		#[pallet::weight((T::WeightInfo::withdraw_asset(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn withdraw_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: Balance,
+			destination: XrplAccountId,
+			destination_tag: Option<u32>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::add_to_asset_withdraw(who, asset_id, amount, destination, destination_tag)
+		}
+
+		/// Map an XRPL issued currency to `asset_id` so it can be deposited and withdrawn
+		/// Setting `None` removes the existing mapping for `asset_id`
+		#[pallet::weight((T::WeightInfo::set_xrpl_asset_map(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_xrpl_asset_map(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			xrpl_currency: Option<XrplCurrency>,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			ensure!(asset_id != T::XrpAssetId::get(), Error::<T>::AssetNotSupported);
+
+			match xrpl_currency {
+				Some(xrpl_currency) => {
+					if let Some(mapped_asset_id) = XrplCurrencyToAssetId::<T>::get(xrpl_currency) {
+						ensure!(mapped_asset_id == asset_id, Error::<T>::XrplCurrencyAlreadyMapped);
+					}
+					// Clear the reverse lookup of any previous mapping for this asset
+					if let Some(previous) = AssetIdToXrplCurrency::<T>::get(asset_id) {
+						XrplCurrencyToAssetId::<T>::remove(previous);
+					}
+					AssetIdToXrplCurrency::<T>::insert(asset_id, xrpl_currency);
+					XrplCurrencyToAssetId::<T>::insert(xrpl_currency, asset_id);
+					Self::deposit_event(Event::<T>::XrplAssetMapSet { asset_id, xrpl_currency });
+				},
+				None => {
+					let xrpl_currency = AssetIdToXrplCurrency::<T>::take(asset_id)
+						.ok_or(Error::<T>::AssetNotSupported)?;
+					XrplCurrencyToAssetId::<T>::remove(xrpl_currency);
+					Self::deposit_event(Event::<T>::XrplAssetMapRemoved {
+						asset_id,
+						xrpl_currency,
+					});
+				},
+			}
+			Ok(())
+		}
+
+		/// Withdraw the XLS-20 compatible token `token_id` to `destination` on XRPL
+		/// The token is locked and a sell offer for 0 XRP is made by the door account to
+		/// `destination`, the XRPL tx fee is paid in XRP by the caller
+		#[pallet::weight((T::WeightInfo::withdraw_xls20(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn withdraw_xls20(
+			origin: OriginFor<T>,
+			token_id: TokenId,
+			destination: XrplAccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::add_to_xls20_withdraw(who, token_id, destination)
+		}
+
+		/// Submit the XRPL ledger `base_fee` (drops) and `load_factor` observed by a relayer
+		/// The door tx fee is recomputed from the median of all relayer observations
+		#[pallet::weight((T::WeightInfo::submit_fee_observation(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn submit_fee_observation(
+			origin: OriginFor<T>,
+			base_fee: u64,
+			load_factor: u32,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let active_relayer = <Relayer<T>>::get(&relayer).unwrap_or(false);
+			ensure!(active_relayer, Error::<T>::NotPermitted);
+			ensure!(
+				!base_fee.is_zero() && !load_factor.is_zero(),
+				Error::<T>::InvalidFeeObservation
+			);
+
+			let observed_at = <frame_system::Pallet<T>>::block_number();
+			DoorTxFeeObservations::<T>::insert(
+				&relayer,
+				(XrplFeeObservation { base_fee, load_factor }, observed_at),
+			);
+			Self::update_door_tx_fee();
+			Ok(())
+		}
+
+		/// Set the bounds in drops the observed door tx fee is clamped to
+		#[pallet::weight((T::WeightInfo::set_door_tx_fee_bounds(), DispatchClass::Operational))]
+		pub fn set_door_tx_fee_bounds(
+			origin: OriginFor<T>,
+			min_fee: u64,
+			max_fee: u64,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!min_fee.is_zero() && min_fee <= max_fee, Error::<T>::InvalidDoorTxFeeBounds);
+			DoorTxFeeBounds::<T>::put((min_fee, max_fee));
+			Self::deposit_event(Event::<T>::DoorTxFeeBoundsSet { min_fee, max_fee });
+			Self::update_door_tx_fee();
+			Ok(())
+		}
+
+		/// Set the number of relayers that must submit identical details for a transaction
+		/// before it is accepted
+		#[pallet::weight((T::WeightInfo::set_relayer_quorum(), DispatchClass::Operational))]
+		pub fn set_relayer_quorum(origin: OriginFor<T>, quorum: u32) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			ensure!(!quorum.is_zero(), Error::<T>::InvalidRelayerQuorum);
+			RelayerQuorum::<T>::put(quorum);
+			Self::deposit_event(Event::<T>::RelayerQuorumSet { quorum });
+			Ok(())
+		}
+
+		/// Set the bond required for an account to be added as a relayer
+		/// Existing relayers are unaffected
+		#[pallet::weight((T::WeightInfo::set_relayer_bond(), DispatchClass::Operational))]
+		pub fn set_relayer_bond(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			RelayerBond::<T>::put(amount);
+			Self::deposit_event(Event::<T>::RelayerBondSet { amount });
+			Ok(())
+		}
+
+		/// Submit bond for relayer account
+		#[pallet::weight(T::WeightInfo::deposit_relayer_bond())]
+		#[transactional]
+		pub fn deposit_relayer_bond(origin: OriginFor<T>) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			// Ensure relayer doesn't already have a bond set
+			ensure!(Self::relayer_paid_bond(&relayer).is_zero(), Error::<T>::CantBondRelayer);
+			let amount = Self::relayer_bond();
+			ensure!(!amount.is_zero(), Error::<T>::CantBondRelayer);
+
+			T::MultiCurrency::place_hold(
+				T::PalletId::get(),
+				&relayer,
+				T::NativeAssetId::get(),
+				amount,
+			)?;
+			RelayerPaidBond::<T>::insert(&relayer, amount);
+			Self::deposit_event(Event::<T>::RelayerBondDeposit { relayer, amount });
+			Ok(())
+		}
+
+		/// Withdraw relayer bond amount
+		#[pallet::weight(T::WeightInfo::withdraw_relayer_bond())]
+		#[transactional]
+		pub fn withdraw_relayer_bond(origin: OriginFor<T>) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			// Ensure account is not an active relayer
+			ensure!(!<Relayer<T>>::contains_key(&relayer), Error::<T>::CantUnbondRelayer);
+			if let Some(unbonding_at) = RelayerUnbondingAt::<T>::take(&relayer) {
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() >= unbonding_at,
+					Error::<T>::RelayerBondUnbonding
+				);
+			}
+			let amount = RelayerPaidBond::<T>::take(&relayer);
+			ensure!(!amount.is_zero(), Error::<T>::CantUnbondRelayer);
+
+			T::MultiCurrency::release_hold(
+				T::PalletId::get(),
+				&relayer,
+				T::NativeAssetId::get(),
+				amount,
+			)?;
+			Self::deposit_event(Event::<T>::RelayerBondWithdraw { relayer, amount });
+			Ok(())
+		}
+
+		/// Sets the payment delay for withdrawals of the issued currency mapped to `asset_id`
+		/// payment_delay is a tuple of payment_threshold (in the asset's smallest unit) and delay
+		/// in blocks
+		#[pallet::weight((T::WeightInfo::set_asset_payment_delay(), DispatchClass::Operational))]
+		pub fn set_asset_payment_delay(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			payment_delay: Option<(Balance, T::BlockNumber)>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			match payment_delay {
+				Some((payment_threshold, delay)) => {
+					AssetPaymentDelay::<T>::insert(asset_id, (payment_threshold, delay));
+					Self::deposit_event(Event::<T>::AssetPaymentDelaySet {
+						asset_id,
+						payment_threshold,
+						delay,
+					});
+				},
+				None => {
+					AssetPaymentDelay::<T>::remove(asset_id);
+					Self::deposit_event(Event::<T>::AssetPaymentDelayRemoved { asset_id });
+				},
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -701,9 +1127,43 @@ impl<T: Config> Pallet<T> {
 						));
 					}
 				},
-				_ => {
-					Self::deposit_event(Event::NotSupportedTransaction);
-					continue
+				XrplTxData::CurrencyPayment { amount, address, currency } => {
+					reads += 1;
+					let result = XrplCurrencyToAssetId::<T>::get(currency)
+						.ok_or(DispatchError::from(Error::<T>::AssetNotSupported))
+						.and_then(|asset_id| {
+							T::MultiCurrency::mint_into(asset_id, &address.into(), amount)
+						});
+					if let Err(e) = result {
+						Self::deposit_event(Event::ProcessingFailed(
+							ledger_index,
+							transaction_hash.clone(),
+							e,
+						));
+					}
+				},
+				XrplTxData::Xls20 { token_id, address } => {
+					reads += 1;
+					if let Err(e) = T::Xls20Ext::unlock_xls20_token(&address.into(), &token_id) {
+						Self::deposit_event(Event::ProcessingFailed(
+							ledger_index,
+							transaction_hash.clone(),
+							e,
+						));
+					}
+				},
+				XrplTxData::TicketCreate { start_sequence, ticket_count } => {
+					reads += 2;
+					writes += 1;
+					if let Err(e) =
+						Self::do_set_ticket_sequence_next_allocation(start_sequence, ticket_count)
+					{
+						Self::deposit_event(Event::ProcessingFailed(
+							ledger_index,
+							transaction_hash.clone(),
+							e,
+						));
+					}
 				},
 			}
 
@@ -740,9 +1200,9 @@ impl<T: Config> Pallet<T> {
 		// Write: NextDelayProcessBlock
 		let base_process_weight = DbWeight::get().reads_writes(2u64, 1u64);
 		// Weight to process one withdraw tx
-		// 1 read for DelayedPayments
+		// 1 read for DelayedPayments, 1 read for DelayedAssetPayments
 		// 2 reads and 2 writes within submit_withdraw_request
-		let weight_per_tx = DbWeight::get().reads_writes(3u64, 2u64);
+		let weight_per_tx = DbWeight::get().reads_writes(4u64, 2u64);
 		// The minimum weight required to clear at least one transaction.
 		// This includes the weight_per_tx (To submit one withdrawal)
 		// And the weight to update DelayedPaymentSchedule
@@ -805,6 +1265,16 @@ impl<T: Config> Pallet<T> {
 						delayed_withdrawal.withdraw_tx,
 						delayed_withdrawal.destination_tag,
 					);
+				} else if let Some(delayed_withdrawal) = <DelayedAssetPayments<T>>::take(payment_id)
+				{
+					let _ = Self::submit_asset_withdraw_request(
+						delayed_withdrawal.sender,
+						door_address.into(),
+						delayed_withdrawal.asset_id,
+						delayed_withdrawal.currency,
+						delayed_withdrawal.withdraw_tx,
+						delayed_withdrawal.destination_tag,
+					);
 				};
 			}
 			// Add weight for the tx's we processed
@@ -931,6 +1401,10 @@ impl<T: Config> Pallet<T> {
 		used_weight
 	}
 
+	/// Record `relayer`'s attestation to the transaction details
+	/// The transaction is scheduled for processing once `RelayerQuorum` relayers have submitted
+	/// identical details, relayers that attested to different details are slashed
+	/// Conflicting details challenge the transaction until the quorum is reached
 	pub fn add_to_relay(
 		relayer: T::AccountId,
 		ledger_index: LedgerIndex,
@@ -938,14 +1412,109 @@ impl<T: Config> Pallet<T> {
 		transaction: XrplTxData,
 		timestamp: Timestamp,
 	) -> DispatchResult {
+		ensure!(
+			!XrplTxAttestations::<T>::contains_key(transaction_hash, &relayer),
+			Error::<T>::RelayerAlreadyAttested
+		);
 		let val = XrpTransaction { transaction_hash, transaction, timestamp };
-		<ProcessXRPTransactionDetails<T>>::insert(&transaction_hash, (ledger_index, val, relayer));
+		let details_hash = T::Hashing::hash_of(&(ledger_index, &val));
+
+		if PendingXrplTxDetails::<T>::iter_key_prefix(transaction_hash)
+			.any(|pending_hash| pending_hash != details_hash)
+		{
+			// keep any existing challenge, it may not have been raised by a relayer
+			if !ChallengeXRPTransactionList::<T>::contains_key(transaction_hash) {
+				ChallengeXRPTransactionList::<T>::insert(transaction_hash, &relayer);
+			}
+			Self::deposit_event(Event::ConflictingTransaction {
+				ledger_index,
+				transaction_hash,
+				relayer: relayer.clone(),
+			});
+		}
+		XrplTxAttestations::<T>::insert(transaction_hash, &relayer, details_hash);
+		let attestations =
+			PendingXrplTxDetails::<T>::mutate(transaction_hash, details_hash, |details| {
+				let (_, _, count) = details.get_or_insert((ledger_index, val.clone(), 0));
+				*count = count.saturating_add(1);
+				*count
+			});
+		Self::deposit_event(Event::TransactionAttested {
+			ledger_index,
+			transaction_hash,
+			relayer: relayer.clone(),
+		});
+		if attestations < Self::relayer_quorum() {
+			return Ok(())
+		}
 
+		// Quorum reached, slash the relayers that attested to other details
+		let (honest, dishonest): (Vec<_>, Vec<_>) =
+			XrplTxAttestations::<T>::drain_prefix(transaction_hash)
+				.partition(|(_, attested_hash)| *attested_hash == details_hash);
+		let honest: Vec<T::AccountId> = honest.into_iter().map(|(account, _)| account).collect();
+		let dishonest: Vec<T::AccountId> =
+			dishonest.into_iter().map(|(account, _)| account).collect();
+		// The quorum settles a challenge raised by a conflicting relayer submission
+		if ChallengeXRPTransactionList::<T>::get(transaction_hash).map_or(false, |challenger| {
+			honest.contains(&challenger) || dishonest.contains(&challenger)
+		}) {
+			ChallengeXRPTransactionList::<T>::remove(transaction_hash);
+		}
+		// Slashing is best effort, it must not block the transaction agreed by the quorum
+		for account in dishonest {
+			if let Err(error) = Self::slash_relayer(&account, &honest) {
+				log::warn!(target: "xrpl-bridge", "failed to slash relayer {:?}: {:?}", account, error);
+				Self::deposit_event(Event::RelayerSlashFailed { relayer: account, error });
+			}
+		}
+		let _ = PendingXrplTxDetails::<T>::clear_prefix(transaction_hash, u32::MAX, None);
+
+		<ProcessXRPTransactionDetails<T>>::insert(&transaction_hash, (ledger_index, val, relayer));
 		Self::add_to_xrp_process(transaction_hash)?;
 		Self::deposit_event(Event::TransactionAdded(ledger_index, transaction_hash));
 		Ok(())
 	}
 
+	/// Split the paid bond of `relayer` between `beneficiaries` and remove the relayer
+	/// Relayers without a paid bond are kept, they can only be removed by governance
+	#[transactional]
+	fn slash_relayer(relayer: &T::AccountId, beneficiaries: &[T::AccountId]) -> DispatchResult {
+		let amount = Self::relayer_paid_bond(relayer);
+		if amount.is_zero() || beneficiaries.is_empty() {
+			return Ok(())
+		}
+		let share = amount / beneficiaries.len() as Balance;
+		let mut spends: Vec<(T::AccountId, Balance)> =
+			beneficiaries.iter().map(|account| (account.clone(), share)).collect();
+		// Any remainder goes to the first beneficiary
+		spends[0].1 = spends[0].1.saturating_add(amount % beneficiaries.len() as Balance);
+		T::MultiCurrency::spend_hold(
+			T::PalletId::get(),
+			relayer,
+			T::NativeAssetId::get(),
+			&spends,
+		)?;
+		RelayerPaidBond::<T>::remove(relayer);
+		RelayerUnbondingAt::<T>::remove(relayer);
+		Self::do_remove_relayer(relayer);
+		Self::deposit_event(Event::RelayerSlashed { relayer: relayer.clone(), amount });
+		Ok(())
+	}
+
+	/// Remove `relayer` and its fee observation, any paid bond starts unbonding
+	fn do_remove_relayer(relayer: &T::AccountId) {
+		<Relayer<T>>::remove(relayer);
+		if !Self::relayer_paid_bond(relayer).is_zero() {
+			let unbonding_at = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::RelayerUnbondingPeriod::get());
+			RelayerUnbondingAt::<T>::insert(relayer, unbonding_at);
+		}
+		if DoorTxFeeObservations::<T>::take(relayer).is_some() {
+			Self::update_door_tx_fee();
+		}
+	}
+
 	pub fn add_to_xrp_process(transaction_hash: XrplTxHash) -> DispatchResult {
 		let process_block_number =
 			<frame_system::Pallet<T>>::block_number() + T::ChallengePeriod::get().into();
@@ -955,6 +1524,51 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Compute the door tx fee from relayer fee observations
+	/// A multi-signed XRPL tx costs the single signed fee multiplied by (1 + number of signers)
+	/// Returns `None` if the fee bounds are not set or no relayer has a current observation
+	pub fn observed_door_tx_fee() -> Option<u64> {
+		let (min_fee, max_fee) = Self::door_tx_fee_bounds()?;
+		let mut fees: Vec<u64> = DoorTxFeeObservations::<T>::iter_values()
+			.filter(|(_, observed_at)| !Self::is_fee_observation_expired(*observed_at))
+			.map(|(observation, _)| observation.fee())
+			.collect();
+		if fees.is_empty() {
+			return None
+		}
+		fees.sort_unstable();
+		let median_fee = fees[fees.len() / 2];
+
+		let signer_count = T::EthyAdapter::xrp_validators().len() as u64;
+		let fee = median_fee.saturating_mul(signer_count.saturating_add(1));
+		Some(fee.clamp(min_fee, max_fee))
+	}
+
+	/// Whether a fee observation submitted at `observed_at` is too old to be used
+	fn is_fee_observation_expired(observed_at: T::BlockNumber) -> bool {
+		observed_at.saturating_add(T::FeeObservationExpiry::get()) <
+			<frame_system::Pallet<T>>::block_number()
+	}
+
+	/// Remove expired fee observations and update `DoorTxFee` with the fee observed by relayers,
+	/// if any
+	fn update_door_tx_fee() {
+		let expired: Vec<T::AccountId> = DoorTxFeeObservations::<T>::iter()
+			.filter(|(_, (_, observed_at))| Self::is_fee_observation_expired(*observed_at))
+			.map(|(relayer, _)| relayer)
+			.collect();
+		for relayer in expired {
+			DoorTxFeeObservations::<T>::remove(relayer);
+		}
+
+		if let Some(fee) = Self::observed_door_tx_fee() {
+			if fee != Self::door_tx_fee() {
+				DoorTxFee::<T>::put(fee);
+				Self::deposit_event(Event::<T>::DoorTxFeeUpdated { fee });
+			}
+		}
+	}
+
 	/// `who` the account requesting the withdraw
 	/// `amount` the amount of XRP drops to withdraw (- the tx fee)
 	///  `destination` the receiver classic `AccountID` on XRPL
@@ -965,8 +1579,6 @@ impl<T: Config> Pallet<T> {
 		destination: XrplAccountId,
 		destination_tag: Option<u32>,
 	) -> DispatchResult {
-		// TODO: need a fee oracle, this is over estimating the fee
-		// https://github.com/futureversecom/seed/issues/107
 		let tx_fee = Self::door_tx_fee();
 		ensure!(!amount.is_zero(), Error::<T>::WithdrawInvalidAmount);
 		ensure!(amount.checked_add(tx_fee as Balance).is_some(), Error::<T>::WithdrawInvalidAmount); // xrp amounts are `u64`
@@ -978,6 +1590,7 @@ impl<T: Config> Pallet<T> {
 			T::MultiCurrency::burn_from(T::XrpAssetId::get(), &who, amount + tx_fee as Balance)?;
 
 		let ticket_sequence = Self::get_door_ticket_sequence()?;
+		Self::request_door_ticket_refill();
 		let tx_data = XrpWithdrawTransaction {
 			tx_nonce: 0_u32, // Sequence = 0 when using TicketSequence
 			tx_fee,
@@ -999,6 +1612,113 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// `who` the account requesting the withdraw
+	/// `asset_id` the asset mapped to the XRPL issued currency to withdraw
+	/// `amount` the amount of the asset to withdraw, the XRP tx fee is charged separately
+	/// `destination` the receiver classic `AccountID` on XRPL
+	#[transactional]
+	pub fn add_to_asset_withdraw(
+		who: AccountOf<T>,
+		asset_id: AssetId,
+		amount: Balance,
+		destination: XrplAccountId,
+		destination_tag: Option<u32>,
+	) -> DispatchResult {
+		let currency =
+			AssetIdToXrplCurrency::<T>::get(asset_id).ok_or(Error::<T>::AssetNotSupported)?;
+		// Ensure the amount can be represented on XRPL before burning
+		XrplIssuedValue::from_balance(amount, T::MultiCurrency::decimals(&asset_id))
+			.ok_or(Error::<T>::WithdrawInvalidAmount)?;
+		let tx_fee = Self::door_tx_fee();
+		let door_address = Self::door_address().ok_or(Error::<T>::DoorAddressNotSet)?;
+
+		// the door address pays the tx fee on XRPL in XRP, the issued currency is burnt in full
+		let _ = T::MultiCurrency::burn_from(T::XrpAssetId::get(), &who, tx_fee as Balance)?;
+		let _ = T::MultiCurrency::burn_from(asset_id, &who, amount)?;
+
+		let ticket_sequence = Self::get_door_ticket_sequence()?;
+		Self::request_door_ticket_refill();
+		let tx_data = XrpWithdrawTransaction {
+			tx_nonce: 0_u32, // Sequence = 0 when using TicketSequence
+			tx_fee,
+			amount,
+			destination,
+			tx_ticket_sequence: ticket_sequence,
+		};
+
+		// Check if there is a payment delay for the asset and delay the payment if necessary
+		if let Some((payment_threshold, delay)) = AssetPaymentDelay::<T>::get(asset_id) {
+			if amount >= payment_threshold {
+				Self::delay_asset_payment(
+					delay,
+					DelayedAssetWithdrawal {
+						sender: who,
+						destination_tag,
+						asset_id,
+						currency,
+						withdraw_tx: tx_data,
+					},
+				)?;
+				return Ok(())
+			}
+		}
+
+		Self::submit_asset_withdraw_request(
+			who,
+			door_address.into(),
+			asset_id,
+			currency,
+			tx_data,
+			destination_tag,
+		)
+	}
+
+	/// `who` the account requesting the withdraw
+	/// `token_id` the XLS-20 compatible token to withdraw
+	/// `destination` the receiver classic `AccountID` on XRPL, able to accept the door's offer
+	#[transactional]
+	pub fn add_to_xls20_withdraw(
+		who: AccountOf<T>,
+		token_id: TokenId,
+		destination: XrplAccountId,
+	) -> DispatchResult {
+		let tx_fee = Self::door_tx_fee();
+		let door_address = Self::door_address().ok_or(Error::<T>::DoorAddressNotSet)?;
+
+		let xls20_token_id = T::Xls20Ext::lock_xls20_token(&who, token_id)?;
+		let nftoken_id =
+			xls20_token_id_to_nftoken_id(&xls20_token_id).ok_or(Error::<T>::InvalidXls20TokenId)?;
+		// the door address pays the tx fee on XRPL in XRP
+		let _ = T::MultiCurrency::burn_from(T::XrpAssetId::get(), &who, tx_fee as Balance)?;
+
+		let ticket_sequence = Self::get_door_ticket_sequence()?;
+		Self::request_door_ticket_refill();
+		let tx_blob = NFTokenCreateOffer::new(
+			door_address.into(),
+			nftoken_id,
+			0_u64, // the token is given away to `destination`
+			destination.into(),
+			0_u32, // Sequence = 0 when using TicketSequence
+			ticket_sequence,
+			tx_fee,
+			SourceTag::<T>::get(),
+			// omit signer key since this is a 'MultiSigner' tx
+			None,
+		)
+		.binary_serialize(true);
+
+		let proof_id = T::EthyAdapter::sign_xrpl_transaction(tx_blob.as_slice())?;
+		Self::deposit_event(Event::Xls20WithdrawRequest {
+			proof_id,
+			sender: who,
+			token_id,
+			xls20_token_id,
+			destination,
+		});
+
+		Ok(())
+	}
+
 	/// Delay a withdrawal until a later block. Called if the withdrawal amount is over the
 	/// PaymentDelay threshold
 	fn delay_payment(
@@ -1007,21 +1727,11 @@ impl<T: Config> Pallet<T> {
 		withdrawal: XrpWithdrawTransaction,
 		destination_tag: Option<u32>,
 	) -> DispatchResult {
-		// Get the next payment ID
-		let delayed_payment_id = NextDelayedPaymentId::<T>::get();
-		ensure!(
-			delayed_payment_id.checked_add(One::one()).is_some(),
-			Error::<T>::NoAvailablePaymentIds
-		);
-
-		let payment_block = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
-		DelayedPaymentSchedule::<T>::try_append(payment_block, delayed_payment_id)
-			.map_err(|_| Error::<T>::DelayScheduleAtCapacity)?;
+		let delayed_payment_id = Self::schedule_delayed_payment(delay)?;
 		DelayedPayments::<T>::insert(
 			delayed_payment_id,
 			DelayedWithdrawal { sender: sender.clone(), destination_tag, withdraw_tx: withdrawal },
 		);
-		NextDelayedPaymentId::<T>::put(delayed_payment_id + 1);
 
 		Self::deposit_event(Event::WithdrawDelayed {
 			sender,
@@ -1032,6 +1742,41 @@ impl<T: Config> Pallet<T> {
 		return Ok(())
 	}
 
+	/// Delay an issued currency withdrawal until a later block. Called if the withdrawal amount is
+	/// over the AssetPaymentDelay threshold of the asset
+	fn delay_asset_payment(
+		delay: T::BlockNumber,
+		withdrawal: DelayedAssetWithdrawal<T::AccountId>,
+	) -> DispatchResult {
+		let delayed_payment_id = Self::schedule_delayed_payment(delay)?;
+		Self::deposit_event(Event::AssetWithdrawDelayed {
+			sender: withdrawal.sender.clone(),
+			asset_id: withdrawal.asset_id,
+			amount: withdrawal.withdraw_tx.amount,
+			destination: withdrawal.withdraw_tx.destination,
+			delayed_payment_id,
+		});
+		DelayedAssetPayments::<T>::insert(delayed_payment_id, withdrawal);
+		Ok(())
+	}
+
+	/// Reserve the next delayed payment id and schedule it for processing `delay` blocks from now
+	fn schedule_delayed_payment(delay: T::BlockNumber) -> Result<DelayedPaymentId, DispatchError> {
+		// Get the next payment ID
+		let delayed_payment_id = NextDelayedPaymentId::<T>::get();
+		ensure!(
+			delayed_payment_id.checked_add(One::one()).is_some(),
+			Error::<T>::NoAvailablePaymentIds
+		);
+
+		let payment_block = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+		DelayedPaymentSchedule::<T>::try_append(payment_block, delayed_payment_id)
+			.map_err(|_| Error::<T>::DelayScheduleAtCapacity)?;
+		NextDelayedPaymentId::<T>::put(delayed_payment_id + 1);
+
+		Ok(delayed_payment_id)
+	}
+
 	/// Construct an XRPL payment transaction and submit for signing
 	/// Returns a (proof_id, tx_blob)
 	fn submit_withdraw_request(
@@ -1078,13 +1823,75 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Construct an XRPL issued currency payment transaction and submit for signing
+	fn submit_asset_withdraw_request(
+		sender: T::AccountId,
+		door_address: [u8; 20],
+		asset_id: AssetId,
+		currency: XrplCurrency,
+		tx_data: XrpWithdrawTransaction,
+		destination_tag: Option<u32>,
+	) -> DispatchResult {
+		let XrpWithdrawTransaction { tx_fee, tx_nonce, tx_ticket_sequence, amount, destination } =
+			tx_data;
+		let issued_value =
+			XrplIssuedValue::from_balance(amount, T::MultiCurrency::decimals(&asset_id))
+				.ok_or(Error::<T>::WithdrawInvalidAmount)?;
+		let amount_issued = IssuedAmount::new(
+			issued_value.mantissa,
+			issued_value.exponent,
+			currency.currency_code,
+			currency.issuer.into(),
+		);
+
+		let tx_blob = match destination_tag {
+			Some(destination_tag) => PaymentAltCurrencyWithDestinationTag::new(
+				door_address,
+				destination.into(),
+				amount_issued,
+				tx_nonce,
+				tx_ticket_sequence,
+				tx_fee,
+				SourceTag::<T>::get(),
+				destination_tag,
+				// omit signer key since this is a 'MultiSigner' tx
+				None,
+			)
+			.binary_serialize(true),
+			None => PaymentAltCurrency::new(
+				door_address,
+				destination.into(),
+				amount_issued,
+				tx_nonce,
+				tx_ticket_sequence,
+				tx_fee,
+				SourceTag::<T>::get(),
+				// omit signer key since this is a 'MultiSigner' tx
+				None,
+			)
+			.binary_serialize(true),
+		};
+
+		let proof_id = T::EthyAdapter::sign_xrpl_transaction(tx_blob.as_slice())?;
+		Self::deposit_event(Event::AssetWithdrawRequest {
+			proof_id,
+			sender,
+			asset_id,
+			amount,
+			destination,
+		});
+
+		Ok(())
+	}
+
 	// Return the current door ticket sequence and increment it in storage
 	pub fn get_door_ticket_sequence() -> Result<XrplTxTicketSequence, DispatchError> {
 		let mut current_sequence = Self::door_ticket_sequence();
 		let ticket_params = Self::door_ticket_sequence_params();
 
 		// check if TicketSequenceThreshold reached. notify by emitting
-		// TicketSequenceThresholdReached
+		// TicketSequenceThresholdReached, callers request a new ticket allocation with
+		// `request_door_ticket_refill`
 		if ticket_params.bucket_size != 0 &&
 			Percent::from_rational(
 				current_sequence - ticket_params.start_sequence + 1,
@@ -1118,12 +1925,87 @@ impl<T: Config> Pallet<T> {
 
 				DoorTicketSequenceParamsNext::<T>::kill();
 				TicketSequenceThresholdReachedEmitted::<T>::kill();
+				DoorTicketRefillRequested::<T>::kill();
 			}
 		}
 		DoorTicketSequence::<T>::set(next_sequence);
 
 		Ok(current_sequence)
 	}
+
+	/// Request a new ticket allocation for the door account once the ticket sequence threshold
+	/// is reached
+	/// Failing to request new tickets must not block the caller, the failure is reported and
+	/// retried on the next door tx. The allocation can still be set manually through
+	/// `set_ticket_sequence_next_allocation`
+	pub fn request_door_ticket_refill() {
+		if !Self::ticket_sequence_threshold_reached_emitted() ||
+			Self::door_ticket_refill_requested() ||
+			Self::door_address().is_none()
+		{
+			return
+		}
+
+		let ticket_count = Self::door_ticket_sequence_params().bucket_size;
+		match Self::submit_ticket_create_request(ticket_count) {
+			Ok(()) => DoorTicketRefillRequested::<T>::put(true),
+			Err(error) => {
+				log::warn!(target: "xrpl-bridge", "🌉 TicketCreate request failed: {:?}", error);
+				Self::deposit_event(Event::<T>::TicketCreateRequestFailed { error });
+			},
+		}
+	}
+
+	/// Construct an XRPL TicketCreate transaction for the door account and submit for signing
+	/// The allocated range is confirmed by relayers via `XrplTxData::TicketCreate`
+	#[transactional]
+	fn submit_ticket_create_request(ticket_count: u32) -> DispatchResult {
+		let door_address = Self::door_address().ok_or(Error::<T>::DoorAddressNotSet)?;
+		let tx_fee = Self::door_tx_fee();
+		let ticket_sequence = Self::get_door_ticket_sequence()?;
+
+		let tx_blob = TicketCreate::new(
+			door_address.into(),
+			0_u32, // Sequence = 0 when using TicketSequence
+			ticket_sequence,
+			ticket_count,
+			tx_fee,
+			SourceTag::<T>::get(),
+			// omit signer key since this is a 'MultiSigner' tx
+			None,
+		)
+		.binary_serialize(true);
+
+		let proof_id = T::EthyAdapter::sign_xrpl_transaction(tx_blob.as_slice())?;
+		Self::deposit_event(Event::TicketCreateRequest { proof_id, ticket_sequence, ticket_count });
+
+		Ok(())
+	}
+
+	/// Set the door account ticket sequence params for the next allocation
+	fn do_set_ticket_sequence_next_allocation(
+		start_ticket_sequence: u32,
+		ticket_bucket_size: u32,
+	) -> DispatchResult {
+		let current_ticket_sequence = Self::door_ticket_sequence();
+		let current_params = Self::door_ticket_sequence_params();
+
+		if start_ticket_sequence < current_ticket_sequence ||
+			start_ticket_sequence < current_params.start_sequence ||
+			ticket_bucket_size == 0
+		{
+			fail!(Error::<T>::NextTicketSequenceParamsInvalid);
+		}
+		DoorTicketSequenceParamsNext::<T>::put(XrplTicketSequenceParams {
+			start_sequence: start_ticket_sequence,
+			bucket_size: ticket_bucket_size,
+		});
+		Self::deposit_event(Event::<T>::DoorNextTicketSequenceParamSet {
+			ticket_sequence_start_next: start_ticket_sequence,
+			ticket_bucket_size_next: ticket_bucket_size,
+		});
+		Ok(())
+	}
 }
 
 impl<T: Config> EthyToXrplBridgeAdapter<XrplAccountId> for Pallet<T> {
@@ -1131,10 +2013,9 @@ impl<T: Config> EthyToXrplBridgeAdapter<XrplAccountId> for Pallet<T> {
 		signer_entries: Vec<(XrplAccountId, u16)>,
 	) -> Result<EventProofId, DispatchError> {
 		let door_address = Self::door_address().ok_or(Error::<T>::DoorAddressNotSet)?;
-		// TODO: need a fee oracle, this is over estimating the fee
-		// https://github.com/futureversecom/seed/issues/107
 		let tx_fee = Self::door_tx_fee();
 		let ticket_sequence = Self::get_door_ticket_sequence()?;
+		Self::request_door_ticket_refill();
 		let signer_quorum: u32 = signer_entries.len().saturating_sub(1) as u32;
 		let signer_entries = signer_entries
 			.into_iter()
@@ -14,7 +14,10 @@
 // You may obtain a copy of the License at the root of this project source code
 
 use seed_pallet_common::test_prelude::*;
-use seed_primitives::ethy::{crypto::AuthorityId, EventProofId};
+use seed_primitives::{
+	ethy::{crypto::AuthorityId, EventProofId},
+	xrpl::Xls20TokenId,
+};
 use sp_core::ByteArray;
 use sp_runtime::Percent;
 
@@ -54,7 +57,11 @@ parameter_types! {
 	pub const MaxPrunedTransactionsPerBlock: u32 = 5000;
 	pub const MaxDelayedPaymentsPerBlock: u32 = 1000;
 	pub const DelayedPaymentBlockLimit: BlockNumber = 1000;
+	pub const RelayerUnbondingPeriod: BlockNumber = 100;
+	pub const FeeObservationExpiry: BlockNumber = 100;
 	pub const XrpAssetId: u32 = XRP_ASSET_ID;
+	pub const NativeAssetId: u32 = ROOT_ASSET_ID;
+	pub const XrplBridgePalletId: PalletId = PalletId(*b"xrplbrdg");
 	pub const SourceTag: u32 = 723456_u32;
 }
 
@@ -62,13 +69,18 @@ impl pallet_xrpl_bridge::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EthyAdapter = MockEthyAdapter;
 	type MultiCurrency = AssetsExt;
+	type Xls20Ext = MockXls20Ext;
 	type ApproveOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 	type XrpAssetId = XrpAssetId;
+	type NativeAssetId = NativeAssetId;
+	type PalletId = XrplBridgePalletId;
 	type ChallengePeriod = XrpTxChallengePeriod;
 	type MaxPrunedTransactionsPerBlock = MaxPrunedTransactionsPerBlock;
 	type MaxDelayedPaymentsPerBlock = MaxDelayedPaymentsPerBlock;
 	type DelayedPaymentBlockLimit = DelayedPaymentBlockLimit;
+	type RelayerUnbondingPeriod = RelayerUnbondingPeriod;
+	type FeeObservationExpiry = FeeObservationExpiry;
 	type UnixTime = TimestampPallet;
 	type TicketSequenceThreshold = TicketSequenceThreshold;
 	type XRPTransactionLimit = XRPTransactionLimit;
@@ -99,3 +111,42 @@ impl XrplBridgeToEthyAdapter<AuthorityId> for MockEthyAdapter {
 		]
 	}
 }
+
+/// The only token known to `MockXls20Ext`
+pub const MOCK_TOKEN_ID: TokenId = (1, 0);
+/// The XLS-20 token id `MOCK_TOKEN_ID` is mapped to
+pub const MOCK_XLS20_TOKEN_ID: Xls20TokenId = [b'a'; 64];
+
+pub struct MockXls20Ext;
+
+impl Xls20Ext for MockXls20Ext {
+	type AccountId = AccountId;
+
+	/// Mock implementation of Xls20Ext, only `MOCK_TOKEN_ID` can be locked
+	fn lock_xls20_token(
+		_who: &Self::AccountId,
+		token_id: TokenId,
+	) -> Result<Xls20TokenId, DispatchError> {
+		if token_id == MOCK_TOKEN_ID {
+			Ok(MOCK_XLS20_TOKEN_ID)
+		} else {
+			Err(DispatchError::Other("No mapping exists"))
+		}
+	}
+
+	fn unlock_xls20_token(
+		_who: &Self::AccountId,
+		xls20_token_id: &Xls20TokenId,
+	) -> Result<TokenId, DispatchError> {
+		if *xls20_token_id == MOCK_XLS20_TOKEN_ID {
+			Ok(MOCK_TOKEN_ID)
+		} else {
+			Err(DispatchError::Other("No mapping exists"))
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_benchmark_token(_owner: &Self::AccountId) -> TokenId {
+		MOCK_TOKEN_ID
+	}
+}
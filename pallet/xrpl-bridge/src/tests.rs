@@ -15,8 +15,9 @@
 
 use super::*;
 use crate::mock::{
-	AssetsExt, DelayedPaymentBlockLimit, MaxPrunedTransactionsPerBlock, RuntimeOrigin, System,
-	Test, XRPLBridge, XrpAssetId, XrpTxChallengePeriod,
+	AssetsExt, DelayedPaymentBlockLimit, FeeObservationExpiry, MaxPrunedTransactionsPerBlock,
+	RuntimeOrigin, System, Test, XRPLBridge, XrpAssetId, XrpTxChallengePeriod, MOCK_TOKEN_ID,
+	MOCK_XLS20_TOKEN_ID,
 };
 use seed_pallet_common::test_prelude::*;
 
@@ -224,6 +225,129 @@ fn set_door_tx_fee_works() {
 	});
 }
 
+#[test]
+fn submit_fee_observation_works() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let relayer_1 = create_account(1);
+		let relayer_2 = create_account(2);
+		let relayer_3 = create_account(3);
+		XRPLBridge::initialize_relayer(&vec![relayer_1, relayer_2, relayer_3]);
+
+		// observations are ignored until the fee bounds are set
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer_1), 10, 256));
+		assert_eq!(XRPLBridge::observed_door_tx_fee(), None);
+		assert_eq!(XRPLBridge::door_tx_fee(), 1_000_000);
+		assert_ok!(XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::root(), 1, 1_000_000));
+
+		// single signed fees of 10, 24 and 15 drops
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer_1), 10, 256));
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer_2), 12, 512));
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer_3), 15, 256));
+
+		// median fee of 15 drops * (1 + 3 mock xrpl signers)
+		assert_eq!(XRPLBridge::observed_door_tx_fee(), Some(60));
+		assert_eq!(XRPLBridge::door_tx_fee(), 60);
+		System::assert_last_event(Event::<Test>::DoorTxFeeUpdated { fee: 60 }.into());
+
+		// observations of removed relayers are discarded
+		assert_ok!(XRPLBridge::remove_relayer(RuntimeOrigin::root(), relayer_3));
+		assert!(DoorTxFeeObservations::<Test>::get(relayer_3).is_none());
+		assert_eq!(XRPLBridge::door_tx_fee(), 96);
+	});
+}
+
+#[test]
+fn submit_fee_observation_expires_stale_observations() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let relayer_1 = create_account(1);
+		let relayer_2 = create_account(2);
+		XRPLBridge::initialize_relayer(&vec![relayer_1, relayer_2]);
+		assert_ok!(XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::root(), 1, 1_000_000));
+
+		// single signed fee of 100 drops * (1 + 3 mock xrpl signers)
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer_1), 100, 256));
+		assert_eq!(XRPLBridge::door_tx_fee(), 400);
+
+		// relayer_1's observation has expired and is replaced by relayer_2's
+		System::set_block_number(FeeObservationExpiry::get() + 2);
+		assert_eq!(XRPLBridge::observed_door_tx_fee(), None);
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer_2), 10, 256));
+		assert!(DoorTxFeeObservations::<Test>::get(relayer_1).is_none());
+		assert_eq!(XRPLBridge::door_tx_fee(), 40);
+	});
+}
+
+#[test]
+fn submit_fee_observation_fails() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+
+		// not a relayer
+		assert_noop!(
+			XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(create_account(2)), 10, 256),
+			Error::<Test>::NotPermitted
+		);
+		assert_noop!(
+			XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer), 0, 256),
+			Error::<Test>::InvalidFeeObservation
+		);
+		assert_noop!(
+			XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer), 10, 0),
+			Error::<Test>::InvalidFeeObservation
+		);
+		assert_eq!(XRPLBridge::door_tx_fee(), 1_000_000);
+	});
+}
+
+#[test]
+fn set_door_tx_fee_bounds_works() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+
+		// without observations the bounds don't change the fee
+		assert_ok!(XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::root(), 100, 200));
+		System::assert_last_event(
+			Event::<Test>::DoorTxFeeBoundsSet { min_fee: 100, max_fee: 200 }.into(),
+		);
+		assert_eq!(XRPLBridge::door_tx_fee(), 1_000_000);
+
+		// observed fee of 40 drops is raised to the minimum
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer), 10, 256));
+		assert_eq!(XRPLBridge::door_tx_fee(), 100);
+
+		// observed fee of 4000 drops is capped at the maximum
+		assert_ok!(XRPLBridge::submit_fee_observation(RuntimeOrigin::signed(relayer), 10, 25_600));
+		assert_eq!(XRPLBridge::door_tx_fee(), 200);
+
+		// lowering the maximum applies to the existing observations
+		assert_ok!(XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::root(), 100, 150));
+		assert_eq!(XRPLBridge::door_tx_fee(), 150);
+	});
+}
+
+#[test]
+fn set_door_tx_fee_bounds_fails() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		assert_noop!(
+			XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::signed(create_account(1)), 1, 2),
+			BadOrigin
+		);
+		assert_noop!(
+			XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::root(), 0, 2),
+			Error::<Test>::InvalidDoorTxFeeBounds
+		);
+		assert_noop!(
+			XRPLBridge::set_door_tx_fee_bounds(RuntimeOrigin::root(), 3, 2),
+			Error::<Test>::InvalidDoorTxFeeBounds
+		);
+	});
+}
+
 #[test]
 fn set_xrp_source_tag_works() {
 	TestExt::<Test>::default().build().execute_with(|| {
@@ -1487,7 +1611,7 @@ fn process_delayed_payments_works() {
 				Weight::from_ref_time(1_000_000_000_000),
 			);
 			// Assert weight used is as expected
-			assert_eq!(weight_used, DbWeight::get().reads_writes(6, 4));
+			assert_eq!(weight_used, DbWeight::get().reads_writes(7, 4));
 
 			// Ensure event is thrown
 			System::assert_last_event(
@@ -1628,7 +1752,7 @@ fn process_delayed_payments_multiple_withdrawals() {
 				Weight::from_ref_time(1_000_000_000_000),
 			);
 			// Assert weight used is as expected
-			let weight_per_tx = DbWeight::get().reads_writes(3u64, 2u64);
+			let weight_per_tx = DbWeight::get().reads_writes(4u64, 2u64);
 			let base_weight = DbWeight::get().reads_writes(7u64, 1u64);
 			let total_weight = base_weight +
 				Weight::from_ref_time(weight_per_tx.ref_time() * withdrawal_count as u64);
@@ -1701,7 +1825,7 @@ fn process_delayed_payments_multiple_withdrawals_across_multiple_blocks() {
 				Weight::from_ref_time(1_000_000_000_000),
 			);
 			// Assert weight used is as expected
-			let weight_per_tx = DbWeight::get().reads_writes(4u64, 3u64);
+			let weight_per_tx = DbWeight::get().reads_writes(5u64, 3u64);
 			let base_weight = DbWeight::get().reads_writes(3u64, 1u64);
 			let total_weight = base_weight +
 				Weight::from_ref_time(weight_per_tx.ref_time() * withdrawal_count as u64);
@@ -1981,6 +2105,149 @@ fn get_door_ticket_sequence_check_events_emitted() {
 	})
 }
 
+#[test]
+fn request_door_ticket_refill_requests_ticket_create() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+		assert_ok!(XRPLBridge::set_door_address(
+			RuntimeOrigin::root(),
+			XrplAccountId::from_low_u64_be(555)
+		));
+		assert_ok!(XRPLBridge::set_ticket_sequence_current_allocation(
+			RuntimeOrigin::root(),
+			1_u32,
+			1_u32,
+			3_u32
+		));
+
+		assert_eq!(XRPLBridge::get_door_ticket_sequence(), Ok(1));
+		// threshold not reached, nothing to request
+		XRPLBridge::request_door_ticket_refill();
+		assert_eq!(XRPLBridge::door_ticket_refill_requested(), false);
+
+		// threshold reached, the getter only notifies
+		assert_eq!(XRPLBridge::get_door_ticket_sequence(), Ok(2));
+		System::assert_last_event(Event::<Test>::TicketSequenceThresholdReached(2).into());
+
+		// ticket 3 is used for the TicketCreate tx
+		XRPLBridge::request_door_ticket_refill();
+		System::assert_last_event(
+			Event::<Test>::TicketCreateRequest { proof_id: 1, ticket_sequence: 3, ticket_count: 3 }
+				.into(),
+		);
+		assert_eq!(XRPLBridge::door_ticket_refill_requested(), true);
+
+		// only one refill is requested per allocation
+		let event_count = System::events().len();
+		XRPLBridge::request_door_ticket_refill();
+		assert_eq!(System::events().len(), event_count);
+		assert_noop!(
+			XRPLBridge::get_door_ticket_sequence(),
+			Error::<Test>::NextTicketSequenceParamsNotSet
+		);
+
+		// relayer confirms the allocated range
+		let transaction_hash = XrplTxHash::from_low_u64_be(123);
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer),
+			1,
+			transaction_hash,
+			XrplTxData::TicketCreate { start_sequence: 10, ticket_count: 3 },
+			1234
+		));
+		XRPLBridge::on_initialize(XrpTxChallengePeriod::get() as u64 + 1);
+		System::assert_has_event(
+			Event::<Test>::DoorNextTicketSequenceParamSet {
+				ticket_sequence_start_next: 10,
+				ticket_bucket_size_next: 3,
+			}
+			.into(),
+		);
+		assert_eq!(
+			XRPLBridge::door_ticket_sequence_params_next(),
+			XrplTicketSequenceParams { start_sequence: 10, bucket_size: 3 }
+		);
+
+		// the next allocation is switched in
+		assert_eq!(XRPLBridge::get_door_ticket_sequence(), Ok(10));
+		assert_eq!(
+			XRPLBridge::door_ticket_sequence_params(),
+			XrplTicketSequenceParams { start_sequence: 10, bucket_size: 3 }
+		);
+	})
+}
+
+#[test]
+fn request_door_ticket_refill_failure_is_reported() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(XRPLBridge::set_door_address(
+			RuntimeOrigin::root(),
+			XrplAccountId::from_low_u64_be(555)
+		));
+		assert_ok!(XRPLBridge::set_ticket_sequence_current_allocation(
+			RuntimeOrigin::root(),
+			1_u32,
+			1_u32,
+			2_u32
+		));
+
+		assert_eq!(XRPLBridge::get_door_ticket_sequence(), Ok(1));
+		assert_eq!(XRPLBridge::get_door_ticket_sequence(), Ok(2));
+
+		// no ticket is left for the TicketCreate tx
+		XRPLBridge::request_door_ticket_refill();
+		System::assert_last_event(
+			Event::<Test>::TicketCreateRequestFailed {
+				error: Error::<Test>::NextTicketSequenceParamsNotSet.into(),
+			}
+			.into(),
+		);
+		assert_eq!(XRPLBridge::door_ticket_refill_requested(), false);
+		assert_eq!(XRPLBridge::door_ticket_sequence(), 3);
+	})
+}
+
+#[test]
+fn process_xrp_tx_ticket_create_invalid_fails() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+		assert_ok!(XRPLBridge::set_ticket_sequence_current_allocation(
+			RuntimeOrigin::root(),
+			5_u32,
+			5_u32,
+			3_u32
+		));
+
+		// start sequence is lower than the current ticket sequence
+		let transaction_hash = XrplTxHash::from_low_u64_be(123);
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer),
+			1,
+			transaction_hash,
+			XrplTxData::TicketCreate { start_sequence: 2, ticket_count: 3 },
+			1234
+		));
+		XRPLBridge::on_initialize(XrpTxChallengePeriod::get() as u64 + 1);
+		System::assert_has_event(
+			Event::<Test>::ProcessingFailed(
+				1,
+				transaction_hash,
+				Error::<Test>::NextTicketSequenceParamsInvalid.into(),
+			)
+			.into(),
+		);
+		assert_eq!(
+			XRPLBridge::door_ticket_sequence_params_next(),
+			XrplTicketSequenceParams::default()
+		);
+	})
+}
+
 #[test]
 fn set_ticket_sequence_current_allocation_success() {
 	TestExt::<Test>::default().build().execute_with(|| {
@@ -2328,35 +2595,68 @@ fn process_xrp_tx_success() {
 }
 
 #[test]
-fn process_xrp_tx_not_supported_transaction() {
+fn process_xrp_tx_xls20_works() {
 	TestExt::<Test>::default().build().execute_with(|| {
 		System::set_block_number(1);
-		let account = create_account(2);
+		let account_address = b"6490B68F1116BFE87DDC";
 		let transaction_hash = b"6490B68F1116BFE87DDDAD4C5482D1514F9CA8B9B5B5BFD3CF81D8E68745317B";
 		let relayer = create_account(1);
 		XRPLBridge::initialize_relayer(&vec![relayer]);
 
-		// submit currency payment tx
-		let currency_payment_tx = XrplTxData::CurrencyPayment {
-			amount: (1 * 1000u64) as Balance,
-			address: account.into(),
-			currency_id: H256::random(),
-		};
+		// submit xls20 tx
 		assert_ok!(XRPLBridge::submit_transaction(
 			RuntimeOrigin::signed(relayer),
 			1_000_000,
 			XrplTxHash::from_slice(transaction_hash),
-			currency_payment_tx,
+			XrplTxData::Xls20 {
+				token_id: MOCK_XLS20_TOKEN_ID,
+				address: H160::from_slice(account_address),
+			},
 			1234
 		));
 
 		System::reset_events();
 		XRPLBridge::process_xrp_tx(XrpTxChallengePeriod::get() as u64 + 1);
 		System::set_block_number(XrpTxChallengePeriod::get() as u64 + 1);
-		System::assert_has_event(Event::<Test>::NotSupportedTransaction.into());
+		System::assert_has_event(
+			Event::<Test>::ProcessingOk(1_000_000, XrplTxHash::from_slice(transaction_hash)).into(),
+		);
+		assert_eq!(
+			XRPLBridge::settled_xrp_transaction_details(1_000_000).unwrap().into_inner(),
+			vec![XrplTxHash::from_slice(transaction_hash)]
+		);
+	})
+}
 
-		let xrp_balance = xrp_balance_of(account);
-		assert_eq!(xrp_balance, 0);
+#[test]
+fn process_xrp_tx_xls20_unknown_token_fails() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let account_address = b"6490B68F1116BFE87DDC";
+		let transaction_hash = b"6490B68F1116BFE87DDDAD4C5482D1514F9CA8B9B5B5BFD3CF81D8E68745317B";
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+
+		// submit xls20 tx for a token that was never bridged out
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer),
+			1_000_000,
+			XrplTxHash::from_slice(transaction_hash),
+			XrplTxData::Xls20 { token_id: [b'b'; 64], address: H160::from_slice(account_address) },
+			1234
+		));
+
+		System::reset_events();
+		XRPLBridge::process_xrp_tx(XrpTxChallengePeriod::get() as u64 + 1);
+		System::set_block_number(XrpTxChallengePeriod::get() as u64 + 1);
+		System::assert_has_event(
+			Event::<Test>::ProcessingFailed(
+				1_000_000,
+				XrplTxHash::from_slice(transaction_hash),
+				DispatchError::Other("No mapping exists"),
+			)
+			.into(),
+		);
 	})
 }
 
@@ -2431,3 +2731,501 @@ fn process_xrp_tx_processing_failed() {
 		}
 	})
 }
+
+/// Helper to create an XRPL issued currency for tests
+fn xrpl_usd() -> XrplCurrency {
+	let mut currency_code = [0_u8; 20];
+	currency_code[12..15].copy_from_slice(b"USD");
+	XrplCurrency { issuer: XrplAccountId::from_slice(b"7490B68F2d16B3E87cba"), currency_code }
+}
+
+#[test]
+fn set_xrpl_asset_map_works() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let asset_id = 3;
+		let xrpl_currency = xrpl_usd();
+
+		assert_ok!(XRPLBridge::set_xrpl_asset_map(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(xrpl_currency)
+		));
+		System::assert_last_event(
+			Event::<Test>::XrplAssetMapSet { asset_id, xrpl_currency }.into(),
+		);
+		assert_eq!(AssetIdToXrplCurrency::<Test>::get(asset_id), Some(xrpl_currency));
+		assert_eq!(XrplCurrencyToAssetId::<Test>::get(xrpl_currency), Some(asset_id));
+
+		// Re-mapping the asset clears the previous currency
+		let mut new_currency = xrpl_currency;
+		new_currency.currency_code[12..15].copy_from_slice(b"EUR");
+		assert_ok!(XRPLBridge::set_xrpl_asset_map(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(new_currency)
+		));
+		assert_eq!(AssetIdToXrplCurrency::<Test>::get(asset_id), Some(new_currency));
+		assert_eq!(XrplCurrencyToAssetId::<Test>::get(new_currency), Some(asset_id));
+		assert!(XrplCurrencyToAssetId::<Test>::get(xrpl_currency).is_none());
+
+		// Remove the mapping
+		assert_ok!(XRPLBridge::set_xrpl_asset_map(RuntimeOrigin::root(), asset_id, None));
+		System::assert_last_event(
+			Event::<Test>::XrplAssetMapRemoved { asset_id, xrpl_currency: new_currency }.into(),
+		);
+		assert!(AssetIdToXrplCurrency::<Test>::get(asset_id).is_none());
+		assert!(XrplCurrencyToAssetId::<Test>::get(new_currency).is_none());
+	});
+}
+
+#[test]
+fn set_xrpl_asset_map_fails() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let xrpl_currency = xrpl_usd();
+
+		// Only the approved origin can set the map
+		assert_noop!(
+			XRPLBridge::set_xrpl_asset_map(
+				RuntimeOrigin::signed(create_account(1)),
+				3,
+				Some(xrpl_currency)
+			),
+			BadOrigin
+		);
+
+		// XRP can't be mapped to an issued currency
+		assert_noop!(
+			XRPLBridge::set_xrpl_asset_map(
+				RuntimeOrigin::root(),
+				XrpAssetId::get(),
+				Some(xrpl_currency)
+			),
+			Error::<Test>::AssetNotSupported
+		);
+
+		// Removing a mapping that doesn't exist fails
+		assert_noop!(
+			XRPLBridge::set_xrpl_asset_map(RuntimeOrigin::root(), 3, None),
+			Error::<Test>::AssetNotSupported
+		);
+
+		// A currency can only be mapped to a single asset
+		assert_ok!(XRPLBridge::set_xrpl_asset_map(RuntimeOrigin::root(), 3, Some(xrpl_currency)));
+		assert_noop!(
+			XRPLBridge::set_xrpl_asset_map(RuntimeOrigin::root(), 4, Some(xrpl_currency)),
+			Error::<Test>::XrplCurrencyAlreadyMapped
+		);
+	});
+}
+
+#[test]
+fn process_xrp_tx_currency_payment_works() {
+	TestExt::<Test>::default().with_asset(3, "USD", &[]).build().execute_with(|| {
+		let asset_id = 3;
+		let account = create_account(12);
+		let transaction_hash = b"6490B68F1116BFE87DDDAD4C5482D1514F9CA8B9B5B5BFD3CF81D8E68745317B";
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+		assert_ok!(XRPLBridge::set_xrpl_asset_map(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(xrpl_usd())
+		));
+
+		let currency_payment_tx = XrplTxData::CurrencyPayment {
+			amount: 5_000_000,
+			address: account.into(),
+			currency: xrpl_usd(),
+		};
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer),
+			1_000_000,
+			XrplTxHash::from_slice(transaction_hash),
+			currency_payment_tx,
+			1234
+		));
+
+		System::reset_events();
+		XRPLBridge::process_xrp_tx(XrpTxChallengePeriod::get() as u64 + 1);
+		System::assert_has_event(
+			Event::<Test>::ProcessingOk(1_000_000_u64, XrplTxHash::from_slice(transaction_hash))
+				.into(),
+		);
+		assert_eq!(AssetsExt::balance(asset_id, &account), 5_000_000);
+	})
+}
+
+#[test]
+fn process_xrp_tx_currency_payment_unmapped_currency_fails() {
+	TestExt::<Test>::default().with_asset(3, "USD", &[]).build().execute_with(|| {
+		let account = create_account(12);
+		let transaction_hash = b"6490B68F1116BFE87DDDAD4C5482D1514F9CA8B9B5B5BFD3CF81D8E68745317B";
+		let relayer = create_account(1);
+		XRPLBridge::initialize_relayer(&vec![relayer]);
+
+		let currency_payment_tx = XrplTxData::CurrencyPayment {
+			amount: 5_000_000,
+			address: account.into(),
+			currency: xrpl_usd(),
+		};
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer),
+			1_000_000,
+			XrplTxHash::from_slice(transaction_hash),
+			currency_payment_tx,
+			1234
+		));
+
+		System::reset_events();
+		XRPLBridge::process_xrp_tx(XrpTxChallengePeriod::get() as u64 + 1);
+		System::assert_has_event(
+			Event::<Test>::ProcessingFailed(
+				1_000_000_u64,
+				XrplTxHash::from_slice(transaction_hash),
+				Error::<Test>::AssetNotSupported.into(),
+			)
+			.into(),
+		);
+		assert_eq!(AssetsExt::balance(3, &account), 0);
+	})
+}
+
+#[test]
+fn withdraw_asset_works() {
+	let account = create_account(1);
+	TestExt::<Test>::default()
+		.with_asset(2, "XRP", &[(account, 2_000_000)])
+		.with_asset(3, "USD", &[(account, 5_000_000)])
+		.build()
+		.execute_with(|| {
+			let asset_id = 3;
+			let door = XrplAccountId::from_slice(b"5490B68F2d16B3E87cba");
+			let destination = XrplAccountId::from_slice(b"6490B68F1116BFE87DDD");
+			assert_ok!(XRPLBridge::set_door_address(RuntimeOrigin::root(), door));
+			assert_ok!(XRPLBridge::set_ticket_sequence_current_allocation(
+				RuntimeOrigin::root(),
+				1_u32,
+				1_u32,
+				200_u32
+			));
+
+			// asset not mapped
+			assert_noop!(
+				XRPLBridge::withdraw_asset(
+					RuntimeOrigin::signed(account),
+					asset_id,
+					1_000_000,
+					destination,
+					None
+				),
+				Error::<Test>::AssetNotSupported
+			);
+			assert_ok!(XRPLBridge::set_xrpl_asset_map(
+				RuntimeOrigin::root(),
+				asset_id,
+				Some(xrpl_usd())
+			));
+
+			assert_ok!(XRPLBridge::withdraw_asset(
+				RuntimeOrigin::signed(account),
+				asset_id,
+				1_000_000,
+				destination,
+				None
+			));
+			System::assert_last_event(
+				Event::<Test>::AssetWithdrawRequest {
+					proof_id: 1,
+					sender: account,
+					asset_id,
+					amount: 1_000_000,
+					destination,
+				}
+				.into(),
+			);
+			// The asset amount is burnt and the door fee is charged in XRP
+			assert_eq!(AssetsExt::balance(asset_id, &account), 4_000_000);
+			assert_eq!(xrp_balance_of(account), 2_000_000 - XRPLBridge::door_tx_fee());
+
+			// with destination tag
+			assert_ok!(XRPLBridge::withdraw_asset(
+				RuntimeOrigin::signed(account),
+				asset_id,
+				1_000_000,
+				destination,
+				Some(123)
+			));
+			assert_eq!(AssetsExt::balance(asset_id, &account), 3_000_000);
+
+			// zero amount
+			assert_noop!(
+				XRPLBridge::withdraw_asset(
+					RuntimeOrigin::signed(account),
+					asset_id,
+					0,
+					destination,
+					None
+				),
+				Error::<Test>::WithdrawInvalidAmount
+			);
+		})
+}
+
+#[test]
+fn set_asset_payment_delay_works() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		let asset_id = 3;
+		assert_noop!(
+			XRPLBridge::set_asset_payment_delay(
+				RuntimeOrigin::signed(create_account(1)),
+				asset_id,
+				Some((100, 1000))
+			),
+			BadOrigin
+		);
+
+		assert_ok!(XRPLBridge::set_asset_payment_delay(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some((100, 1000))
+		));
+		assert_eq!(AssetPaymentDelay::<Test>::get(asset_id), Some((100, 1000)));
+		System::assert_last_event(
+			Event::<Test>::AssetPaymentDelaySet { asset_id, payment_threshold: 100, delay: 1000 }
+				.into(),
+		);
+		// Other assets are unaffected
+		assert_eq!(AssetPaymentDelay::<Test>::get(asset_id + 1), None);
+
+		assert_ok!(XRPLBridge::set_asset_payment_delay(RuntimeOrigin::root(), asset_id, None));
+		assert_eq!(AssetPaymentDelay::<Test>::get(asset_id), None);
+		System::assert_last_event(Event::<Test>::AssetPaymentDelayRemoved { asset_id }.into());
+	})
+}
+
+#[test]
+fn withdraw_asset_with_payment_delay_works() {
+	let account = create_account(1);
+	TestExt::<Test>::default()
+		.with_asset(2, "XRP", &[(account, 2_000_000)])
+		.with_asset(3, "USD", &[(account, 5_000_000)])
+		.build()
+		.execute_with(|| {
+			let asset_id = 3;
+			let amount = 1_000_000;
+			let door = XrplAccountId::from_slice(b"5490B68F2d16B3E87cba");
+			let destination = XrplAccountId::from_slice(b"6490B68F1116BFE87DDD");
+			let delay_blocks = 1000;
+			let block_number = System::block_number();
+			assert_ok!(XRPLBridge::set_door_tx_fee(frame_system::RawOrigin::Root.into(), 0_u64));
+			assert_ok!(XRPLBridge::set_door_address(RuntimeOrigin::root(), door));
+			assert_ok!(XRPLBridge::set_ticket_sequence_current_allocation(
+				RuntimeOrigin::root(),
+				1_u32,
+				1_u32,
+				200_u32
+			));
+			assert_ok!(XRPLBridge::set_xrpl_asset_map(
+				RuntimeOrigin::root(),
+				asset_id,
+				Some(xrpl_usd())
+			));
+			assert_ok!(XRPLBridge::set_asset_payment_delay(
+				RuntimeOrigin::root(),
+				asset_id,
+				Some((amount, delay_blocks))
+			));
+
+			let ticket_sequence = XRPLBridge::door_ticket_sequence();
+			let delayed_payment_id = NextDelayedPaymentId::<Test>::get();
+			assert_ok!(XRPLBridge::withdraw_asset(
+				RuntimeOrigin::signed(account),
+				asset_id,
+				amount,
+				destination,
+				None
+			));
+			System::assert_last_event(
+				Event::<Test>::AssetWithdrawDelayed {
+					sender: account,
+					asset_id,
+					amount,
+					destination,
+					delayed_payment_id,
+				}
+				.into(),
+			);
+
+			// The asset is burnt and the withdrawal is scheduled
+			assert_eq!(AssetsExt::balance(asset_id, &account), 4_000_000);
+			assert_eq!(NextDelayedPaymentId::<Test>::get(), delayed_payment_id + 1);
+			assert_eq!(
+				DelayedAssetPayments::<Test>::get(delayed_payment_id),
+				Some(DelayedAssetWithdrawal {
+					sender: account,
+					destination_tag: None,
+					asset_id,
+					currency: xrpl_usd(),
+					withdraw_tx: XrpWithdrawTransaction {
+						tx_nonce: 0_u32,
+						tx_fee: 0,
+						amount,
+						destination,
+						tx_ticket_sequence: ticket_sequence,
+					},
+				})
+			);
+			assert_eq!(
+				DelayedPaymentSchedule::<Test>::get(block_number + delay_blocks)
+					.unwrap()
+					.into_inner(),
+				vec![delayed_payment_id]
+			);
+
+			// Withdrawals below the threshold are not delayed
+			assert_ok!(XRPLBridge::withdraw_asset(
+				RuntimeOrigin::signed(account),
+				asset_id,
+				amount - 1,
+				destination,
+				None
+			));
+			System::assert_last_event(
+				Event::<Test>::AssetWithdrawRequest {
+					proof_id: 1,
+					sender: account,
+					asset_id,
+					amount: amount - 1,
+					destination,
+				}
+				.into(),
+			);
+
+			// The delayed withdrawal is submitted once the delay has passed
+			NextDelayProcessBlock::<Test>::put(block_number + delay_blocks);
+			XRPLBridge::process_delayed_payments(
+				block_number + delay_blocks,
+				Weight::from_ref_time(1_000_000_000_000),
+			);
+			System::assert_last_event(
+				Event::<Test>::AssetWithdrawRequest {
+					proof_id: 1,
+					sender: account,
+					asset_id,
+					amount,
+					destination,
+				}
+				.into(),
+			);
+			assert_eq!(DelayedAssetPayments::<Test>::get(delayed_payment_id), None);
+			assert_eq!(DelayedPaymentSchedule::<Test>::get(block_number + delay_blocks), None);
+		})
+}
+
+#[test]
+fn xrpl_issued_value_from_balance_works() {
+	// 1.5 with 6 decimals
+	assert_eq!(
+		XrplIssuedValue::from_balance(1_500_000, 6),
+		Some(XrplIssuedValue { mantissa: 1_500_000_000_000_000, exponent: -15 })
+	);
+	// 16 significant digits is the maximum precision
+	assert_eq!(
+		XrplIssuedValue::from_balance(99_999_999_999_999_990, 18),
+		Some(XrplIssuedValue { mantissa: 9_999_999_999_999_999, exponent: -17 })
+	);
+	assert_eq!(XrplIssuedValue::from_balance(12_345_678_901_234_567, 18), None);
+	// zero is not a valid withdrawal amount
+	assert_eq!(XrplIssuedValue::from_balance(0, 6), None);
+}
+
+#[test]
+fn withdraw_xls20_works() {
+	let account = create_account(1);
+	TestExt::<Test>::default()
+		.with_asset(2, "XRP", &[(account, 2_000_000)])
+		.build()
+		.execute_with(|| {
+			let door = XrplAccountId::from_slice(b"5490B68F2d16B3E87cba");
+			let destination = XrplAccountId::from_slice(b"6490B68F1116BFE87DDD");
+			assert_ok!(XRPLBridge::set_door_tx_fee(RuntimeOrigin::root(), 100));
+			assert_ok!(XRPLBridge::set_door_address(RuntimeOrigin::root(), door));
+			assert_ok!(XRPLBridge::set_ticket_sequence_current_allocation(
+				RuntimeOrigin::root(),
+				1_u32,
+				1_u32,
+				200_u32
+			));
+
+			assert_ok!(XRPLBridge::withdraw_xls20(
+				RuntimeOrigin::signed(account),
+				MOCK_TOKEN_ID,
+				destination
+			));
+			System::assert_last_event(
+				Event::<Test>::Xls20WithdrawRequest {
+					proof_id: 1,
+					sender: account,
+					token_id: MOCK_TOKEN_ID,
+					xls20_token_id: MOCK_XLS20_TOKEN_ID,
+					destination,
+				}
+				.into(),
+			);
+			// The door fee is charged in XRP and a ticket is consumed
+			assert_eq!(xrp_balance_of(account), 2_000_000 - 100);
+			assert_eq!(XRPLBridge::door_ticket_sequence(), 2);
+		});
+}
+
+#[test]
+fn withdraw_xls20_fails() {
+	let account = create_account(1);
+	TestExt::<Test>::default()
+		.with_asset(2, "XRP", &[(account, 2_000_000)])
+		.build()
+		.execute_with(|| {
+			let door = XrplAccountId::from_slice(b"5490B68F2d16B3E87cba");
+			let destination = XrplAccountId::from_slice(b"6490B68F1116BFE87DDD");
+
+			// door address not set
+			assert_noop!(
+				XRPLBridge::withdraw_xls20(
+					RuntimeOrigin::signed(account),
+					MOCK_TOKEN_ID,
+					destination
+				),
+				Error::<Test>::DoorAddressNotSet
+			);
+			assert_ok!(XRPLBridge::set_door_address(RuntimeOrigin::root(), door));
+
+			// token has no XLS-20 mapping
+			assert_noop!(
+				XRPLBridge::withdraw_xls20(RuntimeOrigin::signed(account), (2, 0), destination),
+				DispatchError::Other("No mapping exists")
+			);
+
+			// no ticket sequence available
+			assert_noop!(
+				XRPLBridge::withdraw_xls20(
+					RuntimeOrigin::signed(account),
+					MOCK_TOKEN_ID,
+					destination
+				),
+				Error::<Test>::NextTicketSequenceParamsNotSet
+			);
+		});
+}
+
+#[test]
+fn xls20_token_id_to_nftoken_id_works() {
+	let mut token_id: Xls20TokenId = [b'0'; 64];
+	token_id[..4].copy_from_slice(b"0a1F");
+	let mut expected = [0_u8; 32];
+	expected[0] = 0x0a;
+	expected[1] = 0x1f;
+	assert_eq!(types::xls20_token_id_to_nftoken_id(&token_id), Some(expected));
+
+	token_id[10] = b'g';
+	assert_eq!(types::xls20_token_id_to_nftoken_id(&token_id), None);
+}
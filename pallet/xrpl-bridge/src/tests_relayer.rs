@@ -84,3 +84,274 @@ fn test_is_relayer_works() {
 		assert_eq!(XRPLBridge::get_relayer(relayer2), None);
 	})
 }
+
+#[test]
+fn set_relayer_quorum_works() {
+	TestExt::<Test>::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(XRPLBridge::relayer_quorum(), 1);
+
+		assert_ok!(XRPLBridge::set_relayer_quorum(RuntimeOrigin::root(), 3));
+		assert_eq!(XRPLBridge::relayer_quorum(), 3);
+		System::assert_last_event(Event::<Test>::RelayerQuorumSet { quorum: 3 }.into());
+
+		assert_noop!(
+			XRPLBridge::set_relayer_quorum(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidRelayerQuorum
+		);
+		assert_noop!(
+			XRPLBridge::set_relayer_quorum(RuntimeOrigin::signed(create_account(1)), 2),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn submit_transaction_waits_for_relayer_quorum() {
+	TestExt::<Test>::default().with_asset(2, "XRP", &[]).build().execute_with(|| {
+		System::set_block_number(1);
+		let relayer_1 = create_account(1);
+		let relayer_2 = create_account(2);
+		let address = H160::from_low_u64_be(555);
+		let transaction_hash = XrplTxHash::from_low_u64_be(123);
+		let transaction = XrplTxData::Payment { amount: 1000 as Balance, address };
+		XRPLBridge::initialize_relayer(&vec![relayer_1, relayer_2]);
+		assert_ok!(XRPLBridge::set_relayer_quorum(RuntimeOrigin::root(), 2));
+
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer_1),
+			1,
+			transaction_hash,
+			transaction.clone(),
+			1234
+		));
+		System::assert_last_event(
+			Event::<Test>::TransactionAttested {
+				ledger_index: 1,
+				transaction_hash,
+				relayer: relayer_1,
+			}
+			.into(),
+		);
+		assert!(XRPLBridge::process_xrp_transaction_details(transaction_hash).is_none());
+
+		// A relayer can only attest once
+		assert_noop!(
+			XRPLBridge::submit_transaction(
+				RuntimeOrigin::signed(relayer_1),
+				1,
+				transaction_hash,
+				transaction.clone(),
+				1234
+			),
+			Error::<Test>::RelayerAlreadyAttested
+		);
+
+		// Quorum reached, the transaction is accepted
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer_2),
+			1,
+			transaction_hash,
+			transaction,
+			1234
+		));
+		System::assert_last_event(Event::<Test>::TransactionAdded(1, transaction_hash).into());
+		assert!(XRPLBridge::process_xrp_transaction_details(transaction_hash).is_some());
+		assert_eq!(XrplTxAttestations::<Test>::iter_prefix(transaction_hash).count(), 0);
+		assert_eq!(PendingXrplTxDetails::<Test>::iter_prefix(transaction_hash).count(), 0);
+
+		let process_block = XrpTxChallengePeriod::get() as u64 + 1;
+		XRPLBridge::on_initialize(process_block);
+		assert_eq!(AssetsExt::balance(XrpAssetId::get(), &address.into()), 1000);
+	})
+}
+
+#[test]
+fn conflicting_submission_is_slashed() {
+	let relayer_1 = create_account(1);
+	let relayer_2 = create_account(2);
+	let relayer_3 = create_account(3);
+	let bond: Balance = 100;
+	TestExt::<Test>::default()
+		.with_balances(&[(relayer_1, 1_000), (relayer_2, 1_000), (relayer_3, 1_000)])
+		.with_asset(2, "XRP", &[])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let address = H160::from_low_u64_be(555);
+			let transaction_hash = XrplTxHash::from_low_u64_be(123);
+			let transaction = XrplTxData::Payment { amount: 1000 as Balance, address };
+			let conflicting_transaction =
+				XrplTxData::Payment { amount: 1_000_000 as Balance, address };
+
+			assert_ok!(XRPLBridge::set_relayer_bond(RuntimeOrigin::root(), bond));
+			for relayer in [relayer_1, relayer_2, relayer_3] {
+				assert_ok!(XRPLBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer)));
+				assert_ok!(XRPLBridge::add_relayer(RuntimeOrigin::root(), relayer));
+			}
+			assert_ok!(XRPLBridge::set_relayer_quorum(RuntimeOrigin::root(), 2));
+
+			assert_ok!(XRPLBridge::submit_transaction(
+				RuntimeOrigin::signed(relayer_1),
+				1,
+				transaction_hash,
+				transaction.clone(),
+				1234
+			));
+			assert_ok!(XRPLBridge::submit_transaction(
+				RuntimeOrigin::signed(relayer_3),
+				1,
+				transaction_hash,
+				conflicting_transaction,
+				1234
+			));
+			System::assert_has_event(
+				Event::<Test>::ConflictingTransaction {
+					ledger_index: 1,
+					transaction_hash,
+					relayer: relayer_3,
+				}
+				.into(),
+			);
+			// The conflict is flagged as a challenge until the quorum settles it
+			assert_eq!(
+				XRPLBridge::challenge_xrp_transaction_list(transaction_hash),
+				Some(relayer_3)
+			);
+
+			// Quorum reached on the first details, relayer_3 is slashed
+			assert_ok!(XRPLBridge::submit_transaction(
+				RuntimeOrigin::signed(relayer_2),
+				1,
+				transaction_hash,
+				transaction,
+				1234
+			));
+			System::assert_has_event(
+				Event::<Test>::RelayerSlashed { relayer: relayer_3, amount: bond }.into(),
+			);
+			assert_eq!(XRPLBridge::challenge_xrp_transaction_list(transaction_hash), None);
+			assert_eq!(XRPLBridge::get_relayer(relayer_3), None);
+			assert_eq!(XRPLBridge::relayer_paid_bond(relayer_3), 0);
+			assert_eq!(AssetsExt::balance(ROOT_ASSET_ID, &relayer_3), 1_000 - bond);
+			// The bond is split between the honest relayers
+			assert_eq!(AssetsExt::balance(ROOT_ASSET_ID, &relayer_1), 1_000 - bond + 50);
+			assert_eq!(AssetsExt::balance(ROOT_ASSET_ID, &relayer_2), 1_000 - bond + 50);
+
+			// The honest deposit is processed
+			let process_block = XrpTxChallengePeriod::get() as u64 + 1;
+			XRPLBridge::on_initialize(process_block);
+			assert_eq!(AssetsExt::balance(XrpAssetId::get(), &address.into()), 1000);
+		})
+}
+
+#[test]
+fn conflicting_submission_keeps_unbonded_relayer() {
+	let relayer_1 = create_account(1);
+	let relayer_2 = create_account(2);
+	let relayer_3 = create_account(3);
+	TestExt::<Test>::default().with_asset(2, "XRP", &[]).build().execute_with(|| {
+		System::set_block_number(1);
+		let address = H160::from_low_u64_be(555);
+		let transaction_hash = XrplTxHash::from_low_u64_be(123);
+		let transaction = XrplTxData::Payment { amount: 1000 as Balance, address };
+		let conflicting_transaction = XrplTxData::Payment { amount: 1_000_000 as Balance, address };
+
+		// No bond required, relayers are added without one
+		for relayer in [relayer_1, relayer_2, relayer_3] {
+			assert_ok!(XRPLBridge::add_relayer(RuntimeOrigin::root(), relayer));
+		}
+		assert_ok!(XRPLBridge::set_relayer_quorum(RuntimeOrigin::root(), 2));
+
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer_3),
+			1,
+			transaction_hash,
+			conflicting_transaction,
+			1234
+		));
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer_1),
+			1,
+			transaction_hash,
+			transaction.clone(),
+			1234
+		));
+		assert_eq!(XRPLBridge::challenge_xrp_transaction_list(transaction_hash), Some(relayer_1));
+		assert_ok!(XRPLBridge::submit_transaction(
+			RuntimeOrigin::signed(relayer_2),
+			1,
+			transaction_hash,
+			transaction,
+			1234
+		));
+
+		// Nothing to slash, the relayer is kept
+		assert_eq!(XRPLBridge::get_relayer(relayer_3), Some(true));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			mock::RuntimeEvent::XRPLBridge(Event::<Test>::RelayerSlashed { .. })
+		)));
+		assert_eq!(XRPLBridge::challenge_xrp_transaction_list(transaction_hash), None);
+
+		let process_block = XrpTxChallengePeriod::get() as u64 + 1;
+		XRPLBridge::on_initialize(process_block);
+		assert_eq!(AssetsExt::balance(XrpAssetId::get(), &address.into()), 1000);
+	})
+}
+
+#[test]
+fn relayer_bond_works() {
+	let relayer = create_account(1);
+	TestExt::<Test>::default()
+		.with_balances(&[(relayer, 1_000)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			// No bond required
+			assert_noop!(
+				XRPLBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer)),
+				Error::<Test>::CantBondRelayer
+			);
+			assert_ok!(XRPLBridge::set_relayer_bond(RuntimeOrigin::root(), 100));
+			System::assert_last_event(Event::<Test>::RelayerBondSet { amount: 100 }.into());
+
+			assert_noop!(
+				XRPLBridge::add_relayer(RuntimeOrigin::root(), relayer),
+				Error::<Test>::NoBondPaid
+			);
+			assert_ok!(XRPLBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer)));
+			System::assert_last_event(
+				Event::<Test>::RelayerBondDeposit { relayer, amount: 100 }.into(),
+			);
+			assert_eq!(AssetsExt::balance(ROOT_ASSET_ID, &relayer), 900);
+			assert_noop!(
+				XRPLBridge::deposit_relayer_bond(RuntimeOrigin::signed(relayer)),
+				Error::<Test>::CantBondRelayer
+			);
+			assert_ok!(XRPLBridge::add_relayer(RuntimeOrigin::root(), relayer));
+
+			// Can't unbond while active
+			assert_noop!(
+				XRPLBridge::withdraw_relayer_bond(RuntimeOrigin::signed(relayer)),
+				Error::<Test>::CantUnbondRelayer
+			);
+			assert_ok!(XRPLBridge::remove_relayer(RuntimeOrigin::root(), relayer));
+
+			// Can't unbond until the unbonding period has passed
+			assert_noop!(
+				XRPLBridge::withdraw_relayer_bond(RuntimeOrigin::signed(relayer)),
+				Error::<Test>::RelayerBondUnbonding
+			);
+			System::set_block_number(1 + RelayerUnbondingPeriod::get());
+			assert_ok!(XRPLBridge::withdraw_relayer_bond(RuntimeOrigin::signed(relayer)));
+			System::assert_last_event(
+				Event::<Test>::RelayerBondWithdraw { relayer, amount: 100 }.into(),
+			);
+			assert_eq!(AssetsExt::balance(ROOT_ASSET_ID, &relayer), 1_000);
+			assert_noop!(
+				XRPLBridge::withdraw_relayer_bond(RuntimeOrigin::signed(relayer)),
+				Error::<Test>::CantUnbondRelayer
+			);
+		})
+}
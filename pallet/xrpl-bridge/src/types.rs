@@ -16,11 +16,12 @@
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
-use sp_core::{H160, H256};
+use sp_core::H160;
+use sp_runtime::traits::Zero;
 
 use seed_primitives::{
-	xrpl::{XrplAccountId, XrplTxHash, XrplTxNonce, XrplTxTicketSequence},
-	Balance,
+	xrpl::{Xls20TokenId, XrplAccountId, XrplTxHash, XrplTxNonce, XrplTxTicketSequence},
+	AssetId, Balance,
 };
 
 /// Payment id used for distinguishing pending withdrawals/ deposit events
@@ -40,6 +41,16 @@ pub struct DelayedWithdrawal<AccountId> {
 	pub withdraw_tx: XrpWithdrawTransaction,
 }
 
+/// A delayed withdrawal of an XRPL issued currency
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DelayedAssetWithdrawal<AccountId> {
+	pub sender: AccountId,
+	pub destination_tag: Option<u32>,
+	pub asset_id: AssetId,
+	pub currency: XrplCurrency,
+	pub withdraw_tx: XrpWithdrawTransaction,
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy)]
 pub struct XrpWithdrawTransaction {
 	pub tx_fee: u64,
@@ -51,9 +62,27 @@ pub struct XrpWithdrawTransaction {
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum XrplTxData {
-	Payment { amount: Balance, address: H160 },
-	CurrencyPayment { amount: Balance, address: H160, currency_id: H256 },
-	Xls20, // Nft
+	Payment {
+		amount: Balance,
+		address: H160,
+	},
+	/// An issued currency (IOU) payment, `amount` is in the smallest unit of the mapped asset
+	CurrencyPayment {
+		amount: Balance,
+		address: H160,
+		currency: XrplCurrency,
+	},
+	/// An XLS-20 NFT sent to the door account, `token_id` is the hex encoded NFTokenID
+	Xls20 {
+		token_id: Xls20TokenId,
+		address: H160,
+	},
+	/// A door account TicketCreate has been validated, allocating `ticket_count` tickets
+	/// from `start_sequence`
+	TicketCreate {
+		start_sequence: u32,
+		ticket_count: u32,
+	},
 }
 
 impl Default for XrpTransaction {
@@ -84,6 +113,78 @@ impl Default for XrplTxData {
 	}
 }
 
+/// An XRPL currency code, 3 character standard codes are stored in their 160 bit form
+/// https://xrpl.org/currency-formats.html#currency-codes
+pub type XrplCurrencyCode = [u8; 20];
+
+/// An issued currency on XRPL, identified by its issuing account and currency code
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct XrplCurrency {
+	pub issuer: XrplAccountId,
+	pub currency_code: XrplCurrencyCode,
+}
+
+/// An XRPL ledger fee level observed by a relayer
+/// https://xrpl.org/transaction-cost.html#current-transaction-cost
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct XrplFeeObservation {
+	/// The reference transaction cost in drops
+	pub base_fee: u64,
+	/// The server load factor, relative to `XrplFeeObservation::LOAD_BASE`
+	pub load_factor: u32,
+}
+
+impl XrplFeeObservation {
+	/// The load factor of an unloaded XRPL server
+	pub const LOAD_BASE: u64 = 256;
+
+	/// The cost in drops for a single signed tx at the observed load
+	pub fn fee(&self) -> u64 {
+		self.base_fee.saturating_mul(self.load_factor as u64) / Self::LOAD_BASE
+	}
+}
+
+/// The normalized mantissa/exponent form of an XRPL issued currency amount
+/// https://xrpl.org/serialization.html#token-amount-format
+#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub struct XrplIssuedValue {
+	pub mantissa: u64,
+	pub exponent: i8,
+}
+
+impl XrplIssuedValue {
+	const MIN_MANTISSA: u128 = 1_000_000_000_000_000;
+	const MAX_MANTISSA: u128 = 9_999_999_999_999_999;
+	const MIN_EXPONENT: i32 = -96;
+	const MAX_EXPONENT: i32 = 80;
+
+	/// Convert a balance in the smallest unit of an asset with `decimals` into an issued value
+	/// Returns `None` if the balance is zero or can't be represented without loss of precision
+	pub fn from_balance(amount: Balance, decimals: u8) -> Option<Self> {
+		if amount.is_zero() {
+			return None
+		}
+		let mut mantissa = amount;
+		let mut exponent = -(decimals as i32);
+		while mantissa > Self::MAX_MANTISSA {
+			// XRPL only supports 16 significant digits
+			if mantissa % 10 != 0 {
+				return None
+			}
+			mantissa /= 10;
+			exponent += 1;
+		}
+		while mantissa < Self::MIN_MANTISSA {
+			mantissa *= 10;
+			exponent -= 1;
+		}
+		if exponent < Self::MIN_EXPONENT || exponent > Self::MAX_EXPONENT {
+			return None
+		}
+		Some(Self { mantissa: mantissa as u64, exponent: exponent as i8 })
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct XrplTicketSequenceParams {
 	pub start_sequence: u32,
@@ -95,3 +196,22 @@ impl Default for XrplTicketSequenceParams {
 		XrplTicketSequenceParams { start_sequence: 0_u32, bucket_size: 0_u32 }
 	}
 }
+
+/// Decode a hex encoded XLS-20 token id into the raw 32 byte NFTokenID used in XRPL txs
+/// Returns `None` if `token_id` is not valid hex
+pub fn xls20_token_id_to_nftoken_id(token_id: &Xls20TokenId) -> Option<[u8; 32]> {
+	fn nibble(c: u8) -> Option<u8> {
+		match c {
+			b'0'..=b'9' => Some(c - b'0'),
+			b'a'..=b'f' => Some(c - b'a' + 10),
+			b'A'..=b'F' => Some(c - b'A' + 10),
+			_ => None,
+		}
+	}
+
+	let mut nftoken_id = [0_u8; 32];
+	for (i, pair) in token_id.chunks_exact(2).enumerate() {
+		nftoken_id[i] = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+	}
+	Some(nftoken_id)
+}
@@ -16,7 +16,7 @@
 //! Ethereum bridge common types & traits
 //! Shared between eth-bridge pallet & ethy-gadget worker
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
 use ripemd::{Digest as _, Ripemd160};
 use scale_info::TypeInfo;
 use sha2::Sha256;
@@ -26,10 +26,18 @@ use sp_std::prelude::*;
 
 use self::crypto::{AuthorityId, AuthoritySignature};
 
+pub mod bls;
+
 // fixed storage key for offchain config.
 // for consistency expect 4 byte key for prefix and 8 byte key for subkeys
 /// offchain storage config key for Ethereum HTTP URI
 pub const ETH_HTTP_URI: [u8; 8] = *b"ETH_HTTP";
+/// offchain storage config key for the secondary EVM chain HTTP URI
+pub const SECONDARY_EVM_HTTP_URI: [u8; 8] = *b"EV2_HTTP";
+/// offchain storage key for the count (u64) of Ethereum RPC endpoint disagreements
+pub const ETH_RPC_DISAGREEMENTS: [u8; 8] = *b"ETH_DSGR";
+/// offchain storage key for the count (u64) of secondary EVM chain RPC endpoint disagreements
+pub const SECONDARY_EVM_RPC_DISAGREEMENTS: [u8; 8] = *b"EV2_DSGR";
 
 /// The `ConsensusEngineId` of Ethy.
 pub const ETHY_ENGINE_ID: sp_runtime::ConsensusEngineId = *b"ETHY";
@@ -57,16 +65,30 @@ pub mod crypto {
 	pub type AuthorityId = app_crypto::Public;
 }
 
+/// How ethy proves events for a bridged chain
+#[derive(Encode, Decode, Debug, Default, Eq, PartialEq, TypeInfo, MaxEncodedLen, Copy, Clone)]
+pub enum EventProofScheme {
+	/// One ECDSA signature per validator
+	#[default]
+	Ecdsa,
+	/// A single BLS (BN254) signature aggregated from the validators' signatures
+	/// Falls back to `Ecdsa` if too few validators provide BLS signatures
+	BlsAggregate,
+}
+
 /// The index of an authority.
 pub type AuthorityIndex = u32;
 
 /// An ethy specific identifier for a bridged network
-#[derive(Encode, Decode, Debug, Eq, PartialEq, TypeInfo, Copy, Clone)]
+#[derive(Encode, Decode, Debug, Default, Eq, PartialEq, Ord, PartialOrd, TypeInfo, Copy, Clone)]
 pub enum EthyChainId {
 	/// The Chain Id given to Ethereum by ethy
+	#[default]
 	Ethereum = 1,
 	/// The Chain Id given to Xrpl by ethy
 	Xrpl = 2,
+	/// The Chain Id given to a second EVM chain (L1 or L2) by ethy
+	SecondaryEvm = 3,
 }
 
 impl Into<u8> for EthyChainId {
@@ -74,10 +96,63 @@ impl Into<u8> for EthyChainId {
 		match self {
 			Self::Ethereum => 1_u8,
 			Self::Xrpl => 2_u8,
+			Self::SecondaryEvm => 3_u8,
 		}
 	}
 }
 
+/// Number of low bits of an `EventClaimId` holding the bridge contract's event nonce
+/// The high bits hold the chain Id so claims from different chains never collide
+const EVENT_CLAIM_NONCE_BITS: u32 = 56;
+
+impl EthyChainId {
+	/// Whether the chain is bridged with the EVM bridge contract protocol
+	pub fn is_evm(&self) -> bool {
+		matches!(self, Self::Ethereum | Self::SecondaryEvm)
+	}
+	/// The offchain storage key of the chain's JSON-RPC endpoint (EVM chains only)
+	pub fn http_uri_key(&self) -> Option<[u8; 8]> {
+		match self {
+			Self::Ethereum => Some(ETH_HTTP_URI),
+			Self::SecondaryEvm => Some(SECONDARY_EVM_HTTP_URI),
+			Self::Xrpl => None,
+		}
+	}
+	/// The offchain storage key of the chain's RPC endpoint disagreement count (EVM chains only)
+	pub fn rpc_disagreements_key(&self) -> Option<[u8; 8]> {
+		match self {
+			Self::Ethereum => Some(ETH_RPC_DISAGREEMENTS),
+			Self::SecondaryEvm => Some(SECONDARY_EVM_RPC_DISAGREEMENTS),
+			Self::Xrpl => None,
+		}
+	}
+	/// Make the ethy event claim Id for the bridge contract event nonce `event_id`
+	/// Ethereum claim Ids are kept un-prefixed for backwards compatibility
+	///
+	/// Returns `None` if `event_id` overflows the nonce bits
+	pub fn event_claim_id(&self, event_id: u64) -> Option<EventClaimId> {
+		if event_id >> EVENT_CLAIM_NONCE_BITS != 0 {
+			return None
+		}
+		match self {
+			Self::Ethereum => Some(event_id),
+			_ => Some((Into::<u8>::into(*self) as u64) << EVENT_CLAIM_NONCE_BITS | event_id),
+		}
+	}
+	/// Return the EVM chain `event_claim_id` was submitted from
+	pub fn from_event_claim_id(event_claim_id: EventClaimId) -> Option<Self> {
+		match event_claim_id >> EVENT_CLAIM_NONCE_BITS {
+			0 => Some(Self::Ethereum),
+			3 => Some(Self::SecondaryEvm),
+			_ => None,
+		}
+	}
+	/// Return the bridge contract event nonce of `event_claim_id`
+	pub fn event_nonce(event_claim_id: EventClaimId) -> u64 {
+		event_claim_id & ((1_u64 << EVENT_CLAIM_NONCE_BITS) - 1)
+	}
+}
+
 /// Unique nonce for event claim requests
 pub type EventClaimId = u64;
 
@@ -139,6 +214,14 @@ pub enum ConsensusLog<AuthorityId: Encode + Decode> {
 	/// The format of `data` is determined by the bridging protocol for a given `chain_id`
 	#[codec(index = 3)]
 	OpaqueSigningRequest { chain_id: EthyChainId, event_proof_id: EventProofId, data: Vec<u8> },
+	/// The events committed to by the merkle root of the batch signing request `batch_id`
+	/// `leaves` are the (event proof Id, event digest) of each batched event in tree order
+	#[codec(index = 4)]
+	EventBatch {
+		chain_id: EthyChainId,
+		batch_id: EventProofId,
+		leaves: Vec<(EventProofId, [u8; 32])>,
+	},
 }
 
 /// Ethy witness message.
@@ -159,6 +242,80 @@ pub struct Witness {
 	pub authority_id: AuthorityId,
 	/// ECDSA signature over `digest`
 	pub signature: AuthoritySignature,
+	/// BLS signature over `digest`, provided if the chain's proof scheme is
+	/// `EventProofScheme::BlsAggregate`
+	pub bls_signature: Option<bls::Signature>,
+}
+
+/// Ethy witness message as gossiped by nodes without BLS support.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+pub struct WitnessV0 {
+	/// The event digest (the hash function may differ based on chain Id)
+	pub digest: [u8; 32],
+	/// The associated chainId for this witness
+	pub chain_id: EthyChainId,
+	/// Event proof nonce (it is unique across all Ethy event proofs)
+	pub event_id: EventProofId,
+	/// The validator set witnessing the message
+	pub validator_set_id: ValidatorSetId,
+	/// Node public key (i.e. Ethy session key)
+	pub authority_id: AuthorityId,
+	/// ECDSA signature over `digest`
+	pub signature: AuthoritySignature,
+}
+
+impl From<WitnessV0> for Witness {
+	fn from(witness: WitnessV0) -> Self {
+		let WitnessV0 { digest, chain_id, event_id, validator_set_id, authority_id, signature } =
+			witness;
+		Witness {
+			digest,
+			chain_id,
+			event_id,
+			validator_set_id,
+			authority_id,
+			signature,
+			bls_signature: None,
+		}
+	}
+}
+
+/// A gossiped `Witness` with a version number.
+///
+/// Witnesses without a BLS signature are gossiped as an unversioned `WitnessV0` so nodes without
+/// BLS support still accept them. A `WitnessV0` encoding is always shorter than a
+/// `VersionedWitness` encoding so the two formats can't be confused when decoding.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum VersionedWitness {
+	#[codec(index = 1)]
+	/// Witness with a BLS signature (`EventProofScheme::BlsAggregate`)
+	V1(Witness),
+}
+
+impl VersionedWitness {
+	/// Encode `witness` as a gossip message
+	pub fn encode_witness(witness: &Witness) -> Vec<u8> {
+		if witness.bls_signature.is_some() {
+			VersionedWitness::V1(witness.clone()).encode()
+		} else {
+			WitnessV0 {
+				digest: witness.digest,
+				chain_id: witness.chain_id,
+				event_id: witness.event_id,
+				validator_set_id: witness.validator_set_id,
+				authority_id: witness.authority_id.clone(),
+				signature: witness.signature.clone(),
+			}
+			.encode()
+		}
+	}
+	/// Decode a witness gossip message in either format
+	pub fn decode_witness(data: &[u8]) -> Option<Witness> {
+		match VersionedWitness::decode_all(&mut &data[..]) {
+			Ok(VersionedWitness::V1(witness)) => Some(witness),
+			Err(_) => WitnessV0::decode_all(&mut &data[..]).ok().map(Into::into),
+		}
+	}
 }
 
 /// An Ethy event proof with validator signatures.
@@ -212,6 +369,23 @@ impl EventProof {
 	}
 }
 
+/// An Ethy event proof with an aggregate BLS signature of the validators.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct AggregateEventProof {
+	/// The event digest
+	pub digest: [u8; 32],
+	/// The event proof Id.
+	pub event_id: EventProofId,
+	/// The validators set Id that signed the proof
+	pub validator_set_id: ValidatorSetId,
+	/// Indexes of the validators whose signatures are aggregated, ordered
+	pub signers: Vec<AuthorityIndex>,
+	/// The aggregated BLS signature over `digest`
+	pub signature: bls::Signature,
+	/// Finalized block hash of the event (when it was requested)
+	pub block: [u8; 32],
+}
+
 /// Convert an Ethy secp256k1 public key into an Ethereum address
 pub struct EthyEcdsaToEthereum;
 impl Convert<&[u8], [u8; 20]> for EthyEcdsaToEthereum {
@@ -271,16 +445,89 @@ pub enum VersionedEventProof {
 	#[codec(index = 1)]
 	/// Current active version
 	V1(EventProof),
+	#[codec(index = 2)]
+	/// Proof with an aggregate signature (`EventProofScheme::BlsAggregate`)
+	V2(AggregateEventProof),
+}
+
+/// Proof an event is included in a batch of events signed once by ethy
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct EventBatchInclusion {
+	/// The event proof Id of the signed batch
+	pub batch_id: EventProofId,
+	/// The merkle root of the batch
+	pub root: [u8; 32],
+	/// The event digest i.e. the merkle leaf
+	pub leaf: [u8; 32],
+	/// Sibling hashes from the leaf up to the root
+	pub path: Vec<[u8; 32]>,
+}
+
+/// Hash a pair of merkle tree nodes
+/// Nodes are sorted before hashing so an inclusion path needn't record left/right positions
+/// (compatible with OpenZeppelin's `MerkleProof`)
+fn merkle_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+	let (left, right) = if a <= b { (a, b) } else { (b, a) };
+	sp_io::hashing::keccak_256(&[left.as_slice(), right.as_slice()].concat())
+}
+
+/// Hash the nodes of one merkle tree level into the next
+/// An unpaired node is promoted to the next level as is
+fn merkle_level(nodes: &[[u8; 32]]) -> Vec<[u8; 32]> {
+	nodes
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => merkle_node(a, b),
+			[a] => *a,
+			_ => unreachable!("chunks are never empty"),
+		})
+		.collect()
+}
+
+/// Return the merkle root of an event batch with `leaves`
+pub fn event_batch_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+	let mut nodes = leaves.to_vec();
+	while nodes.len() > 1 {
+		nodes = merkle_level(&nodes);
+	}
+	nodes.first().copied().unwrap_or_default()
+}
+
+/// Return the inclusion path for the leaf at `index` of an event batch with `leaves`
+pub fn event_batch_path(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+	if index >= leaves.len() {
+		return None
+	}
+	let mut nodes = leaves.to_vec();
+	let mut path = Vec::new();
+	while nodes.len() > 1 {
+		if let Some(sibling) = nodes.get(index ^ 1) {
+			path.push(*sibling);
+		}
+		nodes = merkle_level(&nodes);
+		index /= 2;
+	}
+	Some(path)
+}
+
+/// Check `leaf` is included in the event batch with `root` given its inclusion `path`
+pub fn verify_event_batch_path(root: &[u8; 32], leaf: &[u8; 32], path: &[[u8; 32]]) -> bool {
+	&path.iter().fold(*leaf, |node, sibling| merkle_node(&node, sibling)) == root
 }
 
 sp_api::decl_runtime_apis! {
 	/// Runtime API for ETHY validators.
+	#[api_version(2)]
 	pub trait EthyApi
 	{
 		/// Return the Ethy validator set (i.e Secp256k1 public keys of the authorized validator set)
 		fn validator_set() -> ValidatorSet<AuthorityId>;
 		/// Return the (subset) of Ethy validators configured for XRPL signing (i.e Secp256k1 public keys of the authorized validator set)
 		fn xrpl_signers() -> ValidatorSet<AuthorityId>;
+		/// Return the registered BLS public keys of the Ethy validator set, ordered as `validator_set`
+		fn bls_public_keys() -> Vec<Option<bls::Public>>;
+		/// Return the event proof scheme of `chain_id`
+		fn event_proof_scheme(chain_id: EthyChainId) -> EventProofScheme;
 	}
 }
 
@@ -352,6 +599,79 @@ mod test {
 	fn ethy_chain_id() {
 		assert_eq!(Into::<u8>::into(EthyChainId::Ethereum), 1_u8);
 		assert_eq!(Into::<u8>::into(EthyChainId::Xrpl), 2_u8);
+		assert_eq!(Into::<u8>::into(EthyChainId::SecondaryEvm), 3_u8);
+	}
+
+	#[test]
+	fn event_claim_id_namespaced_by_chain() {
+		assert_eq!(EthyChainId::Ethereum.event_claim_id(5), Some(5));
+		let claim_id = EthyChainId::SecondaryEvm.event_claim_id(5).unwrap();
+		assert_eq!(claim_id, 3_u64 << 56 | 5);
+		assert_eq!(EthyChainId::event_nonce(claim_id), 5);
+		assert_eq!(EthyChainId::from_event_claim_id(claim_id), Some(EthyChainId::SecondaryEvm));
+		assert_eq!(EthyChainId::from_event_claim_id(5), Some(EthyChainId::Ethereum));
+		assert_eq!(EthyChainId::from_event_claim_id(2_u64 << 56 | 5), None);
+		assert_eq!(EthyChainId::SecondaryEvm.event_claim_id(1_u64 << 56), None);
+
+		assert_eq!(EthyChainId::SecondaryEvm.http_uri_key(), Some(SECONDARY_EVM_HTTP_URI));
+		assert_eq!(EthyChainId::Xrpl.http_uri_key(), None);
+		assert_eq!(
+			EthyChainId::SecondaryEvm.rpc_disagreements_key(),
+			Some(SECONDARY_EVM_RPC_DISAGREEMENTS)
+		);
+		assert_eq!(EthyChainId::Xrpl.rpc_disagreements_key(), None);
+	}
+
+	#[test]
+	fn event_batch_merkle_paths() {
+		let leaves: Vec<[u8; 32]> = (1_u8..=5).map(|i| [i; 32]).collect();
+		let root = event_batch_root(&leaves);
+		for (index, leaf) in leaves.iter().enumerate() {
+			let path = event_batch_path(&leaves, index).unwrap();
+			assert!(verify_event_batch_path(&root, leaf, &path));
+			assert!(!verify_event_batch_path(&root, &[9_u8; 32], &path));
+		}
+		assert!(event_batch_path(&leaves, 5).is_none());
+
+		// a single leaf is its own root
+		assert_eq!(event_batch_root(&leaves[..1]), leaves[0]);
+		assert_eq!(event_batch_path(&leaves[..1], 0), Some(vec![]));
+		assert_eq!(event_batch_root(&[]), [0_u8; 32]);
+	}
+
+	#[test]
+	fn versioned_witness_decodes_both_formats() {
+		let witness = Witness {
+			// the first byte matches the `VersionedWitness::V1` index
+			digest: [1_u8; 32],
+			chain_id: EthyChainId::Ethereum,
+			event_id: 5,
+			validator_set_id: 1,
+			authority_id: sp_core::ecdsa::Public::from_raw([2_u8; 33]).into(),
+			signature: Signature::from_raw([3_u8; 65]).into(),
+			bls_signature: None,
+		};
+		let legacy = WitnessV0 {
+			digest: witness.digest,
+			chain_id: witness.chain_id,
+			event_id: witness.event_id,
+			validator_set_id: witness.validator_set_id,
+			authority_id: witness.authority_id.clone(),
+			signature: witness.signature.clone(),
+		}
+		.encode();
+
+		// witnesses from nodes without BLS support are still accepted
+		assert_eq!(VersionedWitness::decode_witness(&legacy), Some(witness.clone()));
+		// and they can decode witnesses without a BLS signature
+		assert_eq!(VersionedWitness::encode_witness(&witness), legacy);
+
+		let bls_witness = Witness { bls_signature: Some(bls::Signature([4_u8; 64])), ..witness };
+		let encoded = VersionedWitness::encode_witness(&bls_witness);
+		assert_eq!(encoded, VersionedWitness::V1(bls_witness.clone()).encode());
+		assert_eq!(VersionedWitness::decode_witness(&encoded), Some(bls_witness));
+
+		assert_eq!(VersionedWitness::decode_witness(&legacy[1..]), None);
 	}
 
 	#[test]
@@ -0,0 +1,253 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! BLS signatures over the BN254 (alt_bn128) curve for aggregated Ethy event proofs
+//!
+//! Signatures are G1 points and public keys are G2 points so an aggregate signature can be
+//! verified on Ethereum with the `ecPairing` precompile (EIP-197).
+//! Points are encoded uncompressed and big-endian as expected by the Ethereum precompiles.
+//!
+//! Ethy validators don't hold a separate BLS key, it is derived from a signature by their Ethy
+//! session key (see [`BLS_KEY_DERIVATION_MESSAGE`]) so the runtime and ethy-gadget always agree on
+//! it.
+
+use bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
+
+/// Message signed by an Ethy session key to derive the validator's BLS secret key
+/// The ECDSA signature is deterministic (RFC6979) and never published
+pub const BLS_KEY_DERIVATION_MESSAGE: &[u8] = b"ethy:bls-secret-key:v1";
+
+/// Domain separation tag for hashing event digests to G1
+const SIGNATURE_DST: &[u8] = b"ethy:bls-signature:v1";
+
+/// Domain separation tag for hashing public keys to G1 (proof of possession)
+const POSSESSION_DST: &[u8] = b"ethy:bls-possession:v1";
+
+/// A BLS public key, an uncompressed G2 point encoded `(x.c1, x.c0, y.c1, y.c0)`
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct Public(pub [u8; 128]);
+
+/// A BLS signature, an uncompressed G1 point encoded `(x, y)`
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct Signature(pub [u8; 64]);
+
+/// A BLS secret key
+#[derive(Clone)]
+pub struct SecretKey(Fr);
+
+impl SecretKey {
+	/// Derive a secret key from an Ethy session key signature over
+	/// [`BLS_KEY_DERIVATION_MESSAGE`]
+	///
+	/// Returns `None` in the negligible case the derived scalar is zero
+	pub fn derive(ecdsa_signature: &[u8]) -> Option<Self> {
+		let seed = keccak_256(ecdsa_signature);
+		let mut wide = [0_u8; 64];
+		wide[..32].copy_from_slice(&keccak_256(&[&seed[..], &[0_u8]].concat()));
+		wide[32..].copy_from_slice(&keccak_256(&[&seed[..], &[1_u8]].concat()));
+		let secret = Fr::interpret(&wide);
+		if secret.is_zero() {
+			return None
+		}
+		Some(Self(secret))
+	}
+	/// Return the public key of this secret key
+	pub fn public(&self) -> Public {
+		encode_g2(G2::one() * self.0).expect("secret is non-zero; qed")
+	}
+	/// Sign the event `digest`
+	pub fn sign(&self, digest: &[u8; 32]) -> Signature {
+		encode_g1(hash_to_g1(SIGNATURE_DST, digest) * self.0).expect("secret is non-zero; qed")
+	}
+	/// Prove possession of this secret key, required to register its public key
+	/// This prevents rogue key attacks on aggregate signatures
+	pub fn prove_possession(&self) -> Signature {
+		encode_g1(hash_to_g1(POSSESSION_DST, &self.public().0) * self.0)
+			.expect("secret is non-zero; qed")
+	}
+}
+
+/// Verify `signature` is a valid signature of `public` over the event `digest`
+/// `signature` and `public` may be aggregates
+pub fn verify(public: &Public, digest: &[u8; 32], signature: &Signature) -> bool {
+	verify_message(SIGNATURE_DST, public, digest, signature)
+}
+
+/// Verify `proof` is a valid proof of possession for `public`
+pub fn verify_possession(public: &Public, proof: &Signature) -> bool {
+	verify_message(POSSESSION_DST, public, &public.0, proof)
+}
+
+/// Aggregate `signatures` into a single signature
+///
+/// Returns `None` if `signatures` is empty or any signature is invalid
+pub fn aggregate_signatures<'a>(
+	signatures: impl IntoIterator<Item = &'a Signature>,
+) -> Option<Signature> {
+	let mut aggregate = G1::zero();
+	for signature in signatures {
+		aggregate = aggregate + decode_g1(signature)?;
+	}
+	encode_g1(aggregate)
+}
+
+/// Aggregate `keys` into a single public key
+///
+/// Returns `None` if `keys` is empty or any key is invalid
+pub fn aggregate_public_keys<'a>(keys: impl IntoIterator<Item = &'a Public>) -> Option<Public> {
+	let mut aggregate = G2::zero();
+	for key in keys {
+		aggregate = aggregate + decode_g2(key)?;
+	}
+	encode_g2(aggregate)
+}
+
+/// Check e(signature, -g2) * e(H(message), public) == 1
+fn verify_message(dst: &[u8], public: &Public, message: &[u8], signature: &Signature) -> bool {
+	let (Some(public), Some(signature)) = (decode_g2(public), decode_g1(signature)) else {
+		return false
+	};
+	pairing_batch(&[(signature, -G2::one()), (hash_to_g1(dst, message), public)]) == Gt::one()
+}
+
+/// Hash `message` to a G1 point by try-and-increment
+///
+/// x = keccak256(dst ++ message ++ counter) mod p, for the first counter where x^3 + 3 is a
+/// square. y is the smaller of the two square roots so the point is reproducible on Ethereum
+/// (y = (x^3 + 3)^((p + 1) / 4) mod p, then min(y, p - y)).
+fn hash_to_g1(dst: &[u8], message: &[u8]) -> G1 {
+	let curve_b = Fq::from_slice(&{
+		let mut b = [0_u8; 32];
+		b[31] = 3;
+		b
+	})
+	.expect("3 < p; qed");
+	let mut counter = 0_u32;
+	loop {
+		let mut wide = [0_u8; 64];
+		wide[32..].copy_from_slice(&keccak_256(&[dst, message, &counter.to_be_bytes()].concat()));
+		let x = Fq::interpret(&wide);
+		if let Some(y) = (x * x * x + curve_b).sqrt() {
+			let y = if fq_bytes(y) <= fq_bytes(-y) { y } else { -y };
+			if let Ok(point) = AffineG1::new(x, y) {
+				return point.into()
+			}
+		}
+		counter += 1;
+	}
+}
+
+/// Big-endian bytes of a base field element
+fn fq_bytes(element: Fq) -> [u8; 32] {
+	let mut bytes = [0_u8; 32];
+	element.to_big_endian(&mut bytes).expect("32 byte buffer; qed");
+	bytes
+}
+
+/// Decode a (non-zero) G1 point
+fn decode_g1(signature: &Signature) -> Option<G1> {
+	let x = Fq::from_slice(&signature.0[..32]).ok()?;
+	let y = Fq::from_slice(&signature.0[32..]).ok()?;
+	AffineG1::new(x, y).ok().map(Into::into)
+}
+
+/// Encode a G1 point, `None` if it is the point at infinity
+fn encode_g1(point: G1) -> Option<Signature> {
+	let affine = AffineG1::from_jacobian(point)?;
+	let mut bytes = [0_u8; 64];
+	bytes[..32].copy_from_slice(&fq_bytes(affine.x()));
+	bytes[32..].copy_from_slice(&fq_bytes(affine.y()));
+	Some(Signature(bytes))
+}
+
+/// Decode a (non-zero) G2 point, checks it is a member of the G2 subgroup
+fn decode_g2(public: &Public) -> Option<G2> {
+	let fq = |range: core::ops::Range<usize>| Fq::from_slice(&public.0[range]).ok();
+	let x = Fq2::new(fq(32..64)?, fq(0..32)?);
+	let y = Fq2::new(fq(96..128)?, fq(64..96)?);
+	AffineG2::new(x, y).ok().map(Into::into)
+}
+
+/// Encode a G2 point, `None` if it is the point at infinity
+fn encode_g2(point: G2) -> Option<Public> {
+	let affine = AffineG2::from_jacobian(point)?;
+	let mut bytes = [0_u8; 128];
+	bytes[..32].copy_from_slice(&fq_bytes(affine.x().imaginary()));
+	bytes[32..64].copy_from_slice(&fq_bytes(affine.x().real()));
+	bytes[64..96].copy_from_slice(&fq_bytes(affine.y().imaginary()));
+	bytes[96..].copy_from_slice(&fq_bytes(affine.y().real()));
+	Some(Public(bytes))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn secret(seed: u8) -> SecretKey {
+		SecretKey::derive(&[seed; 65]).unwrap()
+	}
+
+	#[test]
+	fn sign_and_verify() {
+		let alice = secret(1);
+		let digest = [7_u8; 32];
+		let signature = alice.sign(&digest);
+
+		assert!(verify(&alice.public(), &digest, &signature));
+		assert!(!verify(&alice.public(), &[8_u8; 32], &signature));
+		assert!(!verify(&secret(2).public(), &digest, &signature));
+		assert!(!verify(&alice.public(), &digest, &Signature([0_u8; 64])));
+	}
+
+	#[test]
+	fn derive_is_deterministic() {
+		assert_eq!(secret(1).public(), secret(1).public());
+		assert_ne!(secret(1).public(), secret(2).public());
+	}
+
+	#[test]
+	fn aggregate_verify() {
+		let signers = [secret(1), secret(2), secret(3)];
+		let digest = [7_u8; 32];
+		let signatures: Vec<Signature> = signers.iter().map(|s| s.sign(&digest)).collect();
+		let publics: Vec<Public> = signers.iter().map(|s| s.public()).collect();
+
+		let aggregate_signature = aggregate_signatures(&signatures).unwrap();
+		let aggregate_public = aggregate_public_keys(&publics).unwrap();
+		assert!(verify(&aggregate_public, &digest, &aggregate_signature));
+
+		// missing a signer
+		let partial_public = aggregate_public_keys(&publics[..2]).unwrap();
+		assert!(!verify(&partial_public, &digest, &aggregate_signature));
+
+		assert!(aggregate_signatures(&[]).is_none());
+		assert!(aggregate_public_keys(&[]).is_none());
+	}
+
+	#[test]
+	fn proof_of_possession() {
+		let alice = secret(1);
+		let proof = alice.prove_possession();
+		assert!(verify_possession(&alice.public(), &proof));
+		assert!(!verify_possession(&secret(2).public(), &proof));
+		// a signature over the public key bytes as a digest is not a valid proof
+		assert!(!verify_possession(&alice.public(), &alice.sign(&[1_u8; 32])));
+	}
+}
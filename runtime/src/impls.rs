@@ -26,7 +26,7 @@ use frame_support::{
 		fungibles,
 		tokens::{DepositConsequence, WithdrawConsequence},
 		Currency, ExistenceRequirement, FindAuthor, Imbalance, InstanceFilter, OnUnbalanced,
-		ReservableCurrency, SignedImbalance, WithdrawReasons,
+		ReservableCurrency, SignedImbalance, UnfilteredDispatchable, WithdrawReasons,
 	},
 	weights::WeightToFee,
 };
@@ -50,13 +50,13 @@ use seed_pallet_common::{
 	EthereumEventRouter as EthereumEventRouterT, EthereumEventSubscriber, EventRouterError,
 	EventRouterResult, FinalSessionTracker, OnNewAssetSubscriber,
 };
-use seed_primitives::{AccountId, AssetId, Balance, Index, Signature};
+use seed_primitives::{ethy::EthyChainId, AccountId, AssetId, Balance, Index, Signature};
 
 use crate::{
 	BlockHashCount, Runtime, RuntimeCall, Session, SessionsPerEra, SlashPotId, Staking, System,
 	UncheckedExtrinsic, EVM,
 };
-use sp_runtime::traits::{Dispatchable, Saturating, UniqueSaturatedInto};
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto};
 
 /// Constant factor for scaling CPAY to its smallest indivisible unit
 const XRP_UNIT_VALUE: Balance = 10_u128.pow(12);
@@ -362,10 +362,19 @@ pub struct EthereumEventRouter;
 
 impl EthereumEventRouterT for EthereumEventRouter {
 	/// Route an event to a handler at `destination`
+	/// - `chain_id` the EVM chain the event was observed on
 	/// - `source` the sender address on Ethereum
 	/// - `destination` the intended handler (pseudo) address
 	/// - `data` the Ethereum ABI encoded event data
-	fn route(source: &H160, destination: &H160, data: &[u8]) -> EventRouterResult {
+	fn route(
+		chain_id: EthyChainId,
+		source: &H160,
+		destination: &H160,
+		data: &[u8],
+	) -> EventRouterResult {
+		if !Self::is_routable(chain_id) {
+			return Err((Weight::zero(), EventRouterError::NoReceiver))
+		}
 		// Route event to specific subscriber pallet
 		if destination == &<pallet_echo::Pallet<Runtime> as EthereumEventSubscriber>::address() {
 			<pallet_echo::Pallet<Runtime> as EthereumEventSubscriber>::process_event(source, data)
@@ -570,7 +579,8 @@ impl pallet_futurepass::ProxyProvider<Runtime> for ProxyPalletProvider {
 			call: call.into(),
 		};
 
-		RuntimeCall::dispatch(call.into(), caller).map_err(|e| e.error)?;
+		// direct proxy calls are filtered, the inner call is still filtered by the proxy type
+		RuntimeCall::from(call).dispatch_bypass_filter(caller).map_err(|e| e.error)?;
 		Ok(())
 	}
 }
@@ -590,12 +600,19 @@ impl pallet_futurepass::ProxyProvider<Runtime> for ProxyPalletProvider {
 	TypeInfo,
 )]
 pub enum ProxyType {
+	/// No calls may be proxied
 	NoPermission = 0,
+	/// All calls may be proxied
 	#[default]
 	Any = 1,
+	/// Calls which can't move funds or tokens out of the account, includes `Governance` and
+	/// `Staking`, EVM calls are denied
 	NonTransfer = 2,
+	/// Governance calls, the runtime has no governance pallets so no calls may be proxied yet
 	Governance = 3,
+	/// Staking calls
 	Staking = 4,
+	/// The futurepass owner, all calls may be proxied
 	Owner = 255,
 }
 
@@ -629,7 +646,6 @@ impl Into<u8> for ProxyType {
 
 // Precompile side proxy filter.
 // NOTE - Precompile and Substrate side filters should be in sync
-// TODO(surangap): check if the granualarity can be improved to the call level (V2)
 impl pallet_evm_precompiles_futurepass::EvmProxyCallFilter for ProxyType {
 	fn is_evm_proxy_call_allowed(
 		&self,
@@ -653,8 +669,9 @@ impl pallet_evm_precompiles_futurepass::EvmProxyCallFilter for ProxyType {
 		match self {
 			ProxyType::Owner => true,
 			ProxyType::Any => true,
-			// ProxyType::NonTransfer can not have value. i.e call.value == U256::zero()
+			// any contract call may move the futurepass' tokens e.g. permits or custom methods
 			ProxyType::NonTransfer => false,
+			// there are no EVM governance or staking calls
 			ProxyType::Governance => false,
 			ProxyType::Staking => false,
 			ProxyType::NoPermission => false,
@@ -674,12 +691,29 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			// Whitelist currently includes pallet_futurepass::Call::register_delegate,
 			// pallet_futurepass::Call::unregister_delegate
 			// pallet_futurepass::Call::transfer_futurepass
+			// pallet_futurepass::Call::set_delegate_policy
+			// pallet_futurepass::Call::set_delegate_expiry
+			// pallet_futurepass::Call::set_recovery_config
+			// pallet_futurepass::Call::remove_recovery_config
+			// pallet_futurepass::Call::initiate_recovery
+			// pallet_futurepass::Call::approve_recovery
+			// pallet_futurepass::Call::cancel_recovery
+			// pallet_futurepass::Call::claim_recovery
 			if !matches!(
 				c,
 				RuntimeCall::Futurepass(
 					pallet_futurepass::Call::register_delegate_with_signature { .. }
 				) | RuntimeCall::Futurepass(pallet_futurepass::Call::unregister_delegate { .. }) |
-					RuntimeCall::Futurepass(pallet_futurepass::Call::transfer_futurepass { .. })
+					RuntimeCall::Futurepass(pallet_futurepass::Call::transfer_futurepass { .. }) |
+					RuntimeCall::Futurepass(pallet_futurepass::Call::set_delegate_policy { .. }) |
+					RuntimeCall::Futurepass(pallet_futurepass::Call::set_delegate_expiry { .. }) |
+					RuntimeCall::Futurepass(pallet_futurepass::Call::set_recovery_config { .. }) |
+					RuntimeCall::Futurepass(
+						pallet_futurepass::Call::remove_recovery_config { .. }
+					) | RuntimeCall::Futurepass(pallet_futurepass::Call::initiate_recovery { .. }) |
+					RuntimeCall::Futurepass(pallet_futurepass::Call::approve_recovery { .. }) |
+					RuntimeCall::Futurepass(pallet_futurepass::Call::cancel_recovery { .. }) |
+					RuntimeCall::Futurepass(pallet_futurepass::Call::claim_recovery { .. })
 			) {
 				return false
 			}
@@ -687,10 +721,49 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 		match self {
 			ProxyType::Owner => true,
 			ProxyType::Any => true,
-			// TODO - need to add allowed calls under this category in v2. allowing all for now.
-			ProxyType::NonTransfer => false,
+			// EVM calls are denied as they may transfer value, the futurepass precompile applies
+			// the EVM side filter
+			ProxyType::NonTransfer =>
+				ProxyType::Governance.filter(c) ||
+					ProxyType::Staking.filter(c) ||
+					matches!(
+						c,
+						RuntimeCall::System(frame_system::Call::remark { .. }) |
+							RuntimeCall::System(frame_system::Call::remark_with_event { .. }) |
+							RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+							RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+							RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
+							RuntimeCall::Futurepass(
+								pallet_futurepass::Call::unregister_delegate { .. }
+							) | RuntimeCall::Nft(pallet_nft::Call::create_collection { .. }) |
+							RuntimeCall::Nft(pallet_nft::Call::set_max_issuance { .. }) |
+							RuntimeCall::Nft(pallet_nft::Call::set_base_uri { .. }) |
+							RuntimeCall::Nft(pallet_nft::Call::set_name { .. }) |
+							RuntimeCall::Nft(pallet_nft::Call::set_royalties_schedule { .. }) |
+							RuntimeCall::Nft(pallet_nft::Call::toggle_public_mint { .. }) |
+							RuntimeCall::Nft(pallet_nft::Call::set_mint_fee { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::create_collection { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::create_token { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::set_max_issuance { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::set_base_uri { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::set_name { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::set_royalties_schedule { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::toggle_public_mint { .. }) |
+							RuntimeCall::Sft(pallet_sft::Call::set_mint_fee { .. }) |
+							RuntimeCall::Echo(..)
+					),
+			// there are no governance pallets
 			ProxyType::Governance => false,
-			ProxyType::Staking => false,
+			// bonded funds remain in the account
+			ProxyType::Staking => matches!(
+				c,
+				RuntimeCall::Staking(..) |
+					RuntimeCall::Session(..) |
+					RuntimeCall::VoterList(..) |
+					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. })
+			),
 			ProxyType::NoPermission => false,
 		}
 	}
@@ -700,11 +773,50 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Owner, _) | (ProxyType::Any, _) => true,
 			(_, ProxyType::Owner) | (_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, ProxyType::Governance) |
+			(ProxyType::NonTransfer, ProxyType::Staking) => true,
+			(_, ProxyType::NoPermission) => true,
 			_ => false,
 		}
 	}
 }
 
+/// Provides the calls wrapped by runtime calls which dispatch other calls, so futurepass delegate
+/// policies apply to batched, fee paying and proxied calls
+/// NOTE - any new call dispatching a call it wraps must be added here
+pub struct RuntimeNestedCalls;
+
+impl pallet_futurepass::NestedCalls<RuntimeCall> for RuntimeNestedCalls {
+	fn nested_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) |
+			RuntimeCall::Futurepass(pallet_futurepass::Call::proxy_extrinsic_batch {
+				calls,
+				..
+			}) => calls.iter().collect(),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+			RuntimeCall::FeeProxy(pallet_fee_proxy::Call::call_with_fee_preferences {
+				call,
+				..
+			}) |
+			RuntimeCall::FeeProxy(pallet_fee_proxy::Call::call_with_sponsor { call, .. }) |
+			RuntimeCall::Futurepass(pallet_futurepass::Call::proxy_extrinsic { call, .. }) |
+			RuntimeCall::Futurepass(pallet_futurepass::Call::proxy_extrinsic_with_signature {
+				call,
+				..
+			}) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { call, .. }) |
+			RuntimeCall::Sudo(pallet_sudo::Call::sudo { call }) |
+			RuntimeCall::Sudo(pallet_sudo::Call::sudo_unchecked_weight { call, .. }) |
+			RuntimeCall::Sudo(pallet_sudo::Call::sudo_as { call, .. }) => sp_std::vec![call.as_ref()],
+			_ => Vec::new(),
+		}
+	}
+}
+
 pub struct FuturepassMigrationProvider;
 
 impl<T: pallet_nft::Config + pallet_assets_ext::Config> pallet_futurepass::FuturepassMigrator<T>
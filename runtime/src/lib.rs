@@ -99,7 +99,7 @@ pub mod keys {
 
 pub use seed_pallet_common::FeeConfig;
 pub use seed_primitives::{
-	ethy::{crypto::AuthorityId as EthBridgeId, ValidatorSet},
+	ethy::{bls, crypto::AuthorityId as EthBridgeId, EthyChainId, EventProofScheme, ValidatorSet},
 	AccountId, Address, AssetId, BabeId, Balance, BlockNumber, CollectionUuid, Hash, Index,
 	SerialNumber, Signature, TokenCount, TokenId,
 };
@@ -120,7 +120,9 @@ use impls::{
 	AddressMapping, EthereumEventRouter, EthereumFindAuthor, EvmCurrencyScaler, HandleTxValidation,
 	SlashImbalanceHandler, StakingSessionTracker,
 };
-use pallet_fee_proxy::{get_fee_preferences_data, FeePreferencesData, FeePreferencesRunner};
+use pallet_fee_proxy::{
+	get_fee_preferences_data, FeePreferencesData, FeePreferencesRunner, SponsoredEVMFeeAdapter,
+};
 
 pub mod precompiles;
 
@@ -137,7 +139,7 @@ use crate::impls::{
 	FutureverseEVMCurrencyAdapter, FutureverseEnsureAddressSame, OnNewAssetSubscription,
 };
 
-use precompile_utils::constants::FEE_PROXY_ADDRESS;
+use precompile_utils::constants::{FEE_PROXY_ADDRESS, FEE_SPONSOR_FUNCTION_SELECTOR};
 use seed_primitives::BlakeTwo256Hash;
 
 #[cfg(test)]
@@ -245,6 +247,10 @@ impl frame_support::traits::Contains<RuntimeCall> for CallFilter {
 			RuntimeCall::Staking(pallet_staking::Call::payout_stakers { .. }) => false,
 			// Disable Proxy::add_proxy
 			RuntimeCall::Proxy(pallet_proxy::Call::add_proxy { .. }) => false,
+			// Futurepass delegates must use Futurepass::proxy_extrinsic, which enforces delegate
+			// policies and expiry
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { .. }) => false,
 			_ => true,
 		}
 	}
@@ -475,6 +481,7 @@ impl pallet_sft::Config for Runtime {
 
 parameter_types! {
 	pub const MaxTokensPerXls20Mint: u32 = 1000;
+	pub const Xls20PalletId: PalletId = PalletId(*b"xls20brg");
 }
 impl pallet_xls20::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -483,6 +490,7 @@ impl pallet_xls20::Config for Runtime {
 	type NFTExt = Nft;
 	type WeightInfo = weights::pallet_xls20::WeightInfo<Runtime>;
 	type Xls20PaymentAsset = XrpAssetId;
+	type PalletId = Xls20PalletId;
 }
 
 parameter_types! {
@@ -496,6 +504,12 @@ impl pallet_echo::Config for Runtime {
 	type WeightInfo = weights::pallet_echo::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	/// PalletId for Fee Proxy pallet, sponsorship funds are held in its sub accounts
+	pub const FeeProxyPalletId: PalletId = PalletId(*b"feeproxy");
+	/// The maximum number of calls, EVM calls and accounts (each) in fee sponsorship rules
+	pub const MaxSponsorshipItems: u32 = 32;
+}
 impl pallet_fee_proxy::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
@@ -505,6 +519,10 @@ impl pallet_fee_proxy::Config for Runtime {
 	type ErcIdConversion = Self;
 	type EVMBaseFeeProvider = FeeControl;
 	type MaintenanceChecker = pallet_maintenance_mode::MaintenanceChecker<Runtime>;
+	type MultiCurrency = AssetsExt;
+	type PalletId = FeeProxyPalletId;
+	type MaxSponsorshipItems = MaxSponsorshipItems;
+	type WeightInfo = weights::pallet_fee_proxy::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -578,6 +596,11 @@ parameter_types! {
 	pub const MaxPrunedTransactionsPerBlock: u32 = 5000;
 	pub const MaxDelayedPaymentsPerBlock: u32 = 1000;
 	pub const DelayedPaymentBlockLimit: BlockNumber = 1000;
+	/// Removed relayers can withdraw their bond after this period, it can be slashed until then
+	pub const XrplRelayerUnbondingPeriod: BlockNumber = 7 * DAYS;
+	/// Relayer XRPL fee observations older than this are ignored
+	pub const FeeObservationExpiry: BlockNumber = 10 * MINUTES;
+	pub const XrplBridgePalletId: PalletId = PalletId(*b"xrplbrdg");
 }
 
 impl pallet_xrpl_bridge::Config for Runtime {
@@ -587,14 +610,19 @@ impl pallet_xrpl_bridge::Config for Runtime {
 	type ApproveOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::pallet_xrpl_bridge::WeightInfo<Runtime>;
 	type XrpAssetId = XrpAssetId;
+	type NativeAssetId = RootAssetId;
+	type PalletId = XrplBridgePalletId;
 	type ChallengePeriod = XrpTxChallengePeriod;
 	type MaxPrunedTransactionsPerBlock = MaxPrunedTransactionsPerBlock;
 	type MaxDelayedPaymentsPerBlock = MaxDelayedPaymentsPerBlock;
 	type DelayedPaymentBlockLimit = DelayedPaymentBlockLimit;
+	type RelayerUnbondingPeriod = XrplRelayerUnbondingPeriod;
+	type FeeObservationExpiry = FeeObservationExpiry;
 	type UnixTime = Timestamp;
 	type TicketSequenceThreshold = TicketSequenceThreshold;
 	type XRPTransactionLimit = XRPTransactionLimit;
 	type XRPLTransactionLimitPerLedger = XRPTransactionLimitPerLedger;
+	type Xls20Ext = Xls20;
 }
 
 parameter_types! {
@@ -951,9 +979,15 @@ parameter_types! {
 	pub const NotarizationThreshold: Percent = Percent::from_percent(66_u8);
 	/// Bond amount for a relayer
 	pub const RelayerBond: Balance = 100 * ONE_XRP;
+	/// Blocks a former relayer's bond stays locked, must outlast any pending challenges
+	pub const RelayerUnbondingPeriod: BlockNumber = 1 * DAYS;
+	/// Blocks without a submission after which a round robin relayer's turns are open to all
+	pub const RoundRobinTimeout: BlockNumber = 30 * MINUTES;
 	/// Max Xrpl notary (validator) public keys
 	pub const MaxXrplKeys: u8 = 8;
 	pub const MaxNewSigners: u8 = 20;
+	/// Max length of the return data of a checked eth_call (bytes)
+	pub const MaxEthCallReturnData: u32 = 1_024;
 	/// 75 blocks is 5 minutes before the end of the era
 	pub const AuthorityChangeDelay: BlockNumber = 75_u32;
 }
@@ -974,7 +1008,7 @@ impl pallet_ethy::Config for Runtime {
 	/// The runtime event type.
 	type RuntimeEvent = RuntimeEvent;
 	/// Subscribers to completed 'eth_call' jobs
-	type EthCallSubscribers = ();
+	type EthCallSubscribers = Echo;
 	/// Subscribers to completed event
 	type EventRouter = EthereumEventRouter;
 	/// Provides Ethereum JSON-RPC client to the pallet (OCW friendly)
@@ -984,6 +1018,7 @@ impl pallet_ethy::Config for Runtime {
 	/// Reports final session status of an era
 	type FinalSessionTracker = StakingSessionTracker;
 	type MaxNewSigners = MaxNewSigners;
+	type MaxEthCallReturnData = MaxEthCallReturnData;
 	/// Handles multi-currency fungible asset system
 	type MultiCurrency = AssetsExt;
 	/// The native asset id used for challenger and relayer bonds
@@ -992,6 +1027,8 @@ impl pallet_ethy::Config for Runtime {
 	type NotarizationThreshold = NotarizationThreshold;
 	/// The bond required to become a relayer
 	type RelayerBond = RelayerBond;
+	type RelayerUnbondingPeriod = RelayerUnbondingPeriod;
+	type RoundRobinTimeout = RoundRobinTimeout;
 	/// The pallet handling scheduled Runtime calls
 	type Scheduler = Scheduler;
 	/// Timestamp provider
@@ -1002,6 +1039,7 @@ impl pallet_ethy::Config for Runtime {
 	type MaxXrplKeys = MaxXrplKeys;
 	/// Xrpl-bridge adapter
 	type XrplBridgeAdapter = XRPLBridge;
+	type WeightInfo = weights::pallet_ethy::WeightInfo<Runtime>;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -1074,7 +1112,8 @@ impl pallet_evm::Config for Runtime {
 	type PrecompilesValue = PrecompilesValue;
 	type ChainId = EVMChainId;
 	type BlockGasLimit = BlockGasLimit;
-	type OnChargeTransaction = FutureverseEVMCurrencyAdapter<Self::Currency, TxFeePot>;
+	type OnChargeTransaction =
+		SponsoredEVMFeeAdapter<FutureverseEVMCurrencyAdapter<Self::Currency, TxFeePot>>;
 	type FindAuthor = EthereumFindAuthor<Babe>;
 	type HandleTxValidation = HandleTxValidation<pallet_evm::Error<Runtime>>;
 	type WeightPerGas = WeightPerGas;
@@ -1156,6 +1195,7 @@ impl pallet_fee_control::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_fee_control::WeightInfo<Runtime>;
 	type FeeConfig = ();
+	type TargetBlockFullness = TargetBlockFullness;
 }
 
 parameter_types! {
@@ -1205,6 +1245,20 @@ impl pallet_proxy::Config for Runtime {
 	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	/// The maximum number of call permissions, EVM call permissions and spend limits (each) in a
+	/// futurepass delegate policy
+	pub const MaxDelegatePolicyItems: u32 = 16;
+	/// The maximum number of guardians who may recover a futurepass
+	pub const MaxGuardians: u32 = 10;
+	/// The time the owner has to cancel a recovery of their futurepass
+	pub const RecoveryDelay: BlockNumber = 3 * DAYS;
+	/// The time guardians have to approve a recovery before another guardian may replace it
+	pub const RecoveryExpiry: BlockNumber = 7 * DAYS;
+	/// The maximum number of calls in a batched futurepass proxy call
+	pub const MaxProxyBatchCalls: u32 = 16;
+}
+
 impl pallet_futurepass::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Proxy = impls::ProxyPalletProvider;
@@ -1213,9 +1267,14 @@ impl pallet_futurepass::Config for Runtime {
 	type ProxyType = impls::ProxyType;
 	type FuturepassMigrator = impls::FuturepassMigrationProvider;
 	type WeightInfo = weights::pallet_futurepass::WeightInfo<Self>;
-
-	#[cfg(feature = "runtime-benchmarks")]
 	type MultiCurrency = AssetsExt;
+	type NestedCalls = impls::RuntimeNestedCalls;
+	type MaxDelegatePolicyItems = MaxDelegatePolicyItems;
+	type MaxGuardians = MaxGuardians;
+	type RecoveryDelay = RecoveryDelay;
+	type RecoveryExpiry = RecoveryExpiry;
+	type ChainId = EVMChainId;
+	type MaxProxyBatchCalls = MaxProxyBatchCalls;
 }
 
 parameter_types! {
@@ -1520,6 +1579,42 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_fee_proxy_rpc_runtime_api::FeeProxyApi<
+		Block,
+		RuntimeCall,
+	> for Runtime {
+		fn quote_call_fee(
+			payment_asset: AssetId,
+			call: RuntimeCall,
+			len: u32,
+		) -> Result<Balance, sp_runtime::DispatchError> {
+			let fee = TransactionPayment::query_call_fee_details(
+				RuntimeCall::FeeProxy(pallet_fee_proxy::Call::call_with_fee_preferences {
+					payment_asset,
+					max_payment: Balance::MAX,
+					call: Box::new(call.clone()),
+				}),
+				len,
+			)
+			.final_fee();
+			FeeProxy::quote_call_fee(payment_asset, &call, fee)
+		}
+
+		fn quote_evm_fee(
+			payment_asset: AssetId,
+			gas_limit: u64,
+			max_fee_per_gas: U256,
+			max_priority_fee_per_gas: Option<U256>,
+		) -> Result<Balance, sp_runtime::DispatchError> {
+			FeeProxy::quote_evm_fee(
+				payment_asset,
+				gas_limit,
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+			)
+		}
+	}
+
 	impl pallet_dex_rpc_runtime_api::DexApi<
 		Block,
 		Runtime,
@@ -1605,6 +1700,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xrpl_bridge_rpc_runtime_api::XrplBridgeApi<Block> for Runtime {
+		fn door_tx_fee() -> u64 {
+			XRPLBridge::door_tx_fee()
+		}
+	}
+
 	impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn chain_id() -> u64 {
 			<Runtime as pallet_evm::Config>::ChainId::get()
@@ -1738,8 +1839,8 @@ impl_runtime_apis! {
 		}
 
 		fn elasticity() -> Option<Permill> {
-			// We currently do not use or set elasticity; always return zero
-			Some(Permill::zero())
+			// Zero unless the dynamic base fee is enabled
+			Some(FeeControl::elasticity())
 		}
 	}
 
@@ -1785,6 +1886,12 @@ impl_runtime_apis! {
 				id: EthBridge::notary_set_id(), // the set Id is the same as the overall Ethy set Id
 			}
 		}
+		fn bls_public_keys() -> Vec<Option<bls::Public>> {
+			EthBridge::bls_public_keys()
+		}
+		fn event_proof_scheme(chain_id: EthyChainId) -> EventProofScheme {
+			EthBridge::event_proof_scheme(chain_id)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -1895,6 +2002,37 @@ fn transaction_asset_check(
 			_ => Err(TransactionValidityError::Invalid(InvalidTransaction::Call))?,
 		};
 
+		// ensure the sponsor covers the max fee of sponsored calls
+		if input.get(..4) == Some(&FEE_SPONSOR_FUNCTION_SELECTOR[..]) {
+			let (sponsor, target, input) =
+				FeePreferencesRunner::<Runtime, Runtime, Futurepass>::decode_sponsored_input(
+					input,
+				)?;
+			let (_, max_fee_scaled) =
+				FeePreferencesRunner::<Runtime, Runtime, Futurepass>::calculate_fee_scaled(
+					gas_limit.as_u64(),
+					<Runtime as pallet_fee_proxy::Config>::EVMBaseFeeProvider::evm_base_fee_per_gas(
+					),
+					Some(max_fee_per_gas),
+					Some(max_priority_fee_per_gas),
+				)?;
+			// the runner executes the call from the user's futurepass if it is the default proxy
+			let account = <Runtime as frame_system::Config>::AccountId::from(*source);
+			let account =
+				<Futurepass as seed_pallet_common::AccountProxy<AccountId>>::primary_proxy(
+					&account,
+				)
+				.unwrap_or(account);
+			return FeeProxy::ensure_sponsored_evm_call(
+				&sponsor,
+				&account,
+				&target,
+				&input,
+				max_fee_scaled,
+			)
+			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		}
+
 		let (payment_asset_id, _target, _input) =
 			FeePreferencesRunner::<Runtime, Runtime, Futurepass>::decode_input(input)?;
 
@@ -1922,6 +2060,30 @@ fn transaction_asset_check(
 			amounts_in[0] <= user_asset_balance,
 			TransactionValidityError::Invalid(InvalidTransaction::Payment)
 		);
+	} else {
+		// ensure the fee asset preference of the account covers the max fee if it applies
+		let account = <Runtime as frame_system::Config>::AccountId::from(*source);
+		let account =
+			<Futurepass as seed_pallet_common::AccountProxy<AccountId>>::primary_proxy(&account)
+				.unwrap_or(account);
+		if pallet_fee_proxy::FeePreferences::<Runtime>::contains_key(&account) {
+			let (gas_limit, max_fee_per_gas, max_priority_fee_per_gas) = match eth_tx {
+				EthereumTransaction::Legacy(t) => (t.gas_limit, t.gas_price, None),
+				EthereumTransaction::EIP2930(t) => (t.gas_limit, t.gas_price, None),
+				EthereumTransaction::EIP1559(t) =>
+					(t.gas_limit, t.max_fee_per_gas, Some(t.max_priority_fee_per_gas)),
+			};
+			let (_, max_fee_scaled) =
+				FeePreferencesRunner::<Runtime, Runtime, Futurepass>::calculate_fee_scaled(
+					gas_limit.as_u64(),
+					<Runtime as pallet_fee_proxy::Config>::EVMBaseFeeProvider::evm_base_fee_per_gas(
+					),
+					Some(max_fee_per_gas),
+					max_priority_fee_per_gas,
+				)?;
+			FeeProxy::ensure_fee_preference(&account, max_fee_scaled)
+				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+		}
 	}
 
 	Ok(())
@@ -2059,8 +2221,10 @@ mod benches {
 		[pallet_nft, Nft]
 		[pallet_sft, Sft]
 		[pallet_fee_control, FeeControl]
+		[pallet_fee_proxy, FeeProxy]
 		[pallet_nft_peg, NftPeg]
 		[pallet_xrpl_bridge, XRPLBridge]
+		[pallet_ethy, EthBridge]
 		[pallet_erc20_peg, Erc20Peg]
 		[pallet_echo, Echo]
 		[pallet_assets_ext, AssetsExt]
@@ -0,0 +1,322 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+use super::Value;
+use crate::{AccountId, EthBridge, Runtime};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::GetStorageVersion,
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use seed_primitives::ethy::{EthyChainId, EventClaimId};
+#[allow(unused_imports)]
+use sp_std::{vec, vec::Vec};
+
+/// `pallet-ethy` storage migrations
+///
+/// v1: moved from `decl_storage!` to `#[frame_support::pallet]`
+/// The storage prefix (`EthBridge`) and all storage item names and hashers are unchanged, so the
+/// existing keys are kept as is and only the storage version is set
+///
+/// v2: the single permissioned `Relayer` is replaced by the `Relayers` set, claims still pending
+/// were submitted by that relayer
+///
+/// v3: `ContractAddress`, `EventBlockConfirmations` and `ProcessedMessageIds` are keyed by
+/// `EthyChainId` and `EventClaim` records the chain it was submitted from. Existing values belong
+/// to Ethereum
+pub struct Upgrade;
+impl OnRuntimeUpgrade for Upgrade {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		log::info!(target: "Migration", "EthBridge: Upgrade to v3 Pre Upgrade.");
+		let onchain = EthBridge::on_chain_storage_version();
+		assert!(onchain < 3);
+		Ok(Vec::new())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let current = EthBridge::current_storage_version();
+		let onchain = EthBridge::on_chain_storage_version();
+		log::info!(target: "Migration", "EthBridge: Running migration with current storage version {current:?} / onchain {onchain:?}");
+
+		let mut weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+		if onchain < 2 {
+			log::info!(target: "Migration", "EthBridge: Migrating from onchain version {onchain:?} to onchain version 2.");
+			weight = weight.saturating_add(v2::migrate());
+			StorageVersion::new(2).put::<EthBridge>();
+			weight =
+				weight.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(1));
+			log::info!(target: "Migration", "EthBridge: Migration successfully finished.");
+		}
+
+		if EthBridge::on_chain_storage_version() < 3 {
+			log::info!(target: "Migration", "EthBridge: Migrating to onchain version 3.");
+			weight = weight.saturating_add(v3::migrate());
+			StorageVersion::new(3).put::<EthBridge>();
+			weight =
+				weight.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(1));
+			log::info!(target: "Migration", "EthBridge: Migration successfully finished.");
+		} else if onchain >= 3 {
+			log::info!(target: "Migration", "EthBridge: No migration was done, however migration code needs to be removed.");
+		}
+
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+		log::info!(target: "Migration", "EthBridge: Upgrade to v3 Post Upgrade.");
+		let current = EthBridge::current_storage_version();
+		let onchain = EthBridge::on_chain_storage_version();
+		assert_eq!(current, 3);
+		assert_eq!(onchain, 3);
+		assert!(!Value::unsafe_exists(b"EthBridge", b"Relayer"));
+		assert!(!Value::unsafe_exists(b"EthBridge", b"ContractAddress"));
+		Ok(())
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Move the active relayer (if any) into the relayer set and record it as the submitter of
+	/// all pending claims, so they can still be slashed
+	pub fn migrate() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let Some(relayer) = Value::unsafe_storage_get::<AccountId>(b"EthBridge", b"Relayer") else {
+			return db_weight.reads(1)
+		};
+		log::info!(target: "Migration", "EthBridge: moving relayer {relayer:?} into the relayer set");
+		pallet_ethy::Relayers::<Runtime>::put(vec![relayer]);
+		pallet_ethy::RelayerLastSubmission::<Runtime>::insert(
+			relayer,
+			frame_system::Pallet::<Runtime>::block_number(),
+		);
+		Value::unsafe_clear(b"EthBridge", b"Relayer");
+		let mut reads = 2_u64;
+		let mut writes = 3_u64;
+
+		for event_id in pallet_ethy::PendingEventClaims::<Runtime>::iter_keys() {
+			pallet_ethy::EventClaimRelayer::<Runtime>::insert(event_id, relayer);
+			reads += 1;
+			writes += 1;
+		}
+
+		db_weight.reads_writes(reads, writes)
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use seed_primitives::EthAddress;
+	use sp_core::H256;
+
+	/// `EventClaim` as stored before v3
+	#[derive(Decode, Encode)]
+	pub struct OldEventClaim {
+		pub tx_hash: H256,
+		pub source: EthAddress,
+		pub destination: EthAddress,
+		pub data: Vec<u8>,
+	}
+
+	/// Move the bridge config values under `EthyChainId::Ethereum` and tag pending claims with
+	/// their chain
+	pub fn migrate() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let mut reads = 3_u64;
+		let mut writes = 0_u64;
+
+		if let Some(contract_address) =
+			Value::unsafe_storage_get::<EthAddress>(b"EthBridge", b"ContractAddress")
+		{
+			Value::unsafe_clear(b"EthBridge", b"ContractAddress");
+			pallet_ethy::ContractAddress::<Runtime>::insert(
+				EthyChainId::Ethereum,
+				contract_address,
+			);
+			writes += 2;
+		}
+		if let Some(confirmations) =
+			Value::unsafe_storage_get::<u64>(b"EthBridge", b"EventBlockConfirmations")
+		{
+			Value::unsafe_clear(b"EthBridge", b"EventBlockConfirmations");
+			pallet_ethy::EventBlockConfirmations::<Runtime>::insert(
+				EthyChainId::Ethereum,
+				confirmations,
+			);
+			writes += 2;
+		}
+		if let Some(processed_message_ids) =
+			Value::unsafe_storage_get::<Vec<EventClaimId>>(b"EthBridge", b"ProcessedMessageIds")
+		{
+			Value::unsafe_clear(b"EthBridge", b"ProcessedMessageIds");
+			pallet_ethy::ProcessedMessageIds::<Runtime>::insert(
+				EthyChainId::Ethereum,
+				processed_message_ids,
+			);
+			writes += 2;
+		}
+
+		pallet_ethy::PendingEventClaims::<Runtime>::translate::<OldEventClaim, _>(|_, old| {
+			reads += 1;
+			writes += 1;
+			Some(pallet_ethy::EventClaim {
+				tx_hash: old.tx_hash,
+				source: old.source,
+				destination: old.destination,
+				data: old.data,
+				chain_id: EthyChainId::Ethereum,
+			})
+		});
+
+		db_weight.reads_writes(reads, writes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::migrations::{tests::new_test_ext, Value};
+	use frame_support::{migration::put_storage_value, StorageHasher, Twox64Concat};
+	use seed_primitives::Balance;
+
+	#[test]
+	fn migration_test() {
+		new_test_ext().execute_with(|| {
+			// Setup storage as written by `decl_storage!`
+			StorageVersion::new(0).put::<EthBridge>();
+			put_storage_value::<u64>(b"EthBridge", b"NotarySetId", b"", 5);
+			put_storage_value::<bool>(b"EthBridge", b"BridgePaused", b"", true);
+			let relayer = seed_primitives::AccountId20([1_u8; 20]);
+			put_storage_value::<Balance>(
+				b"EthBridge",
+				b"RelayerPaidBond",
+				&Twox64Concat::hash(&codec::Encode::encode(&relayer)),
+				100,
+			);
+			put_storage_value::<AccountId>(b"EthBridge", b"Relayer", b"", relayer);
+			// A claim submitted by the relayer is still pending
+			let old_claim = v3::OldEventClaim {
+				tx_hash: sp_core::H256::from_low_u64_be(1),
+				source: seed_primitives::EthAddress::from_low_u64_be(2),
+				destination: seed_primitives::EthAddress::from_low_u64_be(3),
+				data: vec![1, 2, 3],
+			};
+			put_storage_value::<v3::OldEventClaim>(
+				b"EthBridge",
+				b"PendingEventClaims",
+				&Twox64Concat::hash(&codec::Encode::encode(&6_u64)),
+				old_claim,
+			);
+
+			// Do runtime upgrade
+			Upgrade::on_runtime_upgrade();
+			assert_eq!(EthBridge::on_chain_storage_version(), 3);
+
+			// Existing values are still readable
+			assert_eq!(pallet_ethy::NotarySetId::<Runtime>::get(), 5);
+			assert!(pallet_ethy::BridgePaused::<Runtime>::get());
+			assert_eq!(pallet_ethy::RelayerPaidBond::<Runtime>::get(relayer), 100);
+			assert!(Value::exists::<pallet_ethy::NotarySetId<Runtime>, _>());
+
+			// Relayer is moved into the relayer set
+			assert_eq!(pallet_ethy::Relayers::<Runtime>::get(), vec![relayer]);
+			assert!(!Value::unsafe_exists(b"EthBridge", b"Relayer"));
+			assert!(pallet_ethy::RelayerLastSubmission::<Runtime>::contains_key(relayer));
+
+			// The pending claim is attributed to the relayer and migrated
+			assert_eq!(pallet_ethy::EventClaimRelayer::<Runtime>::get(6), Some(relayer));
+			let claim = pallet_ethy::PendingEventClaims::<Runtime>::get(6).unwrap();
+			assert_eq!(claim.chain_id, EthyChainId::Ethereum);
+		});
+	}
+
+	#[test]
+	fn migration_from_v1_without_relayer() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<EthBridge>();
+
+			Upgrade::on_runtime_upgrade();
+			assert_eq!(EthBridge::on_chain_storage_version(), 3);
+			assert!(pallet_ethy::Relayers::<Runtime>::get().is_empty());
+		});
+	}
+
+	#[test]
+	fn migration_from_v2_keys_config_by_chain() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<EthBridge>();
+			let contract_address = seed_primitives::EthAddress::from_low_u64_be(123);
+			put_storage_value::<seed_primitives::EthAddress>(
+				b"EthBridge",
+				b"ContractAddress",
+				b"",
+				contract_address,
+			);
+			put_storage_value::<u64>(b"EthBridge", b"EventBlockConfirmations", b"", 10);
+			put_storage_value::<Vec<EventClaimId>>(
+				b"EthBridge",
+				b"ProcessedMessageIds",
+				b"",
+				vec![1, 2, 5],
+			);
+			let old_claim = v3::OldEventClaim {
+				tx_hash: sp_core::H256::from_low_u64_be(1),
+				source: seed_primitives::EthAddress::from_low_u64_be(2),
+				destination: seed_primitives::EthAddress::from_low_u64_be(3),
+				data: vec![1, 2, 3],
+			};
+			put_storage_value::<v3::OldEventClaim>(
+				b"EthBridge",
+				b"PendingEventClaims",
+				&Twox64Concat::hash(&codec::Encode::encode(&6_u64)),
+				old_claim,
+			);
+
+			Upgrade::on_runtime_upgrade();
+			assert_eq!(EthBridge::on_chain_storage_version(), 3);
+
+			assert_eq!(
+				pallet_ethy::ContractAddress::<Runtime>::get(EthyChainId::Ethereum),
+				contract_address
+			);
+			assert!(
+				pallet_ethy::ContractAddress::<Runtime>::get(EthyChainId::SecondaryEvm).is_zero()
+			);
+			assert_eq!(
+				pallet_ethy::EventBlockConfirmations::<Runtime>::get(EthyChainId::Ethereum),
+				10
+			);
+			assert_eq!(
+				pallet_ethy::EventBlockConfirmations::<Runtime>::get(EthyChainId::SecondaryEvm),
+				3
+			);
+			assert_eq!(
+				pallet_ethy::ProcessedMessageIds::<Runtime>::get(EthyChainId::Ethereum),
+				vec![1, 2, 5]
+			);
+			assert!(!Value::unsafe_exists(b"EthBridge", b"ContractAddress"));
+			assert!(!Value::unsafe_exists(b"EthBridge", b"EventBlockConfirmations"));
+			assert!(!Value::unsafe_exists(b"EthBridge", b"ProcessedMessageIds"));
+
+			let claim = pallet_ethy::PendingEventClaims::<Runtime>::get(6).unwrap();
+			assert_eq!(claim.chain_id, EthyChainId::Ethereum);
+			assert_eq!(claim.data, vec![1, 2, 3]);
+		});
+	}
+}
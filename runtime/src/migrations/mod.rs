@@ -26,20 +26,33 @@ use frame_support::{
 };
 use sp_std::vec::Vec;
 
+mod ethy;
+mod xls20;
+mod xrpl_bridge;
+
 pub struct AllMigrations;
 impl OnRuntimeUpgrade for AllMigrations {
 	#[cfg(feature = "try-runtime")]
 	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		xls20::Upgrade::pre_upgrade()?;
+		ethy::Upgrade::pre_upgrade()?;
+		xrpl_bridge::Upgrade::pre_upgrade()?;
 		Ok(Vec::<u8>::new())
 	}
 
 	fn on_runtime_upgrade() -> Weight {
-		let weight = Weight::from_ref_time(0_u64);
+		let mut weight = Weight::from_ref_time(0_u64);
+		weight = weight.saturating_add(xls20::Upgrade::on_runtime_upgrade());
+		weight = weight.saturating_add(ethy::Upgrade::on_runtime_upgrade());
+		weight = weight.saturating_add(xrpl_bridge::Upgrade::on_runtime_upgrade());
 		weight
 	}
 
 	#[cfg(feature = "try-runtime")]
 	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		xls20::Upgrade::post_upgrade(Vec::new())?;
+		ethy::Upgrade::post_upgrade(Vec::new())?;
+		xrpl_bridge::Upgrade::post_upgrade(Vec::new())?;
 		Ok(())
 	}
 }
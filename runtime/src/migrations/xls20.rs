@@ -0,0 +1,139 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+use crate::{Runtime, RuntimeBlockWeights, Xls20};
+use frame_support::{
+	dispatch::GetStorageVersion,
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+#[allow(unused_imports)]
+use sp_std::vec::Vec;
+
+pub struct Upgrade;
+impl OnRuntimeUpgrade for Upgrade {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		log::info!(target: "Migration", "Xls20: Upgrade to v1 Pre Upgrade.");
+		let onchain = Xls20::on_chain_storage_version();
+		assert_eq!(onchain, 0);
+		Ok(Vec::new())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let current = Xls20::current_storage_version();
+		let onchain = Xls20::on_chain_storage_version();
+		log::info!(target: "Migration", "Xls20: Running migration with current storage version {current:?} / onchain {onchain:?}");
+
+		let mut weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+		if onchain == 0 {
+			log::info!(target: "Migration", "Xls20: Migrating from onchain version 0 to onchain version 1.");
+			let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+			// Each mapping costs one read and one write, never exceed the block weight
+			let limit = RuntimeBlockWeights::get()
+				.max_block
+				.ref_time()
+				.saturating_div(db_weight.reads_writes(1, 1).ref_time().max(1))
+				.saturating_sub(3);
+			let count = v1::migrate::<Runtime>(limit);
+			StorageVersion::new(1).put::<Xls20>();
+			weight = weight.saturating_add(db_weight.reads_writes(count, count.saturating_add(1)));
+			log::info!(target: "Migration", "Xls20: Migration successfully finished.");
+		} else {
+			log::info!(target: "Migration", "Xls20: No migration was done, however migration code needs to be removed.");
+		}
+
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+		log::info!(target: "Migration", "Xls20: Upgrade to v1 Post Upgrade.");
+		let current = Xls20::current_storage_version();
+		let onchain = Xls20::on_chain_storage_version();
+		assert_eq!(current, 1);
+		assert_eq!(onchain, 1);
+		Ok(())
+	}
+}
+
+pub mod v1 {
+	use sp_runtime::SaturatedConversion;
+
+	/// Populate the XLS-20 token id -> native token id reverse lookup from the existing mappings
+	/// At most `limit` mappings are migrated
+	/// Returns the number of mappings migrated
+	pub fn migrate<T: pallet_xls20::Config>(limit: u64) -> u64 {
+		let mut count = 0_u64;
+		let mut mappings = pallet_xls20::Xls20TokenMap::<T>::iter();
+		for (collection_id, serial_number, xls20_token_id) in
+			mappings.by_ref().take(limit.saturated_into())
+		{
+			pallet_xls20::Xls20TokenIdMap::<T>::insert(
+				xls20_token_id,
+				(collection_id, serial_number),
+			);
+			count = count.saturating_add(1);
+		}
+		if mappings.next().is_some() {
+			log::error!(target: "Migration", "Xls20: migration limit of {limit:?} reached, remaining mappings were not migrated");
+		}
+		count
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::{
+			migrations::{tests::new_test_ext, xls20::Upgrade},
+			Runtime, Xls20,
+		};
+		use frame_support::{
+			dispatch::GetStorageVersion,
+			traits::{OnRuntimeUpgrade, StorageVersion},
+		};
+
+		#[test]
+		fn migration_test() {
+			new_test_ext().execute_with(|| {
+				// Setup storage
+				StorageVersion::new(0).put::<Xls20>();
+				let xls20_token_id = [1_u8; 64];
+				pallet_xls20::Xls20TokenMap::<Runtime>::insert(1124, 5, xls20_token_id);
+
+				// Do runtime upgrade
+				Upgrade::on_runtime_upgrade();
+				assert_eq!(Xls20::on_chain_storage_version(), 1);
+
+				assert_eq!(
+					pallet_xls20::Xls20TokenIdMap::<Runtime>::get(xls20_token_id),
+					Some((1124, 5))
+				);
+			});
+		}
+
+		#[test]
+		fn migration_respects_limit() {
+			new_test_ext().execute_with(|| {
+				pallet_xls20::Xls20TokenMap::<Runtime>::insert(1124, 5, [1_u8; 64]);
+				pallet_xls20::Xls20TokenMap::<Runtime>::insert(1124, 6, [2_u8; 64]);
+
+				assert_eq!(migrate::<Runtime>(1), 1);
+				assert_eq!(pallet_xls20::Xls20TokenIdMap::<Runtime>::iter().count(), 1);
+			});
+		}
+	}
+}
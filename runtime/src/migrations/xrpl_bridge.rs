@@ -0,0 +1,200 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+use crate::{Runtime, XRPLBridge};
+use frame_support::{
+	dispatch::GetStorageVersion,
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+#[allow(unused_imports)]
+use sp_std::vec::Vec;
+
+pub struct Upgrade;
+impl OnRuntimeUpgrade for Upgrade {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		log::info!(target: "Migration", "XRPLBridge: Upgrade to v3 Pre Upgrade.");
+		let onchain = XRPLBridge::on_chain_storage_version();
+		assert_eq!(onchain, 2);
+		Ok(Vec::new())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let current = XRPLBridge::current_storage_version();
+		let onchain = XRPLBridge::on_chain_storage_version();
+		log::info!(target: "Migration", "XRPLBridge: Running migration with current storage version {current:?} / onchain {onchain:?}");
+
+		let mut weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+		if onchain == 2 {
+			log::info!(target: "Migration", "XRPLBridge: Migrating from onchain version 2 to onchain version 3.");
+			let (reads, writes) = v3::migrate::<Runtime>();
+			StorageVersion::new(3).put::<XRPLBridge>();
+			weight = weight.saturating_add(
+				<Runtime as frame_system::Config>::DbWeight::get()
+					.reads_writes(reads, writes.saturating_add(1)),
+			);
+			log::info!(target: "Migration", "XRPLBridge: Migration successfully finished.");
+		} else {
+			log::info!(target: "Migration", "XRPLBridge: No migration was done, however migration code needs to be removed.");
+		}
+
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+		log::info!(target: "Migration", "XRPLBridge: Upgrade to v3 Post Upgrade.");
+		let current = XRPLBridge::current_storage_version();
+		let onchain = XRPLBridge::on_chain_storage_version();
+		assert_eq!(current, 3);
+		assert_eq!(onchain, 3);
+		Ok(())
+	}
+}
+
+pub mod v3 {
+	use codec::{Decode, Encode};
+	use pallet_xrpl_bridge::types::{XrpTransaction, XrplTxData};
+	use seed_primitives::{
+		xrpl::{LedgerIndex, XrplTxHash},
+		Balance,
+	};
+	use sp_core::{H160, H256};
+
+	/// `XrplTxData` as stored before v3
+	#[derive(Decode, Encode)]
+	pub enum OldXrplTxData {
+		Payment { amount: Balance, address: H160 },
+		CurrencyPayment { amount: Balance, address: H160, currency_id: H256 },
+		Xls20,
+	}
+
+	/// `XrpTransaction` as stored before v3
+	#[derive(Decode, Encode)]
+	pub struct OldXrpTransaction {
+		pub transaction_hash: XrplTxHash,
+		pub transaction: OldXrplTxData,
+		pub timestamp: u64,
+	}
+
+	/// Re-encode `ProcessXRPTransactionDetails` with the v3 `XrplTxData` layout
+	/// Currency and XLS-20 payments were never processed before v3 and carry no issuer or token
+	/// id, so they are removed
+	/// Returns the number of (reads, writes)
+	pub fn migrate<T: pallet_xrpl_bridge::Config>() -> (u64, u64) {
+		let mut count = 0_u64;
+		pallet_xrpl_bridge::ProcessXRPTransactionDetails::<T>::translate::<
+			(LedgerIndex, OldXrpTransaction, T::AccountId),
+			_,
+		>(|transaction_hash, (ledger_index, old, relayer)| {
+			count = count.saturating_add(1);
+			match old.transaction {
+				OldXrplTxData::Payment { amount, address } => Some((
+					ledger_index,
+					XrpTransaction {
+						transaction_hash: old.transaction_hash,
+						transaction: XrplTxData::Payment { amount, address },
+						timestamp: old.timestamp,
+					},
+					relayer,
+				)),
+				_ => {
+					log::info!(target: "Migration", "XRPLBridge: removing unsupported transaction {transaction_hash:?}");
+					None
+				},
+			}
+		});
+		(count, count)
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::{
+			migrations::{tests::new_test_ext, xrpl_bridge::Upgrade},
+			AccountId, Runtime, XRPLBridge,
+		};
+		use frame_support::{
+			dispatch::GetStorageVersion,
+			migration::put_storage_value,
+			traits::{OnRuntimeUpgrade, StorageVersion},
+			Identity, StorageHasher,
+		};
+
+		#[test]
+		fn migration_test() {
+			new_test_ext().execute_with(|| {
+				// Setup storage
+				StorageVersion::new(2).put::<XRPLBridge>();
+				let relayer = seed_primitives::AccountId20([1_u8; 20]);
+				let address = H160::from_low_u64_be(2);
+				let payment_hash = XrplTxHash::from_low_u64_be(1);
+				let currency_hash = XrplTxHash::from_low_u64_be(2);
+				let xls20_hash = XrplTxHash::from_low_u64_be(3);
+				let txs = vec![
+					(payment_hash, OldXrplTxData::Payment { amount: 100, address }),
+					(
+						currency_hash,
+						OldXrplTxData::CurrencyPayment {
+							amount: 100,
+							address,
+							currency_id: H256::from_low_u64_be(4),
+						},
+					),
+					(xls20_hash, OldXrplTxData::Xls20),
+				];
+				for (transaction_hash, transaction) in txs {
+					put_storage_value::<(LedgerIndex, OldXrpTransaction, AccountId)>(
+						b"XRPLBridge",
+						b"ProcessXRPTransactionDetails",
+						&Identity::hash(&transaction_hash.encode()),
+						(
+							5,
+							OldXrpTransaction { transaction_hash, transaction, timestamp: 1234 },
+							relayer,
+						),
+					);
+				}
+
+				// Do runtime upgrade
+				Upgrade::on_runtime_upgrade();
+				assert_eq!(XRPLBridge::on_chain_storage_version(), 3);
+
+				assert_eq!(
+					pallet_xrpl_bridge::ProcessXRPTransactionDetails::<Runtime>::get(payment_hash),
+					Some((
+						5,
+						XrpTransaction {
+							transaction_hash: payment_hash,
+							transaction: XrplTxData::Payment { amount: 100, address },
+							timestamp: 1234,
+						},
+						relayer
+					))
+				);
+				assert!(pallet_xrpl_bridge::ProcessXRPTransactionDetails::<Runtime>::get(
+					currency_hash
+				)
+				.is_none());
+				assert!(pallet_xrpl_bridge::ProcessXRPTransactionDetails::<Runtime>::get(
+					xls20_hash
+				)
+				.is_none());
+			});
+		}
+	}
+}
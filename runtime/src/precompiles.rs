@@ -22,6 +22,7 @@ use pallet_evm_precompiles_dex::DexPrecompile;
 use pallet_evm_precompiles_erc1155::Erc1155PrecompileSet;
 use pallet_evm_precompiles_erc20::Erc20PrecompileSet;
 use pallet_evm_precompiles_erc721::Erc721PrecompileSet;
+use pallet_evm_precompiles_fee_preference::FeePreferencePrecompile;
 use pallet_evm_precompiles_futurepass::FuturePassPrecompileSet;
 use pallet_evm_precompiles_futurepass_registrar::FuturePassRegistrarPrecompile;
 use pallet_evm_precompiles_marketplace::MarketplacePrecompile;
@@ -31,9 +32,9 @@ use pallet_evm_precompiles_sft::SftPrecompile;
 use precompile_utils::{
 	constants::{
 		DEX_PRECOMPILE, ERC1155_PRECOMPILE_ADDRESS_PREFIX, ERC20_PRECOMPILE_ADDRESS_PREFIX,
-		ERC721_PRECOMPILE_ADDRESS_PREFIX, FUTUREPASS_PRECOMPILE_ADDRESS_PREFIX,
-		FUTUREPASS_REGISTRAR_PRECOMPILE, MARKETPLACE_PRECOMPILE, NFT_PRECOMPILE, PEG_PRECOMPILE,
-		SFT_PRECOMPILE,
+		ERC721_PRECOMPILE_ADDRESS_PREFIX, FEE_PREFERENCE_PRECOMPILE,
+		FUTUREPASS_PRECOMPILE_ADDRESS_PREFIX, FUTUREPASS_REGISTRAR_PRECOMPILE,
+		MARKETPLACE_PRECOMPILE, NFT_PRECOMPILE, PEG_PRECOMPILE, SFT_PRECOMPILE,
 	},
 	precompile_set::*,
 };
@@ -79,6 +80,7 @@ pub type FutureversePrecompiles<R> = PrecompileSetBuilder<
 					FuturePassRegistrarPrecompile<R>,
 				>,
 				PrecompileAt<AddressU64<MARKETPLACE_PRECOMPILE>, MarketplacePrecompile<R>>,
+				PrecompileAt<AddressU64<FEE_PREFERENCE_PRECOMPILE>, FeePreferencePrecompile<R>>,
 			),
 		>,
 		// Prefixed precompile sets (XC20)
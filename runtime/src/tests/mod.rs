@@ -22,6 +22,7 @@ mod evm_gas_costs;
 mod evm_tests;
 mod maintenance_mode;
 mod multiplier;
+mod proxy_types;
 mod staker_payouts;
 
 use frame_support::traits::{fungibles::Inspect as _, GenesisBuild, Get};
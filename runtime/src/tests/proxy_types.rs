@@ -0,0 +1,226 @@
+// Copyright 2022-2023 Futureverse Corporation Limited
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// You may obtain a copy of the License at the root of this project source code
+
+//! Integration tests for the futurepass delegate proxy type filters
+#![cfg(test)]
+
+use crate::{
+	impls::{ProxyType, RuntimeNestedCalls},
+	tests::{alice, bob, ExtBuilder},
+	CallFilter, RuntimeCall,
+};
+use frame_support::traits::{Contains, InstanceFilter};
+use pallet_evm_precompiles_futurepass::{EvmProxyCallFilter, EvmSubCall};
+use pallet_futurepass::NestedCalls;
+use precompile_utils::{
+	constants::{DEX_PRECOMPILE, FEE_PREFERENCE_PRECOMPILE},
+	keccak256, Address,
+};
+use sp_core::{H160, U256};
+
+const ALL_PROXY_TYPES: [ProxyType; 6] = [
+	ProxyType::NoPermission,
+	ProxyType::Any,
+	ProxyType::NonTransfer,
+	ProxyType::Governance,
+	ProxyType::Staking,
+	ProxyType::Owner,
+];
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: b"hello".to_vec() })
+}
+
+fn transfer_call() -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer { dest: bob(), value: 1 })
+}
+
+fn staking_call() -> RuntimeCall {
+	RuntimeCall::Staking(pallet_staking::Call::chill {})
+}
+
+fn evm_subcall(to: H160, value: U256, call_data: Vec<u8>) -> EvmSubCall {
+	EvmSubCall { to: Address(to), value, call_data: call_data.into() }
+}
+
+#[test]
+fn proxy_type_filters_calls() {
+	let batch = RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![transfer_call()] });
+	// (call, [NoPermission, Any, NonTransfer, Governance, Staking, Owner])
+	let cases = vec![
+		(remark_call(), [false, true, true, false, false, true]),
+		(transfer_call(), [false, true, false, false, false, true]),
+		(staking_call(), [false, true, true, false, true, true]),
+		// batched calls are filtered individually on dispatch
+		(batch, [false, true, true, false, true, true]),
+	];
+
+	for (call, allowed) in cases {
+		for (proxy_type, allowed) in ALL_PROXY_TYPES.iter().zip(allowed) {
+			assert_eq!(proxy_type.filter(&call), allowed, "{:?} {:?}", proxy_type, call);
+		}
+	}
+}
+
+#[test]
+fn nested_calls_unwraps_wrapping_calls() {
+	let call = || Box::new(transfer_call());
+	let wrapping_calls = vec![
+		RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![transfer_call()] }),
+		RuntimeCall::Utility(pallet_utility::Call::batch_all { calls: vec![transfer_call()] }),
+		RuntimeCall::Utility(pallet_utility::Call::force_batch { calls: vec![transfer_call()] }),
+		RuntimeCall::Utility(pallet_utility::Call::as_derivative { index: 0, call: call() }),
+		RuntimeCall::FeeProxy(pallet_fee_proxy::Call::call_with_fee_preferences {
+			payment_asset: 100,
+			max_payment: 1,
+			call: call(),
+		}),
+		RuntimeCall::FeeProxy(pallet_fee_proxy::Call::call_with_sponsor {
+			sponsor: bob(),
+			call: call(),
+		}),
+		RuntimeCall::Futurepass(pallet_futurepass::Call::proxy_extrinsic {
+			futurepass: alice(),
+			call: call(),
+		}),
+		RuntimeCall::Futurepass(pallet_futurepass::Call::proxy_extrinsic_batch {
+			futurepass: alice(),
+			calls: vec![transfer_call()],
+			atomic: true,
+		}),
+		RuntimeCall::Futurepass(pallet_futurepass::Call::proxy_extrinsic_with_signature {
+			futurepass: alice(),
+			delegate: bob(),
+			call: call(),
+			nonce: 0,
+			deadline: 1,
+			futurepass_pays_fee: false,
+			signature: [0_u8; 65],
+		}),
+		RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+			real: alice(),
+			force_proxy_type: None,
+			call: call(),
+		}),
+		RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced {
+			delegate: bob(),
+			real: alice(),
+			force_proxy_type: None,
+			call: call(),
+		}),
+		RuntimeCall::Sudo(pallet_sudo::Call::sudo { call: call() }),
+		RuntimeCall::Sudo(pallet_sudo::Call::sudo_unchecked_weight {
+			call: call(),
+			weight: Default::default(),
+		}),
+		RuntimeCall::Sudo(pallet_sudo::Call::sudo_as { who: bob(), call: call() }),
+	];
+
+	for wrapping_call in wrapping_calls {
+		assert_eq!(
+			RuntimeNestedCalls::nested_calls(&wrapping_call),
+			vec![&transfer_call()],
+			"{:?}",
+			wrapping_call
+		);
+	}
+	assert!(RuntimeNestedCalls::nested_calls(&transfer_call()).is_empty());
+}
+
+#[test]
+fn direct_proxy_calls_are_filtered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// delegates must use futurepass proxy_extrinsic
+		let call = RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+			real: alice(),
+			force_proxy_type: None,
+			call: Box::new(remark_call()),
+		});
+		assert!(!CallFilter::contains(&call));
+		assert!(CallFilter::contains(&remark_call()));
+	});
+}
+
+#[test]
+fn proxy_type_is_superset() {
+	for proxy_type in ALL_PROXY_TYPES {
+		assert!(proxy_type.is_superset(&proxy_type));
+		assert!(ProxyType::Owner.is_superset(&proxy_type));
+		assert!(ProxyType::Any.is_superset(&proxy_type));
+		assert!(proxy_type.is_superset(&ProxyType::NoPermission));
+	}
+
+	assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Governance));
+	assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Staking));
+	assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Any));
+	assert!(!ProxyType::Staking.is_superset(&ProxyType::NonTransfer));
+	assert!(!ProxyType::Governance.is_superset(&ProxyType::Staking));
+}
+
+#[test]
+fn proxy_type_filters_evm_calls() {
+	let contract: H160 = alice().into();
+	let mut transfer = keccak256!("transfer(address,uint256)")[..4].to_vec();
+	transfer.extend_from_slice(&[0_u8; 64]);
+	let mut increase_allowance = keccak256!("increaseAllowance(address,uint256)")[..4].to_vec();
+	increase_allowance.extend_from_slice(&[0_u8; 64]);
+	let mut permit =
+		keccak256!("permit(address,address,uint256,uint256,uint8,bytes32,bytes32)")[..4].to_vec();
+	permit.extend_from_slice(&[0_u8; 224]);
+	let mut mint = keccak256!("mint(address,uint256)")[..4].to_vec();
+	mint.extend_from_slice(&[0_u8; 64]);
+
+	// (call, [NoPermission, Any, NonTransfer, Governance, Staking, Owner])
+	let cases = vec![
+		// custom contract methods may move tokens
+		(
+			evm_subcall(contract, U256::zero(), mint.clone()),
+			[false, true, false, false, false, true],
+		),
+		// short call data
+		(evm_subcall(contract, U256::zero(), vec![1, 2]), [false, true, false, false, false, true]),
+		// value transfer
+		(evm_subcall(contract, U256::one(), vec![]), [false, true, false, false, false, true]),
+		// token transfer
+		(evm_subcall(contract, U256::zero(), transfer), [false, true, false, false, false, true]),
+		// token approvals
+		(
+			evm_subcall(contract, U256::zero(), increase_allowance),
+			[false, true, false, false, false, true],
+		),
+		(evm_subcall(contract, U256::zero(), permit), [false, true, false, false, false, true]),
+		// precompile spending the futurepass' tokens
+		(
+			evm_subcall(H160::from_low_u64_be(DEX_PRECOMPILE), U256::zero(), mint.clone()),
+			[false, true, false, false, false, true],
+		),
+		// precompile paying fees with the futurepass' tokens
+		(
+			evm_subcall(H160::from_low_u64_be(FEE_PREFERENCE_PRECOMPILE), U256::zero(), mint),
+			[false, true, false, false, false, true],
+		),
+	];
+
+	for (call, allowed) in cases {
+		for (proxy_type, allowed) in ALL_PROXY_TYPES.iter().zip(allowed) {
+			assert_eq!(
+				proxy_type.is_evm_proxy_call_allowed(&call, true),
+				allowed,
+				"{:?}",
+				proxy_type
+			);
+		}
+	}
+}
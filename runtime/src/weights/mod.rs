@@ -7,8 +7,10 @@ pub mod pallet_dex;
 pub mod pallet_echo;
 pub mod pallet_election_provider_multi_phase;
 pub mod pallet_erc20_peg;
+pub mod pallet_ethy;
 pub mod pallet_evm_chain_id;
 pub mod pallet_fee_control;
+pub mod pallet_fee_proxy;
 pub mod pallet_futurepass;
 pub mod pallet_im_online;
 pub mod pallet_maintenance_mode;
@@ -5,6 +5,9 @@
 //! DATE: 2023-09-19, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-31-102-147`, CPU: `Intel(R) Xeon(R) CPU E5-2686 v4 @ 2.30GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Weights marked `PLACEHOLDER` were written by hand after this run and are estimates only,
+//! they must be replaced by regenerating this file before release
 
 // Executed Command:
 // ./target/release/seed
@@ -43,4 +46,12 @@ impl<T: frame_system::Config> pallet_fee_control::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: FeeControl Data (r:1 w:1)
+	// Storage: FeeControl DynamicBaseFee (r:0 w:1)
+	fn set_dynamic_base_fee() -> Weight {
+		Weight::from_ref_time(21_204_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
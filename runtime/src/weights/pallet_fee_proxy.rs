@@ -0,0 +1,76 @@
+
+//! Placeholder weights for `pallet_fee_proxy`
+//!
+//! THESE WEIGHTS WERE NOT PRODUCED BY A BENCHMARK RUN. The storage accesses follow the
+//! benchmarked code paths but the ref times are estimates, they must be replaced by
+//! regenerating this file before release.
+
+// Regenerate with:
+// ./target/release/seed
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_fee_proxy
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output
+// ./runtime/src/weights/pallet_fee_proxy.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_fee_proxy`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_fee_proxy::WeightInfo for WeightInfo<T> {
+	// Storage: FeeProxy Sponsorships (r:1 w:0)
+	fn call_with_sponsor() -> Weight {
+		Weight::from_ref_time(10_714_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: FeeProxy Sponsorships (r:0 w:1)
+	fn set_sponsorship() -> Weight {
+		Weight::from_ref_time(22_335_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeProxy Sponsorships (r:1 w:1)
+	// Storage: FeeProxy SponsorSpending (r:0 w:1)
+	// Storage: FeeProxy SponsoredSpending (r:0 w:1)
+	fn remove_sponsorship() -> Weight {
+		Weight::from_ref_time(29_043_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_sponsorship_funds() -> Weight {
+		Weight::from_ref_time(52_861_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_sponsorship_funds() -> Weight {
+		Weight::from_ref_time(52_307_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: FeeProxy FeePreferences (r:0 w:1)
+	fn set_fee_preference() -> Weight {
+		Weight::from_ref_time(14_512_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeProxy FeePreferences (r:1 w:1)
+	fn remove_fee_preference() -> Weight {
+		Weight::from_ref_time(17_338_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
@@ -5,6 +5,9 @@
 //! DATE: 2023-09-19, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-31-102-147`, CPU: `Intel(R) Xeon(R) CPU E5-2686 v4 @ 2.30GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Weights marked `PLACEHOLDER` were written by hand after this run and are estimates only,
+//! they must be replaced by regenerating this file before release
 
 // Executed Command:
 // ./target/release/seed
@@ -62,16 +65,19 @@ impl<T: frame_system::Config> pallet_futurepass::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
 	// Storage: Futurepass Holders (r:2 w:2)
 	// Storage: Proxy Proxies (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
+	// Storage: Futurepass ActiveRecoveries (r:0 w:1)
+	// Storage: Futurepass RecoveryConfigs (r:0 w:1)
 	/// The range of component `p` is `[1, 31]`.
 	fn transfer_futurepass(p: u32, ) -> Weight {
 		Weight::from_ref_time(205_036_000 as u64)
 			// Standard Error: 76_809
 			.saturating_add(Weight::from_ref_time(51_031_323 as u64).saturating_mul(p as u64))
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: MaintenanceMode BlockedCalls (r:2 w:0)
 	// Storage: MaintenanceMode BlockedPallets (r:2 w:0)
@@ -83,4 +89,113 @@ impl<T: frame_system::Config> pallet_futurepass::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_ref_time(248_098 as u64).saturating_mul(p as u64))
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass Holders (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Futurepass DelegatePolicies (r:1 w:1)
+	// Storage: Futurepass DelegateSpending (r:0 w:1)
+	fn set_delegate_policy() -> Weight {
+		Weight::from_ref_time(98_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass Holders (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Futurepass DelegateExpiry (r:1 w:1)
+	// Storage: Futurepass NextExpiryCheckBlock (r:1 w:1)
+	// Storage: Futurepass DelegateExpirySchedule (r:0 w:1)
+	fn set_delegate_expiry() -> Weight {
+		Weight::from_ref_time(84_630_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass DelegateExpirySchedule (r:1 w:1)
+	// Storage: Futurepass DelegateExpiry (r:1 w:1)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Futurepass DelegatePolicies (r:0 w:1)
+	fn remove_expired_delegate() -> Weight {
+		Weight::from_ref_time(112_305_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass Holders (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Futurepass ActiveRecoveries (r:0 w:1)
+	// Storage: Futurepass RecoveryConfigs (r:0 w:1)
+	fn set_recovery_config() -> Weight {
+		Weight::from_ref_time(61_284_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass Holders (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Futurepass RecoveryConfigs (r:1 w:1)
+	// Storage: Futurepass ActiveRecoveries (r:0 w:1)
+	fn remove_recovery_config() -> Weight {
+		Weight::from_ref_time(52_117_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass RecoveryConfigs (r:1 w:0)
+	// Storage: Futurepass ActiveRecoveries (r:1 w:1)
+	// Storage: Futurepass Holders (r:1 w:0)
+	fn initiate_recovery() -> Weight {
+		Weight::from_ref_time(48_903_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass RecoveryConfigs (r:1 w:0)
+	// Storage: Futurepass ActiveRecoveries (r:1 w:1)
+	fn approve_recovery() -> Weight {
+		Weight::from_ref_time(45_271_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass Holders (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Futurepass ActiveRecoveries (r:1 w:1)
+	fn cancel_recovery() -> Weight {
+		Weight::from_ref_time(47_588_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Futurepass ActiveRecoveries (r:1 w:1)
+	// Storage: Futurepass RecoveryConfigs (r:1 w:0)
+	// Storage: Futurepass Holders (r:2 w:2)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Futurepass DelegatePolicies (r:0 w:1)
+	// Storage: Futurepass DelegateSpending (r:0 w:1)
+	// Storage: Futurepass DelegateExpiry (r:0 w:1)
+	/// The range of component `p` is `[1, 31]`.
+	fn claim_recovery(p: u32, ) -> Weight {
+		Weight::from_ref_time(141_905_000 as u64)
+			// Standard Error: 74_382
+			.saturating_add(Weight::from_ref_time(50_412_871 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Futurepass SignedProxyNonces (r:1 w:1)
+	// Storage: MaintenanceMode BlockedCalls (r:2 w:0)
+	// Storage: MaintenanceMode BlockedPallets (r:2 w:0)
+	// Storage: Futurepass DelegateExpiry (r:1 w:0)
+	/// The range of component `p` is `[1, 31]`.
+	fn proxy_extrinsic_with_signature(p: u32, ) -> Weight {
+		Weight::from_ref_time(131_874_000 as u64)
+			// Standard Error: 3_518
+			.saturating_add(Weight::from_ref_time(201_532 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
@@ -5,6 +5,9 @@
 //! DATE: 2023-09-19, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-31-102-147`, CPU: `Intel(R) Xeon(R) CPU E5-2686 v4 @ 2.30GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Weights marked `PLACEHOLDER` were written by hand after this run and are estimates only,
+//! they must be replaced by regenerating this file before release
 
 // Executed Command:
 // ./target/release/seed
@@ -31,15 +34,20 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `pallet_xrpl_bridge`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_xrpl_bridge::WeightInfo for WeightInfo<T> {
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
 	// Storage: XRPLBridge Relayer (r:1 w:0)
 	// Storage: XRPLBridge HighestSettledLedgerIndex (r:1 w:0)
 	// Storage: XRPLBridge SubmissionWindowWidth (r:1 w:0)
 	// Storage: XRPLBridge ProcessXRPTransactionDetails (r:1 w:1)
 	// Storage: XRPLBridge ProcessXRPTransaction (r:1 w:1)
+	// Storage: XRPLBridge XrplTxAttestations (r:2 w:2)
+	// Storage: XRPLBridge PendingXrplTxDetails (r:2 w:2)
+	// Storage: XRPLBridge RelayerQuorum (r:1 w:0)
+	// Storage: XRPLBridge ChallengeXRPTransactionList (r:1 w:1)
 	fn submit_transaction() -> Weight {
 		Weight::from_ref_time(77_253_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: XRPLBridge ChallengeXRPTransactionList (r:0 w:1)
 	fn submit_challenge() -> Weight {
@@ -67,16 +75,23 @@ impl<T: frame_system::Config> pallet_xrpl_bridge::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge RelayerPaidBond (r:1 w:0)
+	// Storage: XRPLBridge RelayerBond (r:1 w:0)
 	// Storage: XRPLBridge Relayer (r:0 w:1)
 	fn add_relayer() -> Weight {
 		Weight::from_ref_time(45_426_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
 	// Storage: XRPLBridge Relayer (r:1 w:1)
+	// Storage: XRPLBridge RelayerPaidBond (r:1 w:0)
+	// Storage: XRPLBridge RelayerUnbondingAt (r:0 w:1)
 	fn remove_relayer() -> Weight {
 		Weight::from_ref_time(56_024_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: XRPLBridge DoorTxFee (r:0 w:1)
 	fn set_door_tx_fee() -> Weight {
@@ -137,4 +152,109 @@ impl<T: frame_system::Config> pallet_xrpl_bridge::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(i as u64)))
 	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge AssetIdToXrplCurrency (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: XRPLBridge DoorTxFee (r:1 w:0)
+	// Storage: XRPLBridge DoorAddress (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: XRPLBridge DoorTicketSequence (r:1 w:1)
+	// Storage: XRPLBridge DoorTicketSequenceParams (r:1 w:1)
+	// Storage: XRPLBridge DoorTicketSequenceParamsNext (r:1 w:1)
+	// Storage: EthBridge NextEventProofId (r:1 w:1)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	// Storage: System Digest (r:1 w:1)
+	fn withdraw_asset() -> Weight {
+		Weight::from_ref_time(62_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge XrplCurrencyToAssetId (r:1 w:1)
+	// Storage: XRPLBridge AssetIdToXrplCurrency (r:1 w:1)
+	fn set_xrpl_asset_map() -> Weight {
+		Weight::from_ref_time(21_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge DoorTxFee (r:1 w:0)
+	// Storage: XRPLBridge DoorAddress (r:1 w:0)
+	// Storage: Xls20 Xls20TokenMap (r:1 w:0)
+	// Storage: Nft TokenLocks (r:1 w:0)
+	// Storage: Nft CollectionInfo (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: XRPLBridge DoorTicketSequence (r:1 w:1)
+	// Storage: XRPLBridge DoorTicketSequenceParams (r:1 w:1)
+	// Storage: XRPLBridge DoorTicketSequenceParamsNext (r:1 w:1)
+	// Storage: EthBridge NextEventProofId (r:1 w:1)
+	// Storage: EthBridge BridgePaused (r:1 w:0)
+	// Storage: System Digest (r:1 w:1)
+	fn withdraw_xls20() -> Weight {
+		Weight::from_ref_time(74_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge Relayer (r:1 w:0)
+	// Storage: XRPLBridge DoorTxFeeObservations (r:3 w:1)
+	// Storage: EthBridge NotaryXrplKeys (r:1 w:0)
+	// Storage: XRPLBridge DoorTxFeeBounds (r:1 w:0)
+	// Storage: XRPLBridge DoorTxFee (r:1 w:1)
+	fn submit_fee_observation() -> Weight {
+		Weight::from_ref_time(48_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge DoorTxFeeObservations (r:3 w:0)
+	// Storage: EthBridge NotaryXrplKeys (r:1 w:0)
+	// Storage: XRPLBridge DoorTxFee (r:1 w:1)
+	// Storage: XRPLBridge DoorTxFeeBounds (r:0 w:1)
+	fn set_door_tx_fee_bounds() -> Weight {
+		Weight::from_ref_time(42_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge RelayerQuorum (r:0 w:1)
+	fn set_relayer_quorum() -> Weight {
+		Weight::from_ref_time(12_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge RelayerBond (r:0 w:1)
+	fn set_relayer_bond() -> Weight {
+		Weight::from_ref_time(12_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge RelayerPaidBond (r:1 w:1)
+	// Storage: XRPLBridge RelayerBond (r:1 w:0)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn deposit_relayer_bond() -> Weight {
+		Weight::from_ref_time(52_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge Relayer (r:1 w:0)
+	// Storage: XRPLBridge RelayerUnbondingAt (r:1 w:1)
+	// Storage: XRPLBridge RelayerPaidBond (r:1 w:1)
+	// Storage: AssetsExt Holds (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn withdraw_relayer_bond() -> Weight {
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// PLACEHOLDER: not benchmarked, regenerate this file with the benchmark CLI
+	// Storage: XRPLBridge AssetPaymentDelay (r:0 w:1)
+	fn set_asset_payment_delay() -> Weight {
+		Weight::from_ref_time(10_560_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }